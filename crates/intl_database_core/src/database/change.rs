@@ -0,0 +1,72 @@
+use serde::Serialize;
+
+use crate::database::symbol::KeySymbol;
+
+/// A single change made to the content of a [crate::MessagesDatabase].
+///
+/// When change tracking is enabled, every mutation of the database records the changes it made,
+/// which can then be taken as a diff to find which messages, and therefore which bundles or type
+/// files, were affected.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DatabaseChange {
+    /// A definition was added for a message that didn't previously have one.
+    MessageAdded {
+        key: KeySymbol,
+    },
+    /// The definition of a message was removed. Translations of the message may still exist.
+    MessageRemoved {
+        key: KeySymbol,
+    },
+    /// The definition of a message was replaced with a new value, or moved to another locale.
+    MessageChanged {
+        key: KeySymbol,
+    },
+    /// The meta of a defined message was changed.
+    MetaChanged {
+        key: KeySymbol,
+    },
+    TranslationAdded {
+        key: KeySymbol,
+        locale: KeySymbol,
+    },
+    TranslationChanged {
+        key: KeySymbol,
+        locale: KeySymbol,
+    },
+    TranslationRemoved {
+        key: KeySymbol,
+        locale: KeySymbol,
+    },
+    /// A locale was seen for the first time.
+    LocaleAdded {
+        locale: KeySymbol,
+    },
+}
+
+impl DatabaseChange {
+    /// The message affected by this change, if any.
+    pub fn key(&self) -> Option<KeySymbol> {
+        match self {
+            DatabaseChange::MessageAdded { key }
+            | DatabaseChange::MessageRemoved { key }
+            | DatabaseChange::MessageChanged { key }
+            | DatabaseChange::MetaChanged { key }
+            | DatabaseChange::TranslationAdded { key, .. }
+            | DatabaseChange::TranslationChanged { key, .. }
+            | DatabaseChange::TranslationRemoved { key, .. } => Some(*key),
+            DatabaseChange::LocaleAdded { .. } => None,
+        }
+    }
+
+    /// The locale affected by this change, if it only applies to a single locale.
+    pub fn locale(&self) -> Option<KeySymbol> {
+        match self {
+            DatabaseChange::TranslationAdded { locale, .. }
+            | DatabaseChange::TranslationChanged { locale, .. }
+            | DatabaseChange::TranslationRemoved { locale, .. }
+            | DatabaseChange::LocaleAdded { locale } => Some(*locale),
+            _ => None,
+        }
+    }
+}
@@ -55,6 +55,22 @@ impl Message {
         message
     }
 
+    /// Create a message directly from all of its parts, like when reading a database snapshot.
+    pub(crate) fn from_parts(
+        key: KeySymbol,
+        translations: KeySymbolMap<MessageValue>,
+        source_locale: Option<KeySymbol>,
+        meta: MessageMeta,
+    ) -> Self {
+        Self {
+            key,
+            hashed_key: hash_message_key(&key),
+            translations,
+            source_locale,
+            meta,
+        }
+    }
+
     //#region Accessors
     pub fn translations(&self) -> &KeySymbolMap<MessageValue> {
         &self.translations
@@ -75,8 +91,12 @@ impl Message {
 
     //#region Mutation
 
-    /// Create or update the definition for this message with the given information.
+    /// Create or update the definition for this message with the given information. If the
+    /// definition was previously written in a different locale, the old source value is removed.
     pub fn set_definition(&mut self, source: MessageValue, locale: KeySymbol, meta: MessageMeta) {
+        if let Some(previous) = self.source_locale.filter(|previous| *previous != locale) {
+            self.translations.remove(&previous);
+        }
         self.translations.insert(locale, source);
         self.source_locale = Some(locale);
         self.meta = meta;
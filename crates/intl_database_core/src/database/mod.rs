@@ -5,10 +5,13 @@ use crate::message::meta::MessageMeta;
 use crate::message::source_file::SourceFile;
 use crate::message::value::MessageValue;
 
+use self::change::DatabaseChange;
 use self::message::Message;
 use self::symbol::{get_key_symbol, key_symbol, KeySymbol, KeySymbolMap, KeySymbolSet};
 
+pub mod change;
 pub mod message;
+pub mod snapshot;
 pub mod source;
 pub mod symbol;
 
@@ -18,6 +21,9 @@ pub struct MessagesDatabase {
     pub sources: KeySymbolMap<SourceFile>,
     pub hash_lookup: FxHashMap<String, KeySymbol>,
     pub known_locales: KeySymbolSet,
+    /// Changes made to the database since they were last taken, or `None` if changes are not
+    /// being tracked.
+    changes: Option<Vec<DatabaseChange>>,
 }
 
 impl MessagesDatabase {
@@ -27,6 +33,7 @@ impl MessagesDatabase {
             sources: KeySymbolMap::default(),
             hash_lookup: FxHashMap::default(),
             known_locales: KeySymbolSet::default(),
+            changes: None,
         }
     }
 
@@ -35,6 +42,45 @@ impl MessagesDatabase {
         get_key_symbol(key).and_then(|symbol| self.messages.get(&symbol))
     }
 
+    //#region Changes
+
+    /// Enable or disable recording changes made to the database. Disabling tracking discards any
+    /// changes that have not been taken yet.
+    pub fn set_change_tracking(&mut self, enabled: bool) {
+        match (enabled, &self.changes) {
+            (true, None) => self.changes = Some(vec![]),
+            (false, _) => self.changes = None,
+            _ => {}
+        }
+    }
+
+    pub fn is_tracking_changes(&self) -> bool {
+        self.changes.is_some()
+    }
+
+    /// Return every change recorded since the last call, in the order they were made. Returns an
+    /// empty list if changes are not being tracked.
+    pub fn take_changes(&mut self) -> Vec<DatabaseChange> {
+        self.changes
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    fn record_change(&mut self, change: DatabaseChange) {
+        if let Some(changes) = &mut self.changes {
+            changes.push(change);
+        }
+    }
+
+    fn add_known_locale(&mut self, locale: KeySymbol) {
+        if self.known_locales.insert(locale) {
+            self.record_change(DatabaseChange::LocaleAdded { locale });
+        }
+    }
+
+    //#endregion
+
     //#region Source Files
 
     pub fn get_source_file(&self, file_key: KeySymbol) -> Option<&SourceFile> {
@@ -62,6 +108,23 @@ impl MessagesDatabase {
         self.get_source_file(file_key).unwrap()
     }
 
+    /// Remove the given source file from the database, along with every definition or translation
+    /// that it provided. Returns the removed source file, if it existed.
+    pub fn remove_source_file(&mut self, file_key: KeySymbol) -> Option<SourceFile> {
+        let source = self.sources.remove(&file_key)?;
+        for key in source.message_keys() {
+            match &source {
+                SourceFile::Definition(_) => {
+                    self.remove_definition(*key);
+                }
+                SourceFile::Translation(translation) => {
+                    self.remove_translation(*key, *translation.locale());
+                }
+            }
+        }
+        Some(source)
+    }
+
     /// Immediately replace list of message keys owned by the given source file with the given set
     /// of keys. File membership is not updates when processing messages and must be applied after
     /// the fact using this method.
@@ -76,6 +139,19 @@ impl MessagesDatabase {
             .ok_or(DatabaseError::UnknownSourceFile(file_key))
     }
 
+    /// Record the hash of the content that the given source file was last processed from, so
+    /// that snapshots can tell whether the file has changed since.
+    pub fn set_source_file_content_hash(
+        &mut self,
+        file_key: KeySymbol,
+        content_hash: u64,
+    ) -> DatabaseResult<()> {
+        self.sources
+            .get_mut(&file_key)
+            .map(|source| source.set_content_hash(content_hash))
+            .ok_or(DatabaseError::UnknownSourceFile(file_key))
+    }
+
     /// Return an iterator over all of the message values owned by the given
     /// source file. The returned values are Options of references to the
     /// message for each key. Keys with no value will still be returned in the
@@ -116,6 +192,9 @@ impl MessagesDatabase {
     /// message is already defined and cannot be replaced. However, if `replace_existing` is `true`
     /// and the existing definition comes from the same source file, _or_ if the existing entry is
     /// Undefined, this method will update and convert that entry to a Normal entry and return Ok.
+    ///
+    /// The definition is stored under the `source_locale` from `meta` when one is set, and under
+    /// the given `locale` otherwise.
     pub fn insert_definition(
         &mut self,
         name: &str,
@@ -125,6 +204,8 @@ impl MessagesDatabase {
         replace_existing: bool,
     ) -> DatabaseResult<&Message> {
         let key = key_symbol(name);
+        let locale = meta.source_locale.unwrap_or(locale);
+        self.add_known_locale(locale);
         match self.messages.get_mut(&key) {
             Some(existing) => {
                 // Complete messages that already exist can not be re-added, since
@@ -134,14 +215,27 @@ impl MessagesDatabase {
                     return Err(DatabaseError::AlreadyDefined(key));
                 }
 
+                let was_defined = existing.is_defined();
+                let value_changed = *existing.source_locale() != Some(locale)
+                    || existing.get_source_translation() != Some(&value);
+                let meta_changed = was_defined && *existing.meta() != meta;
                 existing.set_definition(value, locale, meta);
+
+                if !was_defined {
+                    self.record_change(DatabaseChange::MessageAdded { key });
+                } else if value_changed {
+                    self.record_change(DatabaseChange::MessageChanged { key });
+                }
+                if meta_changed {
+                    self.record_change(DatabaseChange::MetaChanged { key });
+                }
             }
             _ => {
                 // Otherwise this is an entirely new message that gets created.
                 let message = Message::from_definition(key, value, locale, meta);
-                self.known_locales.insert(locale);
                 self.hash_lookup.insert(message.hashed_key().clone(), key);
                 self.messages.insert(key, message);
+                self.record_change(DatabaseChange::MessageAdded { key });
             }
         }
         Ok(&self.messages[&key])
@@ -152,9 +246,14 @@ impl MessagesDatabase {
     /// existing translations for that message, they are preserved and the definition becomes
     /// Undefined. Otherwise, if there are no other translations, the message is removed entirely.
     pub fn remove_definition(&mut self, message_key: KeySymbol) -> Option<MessageValue> {
-        self.messages
+        let removed = self
+            .messages
             .get_mut(&message_key)
-            .and_then(|message| message.remove_definition().0)
+            .and_then(|message| message.remove_definition().0);
+        if removed.is_some() {
+            self.record_change(DatabaseChange::MessageRemoved { key: message_key });
+        }
+        removed
     }
 
     //#endregion
@@ -177,21 +276,30 @@ impl MessagesDatabase {
             // translation entry in the map. The type of the entry does not
             // change here.
             Some(message) => {
-                if message.translations().contains_key(&locale) && !replace_existing {
-                    return Err(DatabaseError::TranslationAlreadySet(key, locale));
-                }
+                let change = match message.translations().get(&locale) {
+                    Some(_) if !replace_existing => {
+                        return Err(DatabaseError::TranslationAlreadySet(key, locale));
+                    }
+                    Some(existing) if *existing == value => None,
+                    Some(_) => Some(DatabaseChange::TranslationChanged { key, locale }),
+                    None => Some(DatabaseChange::TranslationAdded { key, locale }),
+                };
 
-                self.known_locales.insert(locale);
                 message.set_translation(locale, value);
+                self.add_known_locale(locale);
+                if let Some(change) = change {
+                    self.record_change(change);
+                }
             }
             // If it doesn't already exist, add a new Undefined message to hold
             // the translation until a definition is found.
             _ => {
                 // Otherwise this is an entirely new message that gets created.
                 let message = Message::from_translation(key, locale, value);
-                self.known_locales.insert(locale);
                 self.hash_lookup.insert(message.hashed_key().clone(), key);
                 self.messages.insert(key.into(), message);
+                self.add_known_locale(locale);
+                self.record_change(DatabaseChange::TranslationAdded { key, locale });
             }
         }
 
@@ -203,9 +311,17 @@ impl MessagesDatabase {
         message_key: KeySymbol,
         locale: KeySymbol,
     ) -> Option<MessageValue> {
-        self.messages
+        let removed = self
+            .messages
             .get_mut(&message_key)
-            .and_then(|message| message.remove_translation(locale))
+            .and_then(|message| message.remove_translation(locale));
+        if removed.is_some() {
+            self.record_change(DatabaseChange::TranslationRemoved {
+                key: message_key,
+                locale,
+            });
+        }
+        removed
     }
 
     //#endregion
@@ -218,6 +334,7 @@ mod tests {
     use intl_message_utils::RUNTIME_PACKAGE_NAME;
 
     use crate::database::MessagesDatabase;
+    use crate::{key_symbol, DatabaseChange, MessageMeta, MessageValue};
 
     fn new_database() -> MessagesDatabase {
         MessagesDatabase::new()
@@ -300,6 +417,104 @@ mod tests {
             .with_message("ANOTHER_STATUS", "This one is a _separate_ message")
     }
 
+    #[test]
+    fn test_definition_source_locale() {
+        let mut database = new_database();
+        let en_us = key_symbol("en-US");
+        let fr = key_symbol("fr");
+
+        let meta = MessageMeta::default().with_source_locale(fr);
+        let message = database
+            .insert_definition(
+                "GREETING",
+                MessageValue::from_raw("Bonjour"),
+                en_us,
+                meta,
+                true,
+            )
+            .unwrap();
+        assert_eq!(message.source_locale(), &Some(fr));
+        assert!(message.translations().contains_key(&fr));
+        assert!(!message.translations().contains_key(&en_us));
+        assert!(database.known_locales.contains(&fr));
+
+        // Moving the definition to another locale drops the old source value.
+        let message = database
+            .insert_definition(
+                "GREETING",
+                MessageValue::from_raw("Hello"),
+                en_us,
+                MessageMeta::default(),
+                true,
+            )
+            .unwrap();
+        assert_eq!(message.source_locale(), &Some(en_us));
+        assert!(!message.translations().contains_key(&fr));
+    }
+
+    #[test]
+    fn test_change_tracking() {
+        let mut database = new_database();
+        let key = key_symbol("GREETING");
+        let en_us = key_symbol("en-US");
+        let fr = key_symbol("fr");
+
+        // Nothing is recorded until tracking is enabled.
+        database
+            .insert_definition(
+                "GREETING",
+                MessageValue::from_raw("Hello"),
+                en_us,
+                MessageMeta::default(),
+                true,
+            )
+            .unwrap();
+        assert!(database.take_changes().is_empty());
+
+        database.set_change_tracking(true);
+        // Re-inserting the same definition is not a change.
+        database
+            .insert_definition(
+                "GREETING",
+                MessageValue::from_raw("Hello"),
+                en_us,
+                MessageMeta::default(),
+                true,
+            )
+            .unwrap();
+        database
+            .insert_definition(
+                "GREETING",
+                MessageValue::from_raw("Hi"),
+                en_us,
+                MessageMeta::default().with_secret(true),
+                true,
+            )
+            .unwrap();
+        database
+            .insert_translation(key, fr, MessageValue::from_raw("Salut"), true)
+            .unwrap();
+        database
+            .insert_translation(key, fr, MessageValue::from_raw("Bonjour"), true)
+            .unwrap();
+        database.remove_translation(key, fr);
+        database.remove_definition(key);
+
+        assert_eq!(
+            database.take_changes(),
+            vec![
+                DatabaseChange::MessageChanged { key },
+                DatabaseChange::MetaChanged { key },
+                DatabaseChange::LocaleAdded { locale: fr },
+                DatabaseChange::TranslationAdded { key, locale: fr },
+                DatabaseChange::TranslationChanged { key, locale: fr },
+                DatabaseChange::TranslationRemoved { key, locale: fr },
+                DatabaseChange::MessageRemoved { key },
+            ]
+        );
+        assert!(database.take_changes().is_empty());
+    }
+
     // #[test]
     // fn test_definitions_removed_message() {
     //     let mut database = new_database();
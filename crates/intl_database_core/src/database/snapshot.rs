@@ -0,0 +1,443 @@
+//! Binary snapshots of an entire [MessagesDatabase], letting a process start from the state that a
+//! previous one had already built instead of reading and parsing every messages file again.
+//!
+//! Snapshots store every message with its parsed documents, every source file, the hash lookup
+//! table and the set of known locales, along with a list of the files that were processed and a
+//! hash of their content at the time, so callers can tell which files changed since the snapshot
+//! was saved and only process those again.
+use intl_message_utils::binary::{
+    BinaryDecode, BinaryEncode, BinaryReadError, BinaryReadResult, BinaryReader, BinaryWriter,
+};
+
+use crate::database::message::Message;
+use crate::database::symbol::{key_symbol, KeySymbol, KeySymbolMap, KeySymbolSet};
+use crate::database::MessagesDatabase;
+use crate::error::{DatabaseError, DatabaseResult};
+use crate::message::meta::{MessageMeta, SourceFileMeta};
+use crate::message::source_file::{DefinitionFile, FilePosition, SourceFile, TranslationFile};
+use crate::message::value::MessageValue;
+use crate::message::variables::collect_message_variables;
+
+/// Written at the start of every snapshot to identify the content.
+const SNAPSHOT_MAGIC: &[u8; 8] = b"INTLSNAP";
+/// Version of the snapshot format.
+///
+/// This must be incremented whenever the encoding of anything in the snapshot changes, including
+/// the Documents of message values, so that snapshots written by older versions are rejected
+/// rather than misread.
+pub const SNAPSHOT_VERSION: u32 = 2;
+
+/// A file that had been processed into the database when a snapshot was written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapshotFile {
+    pub file: KeySymbol,
+    /// Locale that the file was processed with.
+    pub locale: KeySymbol,
+    /// Hash of the content of the file when it was processed.
+    pub content_hash: u64,
+}
+
+impl MessagesDatabase {
+    /// Encode the entire content of this database into a binary snapshot, along with the given
+    /// list of `files` that have been processed into it.
+    pub fn write_snapshot(&self, files: &[SnapshotFile]) -> Vec<u8> {
+        let mut writer = BinaryWriter::new();
+        writer.write_bytes(SNAPSHOT_MAGIC);
+        writer.write_u32(SNAPSHOT_VERSION);
+
+        writer.write(files);
+
+        writer.write_usize(self.known_locales.len());
+        for locale in &self.known_locales {
+            write_symbol(&mut writer, *locale);
+        }
+
+        writer.write_usize(self.hash_lookup.len());
+        for (hashed_key, key) in &self.hash_lookup {
+            writer.write_str(hashed_key);
+            write_symbol(&mut writer, *key);
+        }
+
+        writer.write_usize(self.sources.len());
+        for (key, source) in &self.sources {
+            write_symbol(&mut writer, *key);
+            write_source_file(&mut writer, source);
+        }
+
+        writer.write_usize(self.messages.len());
+        for message in self.messages.values() {
+            write_message(&mut writer, message);
+        }
+
+        writer.into_inner()
+    }
+
+    /// Create a new database from the content of a snapshot written by [Self::write_snapshot],
+    /// returning it along with the list of files that had been processed into it.
+    pub fn read_snapshot(data: &[u8]) -> DatabaseResult<(Self, Vec<SnapshotFile>)> {
+        let mut reader = BinaryReader::new(data);
+        let magic = reader
+            .read_bytes(SNAPSHOT_MAGIC.len(), "snapshot header")
+            .map_err(invalid_snapshot)?;
+        if magic != SNAPSHOT_MAGIC {
+            return Err(DatabaseError::InvalidSnapshot(
+                "Data is not a messages database snapshot".into(),
+            ));
+        }
+        let version = reader.read_u32().map_err(invalid_snapshot)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(DatabaseError::UnsupportedSnapshotVersion(
+                version,
+                SNAPSHOT_VERSION,
+            ));
+        }
+
+        read_database(&mut reader).map_err(invalid_snapshot)
+    }
+}
+
+fn invalid_snapshot(error: BinaryReadError) -> DatabaseError {
+    DatabaseError::InvalidSnapshot(error.to_string())
+}
+
+fn read_database(
+    reader: &mut BinaryReader,
+) -> BinaryReadResult<(MessagesDatabase, Vec<SnapshotFile>)> {
+    let mut database = MessagesDatabase::new();
+
+    let files: Vec<SnapshotFile> = reader.read()?;
+
+    for _ in 0..reader.read_usize()? {
+        database.known_locales.insert(read_symbol(reader)?);
+    }
+
+    for _ in 0..reader.read_usize()? {
+        let hashed_key = reader.read()?;
+        database
+            .hash_lookup
+            .insert(hashed_key, read_symbol(reader)?);
+    }
+
+    for _ in 0..reader.read_usize()? {
+        let key = read_symbol(reader)?;
+        database.sources.insert(key, read_source_file(reader)?);
+    }
+
+    for _ in 0..reader.read_usize()? {
+        let message = read_message(reader)?;
+        database.messages.insert(message.key(), message);
+    }
+
+    for file in &files {
+        if let Some(source) = database.sources.get_mut(&file.file) {
+            source.set_content_hash(file.content_hash);
+        }
+    }
+
+    Ok((database, files))
+}
+
+fn write_symbol(writer: &mut BinaryWriter, symbol: KeySymbol) {
+    writer.write_str(&symbol);
+}
+
+fn read_symbol(reader: &mut BinaryReader) -> BinaryReadResult<KeySymbol> {
+    reader.read_str().map(key_symbol)
+}
+
+fn write_symbol_set(writer: &mut BinaryWriter, symbols: &KeySymbolSet) {
+    writer.write_usize(symbols.len());
+    for symbol in symbols {
+        write_symbol(writer, *symbol);
+    }
+}
+
+fn read_symbol_set(reader: &mut BinaryReader) -> BinaryReadResult<KeySymbolSet> {
+    let length = reader.read_usize()?;
+    let mut symbols = KeySymbolSet::default();
+    for _ in 0..length {
+        symbols.insert(read_symbol(reader)?);
+    }
+    Ok(symbols)
+}
+
+fn write_optional_symbol(writer: &mut BinaryWriter, symbol: Option<KeySymbol>) {
+    writer.write_bool(symbol.is_some());
+    if let Some(symbol) = symbol {
+        write_symbol(writer, symbol);
+    }
+}
+
+fn read_optional_symbol(reader: &mut BinaryReader) -> BinaryReadResult<Option<KeySymbol>> {
+    match reader.read_bool()? {
+        true => read_symbol(reader).map(Some),
+        false => Ok(None),
+    }
+}
+
+fn write_source_file(writer: &mut BinaryWriter, source: &SourceFile) {
+    match source {
+        SourceFile::Definition(definition) => {
+            writer.write_u8(0);
+            writer.write_str(definition.file());
+            write_source_file_meta(writer, definition.meta());
+            write_symbol_set(writer, definition.message_keys());
+        }
+        SourceFile::Translation(translation) => {
+            writer.write_u8(1);
+            writer.write_str(translation.file());
+            write_symbol(writer, *translation.locale());
+            write_symbol_set(writer, translation.message_keys());
+        }
+    }
+}
+
+fn read_source_file(reader: &mut BinaryReader) -> BinaryReadResult<SourceFile> {
+    match reader.read_tag()? {
+        0 => Ok(SourceFile::Definition(DefinitionFile::new(
+            reader.read()?,
+            read_source_file_meta(reader)?,
+            read_symbol_set(reader)?,
+        ))),
+        1 => Ok(SourceFile::Translation(TranslationFile::new(
+            reader.read()?,
+            read_symbol(reader)?,
+            read_symbol_set(reader)?,
+        ))),
+        tag => Err(reader.invalid_tag("SourceFile", tag)),
+    }
+}
+
+fn write_source_file_meta(writer: &mut BinaryWriter, meta: &SourceFileMeta) {
+    writer.write_bool(meta.secret);
+    writer.write_bool(meta.translate);
+    writer.write_str(&meta.translations_path.to_string_lossy());
+    writer.write_str(&meta.source_file_path.to_string_lossy());
+    writer.write(&meta.description);
+    writer.write(&meta.suppress_diagnostics);
+    write_optional_symbol(writer, meta.source_locale);
+}
+
+fn read_source_file_meta(reader: &mut BinaryReader) -> BinaryReadResult<SourceFileMeta> {
+    Ok(SourceFileMeta {
+        secret: reader.read_bool()?,
+        translate: reader.read_bool()?,
+        translations_path: reader.read_str()?.into(),
+        source_file_path: reader.read_str()?.into(),
+        description: reader.read()?,
+        suppress_diagnostics: reader.read()?,
+        source_locale: read_optional_symbol(reader)?,
+    })
+}
+
+fn write_message_meta(writer: &mut BinaryWriter, meta: &MessageMeta) {
+    writer.write_bool(meta.secret);
+    writer.write_bool(meta.translate);
+    writer.write(&meta.description);
+    writer.write(&meta.suppress_diagnostics);
+    write_optional_symbol(writer, meta.source_locale);
+}
+
+fn read_message_meta(reader: &mut BinaryReader) -> BinaryReadResult<MessageMeta> {
+    Ok(MessageMeta {
+        secret: reader.read_bool()?,
+        translate: reader.read_bool()?,
+        description: reader.read()?,
+        suppress_diagnostics: reader.read()?,
+        source_locale: read_optional_symbol(reader)?,
+    })
+}
+
+fn write_message(writer: &mut BinaryWriter, message: &Message) {
+    write_symbol(writer, message.key());
+    write_optional_symbol(writer, *message.source_locale());
+    write_message_meta(writer, message.meta());
+    writer.write_usize(message.translations().len());
+    for (locale, value) in message.translations() {
+        write_symbol(writer, *locale);
+        write_message_value(writer, value);
+    }
+}
+
+fn read_message(reader: &mut BinaryReader) -> BinaryReadResult<Message> {
+    let key = read_symbol(reader)?;
+    let source_locale = read_optional_symbol(reader)?;
+    let meta = read_message_meta(reader)?;
+    let mut translations = KeySymbolMap::default();
+    for _ in 0..reader.read_usize()? {
+        let locale = read_symbol(reader)?;
+        translations.insert(locale, read_message_value(reader)?);
+    }
+    Ok(Message::from_parts(key, translations, source_locale, meta))
+}
+
+fn write_message_value(writer: &mut BinaryWriter, value: &MessageValue) {
+    writer.write_str(&value.raw);
+    writer.write(&value.parsed);
+    writer.write_bool(value.file_position.is_some());
+    if let Some(position) = &value.file_position {
+        write_symbol(writer, position.file);
+        writer.write_u32(position.line);
+        writer.write_u32(position.col);
+    }
+    writer.write(&value.source_offsets);
+    writer.write_bool(value.fuzzy);
+}
+
+fn read_message_value(reader: &mut BinaryReader) -> BinaryReadResult<MessageValue> {
+    let raw = reader.read()?;
+    let parsed = reader.read()?;
+    let file_position = match reader.read_bool()? {
+        true => Some(FilePosition {
+            file: read_symbol(reader)?,
+            line: reader.read_u32()?,
+            col: reader.read_u32()?,
+        }),
+        false => None,
+    };
+    let source_offsets = reader.read()?;
+    let fuzzy = reader.read_bool()?;
+    // Variables are entirely determined by the parsed document, so they are collected again
+    // rather than being stored.
+    let variables = collect_message_variables(&parsed).ok();
+    Ok(MessageValue {
+        raw,
+        parsed,
+        variables,
+        file_position,
+        source_offsets,
+        fuzzy,
+    })
+}
+
+impl BinaryEncode for SnapshotFile {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        write_symbol(writer, self.file);
+        write_symbol(writer, self.locale);
+        writer.write_u64(self.content_hash);
+    }
+}
+
+impl BinaryDecode for SnapshotFile {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        Ok(Self {
+            file: read_symbol(reader)?,
+            locale: read_symbol(reader)?,
+            content_hash: reader.read_u64()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::symbol::{key_symbol, KeySymbolSet};
+    use crate::database::MessagesDatabase;
+    use crate::error::DatabaseError;
+    use crate::message::meta::{MessageMeta, SourceFileMeta};
+    use crate::message::source_file::{DefinitionFile, FilePosition, SourceFile};
+    use crate::message::value::MessageValue;
+
+    use super::{SnapshotFile, SNAPSHOT_VERSION};
+
+    fn database() -> MessagesDatabase {
+        let mut database = MessagesDatabase::new();
+        let file = key_symbol("en.messages.js");
+        let value = MessageValue::from_raw("Hello {name}, you have {count, number} **new** items")
+            .with_file_position(FilePosition {
+                file,
+                line: 4,
+                col: 2,
+            });
+        database
+            .insert_definition(
+                "GREETING",
+                value,
+                key_symbol("en-US"),
+                MessageMeta::default().with_description("Greets the user"),
+                false,
+            )
+            .unwrap();
+        database
+            .insert_translation(
+                key_symbol("GREETING"),
+                key_symbol("fr"),
+                MessageValue::from_raw("Bonjour {name}").with_fuzzy(true),
+                false,
+            )
+            .unwrap();
+        database.create_source_file(
+            file,
+            SourceFile::Definition(DefinitionFile::new(
+                "en.messages.js".into(),
+                SourceFileMeta::new("en.messages.js").with_secret(true),
+                KeySymbolSet::from_iter([key_symbol("GREETING")]),
+            )),
+        );
+        database
+    }
+
+    #[test]
+    fn round_trip() {
+        let database = database();
+        let files = vec![SnapshotFile {
+            file: key_symbol("en.messages.js"),
+            locale: key_symbol("en-US"),
+            content_hash: 1234,
+        }];
+
+        let data = database.write_snapshot(&files);
+        let (restored, restored_files) = MessagesDatabase::read_snapshot(&data).unwrap();
+
+        assert_eq!(files, restored_files);
+        assert_eq!(database.known_locales, restored.known_locales);
+        assert_eq!(database.hash_lookup, restored.hash_lookup);
+        assert_eq!(database.messages, restored.messages);
+
+        let original = database.get_message("GREETING").unwrap();
+        let message = restored.get_message("GREETING").unwrap();
+        assert_eq!(original.meta(), message.meta());
+        assert_eq!(original.source_locale(), message.source_locale());
+        let (original, value) = (
+            original.get_source_translation().unwrap(),
+            message.get_source_translation().unwrap(),
+        );
+        assert_eq!(
+            format!("{:?}", original.parsed),
+            format!("{:?}", value.parsed)
+        );
+        assert_eq!(
+            format!("{:?}", original.variables),
+            format!("{:?}", value.variables)
+        );
+        assert_eq!(original.file_position, value.file_position);
+
+        let Some(SourceFile::Definition(definition)) =
+            restored.get_source_file(key_symbol("en.messages.js"))
+        else {
+            panic!("expected the definition file to be restored");
+        };
+        assert!(definition.meta().secret);
+        assert!(definition.message_keys().contains(&key_symbol("GREETING")));
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        assert!(matches!(
+            MessagesDatabase::read_snapshot(b"not a snapshot"),
+            Err(DatabaseError::InvalidSnapshot(_))
+        ));
+
+        let mut data = database().write_snapshot(&[]);
+        data[8..12].copy_from_slice(&(SNAPSHOT_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            MessagesDatabase::read_snapshot(&data),
+            Err(DatabaseError::UnsupportedSnapshotVersion(..))
+        ));
+
+        let data = database().write_snapshot(&[]);
+        assert!(matches!(
+            MessagesDatabase::read_snapshot(&data[..data.len() - 1]),
+            Err(DatabaseError::InvalidSnapshot(_))
+        ));
+    }
+}
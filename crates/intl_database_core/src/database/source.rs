@@ -1,6 +1,10 @@
+use std::fmt::{Display, Formatter};
+
 use thiserror::Error;
 
-use crate::{KeySymbol, MessageMeta, MessageValue, SourceFileKind, SourceFileMeta};
+use crate::{
+    KeySymbol, MessageMeta, MessageValue, SourceFileKind, SourceFileMeta, SourceOffsetMap,
+};
 
 #[derive(Debug, Error)]
 pub enum MessageSourceError {
@@ -22,11 +26,35 @@ pub enum MessageSourceError {
 
 pub type MessageSourceResult<T> = Result<T, MessageSourceError>;
 
+/// An error with a single definition or meta declaration in a source file, at the position where
+/// it was written. Other definitions in the same file are unaffected and can still be processed.
+#[derive(Debug)]
+pub struct MessageSourceDiagnostic {
+    /// Name of the message the error applies to, or `None` for errors in the source file meta.
+    pub name: Option<KeySymbol>,
+    /// Where the error was written, with a 1-based line and a 0-based column.
+    pub position: RawPosition,
+    pub error: MessageSourceError,
+}
+
+impl Display for MessageSourceDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // The column is shown 1-based to match the line, like editors and compilers report it.
+        write!(
+            f,
+            "{}:{}: {}",
+            self.position.line,
+            self.position.col + 1,
+            self.error
+        )
+    }
+}
+
 pub trait RawMessage {
     fn name(&self) -> KeySymbol;
 }
 
-#[derive(Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct RawPosition {
     pub line: u32,
     pub col: u32,
@@ -54,6 +82,14 @@ impl RawMessageDefinition {
             meta,
         }
     }
+
+    /// Record the literal text of the message value as written in the source file, starting at
+    /// `start`, so that positions within the value can be mapped back to the file.
+    pub fn with_source_literal(mut self, literal: &str, start: RawPosition) -> Self {
+        let source_offsets = SourceOffsetMap::from_literal(literal, start, &self.value.raw);
+        self.value = self.value.with_source_offsets(source_offsets);
+        self
+    }
 }
 
 impl RawMessage for RawMessageDefinition {
@@ -77,6 +113,20 @@ impl RawMessageTranslation {
             value,
         }
     }
+
+    /// Record the literal text of the message value as written in the source file, starting at
+    /// `start`, so that positions within the value can be mapped back to the file.
+    pub fn with_source_literal(mut self, literal: &str, start: RawPosition) -> Self {
+        let source_offsets = SourceOffsetMap::from_literal(literal, start, &self.value.raw);
+        self.value = self.value.with_source_offsets(source_offsets);
+        self
+    }
+
+    /// Mark whether the translation still needs review, like a gettext entry with the `fuzzy` flag.
+    pub fn with_fuzzy(mut self, fuzzy: bool) -> Self {
+        self.value = self.value.with_fuzzy(fuzzy);
+        self
+    }
 }
 
 impl RawMessage for RawMessageTranslation {
@@ -92,6 +142,10 @@ pub trait MessageDefinitionSource {
     /// Return an [`Iterator`] over all of the message definitions contained in the source file.
     /// Any kind of iterator is valid, so long as it yields complete [`RawMessageDefinition`]
     /// structs for the database to handle inserting and updating as needed.
+    ///
+    /// Definitions that can't be extracted are skipped and returned as diagnostics instead, along
+    /// with any errors in the meta of the source file, so that the valid definitions can still be
+    /// used while the errors are reported.
     fn extract_definitions(
         self,
         file_name: KeySymbol,
@@ -99,6 +153,7 @@ pub trait MessageDefinitionSource {
     ) -> MessageSourceResult<(
         SourceFileMeta,
         impl Iterator<Item = RawMessageDefinition> + '_,
+        Vec<MessageSourceDiagnostic>,
     )>;
 }
 
@@ -115,3 +170,20 @@ pub trait MessageTranslationSource {
         content: &str,
     ) -> MessageSourceResult<impl Iterator<Item = RawMessageTranslation> + '_>;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::key_symbol;
+
+    use super::{MessageSourceDiagnostic, MessageSourceError, RawPosition};
+
+    #[test]
+    fn diagnostic_display_is_one_based() {
+        let diagnostic = MessageSourceDiagnostic {
+            name: Some(key_symbol("BROKEN")),
+            position: RawPosition { line: 3, col: 0 },
+            error: MessageSourceError::InvalidSourceFileMeta,
+        };
+        assert!(diagnostic.to_string().starts_with("3:1: "));
+    }
+}
@@ -2,12 +2,16 @@ use thiserror::Error;
 
 use crate::database::symbol::KeySymbol;
 use crate::message::source_file::SourceFileKind;
-use crate::MessageSourceError;
+use crate::{MessageSourceDiagnostic, MessageSourceError};
 
 #[derive(Debug, Error)]
 pub enum DatabaseError {
     #[error(transparent)]
     SourceError(MessageSourceError),
+    #[error("{0} contains invalid message definitions:{}", format_source_diagnostics(.0, .1))]
+    InvalidDefinitions(KeySymbol, Vec<MessageSourceDiagnostic>),
+    #[error("Failed to read messages file {0}: {1}")]
+    FileReadError(String, std::io::Error),
     #[error("Processing {0} yielded neither message definitions nor translations")]
     NoExtractableValues(String),
     #[error("{0} has no matching source implementation")]
@@ -32,6 +36,18 @@ pub enum DatabaseError {
     ValueNotInterned(String),
     #[error("Source file {0} is not a known source file in the database")]
     UnknownSourceFile(KeySymbol),
+    #[error("Database snapshot could not be read: {0}")]
+    InvalidSnapshot(String),
+    #[error("Database snapshot has version {0}, but only version {1} can be read")]
+    UnsupportedSnapshotVersion(u32, u32),
 }
 
 pub type DatabaseResult<T> = Result<T, DatabaseError>;
+
+fn format_source_diagnostics(file: &KeySymbol, diagnostics: &[MessageSourceDiagnostic]) -> String {
+    let mut result = String::new();
+    for diagnostic in diagnostics {
+        result.push_str(&format!("\n  {file}:{diagnostic}"));
+    }
+    result
+}
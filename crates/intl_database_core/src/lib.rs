@@ -1,7 +1,9 @@
+pub use database::change::DatabaseChange;
 pub use database::message::Message;
+pub use database::snapshot::{SnapshotFile, SNAPSHOT_VERSION};
 pub use database::source::{
-    MessageDefinitionSource, MessageSourceError, MessageSourceResult, MessageTranslationSource,
-    RawMessage, RawMessageDefinition, RawMessageTranslation, RawPosition,
+    MessageDefinitionSource, MessageSourceDiagnostic, MessageSourceError, MessageSourceResult,
+    MessageTranslationSource, RawMessage, RawMessageDefinition, RawMessageTranslation, RawPosition,
 };
 pub use database::symbol::{get_key_symbol, key_symbol, KeySymbol, KeySymbolMap, KeySymbolSet};
 pub use database::MessagesDatabase;
@@ -10,6 +12,7 @@ pub use message::meta::{MessageMeta, SourceFileMeta};
 pub use message::source_file::{
     DefinitionFile, FilePosition, SourceFile, SourceFileKind, TranslationFile,
 };
+pub use message::source_offsets::SourceOffsetMap;
 pub use message::value::MessageValue;
 pub use message::variables::{
     collect_message_variables, MessageVariableInstance, MessageVariableType, MessageVariables,
@@ -19,5 +22,6 @@ mod database;
 mod error;
 mod message;
 
-// TODO: Allow this to be configurable, or determined by source files themselves through `meta`.
+/// The locale used for message definitions when neither the source file nor the message declare a
+/// `sourceLocale` in their meta.
 pub static DEFAULT_LOCALE: &str = "en-US";
@@ -3,6 +3,8 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+use crate::database::symbol::KeySymbol;
+
 /// Meta information about how a _set_ of messages should be handled and processed. SourceFileMeta
 /// has the same attributes as [MessageMeta], and acts as the source of default values for it, but
 /// also provides additional higher-level information like the name of the source file and the path
@@ -32,6 +34,14 @@ pub struct SourceFileMeta {
     /// Optional additional context for the source file, giving more information  about where its
     /// messages may be used or how the messages are intended to be grouped.
     pub description: Option<String>,
+    /// Names of validator diagnostics that should never be reported for any message in this
+    /// source file.
+    #[serde(rename = "suppressDiagnostics", default)]
+    pub suppress_diagnostics: Vec<String>,
+    /// The locale that messages in this source file are written in. When `None`, the locale given
+    /// when processing the file is used instead.
+    #[serde(rename = "sourceLocale", default)]
+    pub source_locale: Option<KeySymbol>,
 }
 
 impl SourceFileMeta {
@@ -42,6 +52,8 @@ impl SourceFileMeta {
             translations_path: "./messages".into(),
             source_file_path: source_file_path.into(),
             description: None,
+            suppress_diagnostics: vec![],
+            source_locale: None,
         }
     }
 
@@ -65,6 +77,14 @@ impl SourceFileMeta {
         self.description = Some(String::from(description));
         self
     }
+    pub fn with_suppressed_diagnostic(mut self, name: &str) -> Self {
+        self.suppress_diagnostics.push(String::from(name));
+        self
+    }
+    pub fn with_source_locale(mut self, source_locale: KeySymbol) -> Self {
+        self.source_locale = Some(source_locale);
+        self
+    }
 
     /// Return an absolute, canonical path where translations for messages in this source file in
     /// the given `locale` should reside. If `extension` is given, it will be applied to the
@@ -102,6 +122,14 @@ pub struct MessageMeta {
     /// Optional additional context for the source file, giving more information about where its
     /// messages may be used or how the messages are intended to be grouped.
     pub description: Option<String>,
+    /// Names of validator diagnostics that should never be reported for this message. This
+    /// includes any diagnostics suppressed by the source file's meta.
+    #[serde(rename = "suppressDiagnostics", default)]
+    pub suppress_diagnostics: Vec<String>,
+    /// The locale that the definition of this message is written in, overriding the locale of the
+    /// source file it is defined in.
+    #[serde(rename = "sourceLocale", default)]
+    pub source_locale: Option<KeySymbol>,
 }
 
 impl Default for MessageMeta {
@@ -110,6 +138,8 @@ impl Default for MessageMeta {
             secret: false,
             translate: true,
             description: None,
+            suppress_diagnostics: vec![],
+            source_locale: None,
         }
     }
 }
@@ -127,6 +157,21 @@ impl MessageMeta {
         self.description = Some(String::from(description));
         self
     }
+    pub fn with_suppressed_diagnostic(mut self, name: &str) -> Self {
+        self.suppress_diagnostics.push(String::from(name));
+        self
+    }
+    pub fn with_source_locale(mut self, source_locale: KeySymbol) -> Self {
+        self.source_locale = Some(source_locale);
+        self
+    }
+
+    /// Returns true if the diagnostic with the given `name` is suppressed for this message.
+    pub fn is_diagnostic_suppressed(&self, name: &str) -> bool {
+        self.suppress_diagnostics
+            .iter()
+            .any(|suppressed| suppressed == name)
+    }
 }
 
 impl From<&SourceFileMeta> for MessageMeta {
@@ -135,6 +180,8 @@ impl From<&SourceFileMeta> for MessageMeta {
             secret: value.secret,
             translate: value.translate,
             description: None,
+            suppress_diagnostics: value.suppress_diagnostics.clone(),
+            source_locale: value.source_locale,
         }
     }
 }
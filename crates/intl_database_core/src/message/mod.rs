@@ -1,4 +1,5 @@
 pub mod meta;
 pub mod source_file;
+pub mod source_offsets;
 pub mod value;
 pub mod variables;
@@ -41,6 +41,8 @@ pub struct DefinitionFile {
     meta: SourceFileMeta,
     #[serde(rename = "messageKeys")]
     message_keys: KeySymbolSet,
+    #[serde(skip)]
+    content_hash: Option<u64>,
 }
 
 impl DefinitionFile {
@@ -49,6 +51,7 @@ impl DefinitionFile {
             file,
             meta,
             message_keys,
+            content_hash: None,
         }
     }
     pub fn file(&self) -> &String {
@@ -69,6 +72,8 @@ pub struct TranslationFile {
     locale: KeySymbol,
     #[serde(rename = "messageKeys")]
     message_keys: KeySymbolSet,
+    #[serde(skip)]
+    content_hash: Option<u64>,
 }
 
 impl TranslationFile {
@@ -77,6 +82,7 @@ impl TranslationFile {
             file,
             locale,
             message_keys,
+            content_hash: None,
         }
     }
     pub fn file(&self) -> &String {
@@ -129,4 +135,19 @@ impl SourceFile {
     pub fn set_message_keys(&mut self, new_keys: KeySymbolSet) {
         *self.message_keys_mut() = new_keys;
     }
+
+    /// Hash of the content of the file the last time it was processed, if it is known.
+    pub fn content_hash(&self) -> Option<u64> {
+        match self {
+            SourceFile::Definition(value) => value.content_hash,
+            SourceFile::Translation(value) => value.content_hash,
+        }
+    }
+
+    pub fn set_content_hash(&mut self, content_hash: u64) {
+        match self {
+            SourceFile::Definition(value) => value.content_hash = Some(content_hash),
+            SourceFile::Translation(value) => value.content_hash = Some(content_hash),
+        }
+    }
 }
@@ -0,0 +1,314 @@
+use std::borrow::Cow;
+
+use intl_message_utils::binary::{
+    BinaryDecode, BinaryEncode, BinaryReadResult, BinaryReader, BinaryWriter,
+};
+
+use crate::database::source::RawPosition;
+
+/// Some sources decode escape sequences more than once (e.g., the JS parser cooks a string literal,
+/// and the extractor then applies escapes to the cooked value again). Matching a literal to its
+/// value tries at most this many decoding passes before giving up.
+const MAX_UNESCAPE_PASSES: usize = 2;
+
+#[derive(Clone, Copy, Debug)]
+struct Checkpoint {
+    /// Byte offset in the decoded text.
+    offset: usize,
+    /// Position in the source file of the character at `offset`.
+    position: RawPosition,
+}
+
+/// A mapping from byte offsets in a message value back to line and column positions in the file
+/// where that value was written.
+///
+/// Message values are stored after their string escapes have been applied, so an offset in the
+/// value doesn't directly correspond to a column in the file: `\n` is two characters in the file,
+/// but one in the value, and `\u{1F600}` is nine characters that become a single four-byte char.
+/// The map stores a checkpoint on both sides of every escape sequence, and every other character
+/// is assumed to appear verbatim in the source.
+#[derive(Clone, Debug)]
+pub struct SourceOffsetMap {
+    checkpoints: Vec<Checkpoint>,
+}
+
+impl SourceOffsetMap {
+    /// Create a map for a message whose content was written in a file as `literal` (the text
+    /// between the quotes, exactly as it appears in the file), starting at `start`, and which
+    /// resolved to `value` once escapes were applied.
+    ///
+    /// Returns `None` if the literal could not be decoded into the value, in which case there is
+    /// no reliable way of mapping between the two.
+    pub fn from_literal(literal: &str, start: RawPosition, value: &str) -> Option<Self> {
+        let mut map = Self {
+            checkpoints: vec![Checkpoint {
+                offset: 0,
+                position: start,
+            }],
+        };
+        let mut text = Cow::Borrowed(literal);
+        for pass in 0..=MAX_UNESCAPE_PASSES {
+            if text == value {
+                return Some(map);
+            }
+            if pass == MAX_UNESCAPE_PASSES {
+                break;
+            }
+            let (decoded, next_map) = map.unescape(&text)?;
+            text = Cow::Owned(decoded);
+            map = next_map;
+        }
+        None
+    }
+
+    /// Return the position in the source file of the byte at `offset` in `value`, which must be
+    /// the same value the map was created for.
+    pub fn position_at(&self, value: &str, offset: usize) -> RawPosition {
+        let index = self
+            .checkpoints
+            .partition_point(|checkpoint| checkpoint.offset <= offset)
+            .saturating_sub(1);
+        let checkpoint = self.checkpoints[index];
+        let mut position = checkpoint.position;
+        for (_, c) in value[checkpoint.offset.min(value.len())..]
+            .char_indices()
+            .take_while(|(index, _)| checkpoint.offset + index < offset)
+        {
+            advance_position(&mut position, c);
+        }
+        position
+    }
+
+    /// Decode a single layer of escape sequences in `text`, which is the text that this map
+    /// currently describes. Returns the decoded text and a new map describing it, or `None` if
+    /// `text` contains no escape sequences or an invalid one.
+    fn unescape(&self, text: &str) -> Option<(String, Self)> {
+        let mut decoded = String::with_capacity(text.len());
+        let mut checkpoints: Vec<Checkpoint> = Vec::with_capacity(self.checkpoints.len());
+        let mut cursor = CheckpointCursor::new(&self.checkpoints);
+        let mut has_escapes = false;
+
+        let mut index = 0;
+        while index < text.len() {
+            let synced = cursor.sync(index);
+            if text[index..].starts_with('\\') {
+                let (escaped, length) = decode_escape(&text[index..])?;
+                push_checkpoint(&mut checkpoints, decoded.len(), cursor.position);
+                for (offset, c) in text[index..index + length].char_indices() {
+                    cursor.sync(index + offset);
+                    advance_position(&mut cursor.position, c);
+                }
+                index += length;
+                cursor.sync(index);
+                if let Some(c) = escaped {
+                    decoded.push(c);
+                }
+                push_checkpoint(&mut checkpoints, decoded.len(), cursor.position);
+                has_escapes = true;
+            } else {
+                if synced {
+                    push_checkpoint(&mut checkpoints, decoded.len(), cursor.position);
+                }
+                let c = text[index..].chars().next()?;
+                advance_position(&mut cursor.position, c);
+                decoded.push(c);
+                index += c.len_utf8();
+            }
+        }
+
+        has_escapes.then_some((decoded, Self { checkpoints }))
+    }
+}
+
+/// Walks the checkpoints of an existing map in order while decoding the text it describes, to
+/// know the source position of each character being decoded.
+struct CheckpointCursor<'a> {
+    checkpoints: &'a [Checkpoint],
+    next: usize,
+    position: RawPosition,
+}
+
+impl<'a> CheckpointCursor<'a> {
+    fn new(checkpoints: &'a [Checkpoint]) -> Self {
+        Self {
+            checkpoints,
+            next: 0,
+            position: RawPosition::default(),
+        }
+    }
+
+    /// Move the cursor to `index`, resetting the current position if a checkpoint exists exactly
+    /// at that index. Checkpoints before `index` that were never reached are skipped, since they
+    /// point inside of an escape sequence that has since been decoded. Returns true if the
+    /// position was reset.
+    fn sync(&mut self, index: usize) -> bool {
+        while self
+            .checkpoints
+            .get(self.next)
+            .is_some_and(|checkpoint| checkpoint.offset < index)
+        {
+            self.next += 1;
+        }
+        match self.checkpoints.get(self.next) {
+            Some(checkpoint) if checkpoint.offset == index => {
+                self.position = checkpoint.position;
+                self.next += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+fn push_checkpoint(checkpoints: &mut Vec<Checkpoint>, offset: usize, position: RawPosition) {
+    match checkpoints.last_mut() {
+        Some(last) if last.offset == offset => last.position = position,
+        _ => checkpoints.push(Checkpoint { offset, position }),
+    }
+}
+
+fn advance_position(position: &mut RawPosition, c: char) {
+    if c == '\n' {
+        position.line += 1;
+        position.col = 0;
+    } else {
+        position.col += 1;
+    }
+}
+
+/// Decode the escape sequence at the start of `text`, following the JavaScript string literal
+/// rules (which are a superset of JSON's). Returns the decoded character, if the sequence produces
+/// one, and the length in bytes of the sequence, including the leading backslash.
+fn decode_escape(text: &str) -> Option<(Option<char>, usize)> {
+    let rest = &text[1..];
+    let next = rest.chars().next()?;
+    let escaped = match next {
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        'b' => '\x08',
+        'f' => '\x0C',
+        'v' => '\x0B',
+        '0' => '\0',
+        // Line continuations produce no characters at all.
+        '\r' if rest[1..].starts_with('\n') => return Some((None, 3)),
+        '\r' | '\n' | '\u{2028}' | '\u{2029}' => return Some((None, 1 + next.len_utf8())),
+        'x' => return Some((Some(char::from_u32(parse_hex(&rest[1..], 2)?)?), 4)),
+        'u' => return decode_unicode_escape(rest),
+        // Every other escaped character, like quotes and backslashes, is kept as-is.
+        _ => next,
+    };
+    Some((Some(escaped), 1 + next.len_utf8()))
+}
+
+/// Decode a `\u` escape. `text` starts at the `u`.
+fn decode_unicode_escape(text: &str) -> Option<(Option<char>, usize)> {
+    if let Some(braced) = text[1..].strip_prefix('{') {
+        let end = braced.find('}')?;
+        let code = u32::from_str_radix(&braced[..end], 16).ok()?;
+        return Some((Some(char::from_u32(code)?), 4 + end));
+    }
+
+    let code = parse_hex(&text[1..], 4)?;
+    if (0xD800..0xDC00).contains(&code) {
+        let low = text[5..]
+            .strip_prefix("\\u")
+            .and_then(|low| parse_hex(low, 4))
+            .filter(|low| (0xDC00..0xE000).contains(low));
+        if let Some(low) = low {
+            let combined = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+            return Some((Some(char::from_u32(combined)?), 12));
+        }
+    }
+    Some((Some(char::from_u32(code).unwrap_or('\u{FFFD}')), 6))
+}
+
+fn parse_hex(text: &str, length: usize) -> Option<u32> {
+    let digits = text.get(..length)?;
+    if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
+
+impl BinaryEncode for SourceOffsetMap {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_usize(self.checkpoints.len());
+        for checkpoint in &self.checkpoints {
+            writer.write_usize(checkpoint.offset);
+            writer.write_u32(checkpoint.position.line);
+            writer.write_u32(checkpoint.position.col);
+        }
+    }
+}
+
+impl BinaryDecode for SourceOffsetMap {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        let length = reader.read_usize()?;
+        let mut checkpoints = Vec::with_capacity(length.min(64));
+        for _ in 0..length {
+            checkpoints.push(Checkpoint {
+                offset: reader.read_usize()?,
+                position: RawPosition {
+                    line: reader.read_u32()?,
+                    col: reader.read_u32()?,
+                },
+            });
+        }
+        Ok(Self { checkpoints })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::source::RawPosition;
+
+    use super::SourceOffsetMap;
+
+    fn position_of(literal: &str, value: &str, needle: &str) -> (u32, u32) {
+        let map = SourceOffsetMap::from_literal(literal, RawPosition { line: 1, col: 10 }, value)
+            .expect("literal should map to value");
+        let position = map.position_at(value, value.find(needle).unwrap());
+        (position.line, position.col)
+    }
+
+    #[test]
+    fn verbatim() {
+        assert_eq!(position_of("hello {name}", "hello {name}", "name"), (1, 17));
+    }
+
+    #[test]
+    fn simple_escapes() {
+        assert_eq!(
+            position_of(r#"a\n\"b\" {name}"#, "a\n\"b\" {name}", "name"),
+            (1, 20)
+        );
+    }
+
+    #[test]
+    fn unicode_escapes() {
+        assert_eq!(
+            position_of(r"\u{1F600}é {name}", "😀é {name}", "name"),
+            (1, 22)
+        );
+        assert_eq!(position_of(r"😀 {name}", "😀 {name}", "name"), (1, 13));
+    }
+
+    #[test]
+    fn multiple_passes() {
+        // The literal `\\n` is cooked to `\n`, which is then unescaped again into a newline.
+        assert_eq!(position_of(r"a\\n {name}", "a\n {name}", "name"), (1, 16));
+    }
+
+    #[test]
+    fn raw_newlines() {
+        assert_eq!(position_of("a\n  {name}", "a\n  {name}", "name"), (2, 3));
+    }
+
+    #[test]
+    fn mismatched_value() {
+        assert!(
+            SourceOffsetMap::from_literal("hello", RawPosition::default(), "goodbye").is_none()
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use serde::Serialize;
 
-use intl_markdown::{parse_intl_message, Document};
+use intl_markdown::{format_to_icu_string, parse_intl_message, Document};
 use intl_message_utils::message_may_have_blocks;
 
 use super::source_file::FilePosition;
+use super::source_offsets::SourceOffsetMap;
 use super::variables::{collect_message_variables, MessageVariables};
 
 #[derive(Debug, Serialize)]
@@ -12,6 +13,13 @@ pub struct MessageValue {
     pub parsed: Document,
     pub variables: Option<MessageVariables>,
     pub file_position: Option<FilePosition>,
+    /// Mapping from offsets in `raw` back to positions in the file, used to point at specific
+    /// parts of the message, like a single variable, rather than the start of the whole value.
+    #[serde(skip)]
+    pub source_offsets: Option<SourceOffsetMap>,
+    /// Whether the value was marked as needing review when it was imported, like a gettext entry
+    /// with the `fuzzy` flag. Fuzzy values are still used, but validation reports them.
+    pub fuzzy: bool,
 }
 
 impl MessageValue {
@@ -30,6 +38,23 @@ impl MessageValue {
             parsed: document,
             variables,
             file_position: None,
+            source_offsets: None,
+            fuzzy: false,
+        }
+    }
+
+    /// Creates a new value from an already-parsed document, like one generated or transformed by
+    /// a service rather than read from a file. Since there is no original content, `raw` is the
+    /// document formatted as an ICU string.
+    pub fn from_document(document: Document) -> Self {
+        let variables = collect_message_variables(&document).ok();
+        Self {
+            raw: format_to_icu_string(&document).unwrap_or_default(),
+            parsed: document,
+            variables,
+            file_position: None,
+            source_offsets: None,
+            fuzzy: false,
         }
     }
 
@@ -37,12 +62,35 @@ impl MessageValue {
         self.file_position = Some(position);
         self
     }
+
+    pub fn with_fuzzy(mut self, fuzzy: bool) -> Self {
+        self.fuzzy = fuzzy;
+        self
+    }
+
+    pub fn with_source_offsets(mut self, source_offsets: Option<SourceOffsetMap>) -> Self {
+        self.source_offsets = source_offsets;
+        self
+    }
+
+    /// Return the position in the source file of the byte at `offset` in the raw content of this
+    /// value, or `None` if the value doesn't know where it was written.
+    pub fn file_position_at(&self, offset: usize) -> Option<FilePosition> {
+        let file_position = self.file_position?;
+        let source_offsets = self.source_offsets.as_ref()?;
+        let position = source_offsets.position_at(&self.raw, offset);
+        Some(FilePosition {
+            file: file_position.file,
+            line: position.line,
+            col: position.col,
+        })
+    }
 }
 
-// Messages are equal if they have the same starting raw content. Everything
-// else about a message is derived from that original string.
+// Messages are equal if they have the same starting raw content and review
+// state. Everything else about a message is derived from that original string.
 impl PartialEq for MessageValue {
     fn eq(&self, other: &Self) -> bool {
-        self.raw == other.raw
+        self.raw == other.raw && self.fuzzy == other.fuzzy
     }
 }
@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 
 use rustc_hash::FxHashSet;
 use serde::Serialize;
@@ -48,11 +48,11 @@ pub enum MessageVariableType {
 /// already been seen, a new MessageVariable is created.
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct MessageVariableInstance {
-    /// The location in the message where this variable is used. Each instance
-    /// of a variable in a string has its own struct, so each stores its own
-    /// span as well.
-    /// TODO: Add this back
-    pub span: Option<usize>,
+    /// The byte range in the raw message content where this variable is used.
+    /// Each instance of a variable in a string has its own struct, so each
+    /// stores its own span as well. Builtin tags that are implied by Markdown
+    /// syntax rather than written by name, like paragraphs, have no span.
+    pub span: Option<Range<usize>>,
     /// `true` if this variable is a system-defined variable, typically for
     /// rich text formatting tags like `$b` and `$link`, which are almost never
     /// intended for a user to provide and/or only represent formatting points,
@@ -84,7 +84,7 @@ impl MessageVariables {
         name: KeySymbol,
         kind: MessageVariableType,
         is_builtin: bool,
-        span: Option<usize>,
+        span: Option<Range<usize>>,
     ) {
         let instance = MessageVariableInstance {
             kind,
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use intl_markdown::{
     CodeBlock, CodeSpan, Emphasis, Heading, Hook, IcuDate, IcuNumber, IcuPlural, IcuSelect,
     IcuTime, IcuVariable, Link, LinkDestination, Paragraph, Strikethrough, Strong,
@@ -14,6 +16,7 @@ pub struct MessageVariablesVisitor {
     variables: MessageVariables,
     current_plural_variable_name: Option<KeySymbol>,
     current_variable_type: Option<MessageVariableType>,
+    current_link_span: Option<Range<usize>>,
 }
 
 impl MessageVariablesVisitor {
@@ -22,6 +25,7 @@ impl MessageVariablesVisitor {
             variables: MessageVariables::new(),
             current_plural_variable_name: None,
             current_variable_type: None,
+            current_link_span: None,
         }
     }
 
@@ -77,7 +81,7 @@ impl Visit for MessageVariablesVisitor {
             MessageVariableType::HookFunction,
             // Hooks are always user-defined.
             false,
-            None,
+            Some(hook.span().clone()),
         );
         hook.visit_children_with(self);
     }
@@ -95,8 +99,12 @@ impl Visit for MessageVariablesVisitor {
     fn visit_icu_plural(&mut self, plural: &IcuPlural) {
         let name_symbol = key_symbol(plural.name());
         self.current_plural_variable_name = Some(name_symbol);
-        self.variables
-            .add_instance(name_symbol, MessageVariableType::Plural, false, None);
+        self.variables.add_instance(
+            name_symbol,
+            MessageVariableType::Plural,
+            false,
+            Some(plural.variable().span().clone()),
+        );
         plural.visit_children_with(self);
     }
 
@@ -120,7 +128,7 @@ impl Visit for MessageVariablesVisitor {
                 .take()
                 .unwrap_or(MessageVariableType::Any),
             false,
-            None,
+            Some(variable.span().clone()),
         );
     }
 
@@ -132,9 +140,11 @@ impl Visit for MessageVariablesVisitor {
             // handling of the link tag itself, while the destination
             // or content may still contain user-defined variables.
             true,
-            None,
+            Some(link.span().clone()),
         );
+        self.current_link_span = Some(link.span().clone());
         link.visit_children_with(self);
+        self.current_link_span = None;
     }
 
     fn visit_link_destination(&mut self, node: &LinkDestination) {
@@ -145,7 +155,9 @@ impl Visit for MessageVariablesVisitor {
                     key_symbol(&handler_name),
                     MessageVariableType::HandlerFunction,
                     false,
-                    None,
+                    // Handler names aren't tracked separately from their link, so the whole link
+                    // is the closest location available.
+                    self.current_link_span.clone(),
                 );
             }
             LinkDestination::Placeholder(_) => node.visit_children_with(self),
@@ -0,0 +1,357 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use intl_database_core::{KeySymbol, Message, MessagesDatabase};
+use intl_database_service::IntlDatabaseService;
+use rustc_hash::FxHashMap;
+
+use crate::mobile::{
+    convert_database, expand_plural, write_files, ArgumentKind, MobileExportDiagnostic,
+    MobileExportReport, MobileFeatures, MobileMessage, MobilePart, TextStyle,
+};
+use crate::xliff::escape_xml;
+
+const ANDROID_FEATURES: MobileFeatures = MobileFeatures {
+    multiple_plurals: false,
+    exact_zero: false,
+};
+
+/// A service for exporting the messages in a [MessagesDatabase] as Android string resources, so
+/// that apps can share the same messages as other clients.
+///
+/// Every message is written to `values/strings.xml` in the given resources directory using its
+/// definition, and each translation is written to the `strings.xml` of the resource directory
+/// for its locale, like `values-fr` or `values-pt-rBR`. Variables become positional arguments
+/// like `%1$s`, and a message containing a plural becomes a `<plurals>` resource with the text
+/// around the plural repeated in every quantity. Text styles and links are written as the HTML
+/// tags that Android supports in string resources.
+///
+/// Messages that use syntax Android can't represent, like hooks, selects, or more than one
+/// plural, are left out, with a diagnostic in the [MobileExportReport] that is the result of
+/// this service. Keys are written as resource names with any characters Android doesn't allow
+/// replaced, so when several keys become the same name, like `A.B` and `A_B`, only the first in
+/// sorted order is written and the rest get a diagnostic as well.
+pub struct ExportAndroidStrings<'a> {
+    database: &'a MessagesDatabase,
+    resources_directory: PathBuf,
+}
+
+impl<'a> ExportAndroidStrings<'a> {
+    pub fn new(database: &'a MessagesDatabase, resources_directory: PathBuf) -> Self {
+        Self {
+            database,
+            resources_directory,
+        }
+    }
+}
+
+impl IntlDatabaseService for ExportAndroidStrings<'_> {
+    type Result = anyhow::Result<MobileExportReport>;
+
+    fn run(&mut self) -> Self::Result {
+        let mut report = MobileExportReport::default();
+        let mut resources: BTreeMap<PathBuf, String> = BTreeMap::new();
+        // The key that each resource name in each file was written for.
+        let mut resource_names: FxHashMap<PathBuf, FxHashMap<String, KeySymbol>> =
+            FxHashMap::default();
+        let mut collisions = vec![];
+        convert_database(
+            self.database,
+            ANDROID_FEATURES,
+            &mut report,
+            |message, locale, converted| {
+                let directory = if *message.source_locale() == Some(locale) {
+                    "values".to_string()
+                } else {
+                    format!("values-{}", android_locale_qualifier(&locale))
+                };
+                let path = self.resources_directory.join(directory).join("strings.xml");
+                let name = android_resource_name(&message.key());
+                let written_key = *resource_names
+                    .entry(path.clone())
+                    .or_default()
+                    .entry(name.clone())
+                    .or_insert(message.key());
+                if written_key != message.key() {
+                    collisions.push(MobileExportDiagnostic {
+                        key: message.key(),
+                        locale,
+                        file_position: message.translations()[&locale].file_position,
+                        description: format!(
+                            "The resource name {name} is already used by {written_key}"
+                        ),
+                    });
+                    return;
+                }
+                let content = resources.entry(path).or_default();
+                format_resource(content, message, &name, &converted);
+            },
+        );
+        report.diagnostics.extend(collisions);
+
+        let files = resources
+            .into_iter()
+            .map(|(path, content)| {
+                let document = format!(
+                    "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n{content}</resources>\n"
+                );
+                (path, document)
+            })
+            .collect();
+        write_files(files, &mut report)?;
+        Ok(report)
+    }
+}
+
+/// Return the resource qualifier for `locale`, like `fr`, `pt-rBR`, or `b+zh+Hant` for locales
+/// that need the BCP 47 form.
+fn android_locale_qualifier(locale: &KeySymbol) -> String {
+    let parts: Vec<&str> = locale.split(['-', '_']).collect();
+    match parts.as_slice() {
+        [language] => language.to_string(),
+        [language, region]
+            if region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()) =>
+        {
+            format!("{language}-r{}", region.to_ascii_uppercase())
+        }
+        parts => format!("b+{}", parts.join("+")),
+    }
+}
+
+/// Return `key` as a valid resource name, replacing any characters that aren't allowed.
+fn android_resource_name(key: &KeySymbol) -> String {
+    let mut name: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+fn format_resource(content: &mut String, message: &Message, name: &str, converted: &MobileMessage) {
+    let comments = message
+        .meta()
+        .description
+        .iter()
+        .flat_map(|description| description.lines().map(String::from))
+        .chain(converted.argument_list());
+    for comment in comments {
+        writeln!(content, "    <!-- {} -->", comment.replace("--", "- -")).unwrap();
+    }
+
+    match expand_plural(&converted.parts) {
+        Some((_, forms)) => {
+            writeln!(content, "    <plurals name=\"{name}\">").unwrap();
+            for form in forms {
+                writeln!(
+                    content,
+                    "        <item quantity=\"{}\">{}</item>",
+                    form.category,
+                    format_string(&form.parts, converted)
+                )
+                .unwrap();
+            }
+            content.push_str("    </plurals>\n");
+        }
+        None => {
+            let value = format_string(&converted.parts, converted);
+            // Strings without arguments aren't formatted, so a `%` is just text. Marking them
+            // keeps the resource compiler from treating it as a format specifier.
+            let formatted = if converted.arguments.is_empty() && value.contains('%') {
+                " formatted=\"false\""
+            } else {
+                ""
+            };
+            writeln!(
+                content,
+                "    <string name=\"{name}\"{formatted}>{value}</string>"
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// Format `parts` as the content of a string resource.
+fn format_string(parts: &[MobilePart], message: &MobileMessage) -> String {
+    let mut result = String::new();
+    format_parts(&mut result, parts, message);
+    // A leading `@` or `?` would make the value a reference to another resource.
+    if result.starts_with(['@', '?']) {
+        result.insert(0, '\\');
+    }
+    result
+}
+
+fn format_parts(result: &mut String, parts: &[MobilePart], message: &MobileMessage) {
+    let is_formatted = !message.arguments.is_empty();
+    for part in parts {
+        match part {
+            MobilePart::Text(text) => result.push_str(&escape_android_text(text, is_formatted)),
+            MobilePart::Argument(index) => format_argument(result, *index, message),
+            MobilePart::Styled(style, content) => {
+                let tag = match style {
+                    TextStyle::Strong => "b",
+                    TextStyle::Emphasis => "i",
+                    TextStyle::Strikethrough => "strike",
+                    TextStyle::Code => "tt",
+                };
+                write!(result, "<{tag}>").unwrap();
+                format_parts(result, content, message);
+                write!(result, "</{tag}>").unwrap();
+            }
+            MobilePart::Link(destination, label) => {
+                result.push_str("<a href=\"");
+                match destination.as_ref() {
+                    MobilePart::Argument(index) => format_argument(result, *index, message),
+                    MobilePart::Text(url) => result.push_str(&escape_xml(url)),
+                    _ => {}
+                }
+                result.push_str("\">");
+                format_parts(result, label, message);
+                result.push_str("</a>");
+            }
+            // Plurals are expanded into separate quantities before formatting.
+            MobilePart::Plural(..) => {}
+        }
+    }
+}
+
+fn format_argument(result: &mut String, index: usize, message: &MobileMessage) {
+    let conversion = match message.arguments[index].kind {
+        ArgumentKind::String => 's',
+        ArgumentKind::Number => 'd',
+    };
+    write!(result, "%{}${conversion}", index + 1).unwrap();
+}
+
+/// Escape `text` for the content of a string resource. If the string is `is_formatted`, `%` is
+/// escaped as well so it isn't read as a format specifier.
+fn escape_android_text(text: &str, is_formatted: bool) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '\\' => result.push_str("\\\\"),
+            '\'' => result.push_str("\\'"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '%' if is_formatted => result.push_str("%%"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{key_symbol, MessageMeta, MessageValue, MessagesDatabase};
+    use intl_database_service::IntlDatabaseService;
+
+    use crate::mobile::convert_message;
+
+    use super::{
+        android_locale_qualifier, format_resource, ExportAndroidStrings, ANDROID_FEATURES,
+    };
+
+    fn format(content: &str, meta: MessageMeta) -> String {
+        let mut database = MessagesDatabase::new();
+        database
+            .insert_definition(
+                "MESSAGE",
+                MessageValue::from_raw(content),
+                key_symbol("en-US"),
+                meta,
+                false,
+            )
+            .unwrap();
+        let message = database.get_message(&key_symbol("MESSAGE")).unwrap();
+        let value = message.get_source_translation().unwrap();
+        let converted = convert_message(&value.parsed, ANDROID_FEATURES).unwrap();
+        let mut result = String::new();
+        format_resource(&mut result, message, "MESSAGE", &converted);
+        result
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            format(
+                "Hi **{name}**, it's 100% [done]({url})",
+                MessageMeta::default().with_description("Shown when -- done")
+            ),
+            r#"    <!-- Shown when - - done -->
+    <!-- Arguments: 1: name, 2: url -->
+    <string name="MESSAGE">Hi <b>%1$s</b>, it\'s 100%% <a href="%2$s">done</a></string>
+"#
+        );
+        assert_eq!(
+            format("@home is 50% <off>", MessageMeta::default()),
+            "    <string name=\"MESSAGE\" formatted=\"false\">\\@home is 50% &lt;off&gt;</string>\n"
+        );
+    }
+
+    #[test]
+    fn plurals() {
+        assert_eq!(
+            format(
+                "{user} has {count, plural, one {# item} other {# items}}.",
+                MessageMeta::default()
+            ),
+            r#"    <!-- Arguments: 1: user, 2: count -->
+    <plurals name="MESSAGE">
+        <item quantity="one">%1$s has %2$d item.</item>
+        <item quantity="other">%1$s has %2$d items.</item>
+    </plurals>
+"#
+        );
+    }
+
+    #[test]
+    fn locale_qualifiers() {
+        assert_eq!(android_locale_qualifier(&key_symbol("fr")), "fr");
+        assert_eq!(android_locale_qualifier(&key_symbol("pt-BR")), "pt-rBR");
+        assert_eq!(
+            android_locale_qualifier(&key_symbol("zh-Hant")),
+            "b+zh+Hant"
+        );
+        assert_eq!(android_locale_qualifier(&key_symbol("es-419")), "b+es+419");
+    }
+
+    #[test]
+    fn colliding_resource_names() {
+        let mut database = MessagesDatabase::new();
+        for key in ["A_B", "A-B", "A.B"] {
+            database
+                .insert_definition(
+                    key,
+                    MessageValue::from_raw(key),
+                    key_symbol("en-US"),
+                    MessageMeta::default(),
+                    false,
+                )
+                .unwrap();
+        }
+        let directory = tempfile::tempdir().unwrap();
+        let report = ExportAndroidStrings::new(&database, directory.path().to_path_buf())
+            .run()
+            .unwrap();
+
+        let mut skipped: Vec<String> = report
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.key.to_string())
+            .collect();
+        skipped.sort();
+        assert_eq!(skipped, vec!["A.B", "A_B"]);
+        let content =
+            std::fs::read_to_string(directory.path().join("values").join("strings.xml")).unwrap();
+        assert_eq!(content.matches("name=\"A_B\"").count(), 1);
+        assert!(content.contains(">A-B</string>"));
+    }
+}
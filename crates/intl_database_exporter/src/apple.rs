@@ -0,0 +1,345 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use intl_database_core::{Message, MessagesDatabase};
+use intl_database_service::IntlDatabaseService;
+
+use crate::mobile::{
+    convert_database, write_files, ArgumentKind, MobileExportReport, MobileFeatures, MobileMessage,
+    MobilePart, TextStyle,
+};
+use crate::xliff::escape_xml;
+
+const APPLE_FEATURES: MobileFeatures = MobileFeatures {
+    multiple_plurals: true,
+    exact_zero: true,
+};
+
+/// A service for exporting the messages in a [MessagesDatabase] as Apple strings files, so that
+/// apps can share the same messages as other clients.
+///
+/// Every translation of a message is written to the `<locale>.lproj` directory for its locale in
+/// the given resources directory. Messages without plurals are written to `Localizable.strings`,
+/// with their variables as positional arguments like `%1$@`. Messages with plurals are written
+/// to `Localizable.stringsdict`, with a plural rule for each plural in the message. Text styles
+/// and links are written as Markdown, which `AttributedString` can render.
+///
+/// Messages that use syntax Apple platforms can't represent, like hooks or selects, are left
+/// out, with a diagnostic in the [MobileExportReport] that is the result of this service.
+pub struct ExportAppleStrings<'a> {
+    database: &'a MessagesDatabase,
+    resources_directory: PathBuf,
+}
+
+impl<'a> ExportAppleStrings<'a> {
+    pub fn new(database: &'a MessagesDatabase, resources_directory: PathBuf) -> Self {
+        Self {
+            database,
+            resources_directory,
+        }
+    }
+}
+
+impl IntlDatabaseService for ExportAppleStrings<'_> {
+    type Result = anyhow::Result<MobileExportReport>;
+
+    fn run(&mut self) -> Self::Result {
+        let mut report = MobileExportReport::default();
+        let mut strings: BTreeMap<PathBuf, String> = BTreeMap::new();
+        let mut plurals: BTreeMap<PathBuf, String> = BTreeMap::new();
+        convert_database(
+            self.database,
+            APPLE_FEATURES,
+            &mut report,
+            |message, locale, converted| {
+                let directory = self.resources_directory.join(format!("{locale}.lproj"));
+                if converted.has_plural() {
+                    let path = directory.join("Localizable.stringsdict");
+                    format_plural_entry(plurals.entry(path).or_default(), message, &converted);
+                } else {
+                    let path = directory.join("Localizable.strings");
+                    format_strings_entry(strings.entry(path).or_default(), message, &converted);
+                }
+            },
+        );
+
+        let mut files = strings;
+        files.extend(plurals.into_iter().map(|(path, content)| {
+            let document = format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+{content}</dict>
+</plist>
+"#
+            );
+            (path, document)
+        }));
+        write_files(files, &mut report)?;
+        Ok(report)
+    }
+}
+
+/// The comments describing a message: its description from the meta, followed by the arguments
+/// it expects.
+fn message_comments(message: &Message, converted: &MobileMessage) -> Vec<String> {
+    message
+        .meta()
+        .description
+        .iter()
+        .flat_map(|description| description.lines().map(String::from))
+        .chain(converted.argument_list())
+        .collect()
+}
+
+fn format_strings_entry(content: &mut String, message: &Message, converted: &MobileMessage) {
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    for comment in message_comments(message, converted) {
+        writeln!(content, "/* {} */", comment.replace("*/", "* /")).unwrap();
+    }
+    let mut formatter = AppleFormatter::new(converted, AppleSyntax::Strings);
+    let mut value = String::new();
+    formatter.format_parts(&mut value, &converted.parts);
+    writeln!(
+        content,
+        "\"{}\" = \"{value}\";",
+        escape_strings_text(&message.key(), false)
+    )
+    .unwrap();
+}
+
+fn format_plural_entry(content: &mut String, message: &Message, converted: &MobileMessage) {
+    for comment in message_comments(message, converted) {
+        writeln!(content, "    <!-- {} -->", comment.replace("--", "- -")).unwrap();
+    }
+    let mut formatter = AppleFormatter::new(converted, AppleSyntax::Plist);
+    let mut format_key = String::new();
+    formatter.format_parts(&mut format_key, &converted.parts);
+
+    writeln!(content, "    <key>{}</key>", escape_xml(&message.key())).unwrap();
+    content.push_str("    <dict>\n");
+    content.push_str("        <key>NSStringLocalizedFormatKey</key>\n");
+    writeln!(content, "        <string>{format_key}</string>").unwrap();
+    for (name, forms) in formatter.variables {
+        writeln!(content, "        <key>{}</key>", escape_xml(&name)).unwrap();
+        content.push_str("        <dict>\n");
+        content.push_str("            <key>NSStringFormatSpecTypeKey</key>\n");
+        content.push_str("            <string>NSStringPluralRuleType</string>\n");
+        content.push_str("            <key>NSStringFormatValueTypeKey</key>\n");
+        content.push_str("            <string>ld</string>\n");
+        for (category, value) in forms {
+            writeln!(content, "            <key>{category}</key>").unwrap();
+            writeln!(content, "            <string>{value}</string>").unwrap();
+        }
+        content.push_str("        </dict>\n");
+    }
+    content.push_str("    </dict>\n");
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum AppleSyntax {
+    /// A quoted value in a `.strings` file.
+    Strings,
+    /// A `<string>` in a property list, like a `.stringsdict` file.
+    Plist,
+}
+
+struct AppleFormatter<'a> {
+    message: &'a MobileMessage,
+    syntax: AppleSyntax,
+    /// The plural variables referenced by the formatted string, with the formatted value of
+    /// each of their forms.
+    variables: Vec<(String, Vec<(&'static str, String)>)>,
+}
+
+impl<'a> AppleFormatter<'a> {
+    fn new(message: &'a MobileMessage, syntax: AppleSyntax) -> Self {
+        Self {
+            message,
+            syntax,
+            variables: vec![],
+        }
+    }
+
+    fn format_parts(&mut self, result: &mut String, parts: &[MobilePart]) {
+        for part in parts {
+            match part {
+                MobilePart::Text(text) => result.push_str(&self.escape(text)),
+                MobilePart::Argument(index) => {
+                    let conversion = match self.message.arguments[*index].kind {
+                        ArgumentKind::String => "@",
+                        ArgumentKind::Number => "ld",
+                    };
+                    write!(result, "%{}${conversion}", index + 1).unwrap();
+                }
+                MobilePart::Plural(index, forms) => {
+                    let name = self.variable_name(&self.message.arguments[*index].name);
+                    write!(result, "%{}$#@{name}@", index + 1).unwrap();
+                    // Reserve the variable before formatting the forms, so that nested plurals
+                    // get their own names and follow it in the dictionary.
+                    let position = self.variables.len();
+                    self.variables.push((name, vec![]));
+                    for form in forms {
+                        let mut value = String::new();
+                        self.format_parts(&mut value, &form.parts);
+                        self.variables[position].1.push((form.category, value));
+                    }
+                }
+                MobilePart::Styled(style, content) => {
+                    let marker = match style {
+                        TextStyle::Strong => "**",
+                        TextStyle::Emphasis => "*",
+                        TextStyle::Strikethrough => "~~",
+                        TextStyle::Code => "`",
+                    };
+                    result.push_str(marker);
+                    self.format_parts(result, content);
+                    result.push_str(marker);
+                }
+                MobilePart::Link(destination, label) => {
+                    result.push('[');
+                    self.format_parts(result, label);
+                    result.push_str("](");
+                    self.format_parts(result, std::slice::from_ref(destination));
+                    result.push(')');
+                }
+            }
+        }
+    }
+
+    /// Return a name for the plural variable of `argument` that isn't used yet in this message.
+    fn variable_name(&self, argument: &str) -> String {
+        let mut name = argument.to_string();
+        let mut suffix = 1;
+        while self.variables.iter().any(|(existing, _)| *existing == name) {
+            suffix += 1;
+            name = format!("{argument}_{suffix}");
+        }
+        name
+    }
+
+    fn escape(&self, text: &str) -> String {
+        let is_formatted = !self.message.arguments.is_empty();
+        match self.syntax {
+            AppleSyntax::Strings => escape_strings_text(text, is_formatted),
+            AppleSyntax::Plist => {
+                let text = escape_xml(text);
+                if is_formatted {
+                    text.replace('%', "%%")
+                } else {
+                    text
+                }
+            }
+        }
+    }
+}
+
+/// Escape `text` for a quoted string in a `.strings` file. If the string is `is_formatted`, `%`
+/// is escaped as well so it isn't read as a format specifier.
+fn escape_strings_text(text: &str, is_formatted: bool) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '%' if is_formatted => result.push_str("%%"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{key_symbol, MessageMeta, MessageValue, MessagesDatabase};
+
+    use crate::mobile::convert_message;
+
+    use super::{format_plural_entry, format_strings_entry, APPLE_FEATURES};
+
+    fn format(content: &str, meta: MessageMeta) -> String {
+        let mut database = MessagesDatabase::new();
+        database
+            .insert_definition(
+                "MESSAGE",
+                MessageValue::from_raw(content),
+                key_symbol("en-US"),
+                meta,
+                false,
+            )
+            .unwrap();
+        let message = database.get_message("MESSAGE").unwrap();
+        let value = message.get_source_translation().unwrap();
+        let converted = convert_message(&value.parsed, APPLE_FEATURES).unwrap();
+        let mut result = String::new();
+        if converted.has_plural() {
+            format_plural_entry(&mut result, message, &converted);
+        } else {
+            format_strings_entry(&mut result, message, &converted);
+        }
+        result
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            format(
+                "Hi **{name}**, it's 100% \"[done]({url})\"",
+                MessageMeta::default().with_description("Shown when done")
+            ),
+            r#"/* Shown when done */
+/* Arguments: 1: name, 2: url */
+"MESSAGE" = "Hi **%1$@**, it's 100%% \"[done](%2$@)\"";
+"#
+        );
+        assert_eq!(
+            format("50% off", MessageMeta::default()),
+            "\"MESSAGE\" = \"50% off\";\n"
+        );
+    }
+
+    #[test]
+    fn plurals() {
+        assert_eq!(
+            format(
+                "{user} has {count, plural, =0 {no items} other {# items & {files, plural, one {# file} other {# files}}}}.",
+                MessageMeta::default()
+            ),
+            r#"    <!-- Arguments: 1: user, 2: count, 3: files -->
+    <key>MESSAGE</key>
+    <dict>
+        <key>NSStringLocalizedFormatKey</key>
+        <string>%1$@ has %2$#@count@.</string>
+        <key>count</key>
+        <dict>
+            <key>NSStringFormatSpecTypeKey</key>
+            <string>NSStringPluralRuleType</string>
+            <key>NSStringFormatValueTypeKey</key>
+            <string>ld</string>
+            <key>zero</key>
+            <string>no items</string>
+            <key>other</key>
+            <string>%2$ld items &amp; %3$#@files@</string>
+        </dict>
+        <key>files</key>
+        <dict>
+            <key>NSStringFormatSpecTypeKey</key>
+            <string>NSStringPluralRuleType</string>
+            <key>NSStringFormatValueTypeKey</key>
+            <string>ld</string>
+            <key>one</key>
+            <string>%3$ld file</string>
+            <key>other</key>
+            <string>%3$ld files</string>
+        </dict>
+    </dict>
+"#
+        );
+    }
+}
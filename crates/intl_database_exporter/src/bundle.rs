@@ -1,6 +1,8 @@
 use thiserror::Error;
 
 use intl_database_core::{KeySymbol, Message, MessageValue, MessagesDatabase};
+
+use crate::fallback::LocaleFallbacks;
 use intl_database_service::IntlDatabaseService;
 use intl_markdown::{
     compile_to_format_js, raw_string_to_document, BlockNode, Document, InlineContent,
@@ -17,6 +19,7 @@ pub enum IntlMessageBundlerError {
 pub struct IntlMessageBundlerOptions {
     format: CompiledMessageFormat,
     bundle_secrets: bool,
+    fallbacks: Option<LocaleFallbacks>,
 }
 
 impl IntlMessageBundlerOptions {
@@ -28,6 +31,16 @@ impl IntlMessageBundlerOptions {
         self.bundle_secrets = bundle_secrets;
         self
     }
+    /// Fill in messages that aren't translated in the requested locale using the given fallback
+    /// chains, making the bundle complete on its own. Without this, untranslated messages are left
+    /// out of the bundle entirely.
+    pub fn with_fallbacks(mut self, fallbacks: LocaleFallbacks) -> Self {
+        self.fallbacks = Some(fallbacks);
+        self
+    }
+    pub fn has_fallbacks(&self) -> bool {
+        self.fallbacks.is_some()
+    }
 }
 
 impl Default for IntlMessageBundlerOptions {
@@ -35,6 +48,7 @@ impl Default for IntlMessageBundlerOptions {
         Self {
             format: CompiledMessageFormat::KeylessJson,
             bundle_secrets: false,
+            fallbacks: None,
         }
     }
 }
@@ -52,6 +66,14 @@ pub struct IntlMessageBundler<'a, W: std::io::Write> {
     options: IntlMessageBundlerOptions,
 }
 
+/// Information about the contents of a bundle that was created by [IntlMessageBundler].
+#[derive(Debug, Default)]
+pub struct IntlMessageBundleReport {
+    /// Messages that weren't translated in the requested locale, mapped to the locale that their
+    /// bundled value was taken from instead, in the order they were bundled.
+    pub fallbacks: Vec<(KeySymbol, KeySymbol)>,
+}
+
 pub enum CompiledMessageFormat {
     Json,
     KeylessJson,
@@ -97,6 +119,40 @@ impl<'a, W: std::io::Write> IntlMessageBundler<'a, W> {
         true
     }
 
+    /// Return the locales to look for translations in, in order of preference, not including the
+    /// source locale of each message.
+    fn candidate_locales(&self) -> Vec<KeySymbol> {
+        let mut locales = vec![self.locale_key];
+        if let Some(fallbacks) = &self.options.fallbacks {
+            locales.extend(fallbacks.chain_for(self.locale_key));
+        }
+        locales
+    }
+
+    /// Find the first locale in `candidates` where the message should be bundled and has a
+    /// translation, followed by the message's own source locale when fallbacks are enabled.
+    fn resolve_translation<'m>(
+        &self,
+        message: &'m Message,
+        candidates: &[KeySymbol],
+    ) -> Option<(KeySymbol, &'m MessageValue)> {
+        let source_locale = self
+            .options
+            .fallbacks
+            .as_ref()
+            .and(*message.source_locale());
+        candidates
+            .iter()
+            .chain(source_locale.iter())
+            .filter(|locale| self.should_bundle(message, **locale))
+            .find_map(|locale| {
+                message
+                    .translations()
+                    .get(locale)
+                    .map(|translation| (*locale, translation))
+            })
+    }
+
     /// Returns true if the message _value_ should be obfuscated in the generated bundle.
     /// Obfuscated  messages are just given a non-empty placeholder value. Note that this only
     /// applies to the  _value_ of a message because the keys will _always_ be obfuscated as the
@@ -163,7 +219,7 @@ impl<'a, W: std::io::Write> IntlMessageBundler<'a, W> {
 }
 
 impl<W: std::io::Write> IntlDatabaseService for IntlMessageBundler<'_, W> {
-    type Result = anyhow::Result<()>;
+    type Result = anyhow::Result<IntlMessageBundleReport>;
 
     fn run(&mut self) -> Self::Result {
         let message_keys = self
@@ -172,6 +228,8 @@ impl<W: std::io::Write> IntlDatabaseService for IntlMessageBundler<'_, W> {
             .map(|source| source.message_keys())
             .ok_or_else(|| IntlMessageBundlerError::SourceFileNotFound(self.source_key))?;
 
+        let candidates = self.candidate_locales();
+        let mut report = IntlMessageBundleReport::default();
         write!(self.output, "{{")?;
         let mut is_first = true;
         for key in message_keys {
@@ -181,21 +239,161 @@ impl<W: std::io::Write> IntlDatabaseService for IntlMessageBundler<'_, W> {
                 .get(key)
                 .ok_or_else(|| IntlMessageBundlerError::MessageNotFound(*key))?;
 
-            if !self.should_bundle(message, self.locale_key) {
+            let Some((locale, translation)) = self.resolve_translation(message, &candidates) else {
                 continue;
+            };
+            if locale != self.locale_key {
+                report.fallbacks.push((*key, locale));
             }
 
-            if let Some(translation) = message.translations().get(&self.locale_key) {
-                if !is_first {
-                    write!(self.output, ",")?;
-                } else {
-                    is_first = false;
-                }
-                write!(self.output, "\"{}\":", message.hashed_key())?;
-                self.serialize_value(message, translation)?;
+            if !is_first {
+                write!(self.output, ",")?;
+            } else {
+                is_first = false;
             }
+            write!(self.output, "\"{}\":", message.hashed_key())?;
+            self.serialize_value(message, translation)?;
         }
         write!(self.output, "}}")?;
-        Ok(())
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{
+        key_symbol, DefinitionFile, KeySymbolSet, MessageMeta, MessageValue, MessagesDatabase,
+        SourceFile, SourceFileMeta,
+    };
+    use intl_database_service::IntlDatabaseService;
+
+    use super::{IntlMessageBundleReport, IntlMessageBundler, IntlMessageBundlerOptions};
+    use crate::LocaleFallbacks;
+
+    fn database() -> MessagesDatabase {
+        let mut database = MessagesDatabase::new();
+        let mut keys = KeySymbolSet::default();
+        for (name, meta) in [
+            ("TRANSLATED", MessageMeta::default()),
+            ("FALLBACK", MessageMeta::default()),
+            ("UNTRANSLATED", MessageMeta::default()),
+            ("NOT_READY", MessageMeta::default().with_translate(false)),
+        ] {
+            database
+                .insert_definition(
+                    name,
+                    MessageValue::from_raw(name),
+                    key_symbol("en-US"),
+                    meta,
+                    false,
+                )
+                .unwrap();
+            keys.insert(key_symbol(name));
+        }
+        for (name, locale, value) in [
+            ("TRANSLATED", "es-419", "traducido"),
+            ("TRANSLATED", "es-ES", "traducido en España"),
+            ("FALLBACK", "es-ES", "de España"),
+            ("NOT_READY", "es-ES", "no listo"),
+        ] {
+            database
+                .insert_translation(
+                    key_symbol(name),
+                    key_symbol(locale),
+                    MessageValue::from_raw(value),
+                    false,
+                )
+                .unwrap();
+        }
+        database.create_source_file(
+            key_symbol("en.messages.js"),
+            SourceFile::Definition(DefinitionFile::new(
+                "en.messages.js".into(),
+                SourceFileMeta::new("en.messages.js"),
+                keys,
+            )),
+        );
+        database
+    }
+
+    fn bundle(
+        database: &MessagesDatabase,
+        options: IntlMessageBundlerOptions,
+    ) -> (String, IntlMessageBundleReport) {
+        let mut output = vec![];
+        let report = IntlMessageBundler::new(
+            database,
+            &mut output,
+            key_symbol("en.messages.js"),
+            key_symbol("es-419"),
+            options,
+        )
+        .run()
+        .unwrap();
+        (String::from_utf8(output).unwrap(), report)
+    }
+
+    fn bundled_value(database: &MessagesDatabase, output: &str, name: &str) -> Option<String> {
+        let hashed_key = database.get_message(name).unwrap().hashed_key();
+        let object: serde_json::Value = serde_json::from_str(output).unwrap();
+        object
+            .get(hashed_key)
+            .map(|value| value.as_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn without_fallbacks() {
+        let database = database();
+        let (output, report) = bundle(&database, IntlMessageBundlerOptions::default());
+        assert_eq!(
+            Some("traducido".into()),
+            bundled_value(&database, &output, "TRANSLATED")
+        );
+        assert_eq!(None, bundled_value(&database, &output, "FALLBACK"));
+        assert_eq!(None, bundled_value(&database, &output, "UNTRANSLATED"));
+        assert!(report.fallbacks.is_empty());
+    }
+
+    #[test]
+    fn with_fallback_chain() {
+        let database = database();
+        let fallbacks = LocaleFallbacks::default().with_chain("es-419", &["es-ES"]);
+        let (output, report) = bundle(
+            &database,
+            IntlMessageBundlerOptions::default().with_fallbacks(fallbacks),
+        );
+        assert_eq!(
+            Some("traducido".into()),
+            bundled_value(&database, &output, "TRANSLATED")
+        );
+        assert_eq!(
+            Some("de España".into()),
+            bundled_value(&database, &output, "FALLBACK")
+        );
+        // Messages always fall back to their source locale last.
+        assert_eq!(
+            Some("UNTRANSLATED".into()),
+            bundled_value(&database, &output, "UNTRANSLATED")
+        );
+        // Messages that aren't ready for translation only use the source.
+        assert_eq!(
+            Some("NOT_READY".into()),
+            bundled_value(&database, &output, "NOT_READY")
+        );
+
+        let mut fallbacks: Vec<_> = report
+            .fallbacks
+            .iter()
+            .map(|(key, locale)| (key.as_str(), locale.as_str()))
+            .collect();
+        fallbacks.sort();
+        assert_eq!(
+            vec![
+                ("FALLBACK", "es-ES"),
+                ("NOT_READY", "en-US"),
+                ("UNTRANSLATED", "en-US")
+            ],
+            fallbacks
+        );
     }
 }
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::PathBuf;
 
-use intl_database_core::{DEFAULT_LOCALE, KeySymbol, MessagesDatabase, SourceFile};
+use intl_database_core::{KeySymbol, MessagesDatabase, SourceFile};
 use intl_database_service::IntlDatabaseService;
 use rustc_hash::FxHashMap;
 
@@ -49,35 +49,33 @@ impl IntlDatabaseService for ExportTranslations<'_> {
 
         let mut result: FxHashMap<PathBuf, BTreeMap<KeySymbol, &String>> = FxHashMap::default();
         for file in definition_files {
+            let messages: Vec<_> = file
+                .message_keys()
+                .iter()
+                .filter_map(|key| self.database.get_message(key))
+                .collect();
             for locale in &self.database.known_locales {
-                // TODO: Make DEFAULT_LOCALE configurable. This assumes all definitions are
-                // in the default locale, but it's possible for definitions to use a different
-                // locale as the source, and this is arguably something that can be set per-message.
-                if *locale == DEFAULT_LOCALE {
+                // Definitions are never written back out as translations, so messages that are
+                // written in this locale are skipped, and files where that's every message don't
+                // get a translations file for it at all.
+                let translatable: Vec<_> = messages
+                    .iter()
+                    .filter(|message| {
+                        !message
+                            .source_locale()
+                            .is_some_and(|source| source == *locale)
+                    })
+                    .collect();
+                if translatable.is_empty() {
                     continue;
                 }
 
                 let path = file.meta().get_translations_path(&locale, None);
                 let values = result.entry(path).or_default();
-                for key in file.message_keys() {
-                    let Some(message) = self.database.get_message(&key) else {
-                        continue;
-                    };
-                    if message
-                        .source_locale()
-                        .is_some_and(|source| source == *locale)
-                    {
-                        continue;
+                for message in translatable {
+                    if let Some(value) = message.translations().get(locale) {
+                        values.insert(message.key(), &value.raw);
                     }
-                    let Some(value) = self
-                        .database
-                        .get_message(&key)
-                        .and_then(|message| message.translations().get(&locale))
-                    else {
-                        continue;
-                    };
-
-                    values.insert(*key, &value.raw);
                 }
             }
         }
@@ -100,3 +98,80 @@ impl IntlDatabaseService for ExportTranslations<'_> {
         Ok(affected_files)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{
+        key_symbol, DefinitionFile, KeySymbolSet, MessageMeta, MessageValue, MessagesDatabase,
+        SourceFile, SourceFileMeta,
+    };
+    use intl_database_service::IntlDatabaseService;
+
+    use super::ExportTranslations;
+
+    #[test]
+    fn overridden_source_locales() {
+        let directory = tempfile::tempdir().unwrap();
+        let source_path = directory.path().join("Feature.messages.js");
+        std::fs::write(&source_path, "").unwrap();
+        let source_path = source_path.to_str().unwrap();
+
+        let mut database = MessagesDatabase::new();
+        let mut keys = KeySymbolSet::default();
+        // `OVERRIDDEN` is defined in French within an otherwise en-US file.
+        for (name, locale) in [("DEFINED", "en-US"), ("OVERRIDDEN", "fr")] {
+            database
+                .insert_definition(
+                    name,
+                    MessageValue::from_raw(name),
+                    key_symbol(locale),
+                    MessageMeta::default(),
+                    false,
+                )
+                .unwrap();
+            keys.insert(key_symbol(name));
+        }
+        for (name, locale, value) in [
+            ("DEFINED", "fr", "DEFINED in fr"),
+            ("OVERRIDDEN", "en-US", "OVERRIDDEN in en-US"),
+        ] {
+            database
+                .insert_translation(
+                    key_symbol(name),
+                    key_symbol(locale),
+                    MessageValue::from_raw(value),
+                    false,
+                )
+                .unwrap();
+        }
+        database.create_source_file(
+            key_symbol(source_path),
+            SourceFile::Definition(DefinitionFile::new(
+                source_path.into(),
+                SourceFileMeta::new(source_path).with_source_locale(key_symbol("en-US")),
+                keys,
+            )),
+        );
+
+        let mut files = ExportTranslations::new(&database, None).run().unwrap();
+        files.sort();
+        assert_eq!(files.len(), 2);
+
+        let read = |locale: &str| {
+            let path = directory
+                .path()
+                .join("messages")
+                .join(format!("{locale}.messages.json"));
+            let content = std::fs::read_to_string(path).unwrap();
+            serde_json::from_str::<serde_json::Value>(&content).unwrap()
+        };
+        assert_eq!(
+            read("en-US"),
+            serde_json::json!({ "OVERRIDDEN": "OVERRIDDEN in en-US" })
+        );
+        assert_eq!(
+            read("fr"),
+            serde_json::json!({ "DEFINED": "DEFINED in fr" })
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use intl_database_core::{key_symbol, KeySymbol, KeySymbolMap};
+
+/// Configuration for which locales a bundle should take values from when a message has no
+/// translation in the requested locale.
+///
+/// Each locale either has an explicitly configured chain, like `es-419 → es-ES → en-US`, or uses
+/// the parent locales defined by CLDR, like `es-MX → es-419 → es`. Whatever the chain, messages
+/// always fall back to their own source locale last, so a bundle created with fallbacks contains
+/// every message that would be bundled for the source locale.
+#[derive(Clone, Debug, Default)]
+pub struct LocaleFallbacks {
+    chains: KeySymbolMap<Vec<KeySymbol>>,
+}
+
+impl LocaleFallbacks {
+    /// Use `chain` as the ordered list of locales to try for `locale`, instead of its CLDR parents.
+    pub fn with_chain<S: AsRef<str>>(mut self, locale: &str, chain: &[S]) -> Self {
+        self.chains.insert(
+            key_symbol(locale),
+            chain
+                .iter()
+                .map(|locale| key_symbol(locale.as_ref()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Return the ordered list of locales to try after `locale` itself, not including the source
+    /// locale of any particular message.
+    pub fn chain_for(&self, locale: KeySymbol) -> Vec<KeySymbol> {
+        if let Some(chain) = self.chains.get(&locale) {
+            return chain.clone();
+        }
+
+        let mut chain = vec![];
+        let mut current: &str = &locale;
+        while let Some(parent) = cldr_parent_locale(current) {
+            chain.push(key_symbol(parent));
+            current = parent;
+        }
+        chain
+    }
+}
+
+/// Parent locales from CLDR's `parentLocales` data that differ from simple truncation. Region-only
+/// Chinese tags are included too, since truncating them would fall back to Simplified Chinese.
+static CLDR_PARENT_LOCALES: &[(&str, &str)] = &[
+    ("en-150", "en-001"),
+    ("en-AG", "en-001"),
+    ("en-AU", "en-001"),
+    ("en-BE", "en-150"),
+    ("en-BS", "en-001"),
+    ("en-BZ", "en-001"),
+    ("en-CA", "en-001"),
+    ("en-DE", "en-150"),
+    ("en-GB", "en-001"),
+    ("en-GH", "en-001"),
+    ("en-HK", "en-001"),
+    ("en-IE", "en-001"),
+    ("en-IN", "en-001"),
+    ("en-JM", "en-001"),
+    ("en-KE", "en-001"),
+    ("en-MT", "en-001"),
+    ("en-MY", "en-001"),
+    ("en-NG", "en-001"),
+    ("en-NL", "en-150"),
+    ("en-NZ", "en-001"),
+    ("en-PK", "en-001"),
+    ("en-SG", "en-001"),
+    ("en-ZA", "en-001"),
+    ("es-AR", "es-419"),
+    ("es-BO", "es-419"),
+    ("es-BR", "es-419"),
+    ("es-BZ", "es-419"),
+    ("es-CL", "es-419"),
+    ("es-CO", "es-419"),
+    ("es-CR", "es-419"),
+    ("es-CU", "es-419"),
+    ("es-DO", "es-419"),
+    ("es-EC", "es-419"),
+    ("es-GT", "es-419"),
+    ("es-HN", "es-419"),
+    ("es-MX", "es-419"),
+    ("es-NI", "es-419"),
+    ("es-PA", "es-419"),
+    ("es-PE", "es-419"),
+    ("es-PR", "es-419"),
+    ("es-PY", "es-419"),
+    ("es-SV", "es-419"),
+    ("es-US", "es-419"),
+    ("es-UY", "es-419"),
+    ("es-VE", "es-419"),
+    ("pt-AO", "pt-PT"),
+    ("pt-CH", "pt-PT"),
+    ("pt-CV", "pt-PT"),
+    ("pt-GQ", "pt-PT"),
+    ("pt-GW", "pt-PT"),
+    ("pt-LU", "pt-PT"),
+    ("pt-MO", "pt-PT"),
+    ("pt-MZ", "pt-PT"),
+    ("pt-ST", "pt-PT"),
+    ("pt-TL", "pt-PT"),
+    ("zh-HK", "zh-Hant"),
+    ("zh-MO", "zh-HK"),
+    ("zh-TW", "zh-Hant"),
+    ("zh-Hant-MO", "zh-Hant-HK"),
+];
+
+/// Locales whose parent is the root locale, even though they have multiple subtags.
+static CLDR_ROOT_CHILDREN: &[&str] = &["zh-Hant"];
+
+/// Return the CLDR parent of `locale`, or None if its parent is the root locale.
+fn cldr_parent_locale(locale: &str) -> Option<&str> {
+    if let Some((_, parent)) = CLDR_PARENT_LOCALES
+        .iter()
+        .find(|(child, _)| child.eq_ignore_ascii_case(locale))
+    {
+        return Some(parent);
+    }
+    if CLDR_ROOT_CHILDREN
+        .iter()
+        .any(|child| child.eq_ignore_ascii_case(locale))
+    {
+        return None;
+    }
+
+    locale
+        .rfind(['-', '_'])
+        .map(|separator| &locale[..separator])
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::key_symbol;
+
+    use super::LocaleFallbacks;
+
+    fn chain(fallbacks: &LocaleFallbacks, locale: &str) -> Vec<String> {
+        fallbacks
+            .chain_for(key_symbol(locale))
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn cldr_parents() {
+        let fallbacks = LocaleFallbacks::default();
+        assert_eq!(vec!["es-419", "es"], chain(&fallbacks, "es-MX"));
+        assert_eq!(vec!["en-001", "en"], chain(&fallbacks, "en-GB"));
+        assert_eq!(vec!["zh-Hant"], chain(&fallbacks, "zh-TW"));
+        assert_eq!(
+            vec!["zh-Hant-HK", "zh-Hant"],
+            chain(&fallbacks, "zh-Hant-MO")
+        );
+        assert_eq!(vec!["fr"], chain(&fallbacks, "fr-CA"));
+        assert!(chain(&fallbacks, "de").is_empty());
+    }
+
+    #[test]
+    fn configured_chains() {
+        let fallbacks = LocaleFallbacks::default()
+            .with_chain("es-419", &["es-ES", "en-US"])
+            .with_chain("zh-HK", &["zh-TW"]);
+        assert_eq!(vec!["es-ES", "en-US"], chain(&fallbacks, "es-419"));
+        assert_eq!(vec!["zh-TW"], chain(&fallbacks, "zh-HK"));
+        // Locales without a configured chain still use CLDR.
+        assert_eq!(vec!["es-419", "es"], chain(&fallbacks, "es-AR"));
+    }
+}
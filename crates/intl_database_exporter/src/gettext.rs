@@ -0,0 +1,295 @@
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use intl_database_core::{
+    key_symbol, KeySymbol, MessageValue, MessagesDatabase, SourceFile, DEFAULT_LOCALE,
+};
+use intl_database_po_source::escape_po_string;
+use intl_database_service::IntlDatabaseService;
+use rustc_hash::FxHashMap;
+
+/// A service for exporting the messages in a [MessagesDatabase] as gettext files, for translation
+/// platforms that work with PO files rather than the JSON translation files.
+///
+/// Each translations directory gets a `<source locale>.pot` template containing every message
+/// defined for it, and a `<locale>.messages.po` file for each other known locale, containing the
+/// current translation of every message (or an empty `msgstr` if it hasn't been translated).
+/// Messages are identified by their key in `msgctxt`, the description from their meta is included
+/// as an extracted comment, and their definition is included as a reference. Translations that
+/// were imported as fuzzy keep their `fuzzy` flag.
+///
+/// Messages with `translate: false` are not included, since they shouldn't be sent for
+/// translation. The result of this service is the list of file paths that were written.
+pub struct ExportGettext<'a> {
+    database: &'a MessagesDatabase,
+}
+
+impl<'a> ExportGettext<'a> {
+    pub fn new(database: &'a MessagesDatabase) -> Self {
+        Self { database }
+    }
+
+    /// Format the catalog of the given `keys` as the content of a gettext file. If `locale` is
+    /// `None`, the catalog is a template with no translations. References to source files are
+    /// written relative to `directory`.
+    fn format_catalog(
+        &self,
+        locale: Option<KeySymbol>,
+        keys: &BTreeSet<KeySymbol>,
+        directory: Option<&Path>,
+    ) -> String {
+        let mut content = String::new();
+        content.push_str("msgid \"\"\nmsgstr \"\"\n");
+        if let Some(locale) = locale {
+            writeln!(content, "\"Language: {}\\n\"", escape_po_string(&locale)).unwrap();
+        }
+        content.push_str("\"MIME-Version: 1.0\\n\"\n");
+        content.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+        content.push_str("\"Content-Transfer-Encoding: 8bit\\n\"\n");
+
+        for key in keys {
+            let Some(message) = self.database.get_message(key) else {
+                continue;
+            };
+            let Some(source) = message.get_source_translation() else {
+                continue;
+            };
+            let translation = locale.and_then(|locale| message.translations().get(&locale));
+
+            content.push('\n');
+            if let Some(description) = &message.meta().description {
+                for line in description.lines() {
+                    writeln!(content, "#. {line}").unwrap();
+                }
+            }
+            if let Some(position) = &source.file_position {
+                let file = Path::new(position.file.as_str());
+                let file = match directory {
+                    Some(directory) => relative_path(directory, file),
+                    None => file.to_path_buf(),
+                };
+                writeln!(content, "#: {}:{}", file.display(), position.line).unwrap();
+            }
+            if translation.is_some_and(|translation| translation.fuzzy) {
+                content.push_str("#, fuzzy\n");
+            }
+            write_po_string(&mut content, "msgctxt", key);
+            write_po_string(&mut content, "msgid", &source.raw);
+            write_po_string(
+                &mut content,
+                "msgstr",
+                translation.map_or("", |translation: &MessageValue| &translation.raw),
+            );
+        }
+        content
+    }
+}
+
+impl IntlDatabaseService for ExportGettext<'_> {
+    type Result = anyhow::Result<Vec<String>>;
+
+    fn run(&mut self) -> Self::Result {
+        let definition_files = self
+            .database
+            .sources
+            .values()
+            .filter_map(|source| match source {
+                SourceFile::Definition(definition) => Some(definition),
+                _ => None,
+            });
+
+        let mut catalogs: FxHashMap<PathBuf, (Option<KeySymbol>, BTreeSet<KeySymbol>)> =
+            FxHashMap::default();
+        for file in definition_files {
+            let file_locale = file
+                .meta()
+                .source_locale
+                .unwrap_or_else(|| key_symbol(DEFAULT_LOCALE));
+            let messages: Vec<_> = file
+                .message_keys()
+                .iter()
+                .filter_map(|key| self.database.get_message(key))
+                .filter(|message| message.meta().translate)
+                .collect();
+
+            let template_path = file.meta().get_translations_path(&file_locale, Some("pot"));
+            let (_, template_keys) = catalogs.entry(template_path).or_default();
+            template_keys.extend(messages.iter().map(|message| message.key()));
+
+            for locale in &self.database.known_locales {
+                // Messages written in this locale, including ones that override their source
+                // locale to it, don't need translating. Files where that's every message don't
+                // get a catalog for it at all.
+                let keys: Vec<KeySymbol> = messages
+                    .iter()
+                    .filter(|message| {
+                        !message
+                            .source_locale()
+                            .is_some_and(|source| source == *locale)
+                    })
+                    .map(|message| message.key())
+                    .collect();
+                if keys.is_empty() {
+                    continue;
+                }
+                let path = file
+                    .meta()
+                    .get_translations_path(locale, Some("messages.po"));
+                let (catalog_locale, catalog_keys) = catalogs.entry(path).or_default();
+                *catalog_locale = Some(*locale);
+                catalog_keys.extend(keys);
+            }
+        }
+
+        let mut affected_files = vec![];
+        for (path, (locale, keys)) in catalogs {
+            affected_files.push(path.display().to_string());
+
+            let directory = path.parent();
+            if let Some(directory) = directory {
+                std::fs::create_dir_all(directory)?;
+            }
+
+            let content = self.format_catalog(locale, &keys, directory);
+            let mut output = std::fs::File::create(&path)?;
+            output.write_all(content.as_bytes())?;
+        }
+
+        Ok(affected_files)
+    }
+}
+
+/// Write `value` as a quoted string for `keyword`. Multi-line values are split into one string
+/// per line, following the usual gettext style.
+fn write_po_string(content: &mut String, keyword: &str, value: &str) {
+    let is_multiline = value.trim_end_matches('\n').contains('\n');
+    if !is_multiline {
+        writeln!(content, "{keyword} \"{}\"", escape_po_string(value)).unwrap();
+        return;
+    }
+    writeln!(content, "{keyword} \"\"").unwrap();
+    for line in value.split_inclusive('\n') {
+        writeln!(content, "\"{}\"", escape_po_string(line)).unwrap();
+    }
+}
+
+/// Return the path to `file` relative to `directory`, assuming both are absolute.
+pub(crate) fn relative_path(directory: &Path, file: &Path) -> PathBuf {
+    let directory: Vec<_> = directory.components().collect();
+    let file: Vec<_> = file.components().collect();
+    let common = directory
+        .iter()
+        .zip(&file)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut result = PathBuf::new();
+    for _ in common..directory.len() {
+        result.push("..");
+    }
+    result.extend(&file[common..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    use intl_database_core::{
+        key_symbol, FilePosition, MessageMeta, MessageValue, MessagesDatabase,
+    };
+    use intl_database_po_source::parse_po_entries;
+
+    use super::ExportGettext;
+
+    fn database() -> MessagesDatabase {
+        let mut database = MessagesDatabase::new();
+        for (name, value, line, meta) in [
+            (
+                "GREETING",
+                "Hello {name}",
+                4,
+                MessageMeta::default().with_description("Greets the user"),
+            ),
+            (
+                "MULTILINE",
+                "First\nSecond \"quoted\"",
+                5,
+                MessageMeta::default(),
+            ),
+        ] {
+            let value = MessageValue::from_raw(value).with_file_position(FilePosition {
+                file: key_symbol("/project/src/Feature.messages.js"),
+                line,
+                col: 2,
+            });
+            database
+                .insert_definition(name, value, key_symbol("en-US"), meta, false)
+                .unwrap();
+        }
+        database
+            .insert_translation(
+                key_symbol("GREETING"),
+                key_symbol("fr"),
+                MessageValue::from_raw("Bonjour {name}").with_fuzzy(true),
+                false,
+            )
+            .unwrap();
+        database
+    }
+
+    #[test]
+    fn format_catalog() {
+        let database = database();
+        let exporter = ExportGettext::new(&database);
+        let keys = BTreeSet::from([key_symbol("GREETING"), key_symbol("MULTILINE")]);
+        let content = exporter.format_catalog(
+            Some(key_symbol("fr")),
+            &keys,
+            Some(Path::new("/project/src/i18n")),
+        );
+        assert_eq!(
+            content,
+            r#"msgid ""
+msgstr ""
+"Language: fr\n"
+"MIME-Version: 1.0\n"
+"Content-Type: text/plain; charset=UTF-8\n"
+"Content-Transfer-Encoding: 8bit\n"
+
+#. Greets the user
+#: ../Feature.messages.js:4
+#, fuzzy
+msgctxt "GREETING"
+msgid "Hello {name}"
+msgstr "Bonjour {name}"
+
+#: ../Feature.messages.js:5
+msgctxt "MULTILINE"
+msgid ""
+"First\n"
+"Second \"quoted\""
+msgstr ""
+"#
+        );
+
+        let entries = parse_po_entries(&content).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[2].msgid.value, "First\nSecond \"quoted\"");
+    }
+
+    #[test]
+    fn format_template() {
+        let database = database();
+        let exporter = ExportGettext::new(&database);
+        let keys = BTreeSet::from([key_symbol("GREETING")]);
+        let content = exporter.format_catalog(None, &keys, None);
+        assert!(!content.contains("Language:"));
+        assert!(!content.contains("fuzzy"));
+        assert!(content.contains("#: /project/src/Feature.messages.js:4\n"));
+        assert!(content.ends_with("msgid \"Hello {name}\"\nmsgstr \"\"\n"));
+    }
+}
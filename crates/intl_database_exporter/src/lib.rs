@@ -1,7 +1,20 @@
+pub use android::ExportAndroidStrings;
+pub use apple::ExportAppleStrings;
 pub use bundle::{
-    CompiledMessageFormat, IntlMessageBundler, IntlMessageBundlerError, IntlMessageBundlerOptions,
+    CompiledMessageFormat, IntlMessageBundleReport, IntlMessageBundler, IntlMessageBundlerError,
+    IntlMessageBundlerOptions,
 };
 pub use export::ExportTranslations;
+pub use fallback::LocaleFallbacks;
+pub use gettext::ExportGettext;
+pub use mobile::{MobileExportDiagnostic, MobileExportReport};
+pub use xliff::ExportXliff;
 
+mod android;
+mod apple;
 mod bundle;
 mod export;
+mod fallback;
+mod gettext;
+mod mobile;
+mod xliff;
@@ -0,0 +1,605 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::PathBuf;
+
+use intl_database_core::{FilePosition, KeySymbol, Message, MessagesDatabase};
+use intl_markdown::{
+    BlockNode, CodeSpan, Document, Emphasis, Hook, Icu, IcuDate, IcuNumber, IcuPlural,
+    IcuPluralKind, IcuSelect, IcuTime, IcuVariable, Link, LinkDestination, Strikethrough, Strong,
+};
+use intl_markdown_visitor::{visit_with_mut, Visit, VisitWith};
+
+/// A message that was left out of a mobile export because its content or key can't be
+/// represented faithfully in the platform's string resources.
+#[derive(Debug)]
+pub struct MobileExportDiagnostic {
+    pub key: KeySymbol,
+    pub locale: KeySymbol,
+    pub file_position: Option<FilePosition>,
+    pub description: String,
+}
+
+/// Information about the files written by a mobile exporter.
+#[derive(Debug, Default)]
+pub struct MobileExportReport {
+    /// Paths of every file that was written.
+    pub files: Vec<String>,
+    /// Every message value that couldn't be exported, in the order they were encountered.
+    pub diagnostics: Vec<MobileExportDiagnostic>,
+}
+
+/// The parts of message syntax that a platform's string resources are able to express.
+#[derive(Clone, Copy)]
+pub(crate) struct MobileFeatures {
+    /// Whether a message can contain more than one plural, including plurals nested inside the
+    /// arms of another.
+    pub multiple_plurals: bool,
+    /// Whether an exact `=0` selector can be written as the `zero` plural form.
+    pub exact_zero: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ArgumentKind {
+    String,
+    Number,
+}
+
+/// A value passed in to the formatted string. Arguments are numbered by the order that their
+/// variables first appear in the message.
+#[derive(Debug, PartialEq)]
+pub(crate) struct MobileArgument {
+    pub name: String,
+    pub kind: ArgumentKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TextStyle {
+    Strong,
+    Emphasis,
+    Strikethrough,
+    Code,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PluralForm {
+    /// The CLDR plural category of this form, like `one` or `other`.
+    pub category: &'static str,
+    pub parts: Vec<MobilePart>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum MobilePart {
+    Text(String),
+    /// A reference to the argument at the given index.
+    Argument(usize),
+    /// A plural selected by the numeric argument at the given index.
+    Plural(usize, Vec<PluralForm>),
+    Styled(TextStyle, Vec<MobilePart>),
+    /// A link to the destination in the first part, with the remaining parts as its label.
+    Link(Box<MobilePart>, Vec<MobilePart>),
+}
+
+/// A message converted to the subset of message syntax that mobile string resources support.
+#[derive(Debug, PartialEq)]
+pub(crate) struct MobileMessage {
+    pub parts: Vec<MobilePart>,
+    pub arguments: Vec<MobileArgument>,
+}
+
+impl MobileMessage {
+    pub fn has_plural(&self) -> bool {
+        fn contains_plural(parts: &[MobilePart]) -> bool {
+            parts.iter().any(|part| match part {
+                MobilePart::Plural(..) => true,
+                MobilePart::Styled(_, content) | MobilePart::Link(_, content) => {
+                    contains_plural(content)
+                }
+                _ => false,
+            })
+        }
+        contains_plural(&self.parts)
+    }
+
+    /// A description of the arguments the formatted string expects, in order, to help callers
+    /// pass them correctly.
+    pub fn argument_list(&self) -> Option<String> {
+        if self.arguments.is_empty() {
+            return None;
+        }
+        let names: Vec<_> = self
+            .arguments
+            .iter()
+            .enumerate()
+            .map(|(index, argument)| format!("{}: {}", index + 1, argument.name))
+            .collect();
+        Some(format!("Arguments: {}", names.join(", ")))
+    }
+}
+
+/// Return the message with its plural replaced by the content of each of its forms, along with
+/// the index of the plural's argument. This lets the text surrounding a plural become part of
+/// every form, for platforms that can only express a plural as the entire string.
+pub(crate) fn expand_plural(parts: &[MobilePart]) -> Option<(usize, Vec<PluralForm>)> {
+    fn find_plural(parts: &[MobilePart]) -> Option<(usize, &Vec<PluralForm>)> {
+        parts.iter().find_map(|part| match part {
+            MobilePart::Plural(argument, forms) => Some((*argument, forms)),
+            MobilePart::Styled(_, content) | MobilePart::Link(_, content) => find_plural(content),
+            _ => None,
+        })
+    }
+
+    fn replace_plural(parts: &[MobilePart], form: &[MobilePart]) -> Vec<MobilePart> {
+        let mut result = vec![];
+        for part in parts {
+            match part {
+                MobilePart::Plural(..) => result.extend(form.iter().cloned()),
+                MobilePart::Styled(style, content) => {
+                    result.push(MobilePart::Styled(*style, replace_plural(content, form)))
+                }
+                MobilePart::Link(destination, content) => result.push(MobilePart::Link(
+                    destination.clone(),
+                    replace_plural(content, form),
+                )),
+                part => result.push(part.clone()),
+            }
+        }
+        result
+    }
+
+    let (argument, forms) = find_plural(parts)?;
+    let forms = forms
+        .iter()
+        .map(|form| PluralForm {
+            category: form.category,
+            parts: replace_plural(parts, &form.parts),
+        })
+        .collect();
+    Some((argument, forms))
+}
+
+/// Convert every translation of every message in the database, in order of their keys. `write`
+/// is called with each translation that can be represented with the given `features`, and a
+/// diagnostic is added to `report` for every one that can't.
+pub(crate) fn convert_database(
+    database: &MessagesDatabase,
+    features: MobileFeatures,
+    report: &mut MobileExportReport,
+    mut write: impl FnMut(&Message, KeySymbol, MobileMessage),
+) {
+    let keys: BTreeSet<KeySymbol> = database.messages.keys().copied().collect();
+    for key in keys {
+        let Some(message) = database.get_message(&key) else {
+            continue;
+        };
+        let locales: BTreeSet<KeySymbol> = message.translations().keys().copied().collect();
+        for locale in locales {
+            let value = &message.translations()[&locale];
+            match convert_message(&value.parsed, features) {
+                Ok(converted) => write(message, locale, converted),
+                Err(errors) => report
+                    .diagnostics
+                    .extend(
+                        errors
+                            .into_iter()
+                            .map(|description| MobileExportDiagnostic {
+                                key,
+                                locale,
+                                file_position: value.file_position,
+                                description,
+                            }),
+                    ),
+            }
+        }
+    }
+}
+
+/// Write each of the given files, creating their directories as needed, and add them to the
+/// files of `report`.
+pub(crate) fn write_files(
+    files: BTreeMap<PathBuf, String>,
+    report: &mut MobileExportReport,
+) -> anyhow::Result<()> {
+    for (path, content) in files {
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        let mut output = std::fs::File::create(&path)?;
+        output.write_all(content.as_bytes())?;
+        report.files.push(path.display().to_string());
+    }
+    Ok(())
+}
+
+/// Convert the parsed content of a message into a [MobileMessage], or return a description of
+/// every part of it that can't be represented with the given `features`.
+pub(crate) fn convert_message(
+    document: &Document,
+    features: MobileFeatures,
+) -> Result<MobileMessage, Vec<String>> {
+    let mut converter = MobileMessageConverter {
+        features,
+        frames: vec![vec![]],
+        arguments: vec![],
+        plurals: vec![],
+        plural_count: 0,
+        block_count: 0,
+        errors: vec![],
+    };
+    visit_with_mut(document, &mut converter);
+
+    if !converter.errors.is_empty() {
+        return Err(converter.errors);
+    }
+    Ok(MobileMessage {
+        parts: converter.frames.pop().unwrap_or_default(),
+        arguments: converter.arguments,
+    })
+}
+
+struct MobileMessageConverter {
+    features: MobileFeatures,
+    /// Stack of the parts being collected for each nested element, with the message itself at
+    /// the bottom.
+    frames: Vec<Vec<MobilePart>>,
+    arguments: Vec<MobileArgument>,
+    /// Arguments of the plurals currently being visited, used to resolve `#`.
+    plurals: Vec<usize>,
+    plural_count: usize,
+    block_count: usize,
+    errors: Vec<String>,
+}
+
+impl MobileMessageConverter {
+    fn push(&mut self, part: MobilePart) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.push(part);
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if let Some(MobilePart::Text(last)) = self.frames.last_mut().and_then(|f| f.last_mut()) {
+            last.push_str(text);
+            return;
+        }
+        self.push(MobilePart::Text(text.into()));
+    }
+
+    /// Return the index of the argument for `name`, adding it if it hasn't been used yet. An
+    /// argument used as a number anywhere is always passed as a number.
+    fn argument(&mut self, name: &str, kind: ArgumentKind) -> usize {
+        if let Some(index) = self.arguments.iter().position(|arg| arg.name == name) {
+            if kind == ArgumentKind::Number {
+                self.arguments[index].kind = ArgumentKind::Number;
+            }
+            return index;
+        }
+        self.arguments.push(MobileArgument {
+            name: name.into(),
+            kind,
+        });
+        self.arguments.len() - 1
+    }
+
+    /// Visit `visit` with a new frame, returning the parts that it collected.
+    fn collect(&mut self, visit: impl FnOnce(&mut Self)) -> Vec<MobilePart> {
+        self.frames.push(vec![]);
+        visit(self);
+        self.frames.pop().unwrap_or_default()
+    }
+
+    fn visit_styled(&mut self, style: TextStyle, visit: impl FnOnce(&mut Self)) {
+        let content = self.collect(visit);
+        self.push(MobilePart::Styled(style, content));
+    }
+}
+
+impl Visit for MobileMessageConverter {
+    fn visit_block_node(&mut self, node: &BlockNode) {
+        let kind = match node {
+            BlockNode::Paragraph(_) | BlockNode::InlineContent(_) => {
+                if self.block_count > 0 {
+                    self.push_text("\n\n");
+                }
+                self.block_count += 1;
+                node.visit_children_with(self);
+                return;
+            }
+            BlockNode::Heading(_) => "Headings",
+            BlockNode::CodeBlock(_) => "Code blocks",
+            BlockNode::ThematicBreak => "Thematic breaks",
+            BlockNode::BlockQuote(_) => "Block quotes",
+            BlockNode::List(_) => "Lists",
+            BlockNode::Table(_) => "Tables",
+        };
+        self.errors
+            .push(format!("{kind} can't be represented in mobile strings"));
+    }
+
+    fn visit_code_span(&mut self, node: &CodeSpan) {
+        let content = vec![MobilePart::Text(node.content().clone())];
+        self.push(MobilePart::Styled(TextStyle::Code, content));
+    }
+
+    fn visit_emphasis(&mut self, node: &Emphasis) {
+        self.visit_styled(TextStyle::Emphasis, |this| node.visit_children_with(this));
+    }
+
+    fn visit_hook(&mut self, node: &Hook) {
+        self.errors.push(format!(
+            "The hook '$[...]({})' can't be represented in mobile strings",
+            node.name()
+        ));
+    }
+
+    fn visit_icu_date(&mut self, node: &IcuDate) {
+        self.errors.push(format!(
+            "The date '{}' can't be represented in mobile strings, since it is formatted by the message",
+            node.variable().name()
+        ));
+    }
+
+    fn visit_icu_number(&mut self, node: &IcuNumber) {
+        let name = node.variable().name();
+        if node.style().is_some() {
+            self.errors.push(format!(
+                "The styled number '{name}' can't be represented in mobile strings"
+            ));
+            return;
+        }
+        let index = self.argument(name, ArgumentKind::Number);
+        self.push(MobilePart::Argument(index));
+    }
+
+    fn visit_icu_plural(&mut self, node: &IcuPlural) {
+        let name = node.name();
+        if *node.kind() == IcuPluralKind::SelectOrdinal {
+            self.errors.push(format!(
+                "The ordinal '{name}' can't be represented in mobile strings"
+            ));
+            return;
+        }
+        if node.offset() != 0 {
+            self.errors.push(format!(
+                "The plural '{name}' uses an offset, which can't be represented in mobile strings"
+            ));
+            return;
+        }
+        if self.plural_count > 0 && !self.features.multiple_plurals {
+            self.errors.push(format!(
+                "The plural '{name}' can't be represented, since only one plural is supported per message"
+            ));
+            return;
+        }
+        self.plural_count += 1;
+
+        let index = self.argument(name, ArgumentKind::Number);
+        let mut forms: Vec<PluralForm> = vec![];
+        for arm in node.arms() {
+            let selector = arm.selector().as_str();
+            let category = match selector {
+                "zero" | "=0" if selector == "zero" || self.features.exact_zero => "zero",
+                "one" => "one",
+                "two" => "two",
+                "few" => "few",
+                "many" => "many",
+                "other" => "other",
+                _ => {
+                    self.errors.push(format!(
+                        "The option '{selector}' of the plural '{name}' can't be represented in mobile strings"
+                    ));
+                    continue;
+                }
+            };
+            if forms.iter().any(|form| form.category == category) {
+                self.errors.push(format!(
+                    "The plural '{name}' has more than one option for the '{category}' form"
+                ));
+                continue;
+            }
+
+            self.plurals.push(index);
+            let parts = self.collect(|this| arm.visit_children_with(this));
+            self.plurals.pop();
+            forms.push(PluralForm { category, parts });
+        }
+
+        if !forms.iter().any(|form| form.category == "other") {
+            self.errors.push(format!(
+                "The plural '{name}' must have an 'other' option to be represented in mobile strings"
+            ));
+        }
+        self.push(MobilePart::Plural(index, forms));
+    }
+
+    fn visit_icu_pound(&mut self) {
+        match self.plurals.last() {
+            Some(index) => self.push(MobilePart::Argument(*index)),
+            None => self.push_text("#"),
+        }
+    }
+
+    fn visit_icu_select(&mut self, node: &IcuSelect) {
+        self.errors.push(format!(
+            "The select '{}' can't be represented in mobile strings",
+            node.name()
+        ));
+    }
+
+    fn visit_icu_time(&mut self, node: &IcuTime) {
+        self.errors.push(format!(
+            "The time '{}' can't be represented in mobile strings, since it is formatted by the message",
+            node.variable().name()
+        ));
+    }
+
+    fn visit_icu_variable(&mut self, node: &IcuVariable) {
+        let index = self.argument(node.name(), ArgumentKind::String);
+        self.push(MobilePart::Argument(index));
+    }
+
+    fn visit_link(&mut self, node: &Link) {
+        let destination = match node.destination() {
+            LinkDestination::Text(url) => MobilePart::Text(url.clone()),
+            LinkDestination::Placeholder(Icu::IcuVariable(variable)) => {
+                MobilePart::Argument(self.argument(variable.name(), ArgumentKind::String))
+            }
+            LinkDestination::Placeholder(_) => {
+                self.errors.push(
+                    "Links can only use plain variables as their destination in mobile strings"
+                        .into(),
+                );
+                return;
+            }
+            LinkDestination::Handler(name) => {
+                self.errors.push(format!(
+                    "The link handler '{name}' can't be represented in mobile strings"
+                ));
+                return;
+            }
+        };
+        let label = self.collect(|this| {
+            for content in node.label() {
+                content.visit_with(this);
+            }
+        });
+        self.push(MobilePart::Link(Box::new(destination), label));
+    }
+
+    fn visit_strikethrough(&mut self, node: &Strikethrough) {
+        self.visit_styled(TextStyle::Strikethrough, |this| {
+            node.visit_children_with(this)
+        });
+    }
+
+    fn visit_strong(&mut self, node: &Strong) {
+        self.visit_styled(TextStyle::Strong, |this| node.visit_children_with(this));
+    }
+
+    fn visit_text(&mut self, node: &String) {
+        self.push_text(node);
+    }
+
+    fn visit_hard_line_break(&mut self) {
+        self.push_text("\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::MessageValue;
+
+    use super::{
+        convert_message, expand_plural, ArgumentKind, MobileFeatures, MobilePart, PluralForm,
+        TextStyle,
+    };
+
+    const ANDROID: MobileFeatures = MobileFeatures {
+        multiple_plurals: false,
+        exact_zero: false,
+    };
+    const APPLE: MobileFeatures = MobileFeatures {
+        multiple_plurals: true,
+        exact_zero: true,
+    };
+
+    fn convert(
+        content: &str,
+        features: MobileFeatures,
+    ) -> Result<super::MobileMessage, Vec<String>> {
+        convert_message(&MessageValue::from_raw(content).parsed, features)
+    }
+
+    #[test]
+    fn arguments() {
+        let message = convert(
+            "Hi {name}, you have {count, number} new **{kind}**",
+            ANDROID,
+        )
+        .unwrap();
+        assert_eq!(
+            message.parts,
+            vec![
+                MobilePart::Text("Hi ".into()),
+                MobilePart::Argument(0),
+                MobilePart::Text(", you have ".into()),
+                MobilePart::Argument(1),
+                MobilePart::Text(" new ".into()),
+                MobilePart::Styled(TextStyle::Strong, vec![MobilePart::Argument(2)]),
+            ]
+        );
+        let kinds: Vec<_> = message.arguments.iter().map(|arg| arg.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ArgumentKind::String,
+                ArgumentKind::Number,
+                ArgumentKind::String
+            ]
+        );
+        assert_eq!(
+            message.argument_list().as_deref(),
+            Some("Arguments: 1: name, 2: count, 3: kind")
+        );
+    }
+
+    #[test]
+    fn expanded_plural() {
+        let message = convert(
+            "{user} has {count, plural, one {# item} other {# items}}.",
+            ANDROID,
+        )
+        .unwrap();
+        assert!(message.has_plural());
+        let (argument, forms) = expand_plural(&message.parts).unwrap();
+        assert_eq!(argument, 1);
+        assert_eq!(
+            forms[0],
+            PluralForm {
+                category: "one",
+                parts: vec![
+                    MobilePart::Argument(0),
+                    MobilePart::Text(" has ".into()),
+                    MobilePart::Argument(1),
+                    MobilePart::Text(" item".into()),
+                    MobilePart::Text(".".into()),
+                ],
+            }
+        );
+        assert_eq!(forms[1].category, "other");
+    }
+
+    #[test]
+    fn unrepresentable_content() {
+        assert_eq!(
+            convert(
+                "$[Click](mention), [go](onClick) and {kind, select, a {A} other {B}}",
+                APPLE
+            )
+            .unwrap_err(),
+            vec![
+                "The hook '$[...](mention)' can't be represented in mobile strings",
+                "The link handler 'onClick' can't be represented in mobile strings",
+                "The select 'kind' can't be represented in mobile strings",
+            ]
+        );
+        assert_eq!(
+            convert("{a, plural, other {{b, plural, other {#}}}}", ANDROID).unwrap_err(),
+            vec!["The plural 'b' can't be represented, since only one plural is supported per message"]
+        );
+        assert!(convert("{a, plural, other {{b, plural, other {#}}}}", APPLE).is_ok());
+    }
+
+    #[test]
+    fn exact_selectors() {
+        let content = "{count, plural, =0 {None} one {#} other {#}}";
+        assert_eq!(
+            convert(content, ANDROID).unwrap_err(),
+            vec!["The option '=0' of the plural 'count' can't be represented in mobile strings"]
+        );
+        let message = convert(content, APPLE).unwrap();
+        let MobilePart::Plural(_, forms) = &message.parts[0] else {
+            panic!("Expected a plural");
+        };
+        assert_eq!(forms[0].category, "zero");
+    }
+}
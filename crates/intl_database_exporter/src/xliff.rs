@@ -0,0 +1,445 @@
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use intl_database_core::{
+    key_symbol, KeySymbol, MessageValue, MessagesDatabase, SourceFile, DEFAULT_LOCALE,
+};
+use intl_database_service::IntlDatabaseService;
+use intl_database_xliff_source::{split_placeholders, MessageSegment, XliffVersion};
+use rustc_hash::FxHashMap;
+
+use crate::gettext::relative_path;
+
+/// The messages from a single definition file to include in an XLIFF document.
+struct XliffFile {
+    source_file_path: PathBuf,
+    source_locale: KeySymbol,
+    keys: BTreeSet<KeySymbol>,
+}
+
+/// A service for exporting the messages in a [MessagesDatabase] as XLIFF documents, for
+/// translation tools that exchange XLIFF rather than the JSON translation files.
+///
+/// Each translations directory gets a `<locale>.messages.xlf` document for every known locale
+/// other than the source locale, containing a `<file>` for each definition file that stores its
+/// translations there. Every message includes its source text, its current translation if it has
+/// one, its description as a note, and the state of the translation. The ICU syntax of each value
+/// is written as inline `<ph>` elements so that translation tools protect it from being changed.
+///
+/// Messages with `translate: false` are not included, since they shouldn't be sent for
+/// translation. The result of this service is the list of file paths that were written.
+pub struct ExportXliff<'a> {
+    database: &'a MessagesDatabase,
+    version: XliffVersion,
+}
+
+impl<'a> ExportXliff<'a> {
+    pub fn new(database: &'a MessagesDatabase, version: XliffVersion) -> Self {
+        Self { database, version }
+    }
+
+    /// Format the XLIFF document translating all of the given `files` into `locale`. References
+    /// to source files are written relative to `directory`.
+    fn format_document(
+        &self,
+        locale: KeySymbol,
+        files: &[XliffFile],
+        directory: Option<&Path>,
+    ) -> String {
+        let mut content = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        match self.version {
+            XliffVersion::V1_2 => content.push_str(
+                "<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n",
+            ),
+            XliffVersion::V2_0 => writeln!(
+                content,
+                "<xliff version=\"2.0\" xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" srcLang=\"{}\" trgLang=\"{}\">",
+                escape_xml(files.first().map_or(DEFAULT_LOCALE, |file| &file.source_locale)),
+                escape_xml(&locale)
+            )
+            .unwrap(),
+        }
+
+        for (index, file) in files.iter().enumerate() {
+            let original = match directory {
+                Some(directory) => relative_path(directory, &file.source_file_path),
+                None => file.source_file_path.clone(),
+            };
+            let original = escape_xml(&original.display().to_string());
+            match self.version {
+                XliffVersion::V1_2 => {
+                    writeln!(
+                        content,
+                        "  <file original=\"{original}\" source-language=\"{}\" target-language=\"{}\" datatype=\"plaintext\">",
+                        escape_xml(&file.source_locale),
+                        escape_xml(&locale)
+                    )
+                    .unwrap();
+                    content.push_str("    <body>\n");
+                }
+                XliffVersion::V2_0 => {
+                    writeln!(
+                        content,
+                        "  <file id=\"f{}\" original=\"{original}\">",
+                        index + 1
+                    )
+                    .unwrap();
+                }
+            }
+
+            for key in &file.keys {
+                let Some(message) = self.database.get_message(key) else {
+                    continue;
+                };
+                let Some(source) = message.get_source_translation() else {
+                    continue;
+                };
+                let target = message.translations().get(&locale);
+                let description = message.meta().description.as_deref();
+                match self.version {
+                    XliffVersion::V1_2 => {
+                        write_trans_unit(&mut content, key, source, target, description)
+                    }
+                    XliffVersion::V2_0 => {
+                        write_unit(&mut content, key, source, target, description)
+                    }
+                }
+            }
+
+            match self.version {
+                XliffVersion::V1_2 => content.push_str("    </body>\n  </file>\n"),
+                XliffVersion::V2_0 => content.push_str("  </file>\n"),
+            }
+        }
+        content.push_str("</xliff>\n");
+        content
+    }
+}
+
+impl IntlDatabaseService for ExportXliff<'_> {
+    type Result = anyhow::Result<Vec<String>>;
+
+    fn run(&mut self) -> Self::Result {
+        let definition_files = self
+            .database
+            .sources
+            .values()
+            .filter_map(|source| match source {
+                SourceFile::Definition(definition) => Some(definition),
+                _ => None,
+            });
+
+        let mut documents: FxHashMap<PathBuf, (KeySymbol, Vec<XliffFile>)> = FxHashMap::default();
+        for file in definition_files {
+            let file_locale = file
+                .meta()
+                .source_locale
+                .unwrap_or_else(|| key_symbol(DEFAULT_LOCALE));
+            let messages: Vec<_> = file
+                .message_keys()
+                .iter()
+                .filter_map(|key| self.database.get_message(key))
+                .filter(|message| message.meta().translate)
+                .collect();
+
+            for locale in &self.database.known_locales {
+                // Messages written in this locale don't need translating, and files where that's
+                // every message aren't included in its document at all.
+                let keys: BTreeSet<KeySymbol> = messages
+                    .iter()
+                    .filter(|message| {
+                        !message
+                            .source_locale()
+                            .is_some_and(|source| source == *locale)
+                    })
+                    .map(|message| message.key())
+                    .collect();
+                if keys.is_empty() {
+                    continue;
+                }
+                let path = file
+                    .meta()
+                    .get_translations_path(locale, Some("messages.xlf"));
+                let (_, files) = documents.entry(path).or_insert_with(|| (*locale, vec![]));
+                files.push(XliffFile {
+                    source_file_path: file.meta().source_file_path.clone(),
+                    source_locale: file_locale,
+                    keys,
+                });
+            }
+        }
+
+        let mut affected_files = vec![];
+        for (path, (locale, mut files)) in documents {
+            affected_files.push(path.display().to_string());
+
+            let directory = path.parent();
+            if let Some(directory) = directory {
+                std::fs::create_dir_all(directory)?;
+            }
+
+            files.sort_by(|a, b| a.source_file_path.cmp(&b.source_file_path));
+            let content = self.format_document(locale, &files, directory);
+            let mut output = std::fs::File::create(&path)?;
+            output.write_all(content.as_bytes())?;
+        }
+
+        Ok(affected_files)
+    }
+}
+
+fn write_trans_unit(
+    content: &mut String,
+    key: &str,
+    source: &MessageValue,
+    target: Option<&MessageValue>,
+    description: Option<&str>,
+) {
+    writeln!(content, "      <trans-unit id=\"{}\">", escape_xml(key)).unwrap();
+    writeln!(
+        content,
+        "        <source>{}</source>",
+        inline_1_2(&source.raw)
+    )
+    .unwrap();
+    if let Some(target) = target {
+        let state = match target.fuzzy {
+            true => "needs-review-translation",
+            false => "translated",
+        };
+        writeln!(
+            content,
+            "        <target state=\"{state}\">{}</target>",
+            inline_1_2(&target.raw)
+        )
+        .unwrap();
+    }
+    if let Some(description) = description {
+        writeln!(content, "        <note>{}</note>", escape_xml(description)).unwrap();
+    }
+    content.push_str("      </trans-unit>\n");
+}
+
+fn write_unit(
+    content: &mut String,
+    key: &str,
+    source: &MessageValue,
+    target: Option<&MessageValue>,
+    description: Option<&str>,
+) {
+    writeln!(content, "    <unit id=\"{}\">", escape_xml(key)).unwrap();
+    if let Some(description) = description {
+        content.push_str("      <notes>\n");
+        writeln!(content, "        <note>{}</note>", escape_xml(description)).unwrap();
+        content.push_str("      </notes>\n");
+    }
+
+    // Placeholders are stored once each in the original data, and referenced from both the
+    // source and the target.
+    let mut original_data: Vec<&str> = vec![];
+    let source_content = inline_2_0(&source.raw, &mut original_data);
+    let target_content = target.map(|target| inline_2_0(&target.raw, &mut original_data));
+    if !original_data.is_empty() {
+        content.push_str("      <originalData>\n");
+        for (index, data) in original_data.iter().enumerate() {
+            writeln!(
+                content,
+                "        <data id=\"d{}\">{}</data>",
+                index + 1,
+                escape_xml(data)
+            )
+            .unwrap();
+        }
+        content.push_str("      </originalData>\n");
+    }
+
+    let state = match target {
+        Some(target) if !target.fuzzy => "translated",
+        _ => "initial",
+    };
+    writeln!(content, "      <segment state=\"{state}\">").unwrap();
+    writeln!(content, "        <source>{source_content}</source>").unwrap();
+    if let Some(target_content) = target_content {
+        writeln!(content, "        <target>{target_content}</target>").unwrap();
+    }
+    content.push_str("      </segment>\n    </unit>\n");
+}
+
+/// Format `value` as XLIFF 1.2 inline content, with each placeholder written as a `<ph>` element
+/// containing the original ICU syntax.
+fn inline_1_2(value: &str) -> String {
+    let mut result = String::new();
+    let mut next_id = 1;
+    for segment in split_placeholders(value) {
+        match segment {
+            MessageSegment::Text(text) => result.push_str(&escape_xml(text)),
+            MessageSegment::Placeholder(placeholder) => {
+                write!(
+                    result,
+                    "<ph id=\"{next_id}\">{}</ph>",
+                    escape_xml(placeholder)
+                )
+                .unwrap();
+                next_id += 1;
+            }
+        }
+    }
+    result
+}
+
+/// Format `value` as XLIFF 2.0 inline content, with each placeholder written as a `<ph>` element
+/// referencing its ICU syntax in `original_data`. The same placeholder in the source and target
+/// gets the same id, so that translation tools can match them.
+fn inline_2_0<'a>(value: &'a str, original_data: &mut Vec<&'a str>) -> String {
+    let mut result = String::new();
+    let mut occurrences: FxHashMap<usize, usize> = FxHashMap::default();
+    for segment in split_placeholders(value) {
+        match segment {
+            MessageSegment::Text(text) => result.push_str(&escape_xml(text)),
+            MessageSegment::Placeholder(placeholder) => {
+                let index = match original_data.iter().position(|data| *data == placeholder) {
+                    Some(index) => index,
+                    None => {
+                        original_data.push(placeholder);
+                        original_data.len() - 1
+                    }
+                };
+                let occurrence = occurrences.entry(index).or_default();
+                *occurrence += 1;
+                // Ids must be unique within the content, so repeated placeholders are numbered.
+                let id = match *occurrence {
+                    1 => format!("{}", index + 1),
+                    occurrence => format!("{}-{occurrence}", index + 1),
+                };
+                write!(result, "<ph id=\"{id}\" dataRef=\"d{}\"/>", index + 1).unwrap();
+            }
+        }
+    }
+    result
+}
+
+pub(crate) fn escape_xml(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::path::{Path, PathBuf};
+
+    use intl_database_core::{key_symbol, MessageMeta, MessageValue, MessagesDatabase};
+    use intl_database_xliff_source::{parse_xliff, XliffVersion};
+
+    use super::{ExportXliff, XliffFile};
+
+    fn database() -> MessagesDatabase {
+        let mut database = MessagesDatabase::new();
+        for (name, value, meta) in [
+            (
+                "GREETING",
+                "Hello {name} & {name}",
+                MessageMeta::default().with_description("Greets <the> user"),
+            ),
+            (
+                "ITEMS",
+                "{count, plural, one {# item} other {# items}}",
+                MessageMeta::default(),
+            ),
+        ] {
+            database
+                .insert_definition(
+                    name,
+                    MessageValue::from_raw(value),
+                    key_symbol("en-US"),
+                    meta,
+                    false,
+                )
+                .unwrap();
+        }
+        database
+            .insert_translation(
+                key_symbol("GREETING"),
+                key_symbol("fr"),
+                MessageValue::from_raw("Bonjour {name} & {name}").with_fuzzy(true),
+                false,
+            )
+            .unwrap();
+        database
+    }
+
+    fn files() -> Vec<XliffFile> {
+        vec![XliffFile {
+            source_file_path: PathBuf::from("/project/src/Feature.messages.js"),
+            source_locale: key_symbol("en-US"),
+            keys: BTreeSet::from([key_symbol("GREETING"), key_symbol("ITEMS")]),
+        }]
+    }
+
+    #[test]
+    fn format_xliff_1_2() {
+        let database = database();
+        let exporter = ExportXliff::new(&database, XliffVersion::V1_2);
+        let content = exporter.format_document(
+            key_symbol("fr"),
+            &files(),
+            Some(Path::new("/project/src/i18n")),
+        );
+        assert_eq!(
+            content,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file original="../Feature.messages.js" source-language="en-US" target-language="fr" datatype="plaintext">
+    <body>
+      <trans-unit id="GREETING">
+        <source>Hello <ph id="1">{name}</ph> &amp; <ph id="2">{name}</ph></source>
+        <target state="needs-review-translation">Bonjour <ph id="1">{name}</ph> &amp; <ph id="2">{name}</ph></target>
+        <note>Greets &lt;the&gt; user</note>
+      </trans-unit>
+      <trans-unit id="ITEMS">
+        <source><ph id="1">{count, plural, one {</ph># item<ph id="2">} other {</ph># items<ph id="3">}}</ph></source>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>
+"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let database = database();
+        for version in [XliffVersion::V1_2, XliffVersion::V2_0] {
+            let exporter = ExportXliff::new(&database, version);
+            let content = exporter.format_document(key_symbol("fr"), &files(), None);
+            let document = parse_xliff(&content).unwrap();
+            assert_eq!(document.version, version);
+
+            let greeting = &document.units[0];
+            assert_eq!(greeting.key, "GREETING");
+            assert_eq!(greeting.source, "Hello {name} & {name}");
+            assert_eq!(greeting.notes, vec!["Greets <the> user"]);
+            let target = greeting.target.as_ref().unwrap();
+            assert_eq!(target.value, "Bonjour {name} & {name}");
+            assert!(target.needs_review(version));
+
+            let items = &document.units[1];
+            assert_eq!(
+                items.source,
+                "{count, plural, one {# item} other {# items}}"
+            );
+            assert!(items.target.is_none());
+        }
+    }
+}
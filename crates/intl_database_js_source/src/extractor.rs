@@ -1,20 +1,58 @@
 use std::borrow::{Borrow, Cow};
+use swc_common::errors::SourceMapper;
 use swc_common::source_map::Pos;
 use swc_common::sync::Lrc;
-use swc_common::{BytePos, FileName, SourceMap, Spanned};
+use swc_common::{BytePos, FileName, SourceMap, Span, Spanned};
 use swc_core::ecma::ast::{
-    ExportDecl, ExportDefaultExpr, Expr, Id, ImportDecl, ImportSpecifier, Lit, Module, ObjectLit,
+    ExportDecl, ExportDefaultExpr, Expr, Id, ImportDecl, ImportSpecifier, Lit, Module,
+    ModuleExportName, ObjectLit,
+};
+use swc_core::ecma::parser::{
+    lexer::Lexer, EsConfig, PResult, Parser, StringInput, Syntax, TsConfig,
 };
-use swc_core::ecma::parser::{lexer::Lexer, PResult, Parser, StringInput, Syntax};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 use unescape_zero_copy::unescape_default;
 
 use intl_database_core::{
-    MessageMeta, MessageSourceError, MessageSourceResult, RawMessageDefinition, RawPosition,
-    SourceFileMeta,
+    key_symbol, MessageMeta, MessageSourceDiagnostic, MessageSourceError, MessageSourceResult,
+    RawMessageDefinition, RawPosition, SourceFileMeta,
 };
 use intl_message_utils::RUNTIME_PACKAGE_NAME;
 
+/// Return the syntax to parse the given file with, based on its extension. Anything that isn't
+/// TypeScript or JSX is parsed as plain JavaScript.
+fn syntax_for_file_name(file_name: &str) -> Syntax {
+    if file_name.ends_with(".ts") {
+        Syntax::Typescript(TsConfig::default())
+    } else if file_name.ends_with(".tsx") {
+        Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        })
+    } else if file_name.ends_with(".jsx") {
+        Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        })
+    } else {
+        Syntax::Es(Default::default())
+    }
+}
+
+/// Return the expression underneath any parentheses and TypeScript-only wrappers, like `as`,
+/// `satisfies`, and non-null assertions, which don't change the value at runtime.
+fn unwrap_expression(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(paren) => unwrap_expression(&paren.expr),
+        Expr::TsAs(as_expr) => unwrap_expression(&as_expr.expr),
+        Expr::TsSatisfies(satisfies) => unwrap_expression(&satisfies.expr),
+        Expr::TsConstAssertion(assertion) => unwrap_expression(&assertion.expr),
+        Expr::TsTypeAssertion(assertion) => unwrap_expression(&assertion.expr),
+        Expr::TsNonNull(non_null) => unwrap_expression(&non_null.expr),
+        _ => expr,
+    }
+}
+
 pub fn parse_message_definitions_file(
     file_name: &str,
     source: &str,
@@ -23,7 +61,7 @@ pub fn parse_message_definitions_file(
 
     let fm = cm.new_source_file(FileName::Custom(file_name.into()), source.into());
     let lexer = Lexer::new(
-        Syntax::Es(Default::default()),
+        syntax_for_file_name(file_name),
         Default::default(),
         StringInput::from(&*fm),
         None,
@@ -47,7 +85,7 @@ pub fn extract_message_definitions(
 /// A Visitor to extract message definitions from a source AST.
 pub struct MessageDefinitionsExtractor {
     pub message_definitions: Vec<RawMessageDefinition>,
-    pub failed_definitions: Vec<MessageSourceError>,
+    pub failed_definitions: Vec<MessageSourceDiagnostic>,
     pub root_meta: SourceFileMeta,
     define_messages_id: Option<Id>,
     source_map: Lrc<SourceMap>,
@@ -79,11 +117,12 @@ impl MessageDefinitionsExtractor {
                 continue;
             };
 
-            let parse_result = if let Some(object) = keyvalue.value.as_object() {
+            let value = unwrap_expression(&keyvalue.value);
+            let parse_result = if let Some(object) = value.as_object() {
                 self.parse_complete_definition(&name, &object)
-            } else if let Some(lit @ Lit::Str(string)) = keyvalue.value.as_lit() {
-                self.parse_oneline_definition(&name, &string.value, lit.span_lo())
-            } else if let Some(template) = keyvalue.value.as_tpl() {
+            } else if let Some(lit @ Lit::Str(string)) = value.as_lit() {
+                self.parse_oneline_definition(&name, &string.value, lit.span())
+            } else if let Some(template) = value.as_tpl() {
                 // With JS, you can write static strings as template strings to
                 // avoid needing to escape different quotes, like:
                 //     SOME_STRING: `"this" is valid, isn't it?`
@@ -93,7 +132,7 @@ impl MessageDefinitionsExtractor {
                 let is_static = template.quasis.len() == 1 && template.exprs.len() == 0;
 
                 match string_value {
-                    Some(string) if is_static => self.parse_oneline_definition(&name, &string, template.span_lo()),
+                    Some(string) if is_static => self.parse_oneline_definition(&name, &string, template.span()),
                     _ => Err(MessageSourceError::DefinitionRestrictionViolated("Encountered non-static template string. Interpolations are currently invalid".into()))
                 }
             } else {
@@ -104,21 +143,26 @@ impl MessageDefinitionsExtractor {
 
             match parse_result {
                 Ok(definition) => self.message_definitions.push(definition),
-                Err(error) => self.failed_definitions.push(error),
+                Err(error) => self.failed_definitions.push(MessageSourceDiagnostic {
+                    name: Some(key_symbol(&name)),
+                    position: self.position_of(keyvalue.key.span().lo),
+                    error,
+                }),
             }
         }
     }
 
     /// Parse a single message definition into a structured object, resolving
-    /// all meta information needed for it.
+    /// all meta information needed for it. Meta properties with invalid values
+    /// are skipped and reported in `self.failed_definitions`.
     fn parse_complete_definition(
-        &self,
+        &mut self,
         key: &str,
         object: &ObjectLit,
     ) -> MessageSourceResult<RawMessageDefinition> {
         let mut default_value: Option<String> = None;
         let mut local_meta = self.clone_meta();
-        let mut message_loc = BytePos::default();
+        let mut message_span = Span::default();
 
         for property in object.props.iter() {
             let Some(keyvalue) = property.as_prop().and_then(|prop| prop.as_key_value()) else {
@@ -130,12 +174,22 @@ impl MessageDefinitionsExtractor {
 
             match name.sym.as_str() {
                 "message" => {
-                    message_loc = keyvalue.value.span_lo();
+                    message_span = unwrap_expression(&keyvalue.value).span();
                     self.parse_string_value(keyvalue.value.borrow())
                         .map(|value| default_value = Some(value));
                 }
                 name => {
-                    self.parse_message_meta_property(name, keyvalue.value.borrow(), &mut local_meta)
+                    if !self.parse_message_meta_property(
+                        name,
+                        keyvalue.value.borrow(),
+                        &mut local_meta,
+                    ) {
+                        self.failed_definitions.push(MessageSourceDiagnostic {
+                            name: Some(key_symbol(key)),
+                            position: self.position_of(keyvalue.value.span().lo),
+                            error: MessageSourceError::InvalidMessageMeta(key.into()),
+                        });
+                    }
                 }
             }
         }
@@ -146,17 +200,13 @@ impl MessageDefinitionsExtractor {
             return Err(MessageSourceError::NoMessageValue(key.into()));
         };
 
-        let loc = self.source_map.lookup_char_pos(message_loc);
-
-        Ok(RawMessageDefinition::new(
+        let definition = RawMessageDefinition::new(
             key.into(),
-            RawPosition {
-                line: loc.line as u32,
-                col: loc.col.to_u32(),
-            },
+            self.position_of(message_span.lo),
             default_value,
             local_meta,
-        ))
+        );
+        Ok(self.attach_source_literal(definition, message_span))
     }
 
     /// Parse a message definition using the shorthand `name: "value"`
@@ -164,18 +214,44 @@ impl MessageDefinitionsExtractor {
         &self,
         key: &str,
         value: &str,
-        pos: BytePos,
+        span: Span,
     ) -> MessageSourceResult<RawMessageDefinition> {
-        let loc = self.source_map.lookup_char_pos(pos);
-        Ok(RawMessageDefinition::new(
+        let definition = RawMessageDefinition::new(
             key.into(),
-            RawPosition {
-                line: loc.line as u32,
-                col: loc.col.to_u32(),
-            },
+            self.position_of(span.lo),
             self.apply_string_escapes(value),
             self.clone_meta(),
-        ))
+        );
+        Ok(self.attach_source_literal(definition, span))
+    }
+
+    fn position_of(&self, pos: BytePos) -> RawPosition {
+        let loc = self.source_map.lookup_char_pos(pos);
+        RawPosition {
+            line: loc.line as u32,
+            col: loc.col.to_u32(),
+        }
+    }
+
+    /// Give the definition the content of the quoted literal at `span` as it was written in the
+    /// source, so that positions within the message can be mapped back to the file.
+    fn attach_source_literal(
+        &self,
+        definition: RawMessageDefinition,
+        span: Span,
+    ) -> RawMessageDefinition {
+        let Ok(snippet) = self.source_map.span_to_snippet(span) else {
+            return definition;
+        };
+        let mut chars = snippet.chars();
+        let quote = chars.next();
+        if !matches!(quote, Some('"' | '\'' | '`')) || chars.next_back() != quote {
+            return definition;
+        }
+        // The literal starts after the opening quote.
+        let mut start = self.position_of(span.lo);
+        start.col += 1;
+        definition.with_source_literal(chars.as_str(), start)
     }
 
     /// Return a clone of the root meta, or a new object with the default
@@ -195,18 +271,23 @@ impl MessageDefinitionsExtractor {
                 continue;
             };
 
-            self.parse_source_file_meta_property(&name.sym, keyvalue.value.borrow());
+            if !self.parse_source_file_meta_property(&name.sym, keyvalue.value.borrow()) {
+                self.failed_definitions.push(MessageSourceDiagnostic {
+                    name: None,
+                    position: self.position_of(keyvalue.value.span().lo),
+                    error: MessageSourceError::InvalidSourceFileMeta,
+                });
+            }
         }
     }
 
     /// Interpret a given name/value pair to see if it represents a SourceFileMeta
     /// property. If it does, apply the value to the corresponding field in
     /// `target`. Otherwise, nothing is done.
-    fn parse_source_file_meta_property(&mut self, name: &str, value: &Expr) {
-        // NOTE: This effectively relies on TypeScript's checker to provide
-        // hints when the value types would be incorrect (e.g., `secret` is
-        // given a number value instead of a boolean).
-        match name {
+    ///
+    /// Returns false if the name is a known property but the value is not valid for it.
+    fn parse_source_file_meta_property(&mut self, name: &str, value: &Expr) -> bool {
+        let result = match name {
             "secret" => self
                 .parse_boolean_value(value)
                 .map(|value| self.root_meta.secret = value),
@@ -219,18 +300,29 @@ impl MessageDefinitionsExtractor {
             "description" => self
                 .parse_string_value(value)
                 .map(|value| self.root_meta.description = Some(value)),
-            _ => None,
+            "suppressDiagnostics" => self
+                .parse_string_array_value(value)
+                .map(|value| self.root_meta.suppress_diagnostics = value),
+            "sourceLocale" => self
+                .parse_string_value(value)
+                .map(|value| self.root_meta.source_locale = Some(key_symbol(&value))),
+            _ => return true,
         };
+        result.is_some()
     }
 
     /// Interpret a given name/value pair to see if it represents a MessageMeta
     /// property. If it does, apply the value to the corresponding field in
     /// `target`. Otherwise, nothing is done.
-    fn parse_message_meta_property(&self, name: &str, value: &Expr, target: &mut MessageMeta) {
-        // NOTE: This effectively relies on TypeScript's checker to provide
-        // hints when the value types would be incorrect (e.g., `secret` is
-        // given a number value instead of a boolean).
-        match name {
+    ///
+    /// Returns false if the name is a known property but the value is not valid for it.
+    fn parse_message_meta_property(
+        &self,
+        name: &str,
+        value: &Expr,
+        target: &mut MessageMeta,
+    ) -> bool {
+        let result = match name {
             "secret" => self
                 .parse_boolean_value(value)
                 .map(|value| target.secret = value),
@@ -240,14 +332,22 @@ impl MessageDefinitionsExtractor {
             "description" => self
                 .parse_string_value(value)
                 .map(|value| target.description = Some(value)),
-            _ => None,
+            // Suppressions from the source file meta still apply, so these are added on top.
+            "suppressDiagnostics" => self
+                .parse_string_array_value(value)
+                .map(|value| target.suppress_diagnostics.extend(value)),
+            "sourceLocale" => self
+                .parse_string_value(value)
+                .map(|value| target.source_locale = Some(key_symbol(&value))),
+            _ => return true,
         };
+        result.is_some()
     }
 
     /// If the given expression is a boolean literal, it is interpreted into an
     /// actual boolean value. Any other expression will return None.
     fn parse_boolean_value(&self, expr: &Expr) -> Option<bool> {
-        match expr.as_lit() {
+        match unwrap_expression(expr).as_lit() {
             Some(Lit::Bool(bool)) => Some(bool.value),
             _ => None,
         }
@@ -256,12 +356,26 @@ impl MessageDefinitionsExtractor {
     /// If the given expression is a string literal, the value of that literal
     /// is returned. Any other expression will return None.
     fn parse_string_value(&self, expr: &Expr) -> Option<String> {
-        match expr.as_lit() {
+        match unwrap_expression(expr).as_lit() {
             Some(Lit::Str(string)) => Some(self.apply_string_escapes(&string.value).to_string()),
             _ => None,
         }
     }
 
+    /// If the given expression is an array literal containing only string literals, the values of
+    /// those literals are returned. Any other expression will return None.
+    fn parse_string_array_value(&self, expr: &Expr) -> Option<Vec<String>> {
+        let array = unwrap_expression(expr).as_array()?;
+        array
+            .elems
+            .iter()
+            .map(|element| match element {
+                Some(element) if element.spread.is_none() => self.parse_string_value(&element.expr),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Apply literal escape sequences like `\n` from the string value.
     fn apply_string_escapes<'a>(&self, value: &'a str) -> Cow<'a, str> {
         unescape_default(value).unwrap_or(Cow::from(value))
@@ -286,13 +400,20 @@ impl Visit for MessageDefinitionsExtractor {
                 continue;
             }
 
-            if let Some(initializer) = decl.init.as_ref().and_then(|init| init.as_object()) {
+            if let Some(initializer) = decl
+                .init
+                .as_ref()
+                .and_then(|init| unwrap_expression(init).as_object())
+            {
                 self.parse_root_meta_initializer(initializer);
             } else {
                 // We've found the meta and determined it didn't have an
                 // initializer, so we don't need to continue iterating.
-                // TODO: Use this error.
-                drop(MessageSourceError::InvalidSourceFileMeta);
+                self.failed_definitions.push(MessageSourceDiagnostic {
+                    name: None,
+                    position: self.position_of(decl.span.lo),
+                    error: MessageSourceError::InvalidSourceFileMeta,
+                });
                 break;
             }
         }
@@ -300,7 +421,7 @@ impl Visit for MessageDefinitionsExtractor {
 
     // Captures `defineMessages` calls as the default export.
     fn visit_export_default_expr(&mut self, default_export: &ExportDefaultExpr) {
-        let Some(call_expr) = default_export.expr.as_call() else {
+        let Some(call_expr) = unwrap_expression(&default_export.expr).as_call() else {
             return;
         };
 
@@ -322,7 +443,10 @@ impl Visit for MessageDefinitionsExtractor {
         }
 
         // If it has an object expression as the first argument
-        if let Some(definition_object) = call_expr.args.get(0).and_then(|arg| arg.expr.as_object())
+        if let Some(definition_object) = call_expr
+            .args
+            .get(0)
+            .and_then(|arg| unwrap_expression(&arg.expr).as_object())
         {
             self.parse_definitions_object(definition_object);
         }
@@ -330,14 +454,27 @@ impl Visit for MessageDefinitionsExtractor {
 
     fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
         let import_source_path = &import_decl.src.value;
-        if import_source_path != RUNTIME_PACKAGE_NAME {
+        if import_source_path != RUNTIME_PACKAGE_NAME || import_decl.type_only {
             return;
         }
 
         for spec in import_decl.specifiers.iter() {
             match spec {
-                ImportSpecifier::Named(specifier) => {
-                    self.define_messages_id = Some(specifier.local.to_id());
+                // Type imports, like `import {type MessagesMeta}`, can't be `defineMessages`.
+                ImportSpecifier::Named(specifier) if !specifier.is_type_only => {
+                    let imported_name = match &specifier.imported {
+                        Some(ModuleExportName::Ident(ident)) => &ident.sym,
+                        Some(ModuleExportName::Str(string)) => &string.value,
+                        None => &specifier.local.sym,
+                    };
+                    if imported_name == "defineMessages" {
+                        self.define_messages_id = Some(specifier.local.to_id());
+                        break;
+                    }
+                    // Otherwise, fall back to the first named import.
+                    if self.define_messages_id.is_none() {
+                        self.define_messages_id = Some(specifier.local.to_id());
+                    }
                 }
                 _ => continue,
             }
@@ -347,9 +484,9 @@ impl Visit for MessageDefinitionsExtractor {
 
 #[cfg(test)]
 mod tests {
-    use intl_database_core::key_symbol;
+    use intl_database_core::{key_symbol, MessageSourceError};
 
-    use super::parse_message_definitions_file;
+    use super::{extract_message_definitions, parse_message_definitions_file};
 
     #[test]
     fn test_parsing() {
@@ -378,4 +515,228 @@ mod tests {
 
         let file_symbol = key_symbol("testing.js");
     }
+
+    #[test]
+    fn test_source_literal_positions() {
+        let source = format!(
+            "import {{defineMessages}} from '{}';\n\nexport default defineMessages({{\n  ESCAPED: 'a\\n\\'b\\' {{name}}',\n}});\n",
+            intl_message_utils::RUNTIME_PACKAGE_NAME
+        );
+        let (source_map, module) = parse_message_definitions_file("testing.js", &source)
+            .expect("failed to parse source code");
+        let extractor = extract_message_definitions("testing.js", source_map, module);
+        let definition = &extractor.message_definitions[0];
+        let raw = &definition.value.raw;
+        assert_eq!(raw, "a\n'b' {name}");
+
+        let position = definition
+            .value
+            .source_offsets
+            .as_ref()
+            .expect("definition should have source offsets")
+            .position_at(raw, raw.find("name").unwrap());
+        assert_eq!((position.line, position.col), (4, 22));
+    }
+
+    #[test]
+    fn test_source_locale_meta() {
+        let source = format!(
+            r#"
+        import {{defineMessages}} from '{}';
+
+        export const meta = {{
+            sourceLocale: 'fr',
+        }};
+
+        export default defineMessages({{
+            FROM_FILE: 'Bonjour',
+            FROM_MESSAGE: {{
+                message: 'Hallo',
+                sourceLocale: 'de',
+            }},
+        }});
+        "#,
+            intl_message_utils::RUNTIME_PACKAGE_NAME
+        );
+        let (source_map, module) = parse_message_definitions_file("testing.js", &source)
+            .expect("failed to parse source code");
+        let extractor = extract_message_definitions("testing.js", source_map, module);
+        assert_eq!(extractor.root_meta.source_locale, Some(key_symbol("fr")));
+
+        let locales: Vec<_> = extractor
+            .message_definitions
+            .iter()
+            .map(|definition| definition.meta.source_locale)
+            .collect();
+        assert_eq!(
+            locales,
+            vec![Some(key_symbol("fr")), Some(key_symbol("de"))]
+        );
+    }
+
+    #[test]
+    fn test_typescript_definitions() {
+        let source = format!(
+            r#"
+        import {{defineMessages, type MessagesMeta}} from '{}';
+
+        export const meta: MessagesMeta = {{
+            secret: true,
+            description: 'Typed meta' as string,
+        }} satisfies MessagesMeta;
+
+        export default defineMessages({{
+            ONELINE: 'One line' as const,
+            COMPLETE: {{
+                message: 'Complete',
+                translate: false as boolean,
+            }},
+        }} as const);
+        "#,
+            intl_message_utils::RUNTIME_PACKAGE_NAME
+        );
+        let (source_map, module) = parse_message_definitions_file("testing.messages.ts", &source)
+            .expect("failed to parse source code");
+        let extractor = extract_message_definitions("testing.messages.ts", source_map, module);
+        assert!(extractor.root_meta.secret);
+        assert_eq!(
+            extractor.root_meta.description.as_deref(),
+            Some("Typed meta")
+        );
+
+        let definitions: Vec<_> = extractor
+            .message_definitions
+            .iter()
+            .map(|definition| (definition.name.as_str(), definition.value.raw.as_str()))
+            .collect();
+        assert_eq!(
+            definitions,
+            vec![("ONELINE", "One line"), ("COMPLETE", "Complete")]
+        );
+        assert!(!extractor.message_definitions[1].meta.translate);
+    }
+
+    #[test]
+    fn test_jsx_syntax() {
+        for file_name in ["testing.messages.jsx", "testing.messages.tsx"] {
+            let source = format!(
+                r#"
+        import {{defineMessages}} from '{}';
+
+        export const Example = () => <div>Example</div>;
+
+        export default defineMessages({{
+            GREETING: 'Hello',
+        }});
+        "#,
+                intl_message_utils::RUNTIME_PACKAGE_NAME
+            );
+            let (source_map, module) = parse_message_definitions_file(file_name, &source)
+                .expect("failed to parse source code");
+            let extractor = extract_message_definitions(file_name, source_map, module);
+            assert_eq!(extractor.message_definitions.len(), 1, "{file_name}");
+        }
+    }
+
+    #[test]
+    fn test_failed_definitions() {
+        let source = format!(
+            "import {{defineMessages}} from '{}';\n\
+            export const meta = {{\n  secret: 'yes',\n}};\n\
+            export default defineMessages({{\n\
+            \x20 VALID: 'Valid',\n\
+            \x20 TEMPLATED: `Hello ${{name}}`,\n\
+            \x20 MISSING_MESSAGE: {{\n    mesage: 'Typo',\n  }},\n\
+            \x20 INVALID_META: {{\n    message: 'Invalid meta',\n    translate: 'no',\n  }},\n\
+            }});\n",
+            intl_message_utils::RUNTIME_PACKAGE_NAME
+        );
+        let (source_map, module) = parse_message_definitions_file("testing.js", &source)
+            .expect("failed to parse source code");
+        let extractor = extract_message_definitions("testing.js", source_map, module);
+        let names: Vec<_> = extractor
+            .message_definitions
+            .iter()
+            .map(|definition| definition.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["VALID", "INVALID_META"]);
+        assert!(extractor.message_definitions[1].meta.translate);
+
+        let failures: Vec<_> = extractor
+            .failed_definitions
+            .iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.name.as_deref(),
+                    diagnostic.position.line,
+                    diagnostic.position.col,
+                )
+            })
+            .collect();
+        assert_eq!(
+            failures,
+            vec![
+                (None, 3, 10),
+                (Some("TEMPLATED"), 7, 2),
+                (Some("MISSING_MESSAGE"), 8, 2),
+                (Some("INVALID_META"), 13, 15),
+            ]
+        );
+        assert!(matches!(
+            extractor.failed_definitions[0].error,
+            MessageSourceError::InvalidSourceFileMeta
+        ));
+        assert!(matches!(
+            extractor.failed_definitions[2].error,
+            MessageSourceError::NoMessageValue(_)
+        ));
+        assert!(matches!(
+            extractor.failed_definitions[3].error,
+            MessageSourceError::InvalidMessageMeta(_)
+        ));
+    }
+
+    #[test]
+    fn test_template_description_is_skipped() {
+        let source = format!(
+            "import {{defineMessages}} from '{}';\n\
+            export default defineMessages({{\n\
+            \x20 DESCRIBED: {{\n    message: 'Hello',\n    description: `Greeting ${{x}}`,\n  }},\n\
+            }});\n",
+            intl_message_utils::RUNTIME_PACKAGE_NAME
+        );
+        let (source_map, module) = parse_message_definitions_file("testing.js", &source)
+            .expect("failed to parse source code");
+        let extractor = extract_message_definitions("testing.js", source_map, module);
+        assert_eq!(extractor.message_definitions.len(), 1);
+        let definition = &extractor.message_definitions[0];
+        assert_eq!(definition.name, "DESCRIBED");
+        assert_eq!(definition.meta.description, None);
+
+        assert_eq!(extractor.failed_definitions.len(), 1);
+        let diagnostic = &extractor.failed_definitions[0];
+        assert_eq!(diagnostic.name.as_deref(), Some("DESCRIBED"));
+        assert_eq!((diagnostic.position.line, diagnostic.position.col), (5, 17));
+        assert!(matches!(
+            diagnostic.error,
+            MessageSourceError::InvalidMessageMeta(_)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_meta_declaration() {
+        let source = format!(
+            "import {{defineMessages}} from '{}';\n\
+            export const meta = getMeta();\n\
+            export default defineMessages({{\n  VALID: 'Valid',\n}});\n",
+            intl_message_utils::RUNTIME_PACKAGE_NAME
+        );
+        let (source_map, module) = parse_message_definitions_file("testing.js", &source)
+            .expect("failed to parse source code");
+        let extractor = extract_message_definitions("testing.js", source_map, module);
+        assert_eq!(extractor.message_definitions.len(), 1);
+        let diagnostic = &extractor.failed_definitions[0];
+        assert!(diagnostic.name.is_none());
+        assert_eq!((diagnostic.position.line, diagnostic.position.col), (2, 13));
+    }
 }
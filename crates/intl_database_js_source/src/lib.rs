@@ -1,8 +1,8 @@
 use swc_common::errors::HANDLER;
 
 use intl_database_core::{
-    key_symbol, KeySymbol, MessageDefinitionSource, MessageSourceError, MessageSourceResult,
-    RawMessageDefinition, SourceFileKind, SourceFileMeta,
+    key_symbol, KeySymbol, MessageDefinitionSource, MessageSourceDiagnostic, MessageSourceError,
+    MessageSourceResult, RawMessageDefinition, SourceFileKind, SourceFileMeta, DEFAULT_LOCALE,
 };
 
 use crate::extractor::{extract_message_definitions, parse_message_definitions_file};
@@ -13,14 +13,18 @@ pub struct JsMessageSource;
 
 impl MessageDefinitionSource for JsMessageSource {
     fn get_default_locale(&self, _file_name: &str) -> KeySymbol {
-        key_symbol("en-US")
+        key_symbol(DEFAULT_LOCALE)
     }
 
     fn extract_definitions(
         self,
         file_name: KeySymbol,
         content: &str,
-    ) -> MessageSourceResult<(SourceFileMeta, impl Iterator<Item = RawMessageDefinition>)> {
+    ) -> MessageSourceResult<(
+        SourceFileMeta,
+        impl Iterator<Item = RawMessageDefinition>,
+        Vec<MessageSourceDiagnostic>,
+    )> {
         let (source, module) =
             parse_message_definitions_file(&file_name, content).map_err(|error| {
                 let diagnostic = HANDLER.with(|handler| error.into_diagnostic(&handler).message());
@@ -30,6 +34,7 @@ impl MessageDefinitionSource for JsMessageSource {
         Ok((
             extractor.root_meta,
             extractor.message_definitions.into_iter(),
+            extractor.failed_definitions,
         ))
     }
 }
@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 use std::vec::IntoIter;
 
-use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde::de::{DeserializeSeed, Deserializer, Error, MapAccess, Visitor};
+use serde_json::value::RawValue;
 
 use intl_database_core::{key_symbol, RawMessageTranslation, RawPosition};
 
@@ -9,9 +10,14 @@ use intl_database_core::{key_symbol, RawMessageTranslation, RawPosition};
 /// entries [RawMessageTranslation]. This is much more efficient than reading the file as plain
 /// JSON into a Map, then iterating the map to create another map of parsed message values, then
 /// returning that and iterating _again_ to insert into the database.
-struct TranslationEntryVisitor;
+///
+/// Values are read as raw JSON first so that their location in `content` is known, which lets
+/// each translation map positions in its value back to the file.
+struct TranslationEntryVisitor<'de> {
+    content: &'de str,
+}
 
-impl<'de> Visitor<'de> for TranslationEntryVisitor {
+impl<'de> Visitor<'de> for TranslationEntryVisitor<'de> {
     type Value = Translations;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -23,30 +29,82 @@ impl<'de> Visitor<'de> for TranslationEntryVisitor {
         V: MapAccess<'de>,
     {
         let mut entries: Vec<RawMessageTranslation> = vec![];
+        let mut tracker = PositionTracker::new(self.content);
         loop {
-            let entry: Option<(&str, Cow<str>)> = map.next_entry()?;
+            let entry: Option<(&str, &RawValue)> = map.next_entry()?;
             if entry.is_none() {
                 break;
             }
-            let (key, value) = entry.unwrap();
+            let (key, raw_value) = entry.unwrap();
+            let value: Cow<str> =
+                serde_json::from_str(raw_value.get()).map_err(V::Error::custom)?;
 
-            entries.push(RawMessageTranslation::new(
-                key_symbol(key),
-                RawPosition::default(),
-                value,
-            ))
+            let literal = raw_value.get();
+            let position = tracker.position_of(literal);
+            // The literal content starts after the opening quote.
+            let start = RawPosition {
+                line: position.line,
+                col: position.col + 1,
+            };
+            entries.push(
+                RawMessageTranslation::new(key_symbol(key), position, value)
+                    .with_source_literal(&literal[1..literal.len() - 1], start),
+            )
         }
 
         Ok(Translations { entries })
     }
 }
 
+/// Computes line and column positions of slices of the content being deserialized. Entries are
+/// visited in order, so the tracker only ever needs to move forward through the content.
+struct PositionTracker<'de> {
+    content: &'de str,
+    offset: usize,
+    position: RawPosition,
+}
+
+impl<'de> PositionTracker<'de> {
+    fn new(content: &'de str) -> Self {
+        Self {
+            content,
+            offset: 0,
+            position: RawPosition { line: 1, col: 0 },
+        }
+    }
+
+    /// Return the position of `slice`, which must be a slice of the tracked content that starts
+    /// after any previously-requested slice.
+    fn position_of(&mut self, slice: &str) -> RawPosition {
+        let target = slice.as_ptr() as usize - self.content.as_ptr() as usize;
+        for c in self.content[self.offset..target].chars() {
+            if c == '\n' {
+                self.position.line += 1;
+                self.position.col = 0;
+            } else {
+                self.position.col += 1;
+            }
+        }
+        self.offset = target;
+        self.position
+    }
+}
+
 /// A newtype wrapping a Vec of translations so that we can validly create the
-/// custom Deserialize implementation below.
+/// custom deserialization below.
 pub struct Translations {
     pub entries: Vec<RawMessageTranslation>,
 }
 
+impl Translations {
+    pub fn from_str(content: &str) -> serde_json::Result<Self> {
+        let mut deserializer = serde_json::Deserializer::from_str(content);
+        let translations = TranslationsSeed { content }.deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(translations)
+    }
+}
+
 impl IntoIterator for Translations {
     type Item = RawMessageTranslation;
     type IntoIter = IntoIter<RawMessageTranslation>;
@@ -56,11 +114,44 @@ impl IntoIterator for Translations {
     }
 }
 
-impl<'de> Deserialize<'de> for Translations {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+/// Deserializing translations requires the original content to locate each value, so it is done
+/// with a seed rather than a plain `Deserialize` implementation.
+struct TranslationsSeed<'de> {
+    content: &'de str,
+}
+
+impl<'de> DeserializeSeed<'de> for TranslationsSeed<'de> {
+    type Value = Translations;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(TranslationEntryVisitor)
+        deserializer.deserialize_map(TranslationEntryVisitor {
+            content: self.content,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Translations;
+
+    #[test]
+    fn value_positions() {
+        let content = "{\n  \"FIRST\": \"hello\",\n  \"SECOND\": \"a\\\"b\\\" {name}\"\n}";
+        let translations = Translations::from_str(content).unwrap();
+        let second = &translations.entries[1];
+        assert_eq!((second.position.line, second.position.col), (3, 12));
+
+        let raw = &second.value.raw;
+        assert_eq!(raw, "a\"b\" {name}");
+        let position = second
+            .value
+            .source_offsets
+            .as_ref()
+            .unwrap()
+            .position_at(raw, raw.find("name").unwrap());
+        assert_eq!((position.line, position.col), (3, 21));
     }
 }
@@ -19,7 +19,7 @@ impl MessageTranslationSource for JsonMessageSource {
         _file_name: KeySymbol,
         content: &str,
     ) -> MessageSourceResult<impl Iterator<Item = RawMessageTranslation>> {
-        let translations: Translations = serde_json::from_str(content).map_err(|error| {
+        let translations = Translations::from_str(content).map_err(|error| {
             MessageSourceError::ParseError(SourceFileKind::Translation, error.to_string())
         })?;
         Ok(translations.into_iter())
@@ -0,0 +1,140 @@
+use intl_database_core::{
+    key_symbol, KeySymbol, MessageSourceError, MessageSourceResult, MessageTranslationSource,
+    RawMessageTranslation, RawPosition, SourceFileKind,
+};
+
+pub use crate::parser::{escape_po_string, parse_po_entries, PoEntry, PoParseError, PoString};
+
+mod parser;
+
+pub struct PoMessageSource;
+
+impl MessageTranslationSource for PoMessageSource {
+    fn get_locale_from_file_name(&self, file_name: &str) -> KeySymbol {
+        file_name.split('.').next().unwrap_or("en-US").into()
+    }
+
+    fn extract_translations(
+        self,
+        _file_name: KeySymbol,
+        content: &str,
+    ) -> MessageSourceResult<impl Iterator<Item = RawMessageTranslation>> {
+        let entries = parse_po_entries(content).map_err(|error| {
+            MessageSourceError::ParseError(SourceFileKind::Translation, error.to_string())
+        })?;
+        Ok(entries.into_iter().filter_map(entry_to_translation))
+    }
+}
+
+/// Convert a parsed entry into a translation of the message it describes. Messages are keyed by
+/// `msgctxt`, falling back to `msgid` for files that don't use contexts. The header entry and
+/// entries that haven't been translated yet are skipped.
+fn entry_to_translation(entry: PoEntry) -> Option<RawMessageTranslation> {
+    if entry.is_header() || entry.msgstr.value.is_empty() {
+        return None;
+    }
+    let key = entry
+        .context
+        .as_ref()
+        .unwrap_or(&entry.msgid)
+        .value
+        .as_str();
+    let fuzzy = entry.is_fuzzy();
+    let PoString {
+        value,
+        position,
+        literal,
+    } = entry.msgstr;
+
+    let translation = RawMessageTranslation::new(key_symbol(key), position, &value);
+    let translation = match literal {
+        // The literal content starts after the opening quote.
+        Some(literal) => translation.with_source_literal(
+            &literal,
+            RawPosition {
+                line: position.line,
+                col: position.col + 1,
+            },
+        ),
+        None => translation,
+    };
+    Some(translation.with_fuzzy(fuzzy))
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{key_symbol, MessageTranslationSource};
+
+    use super::PoMessageSource;
+
+    #[test]
+    fn extract_translations() {
+        let content = r#"msgid ""
+msgstr ""
+"Language: fr\n"
+"Content-Type: text/plain; charset=UTF-8\n"
+
+# Reviewed by the vendor
+#. Greets the user
+#: src/Feature.messages.js:4
+msgctxt "GREETING"
+msgid "Hello {name}"
+msgstr "Bonjour {name}"
+
+#, fuzzy
+msgctxt "FAREWELL"
+msgid "Goodbye"
+msgstr "Au revoir"
+
+msgctxt "UNTRANSLATED"
+msgid "Not yet"
+msgstr ""
+
+msgid "NO_CONTEXT"
+msgstr ""
+"Multiple\n"
+"lines"
+"#;
+        let translations: Vec<_> = PoMessageSource
+            .extract_translations(key_symbol("fr.messages.po"), content)
+            .unwrap()
+            .collect();
+        let values: Vec<_> = translations
+            .iter()
+            .map(|translation| {
+                (
+                    translation.name.as_str(),
+                    translation.value.raw.as_str(),
+                    translation.value.fuzzy,
+                )
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("GREETING", "Bonjour {name}", false),
+                ("FAREWELL", "Au revoir", true),
+                ("NO_CONTEXT", "Multiple\nlines", false),
+            ]
+        );
+
+        let greeting = &translations[0];
+        assert_eq!((greeting.position.line, greeting.position.col), (11, 7));
+        let raw = &greeting.value.raw;
+        let position = greeting
+            .value
+            .source_offsets
+            .as_ref()
+            .unwrap()
+            .position_at(raw, raw.find("name").unwrap());
+        assert_eq!((position.line, position.col), (11, 17));
+    }
+
+    #[test]
+    fn locale_from_file_name() {
+        assert_eq!(
+            PoMessageSource.get_locale_from_file_name("pt-BR.messages.po"),
+            key_symbol("pt-BR")
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use thiserror::Error;
+
+use intl_database_core::RawPosition;
+
+#[derive(Debug, Error)]
+#[error("line {line}: {message}")]
+pub struct PoParseError {
+    pub line: u32,
+    pub message: String,
+}
+
+/// A quoted string from a PO file, which may be split across multiple lines of adjacent strings
+/// that are concatenated together.
+#[derive(Debug, Default)]
+pub struct PoString {
+    /// The value of the string, with all parts joined and escapes applied.
+    pub value: String,
+    /// Position of the opening quote of the first part that has any content.
+    pub position: RawPosition,
+    /// The content of the string as written in the file, if it was written as a single part, so
+    /// that positions within the value can be mapped back to the file.
+    pub literal: Option<String>,
+}
+
+impl PoString {
+    fn push_part(&mut self, literal: &str, position: RawPosition) {
+        if literal.is_empty() {
+            return;
+        }
+        if self.value.is_empty() && self.literal.is_none() {
+            self.position = position;
+            self.literal = Some(literal.to_string());
+        } else {
+            // Positions can't be mapped across multiple parts.
+            self.literal = None;
+        }
+        self.value.push_str(&unescape(literal));
+    }
+}
+
+/// A single entry from a PO file, like:
+///
+/// ```po
+/// # translator comment
+/// #. extracted comment
+/// #: src/Feature.messages.js:4
+/// #, fuzzy
+/// msgctxt "GREETING"
+/// msgid "Hello"
+/// msgstr "Bonjour"
+/// ```
+#[derive(Debug, Default)]
+pub struct PoEntry {
+    pub translator_comments: Vec<String>,
+    pub extracted_comments: Vec<String>,
+    pub references: Vec<String>,
+    pub flags: Vec<String>,
+    pub context: Option<PoString>,
+    pub msgid: PoString,
+    pub msgstr: PoString,
+}
+
+impl PoEntry {
+    /// The entry with an empty `msgid` and no context holds the metadata of the file.
+    pub fn is_header(&self) -> bool {
+        self.context.is_none() && self.msgid.value.is_empty()
+    }
+
+    /// Fuzzy entries have a translation that was guessed or is out of date, and needs review.
+    pub fn is_fuzzy(&self) -> bool {
+        self.flags.iter().any(|flag| flag == "fuzzy")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Field {
+    Context,
+    Id,
+    Str,
+}
+
+#[derive(Default)]
+struct EntryBuilder {
+    entry: PoEntry,
+    /// The last keyword that was seen, which continuation strings are added to.
+    field: Option<Field>,
+    has_msgid: bool,
+    has_msgstr: bool,
+}
+
+impl EntryBuilder {
+    fn is_empty(&self) -> bool {
+        self.field.is_none()
+            && self.entry.translator_comments.is_empty()
+            && self.entry.extracted_comments.is_empty()
+            && self.entry.references.is_empty()
+            && self.entry.flags.is_empty()
+    }
+
+    fn finish(&mut self, line: u32, entries: &mut Vec<PoEntry>) -> Result<(), PoParseError> {
+        let builder = std::mem::take(self);
+        if builder.field.is_none() {
+            // Comments with no entry, like a file that only has a copyright notice.
+            return Ok(());
+        }
+        if !builder.has_msgid || !builder.has_msgstr {
+            return Err(PoParseError {
+                line,
+                message: "Entry must have both a msgid and a msgstr".into(),
+            });
+        }
+        entries.push(builder.entry);
+        Ok(())
+    }
+}
+
+/// Parse the content of a PO or POT file into its entries. Obsolete entries (`#~`) are skipped.
+///
+/// Plural entries (`msgid_plural` and `msgstr[n]`) are not supported, since plurals are written
+/// within the message itself using ICU syntax.
+pub fn parse_po_entries(content: &str) -> Result<Vec<PoEntry>, PoParseError> {
+    let mut entries = vec![];
+    let mut builder = EntryBuilder::default();
+    let mut line_number = 0;
+
+    for line in content.lines() {
+        line_number += 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            builder.finish(line_number, &mut entries)?;
+            continue;
+        }
+
+        if let Some(comment) = trimmed.strip_prefix('#') {
+            // A comment after the strings of an entry starts the next one.
+            if builder.has_msgstr {
+                builder.finish(line_number, &mut entries)?;
+            }
+            let entry = &mut builder.entry;
+            match comment.chars().next() {
+                Some('.') => entry
+                    .extracted_comments
+                    .push(comment[1..].trim().to_string()),
+                Some(':') => entry
+                    .references
+                    .extend(comment[1..].split_whitespace().map(String::from)),
+                Some(',') => entry.flags.extend(
+                    comment[1..]
+                        .split(',')
+                        .map(|flag| flag.trim().to_string())
+                        .filter(|flag| !flag.is_empty()),
+                ),
+                // Previous values (`#|`) and obsolete entries (`#~`) are ignored.
+                Some('|' | '~') => {}
+                _ => entry.translator_comments.push(comment.trim().to_string()),
+            }
+            continue;
+        }
+
+        let indent = (line.len() - line.trim_start().len()) as u32;
+        let (field, rest) = if trimmed.starts_with('"') {
+            let Some(field) = builder.field else {
+                return Err(error(line_number, "String is not part of any keyword"));
+            };
+            (field, trimmed)
+        } else {
+            let (keyword, rest) = trimmed
+                .split_once(char::is_whitespace)
+                .unwrap_or((trimmed, ""));
+            let field = match keyword {
+                "msgctxt" => Field::Context,
+                "msgid" => Field::Id,
+                "msgstr" => Field::Str,
+                "msgid_plural" => {
+                    return Err(error(
+                        line_number,
+                        "Plural entries are not supported, use ICU plural syntax in the message instead",
+                    ))
+                }
+                _ if keyword.starts_with("msgstr[") => {
+                    return Err(error(
+                        line_number,
+                        "Plural entries are not supported, use ICU plural syntax in the message instead",
+                    ))
+                }
+                _ => return Err(error(line_number, &format!("Unknown keyword {keyword}"))),
+            };
+            // A new context or id after the strings of an entry starts the next one.
+            if builder.has_msgstr && field != Field::Str {
+                builder.finish(line_number, &mut entries)?;
+            }
+            let is_repeated = match field {
+                Field::Context => builder.entry.context.is_some() || builder.has_msgid,
+                Field::Id => builder.has_msgid,
+                Field::Str => builder.has_msgstr || !builder.has_msgid,
+            };
+            if is_repeated {
+                return Err(error(
+                    line_number,
+                    &format!("Unexpected {keyword} in this entry"),
+                ));
+            }
+            match field {
+                Field::Context => builder.entry.context = Some(PoString::default()),
+                Field::Id => builder.has_msgid = true,
+                Field::Str => builder.has_msgstr = true,
+            }
+            builder.field = Some(field);
+            (field, rest.trim_start())
+        };
+
+        let Some(literal) = rest
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+            .filter(|literal| !ends_with_escape(literal))
+        else {
+            return Err(error(line_number, "Expected a quoted string"));
+        };
+        let position = RawPosition {
+            line: line_number,
+            col: indent + (trimmed.len() - rest.len()) as u32,
+        };
+        let target = match field {
+            Field::Context => builder.entry.context.get_or_insert_with(Default::default),
+            Field::Id => &mut builder.entry.msgid,
+            Field::Str => &mut builder.entry.msgstr,
+        };
+        target.push_part(literal, position);
+    }
+
+    if !builder.is_empty() {
+        builder.finish(line_number, &mut entries)?;
+    }
+    Ok(entries)
+}
+
+fn error(line: u32, message: &str) -> PoParseError {
+    PoParseError {
+        line,
+        message: message.into(),
+    }
+}
+
+/// Returns true if the final quote of a string was escaped, meaning the string isn't closed.
+fn ends_with_escape(literal: &str) -> bool {
+    literal.chars().rev().take_while(|c| *c == '\\').count() % 2 == 1
+}
+
+/// Apply the C-style escape sequences used in PO strings.
+fn unescape(literal: &str) -> String {
+    let mut result = String::with_capacity(literal.len());
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// Escape `value` to be written within a quoted PO string.
+pub fn escape_po_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_po_string, parse_po_entries};
+
+    #[test]
+    fn parse_entries() {
+        let content = "# Header comment\nmsgid \"\"\nmsgstr \"Language: fr\\n\"\n\n#: a.js:1 b.js:2\n#, fuzzy, c-format\nmsgctxt \"KEY\"\nmsgid \"\"\n\"Say \\\"hi\\\"\"\nmsgstr \"Dis \\\"salut\\\"\"\n#~ msgid \"Obsolete\"\n";
+        let entries = parse_po_entries(content).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].is_header());
+        assert_eq!(entries[0].translator_comments, vec!["Header comment"]);
+        assert_eq!(entries[0].msgstr.value, "Language: fr\n");
+
+        let entry = &entries[1];
+        assert_eq!(entry.references, vec!["a.js:1", "b.js:2"]);
+        assert_eq!(entry.flags, vec!["fuzzy", "c-format"]);
+        assert!(entry.is_fuzzy());
+        assert_eq!(entry.context.as_ref().unwrap().value, "KEY");
+        assert_eq!(entry.msgid.value, "Say \"hi\"");
+        assert_eq!(entry.msgstr.value, "Dis \"salut\"");
+        assert_eq!(
+            (entry.msgid.position.line, entry.msgid.position.col),
+            (9, 0)
+        );
+    }
+
+    #[test]
+    fn reject_invalid_entries() {
+        for content in [
+            "msgid \"A\"\nmsgid \"B\"\n",
+            "msgid \"A\"\n",
+            "msgid \"unclosed\\\"\nmsgstr \"\"\n",
+            "msgid \"A\"\nmsgid_plural \"As\"\nmsgstr[0] \"\"\n",
+            "msgstr \"A\"\n",
+        ] {
+            assert!(parse_po_entries(content).is_err(), "{content}");
+        }
+    }
+
+    #[test]
+    fn escape_round_trip() {
+        let value = "Line \"one\"\n\tback\\slash";
+        let content = format!("msgid \"KEY\"\nmsgstr \"{}\"\n", escape_po_string(value));
+        let entries = parse_po_entries(&content).unwrap();
+        assert_eq!(entries[0].msgstr.value, value);
+    }
+}
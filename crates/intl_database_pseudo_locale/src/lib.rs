@@ -0,0 +1,440 @@
+use intl_database_core::{key_symbol, DatabaseResult, KeySymbol, MessageValue, MessagesDatabase};
+use intl_database_service::IntlDatabaseService;
+use intl_markdown::{BlockNode, Document, InlineContent};
+use intl_markdown_visitor::{visit_mut, VisitMut};
+
+/// Right-to-left mark, which makes the surrounding text render as right-to-left.
+const RLM: char = '\u{200F}';
+/// Right-to-left override, which renders the following characters in reverse order.
+const RLO: char = '\u{202E}';
+/// Pop directional formatting, ending the previous override.
+const PDF: char = '\u{202C}';
+
+/// Character used to pad messages to their expanded length.
+const EXPANSION_FILLER: char = '~';
+
+pub struct PseudoLocaleOptions {
+    locale: KeySymbol,
+    accents: bool,
+    mirror: bool,
+    brackets: bool,
+    expansion: f64,
+}
+
+impl PseudoLocaleOptions {
+    /// Options for the `en-XA` pseudo-locale, which accents every letter, surrounds each message
+    /// with brackets, and makes it 40% longer, to catch hard-coded strings and text that gets
+    /// truncated when translated.
+    pub fn accented() -> Self {
+        Self {
+            locale: key_symbol("en-XA"),
+            accents: true,
+            mirror: false,
+            brackets: true,
+            expansion: 0.4,
+        }
+    }
+
+    /// Options for the `ar-XB` pseudo-locale, which renders every word right-to-left to catch
+    /// layouts that don't support right-to-left languages.
+    pub fn mirrored() -> Self {
+        Self {
+            locale: key_symbol("ar-XB"),
+            accents: false,
+            mirror: true,
+            brackets: false,
+            expansion: 0.0,
+        }
+    }
+
+    pub fn with_locale(mut self, locale: KeySymbol) -> Self {
+        self.locale = locale;
+        self
+    }
+    pub fn with_accents(mut self, accents: bool) -> Self {
+        self.accents = accents;
+        self
+    }
+    pub fn with_mirror(mut self, mirror: bool) -> Self {
+        self.mirror = mirror;
+        self
+    }
+    pub fn with_brackets(mut self, brackets: bool) -> Self {
+        self.brackets = brackets;
+        self
+    }
+    /// Pad each message with enough extra characters to make its text longer by the given
+    /// fraction, like `0.4` for 40% longer.
+    pub fn with_expansion(mut self, expansion: f64) -> Self {
+        self.expansion = expansion.max(0.0);
+        self
+    }
+}
+
+impl Default for PseudoLocaleOptions {
+    fn default() -> Self {
+        Self::accented()
+    }
+}
+
+/// A service that generates a pseudo-localized translation of every defined message in the
+/// database.
+///
+/// Each translation is inserted in the configured pseudo-locale, replacing any that already
+/// exists. Once inserted, they are bundled and exported like any other translations.
+///
+/// Only the visible text of each message is transformed. ICU variables, plural and select
+/// selectors, hook names, link destinations, and code are kept intact so that the translations
+/// format the same way as their source messages. The result of this service is the number of
+/// translations that were inserted.
+pub struct PseudoLocalizer<'a> {
+    database: &'a mut MessagesDatabase,
+    options: PseudoLocaleOptions,
+}
+
+impl<'a> PseudoLocalizer<'a> {
+    pub fn new(database: &'a mut MessagesDatabase, options: PseudoLocaleOptions) -> Self {
+        Self { database, options }
+    }
+
+    /// Return a pseudo-localized copy of the given document.
+    pub fn pseudo_localize(&self, document: &Document) -> Document {
+        let mut document = document.clone();
+        let mut visitor = PseudoLocalizeText {
+            options: &self.options,
+            text_length: 0,
+        };
+        visit_mut(&mut document, &mut visitor);
+
+        let padding_length = (visitor.text_length as f64 * self.options.expansion).ceil() as usize;
+        let mut prefix = String::new();
+        let mut suffix = String::new();
+        if padding_length > 0 {
+            suffix.push(' ');
+            suffix.extend(std::iter::repeat(EXPANSION_FILLER).take(padding_length));
+        }
+        if self.options.brackets {
+            prefix.push('[');
+            suffix.push(']');
+        }
+
+        // Added text is merged into existing text where possible, keeping static messages as a
+        // single string.
+        let blocks = document.blocks_mut();
+        let first_content = blocks
+            .first_mut()
+            .and_then(|block| edge_content(block, false));
+        if let Some(content) = first_content.filter(|_| !prefix.is_empty()) {
+            match content.first_mut() {
+                Some(InlineContent::Text(text)) => text.insert_str(0, &prefix),
+                _ => content.insert(0, InlineContent::Text(prefix)),
+            }
+        }
+        let last_content = blocks
+            .last_mut()
+            .and_then(|block| edge_content(block, true));
+        if let Some(content) = last_content.filter(|_| !suffix.is_empty()) {
+            match content.last_mut() {
+                Some(InlineContent::Text(text)) => text.push_str(&suffix),
+                _ => content.push(InlineContent::Text(suffix)),
+            }
+        }
+        document
+    }
+}
+
+impl IntlDatabaseService for PseudoLocalizer<'_> {
+    type Result = DatabaseResult<usize>;
+
+    fn run(&mut self) -> Self::Result {
+        let translations: Vec<(KeySymbol, MessageValue)> = self
+            .database
+            .messages
+            .values()
+            .filter(|message| {
+                message
+                    .source_locale()
+                    .is_some_and(|locale| locale != self.options.locale)
+            })
+            .filter_map(|message| {
+                let source = message.get_source_translation()?;
+                let document = self.pseudo_localize(&source.parsed);
+                let mut value = MessageValue::from_document(document);
+                // Positions within the content don't map onto the pseudo-localized text, but the
+                // whole value still comes from where the source was written.
+                value.file_position = source.file_position;
+                Some((message.key(), value))
+            })
+            .collect();
+
+        let count = translations.len();
+        for (key, value) in translations {
+            self.database
+                .insert_translation(key, self.options.locale, value, true)?;
+        }
+        Ok(count)
+    }
+}
+
+/// Return the inline content at the start or end of the given block, looking into containers like
+/// lists and block quotes. Tables, code blocks, and thematic breaks have none.
+fn edge_content(block: &mut BlockNode, last: bool) -> Option<&mut Vec<InlineContent>> {
+    fn edge<T>(items: &mut [T], last: bool) -> Option<&mut T> {
+        match last {
+            true => items.last_mut(),
+            false => items.first_mut(),
+        }
+    }
+
+    match block {
+        BlockNode::InlineContent(content) => Some(content),
+        BlockNode::Paragraph(paragraph) => Some(paragraph.content_mut()),
+        BlockNode::Heading(heading) => Some(heading.content_mut()),
+        BlockNode::BlockQuote(quote) => {
+            edge(quote.content_mut(), last).and_then(|block| edge_content(block, last))
+        }
+        BlockNode::List(list) => edge(list.items_mut(), last)
+            .and_then(|item| edge(item.content_mut(), last))
+            .and_then(|block| edge_content(block, last)),
+        BlockNode::CodeBlock(_) | BlockNode::ThematicBreak | BlockNode::Table(_) => None,
+    }
+}
+
+struct PseudoLocalizeText<'a> {
+    options: &'a PseudoLocaleOptions,
+    /// Number of characters of text seen in the document, used to determine how much to expand it.
+    text_length: usize,
+}
+
+impl VisitMut for PseudoLocalizeText<'_> {
+    fn visit_mut_text(&mut self, node: &mut String) {
+        self.text_length += node.chars().count();
+
+        let mut result = String::with_capacity(node.len() * 2);
+        let mut in_word = false;
+        for c in node.chars() {
+            if self.options.mirror && c.is_whitespace() == in_word {
+                // Wrap every word in an override, so that it renders reversed while the words
+                // themselves are read right-to-left.
+                match in_word {
+                    true => result.extend([PDF, RLM]),
+                    false => result.extend([RLM, RLO]),
+                }
+                in_word = !in_word;
+            }
+            result.push(match self.options.accents {
+                true => accented(c),
+                false => c,
+            });
+        }
+        if in_word {
+            result.extend([PDF, RLM]);
+        }
+        *node = result;
+    }
+}
+
+/// Return an accented version of the given letter that is still recognizable. Any other character
+/// is returned unchanged.
+fn accented(c: char) -> char {
+    match c {
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ð',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        'a' => 'å',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'î',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        c => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{
+        key_symbol, DefinitionFile, FilePosition, KeySymbolSet, MessageMeta, MessageValue,
+        MessagesDatabase, SourceFile, SourceFileMeta, DEFAULT_LOCALE,
+    };
+    use intl_database_exporter::{
+        CompiledMessageFormat, IntlMessageBundler, IntlMessageBundlerOptions,
+    };
+    use intl_database_service::IntlDatabaseService;
+    use intl_markdown::format_to_icu_string;
+    use intl_validator::{validate_message, ValidatorConfig};
+
+    use super::{PseudoLocaleOptions, PseudoLocalizer};
+
+    fn database_with_messages(messages: &[(&str, &str)]) -> MessagesDatabase {
+        let mut database = MessagesDatabase::new();
+        let mut keys = KeySymbolSet::default();
+        for (name, content) in messages {
+            database
+                .insert_definition(
+                    name,
+                    MessageValue::from_raw(content),
+                    key_symbol(DEFAULT_LOCALE),
+                    MessageMeta::default(),
+                    false,
+                )
+                .unwrap();
+            keys.insert(key_symbol(name));
+        }
+        database.create_source_file(
+            key_symbol("en.messages.js"),
+            SourceFile::Definition(DefinitionFile::new(
+                "en.messages.js".into(),
+                SourceFileMeta::new("en.messages.js"),
+                keys,
+            )),
+        );
+        database
+    }
+
+    fn pseudo_localize(content: &str, options: PseudoLocaleOptions) -> String {
+        let mut database = MessagesDatabase::new();
+        let document = MessageValue::from_raw(content).parsed;
+        let localized = PseudoLocalizer::new(&mut database, options).pseudo_localize(&document);
+        format_to_icu_string(&localized).unwrap()
+    }
+
+    #[test]
+    fn accents_only_visible_text() {
+        let options = PseudoLocaleOptions::accented()
+            .with_brackets(false)
+            .with_expansion(0.0);
+        assert_eq!(
+            "Ĥéļļö {name}, <b>ýöû</b> ĥåṽé {count, plural, one {# ñéŵ ɱéššåĝé} other {# ñéŵ ɱéššåĝéš}}",
+            pseudo_localize(
+                "Hello {name}, **you** have {count, plural, one {# new message} other {# new messages}}",
+                options
+            )
+        );
+        assert_eq!(
+            "<link>https://example.com{_}çļîçķ</link> åñð <myHook>ĥéŕé</myHook>",
+            pseudo_localize(
+                "[click](https://example.com) and $[here](myHook)",
+                PseudoLocaleOptions::accented()
+                    .with_brackets(false)
+                    .with_expansion(0.0)
+            )
+        );
+    }
+
+    #[test]
+    fn brackets_and_expansion() {
+        assert_eq!(
+            "[Ĥéļļö {name} ~~~]",
+            pseudo_localize("Hello {name}", PseudoLocaleOptions::accented())
+        );
+    }
+
+    #[test]
+    fn mirrors_words() {
+        assert_eq!(
+            "\u{200F}\u{202E}Hello\u{202C}\u{200F} {name}",
+            pseudo_localize("Hello {name}", PseudoLocaleOptions::mirrored())
+        );
+    }
+
+    #[test]
+    fn bundles_pseudo_locale() {
+        let mut database =
+            database_with_messages(&[("GREETING", "Hi {name}"), ("PLAIN", "Plain text")]);
+        let count = PseudoLocalizer::new(&mut database, PseudoLocaleOptions::default())
+            .run()
+            .unwrap();
+        assert_eq!(2, count);
+        assert!(database.known_locales.contains(&key_symbol("en-XA")));
+
+        let mut output = vec![];
+        IntlMessageBundler::new(
+            &database,
+            &mut output,
+            key_symbol("en.messages.js"),
+            key_symbol("en-XA"),
+            IntlMessageBundlerOptions::default().with_format(CompiledMessageFormat::KeylessJson),
+        )
+        .run()
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let greeting = database.get_message("GREETING").unwrap().hashed_key();
+        let plain = database.get_message("PLAIN").unwrap().hashed_key();
+        assert!(output.contains(&format!(r#""{greeting}":["[Ĥî ",[1,"name"]," ~~]"]"#)));
+        assert!(output.contains(&format!(r#""{plain}":"[Þļåîñ ţéẋţ ~~~~]""#)));
+    }
+
+    #[test]
+    fn validate_pseudo_localized() {
+        let mut database = MessagesDatabase::new();
+        let position = FilePosition {
+            file: key_symbol("en.messages.js"),
+            line: 3,
+            col: 2,
+        };
+        database
+            .insert_definition(
+                "GREETING",
+                MessageValue::from_raw("Hello {name}").with_file_position(position),
+                key_symbol(DEFAULT_LOCALE),
+                MessageMeta::default(),
+                false,
+            )
+            .unwrap();
+        let options = PseudoLocaleOptions::accented();
+        let locale = options.locale;
+        PseudoLocalizer::new(&mut database, options).run().unwrap();
+
+        let message = database.get_message("GREETING").unwrap();
+        assert_eq!(
+            message.translations()[&locale].file_position,
+            Some(position)
+        );
+        // Validating pseudo-localized values must not require positions within their content.
+        validate_message(message, &database, &ValidatorConfig::default());
+    }
+}
@@ -1,3 +1,5 @@
+use intl_database_core::{KeySymbol, DEFAULT_LOCALE};
+
 use crate::writer::{
     write_doc, AlphabeticSymbolMap, AlphabeticSymbolSet, TypeDocFormat, TypeDocWriter, WriteResult,
 };
@@ -7,6 +9,8 @@ pub struct DocComment<'a> {
     pub(super) key: &'a str,
     /// Raw text of the definition of the message
     pub(super) value: Option<&'a str>,
+    /// Locale that the definition of the message is written in
+    pub(super) source_locale: Option<KeySymbol>,
     /// Optional description of the message provided from the definition
     pub(super) description: Option<&'a str>,
     /// Locales where the message expected a translation but was not found
@@ -25,10 +29,15 @@ impl TypeDocFormat for DocComment<'_> {
     fn fmt(&self, mut w: &mut TypeDocWriter) -> WriteResult {
         w.push_prefix(" * ");
         write_doc!(w, ["/**\nKey: `", &self.key, "`"])?;
-        write_doc!(
-            w,
-            ["\n\n### Definition\n", "```text\n", &self.value, "\n```"]
-        )?;
+        write_doc!(w, ["\n\n### Definition"])?;
+        // Only call out the locale when it isn't the one most definitions are written in.
+        if let Some(locale) = self
+            .source_locale
+            .filter(|locale| *locale != DEFAULT_LOCALE)
+        {
+            write_doc!(w, [" (`", &locale, "`)"])?;
+        }
+        write_doc!(w, ["\n```text\n", &self.value, "\n```"])?;
 
         if !self.ready_to_translate {
             write_doc!(w, ["\n\n**Not ready for translation**"])?;
@@ -59,6 +59,7 @@ impl<'a> IntlTypesGenerator<'a> {
                 .map(|definition| definition.raw.as_str()),
             description: None,
             missing_translations: AlphabeticSymbolSet::from_iter(missing_locales),
+            source_locale: *message.source_locale(),
             is_secret: message.meta().secret,
             ready_to_translate: message.meta().translate,
             spurious_variables,
@@ -91,7 +92,10 @@ impl<'a> IntlTypesGenerator<'a> {
             AlphabeticSymbolMap::default();
 
         for (locale_key, translation) in message.translations() {
-            if translation == source {
+            if message
+                .source_locale()
+                .is_some_and(|source| source == *locale_key)
+            {
                 continue;
             }
 
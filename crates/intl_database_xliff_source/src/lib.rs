@@ -0,0 +1,99 @@
+use intl_database_core::{
+    key_symbol, KeySymbol, MessageSourceError, MessageSourceResult, MessageTranslationSource,
+    RawMessageTranslation, SourceFileKind,
+};
+
+pub use crate::placeholders::{split_placeholders, MessageSegment};
+pub use crate::reader::{parse_xliff, XliffDocument, XliffTarget, XliffUnit, XliffVersion};
+
+mod placeholders;
+mod reader;
+
+pub struct XliffMessageSource;
+
+impl MessageTranslationSource for XliffMessageSource {
+    fn get_locale_from_file_name(&self, file_name: &str) -> KeySymbol {
+        file_name.split('.').next().unwrap_or("en-US").into()
+    }
+
+    fn extract_translations(
+        self,
+        _file_name: KeySymbol,
+        content: &str,
+    ) -> MessageSourceResult<impl Iterator<Item = RawMessageTranslation>> {
+        let XliffDocument { version, units } = parse_xliff(content)
+            .map_err(|error| MessageSourceError::ParseError(SourceFileKind::Translation, error))?;
+        // Units without a target haven't been translated yet.
+        Ok(units.into_iter().filter_map(move |unit| {
+            let target = unit.target.filter(|target| !target.value.is_empty())?;
+            let fuzzy = target.needs_review(version);
+            let translation =
+                RawMessageTranslation::new(key_symbol(&unit.key), target.position, &target.value);
+            let translation = match &target.literal {
+                Some(literal) => translation.with_source_literal(literal, target.position),
+                None => translation,
+            };
+            Some(translation.with_fuzzy(fuzzy))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{key_symbol, MessageTranslationSource};
+
+    use super::XliffMessageSource;
+
+    #[test]
+    fn extract_translations() {
+        let content = r#"<xliff version="1.2">
+  <file source-language="en-US" target-language="fr">
+    <body>
+      <trans-unit id="GREETING">
+        <source>Hello {name}</source>
+        <target>Bonjour {name}</target>
+      </trans-unit>
+      <trans-unit id="FAREWELL">
+        <source>Goodbye</source>
+        <target state="needs-review-translation">Au revoir</target>
+      </trans-unit>
+      <trans-unit id="EMPTY">
+        <source>Empty</source>
+        <target/>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"#;
+        let translations: Vec<_> = XliffMessageSource
+            .extract_translations(key_symbol("fr.messages.xlf"), content)
+            .unwrap()
+            .collect();
+        let values: Vec<_> = translations
+            .iter()
+            .map(|translation| {
+                (
+                    translation.name.as_str(),
+                    translation.value.raw.as_str(),
+                    translation.value.fuzzy,
+                )
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("GREETING", "Bonjour {name}", false),
+                ("FAREWELL", "Au revoir", true)
+            ]
+        );
+
+        let greeting = &translations[0];
+        let raw = &greeting.value.raw;
+        let position = greeting
+            .value
+            .source_offsets
+            .as_ref()
+            .unwrap()
+            .position_at(raw, raw.find("name").unwrap());
+        assert_eq!((position.line, position.col), (6, 25));
+    }
+}
@@ -0,0 +1,222 @@
+/// A part of a message value, split so that the ICU syntax of the message can be protected from
+/// being changed during translation while the text around it remains translatable.
+#[derive(Debug, PartialEq)]
+pub enum MessageSegment<'a> {
+    Text(&'a str),
+    /// ICU syntax that must be kept as-is, like an entire `{name}` argument, or the parts of a
+    /// plural argument that surround each of its options, like `{count, plural, one {`.
+    Placeholder(&'a str),
+}
+
+/// Split `value` into translatable text and ICU placeholders.
+///
+/// Simple arguments like `{name}` or `{count, number}` become a single placeholder, while
+/// `plural`, `select`, and `selectordinal` arguments are split around the content of each option,
+/// since that content still needs to be translated.
+///
+/// Values that aren't valid ICU syntax are split as well as possible, and any unterminated
+/// argument is kept as one placeholder.
+pub fn split_placeholders(value: &str) -> Vec<MessageSegment<'_>> {
+    let mut splitter = Splitter {
+        value,
+        offset: 0,
+        segments: vec![],
+        placeholder_start: None,
+    };
+    splitter.message(false);
+    splitter.end_placeholder(value.len());
+    splitter.segments
+}
+
+struct Splitter<'a> {
+    value: &'a str,
+    offset: usize,
+    segments: Vec<MessageSegment<'a>>,
+    /// Start of the placeholder currently being built, which continues until text is seen.
+    placeholder_start: Option<usize>,
+}
+
+impl Splitter<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.value.as_bytes().get(self.offset).copied()
+    }
+
+    fn start_placeholder(&mut self) {
+        if self.placeholder_start.is_none() {
+            self.placeholder_start = Some(self.offset);
+        }
+    }
+
+    /// Finish the current placeholder, if any, at the offset `end`.
+    fn end_placeholder(&mut self, end: usize) {
+        if let Some(start) = self.placeholder_start.take() {
+            if start < end {
+                self.segments
+                    .push(MessageSegment::Placeholder(&self.value[start..end]));
+            }
+        }
+    }
+
+    /// Consume message content until the end of the value or, if `nested`, until the `}` that
+    /// closes the option containing it, which is left unconsumed.
+    fn message(&mut self, nested: bool) {
+        let mut text_start = self.offset;
+        while let Some(c) = self.peek() {
+            match c {
+                b'{' => {
+                    self.push_text(text_start);
+                    self.argument();
+                    text_start = self.offset;
+                }
+                b'}' if nested => break,
+                _ => self.offset += 1,
+            }
+        }
+        self.push_text(text_start);
+    }
+
+    fn push_text(&mut self, start: usize) {
+        if start < self.offset {
+            self.end_placeholder(start);
+            self.segments
+                .push(MessageSegment::Text(&self.value[start..self.offset]));
+        }
+    }
+
+    /// Consume an entire argument, starting at its opening `{`.
+    fn argument(&mut self) {
+        self.start_placeholder();
+        self.offset += 1;
+        let header_start = self.offset;
+        // Read up to the end of the argument or the comma after its type.
+        let mut commas = 0;
+        while let Some(c) = self.peek() {
+            match c {
+                b'}' => {
+                    self.offset += 1;
+                    return;
+                }
+                b',' => {
+                    commas += 1;
+                    self.offset += 1;
+                    if commas == 2 {
+                        break;
+                    }
+                }
+                b'{' => break,
+                _ => self.offset += 1,
+            }
+        }
+
+        let argument_type = self.value[header_start..self.offset]
+            .split(',')
+            .nth(1)
+            .map(str::trim);
+        if matches!(argument_type, Some("plural" | "select" | "selectordinal")) {
+            self.options();
+        } else {
+            self.skip_balanced();
+        }
+    }
+
+    /// Consume the options of a plural or select argument, up to and including the `}` that
+    /// closes the argument.
+    fn options(&mut self) {
+        while let Some(c) = self.peek() {
+            match c {
+                b'{' => {
+                    self.offset += 1;
+                    self.message(true);
+                    // The closing brace of the option continues the next placeholder.
+                    self.start_placeholder();
+                    if self.peek() == Some(b'}') {
+                        self.offset += 1;
+                    }
+                }
+                b'}' => {
+                    self.offset += 1;
+                    return;
+                }
+                _ => self.offset += 1,
+            }
+        }
+    }
+
+    /// Consume the rest of an argument that has no translatable content, including any nested
+    /// braces, up to and including its closing `}`.
+    fn skip_balanced(&mut self) {
+        let mut depth = 1;
+        while let Some(c) = self.peek() {
+            self.offset += 1;
+            match c {
+                b'{' => depth += 1,
+                b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_placeholders, MessageSegment};
+
+    fn split(value: &str) -> Vec<String> {
+        split_placeholders(value)
+            .into_iter()
+            .map(|segment| match segment {
+                MessageSegment::Text(text) => text.to_string(),
+                MessageSegment::Placeholder(placeholder) => format!("<{placeholder}>"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn simple_arguments() {
+        assert_eq!(split("No arguments"), vec!["No arguments"]);
+        assert_eq!(
+            split("Hello {name}, it costs {price, number, ::currency/USD}!"),
+            vec![
+                "Hello ",
+                "<{name}>",
+                ", it costs ",
+                "<{price, number, ::currency/USD}>",
+                "!"
+            ]
+        );
+        assert_eq!(split("{a}{b}"), vec!["<{a}{b}>"]);
+    }
+
+    #[test]
+    fn option_arguments() {
+        assert_eq!(
+            split("You have {count, plural, =0 {no items} one {# item} other {{count} items}}."),
+            vec![
+                "You have ",
+                "<{count, plural, =0 {>",
+                "no items",
+                "<} one {>",
+                "# item",
+                "<} other {{count}>",
+                " items",
+                "<}}>",
+                "."
+            ]
+        );
+        assert_eq!(
+            split("{gender, select, other {}}"),
+            vec!["<{gender, select, other {}}>"]
+        );
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        assert_eq!(split("Unclosed {name"), vec!["Unclosed ", "<{name>"]);
+        assert_eq!(split("Stray } brace"), vec!["Stray } brace"]);
+    }
+}
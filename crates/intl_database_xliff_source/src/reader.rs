@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+
+use roxmltree::{Document, Node};
+
+use intl_database_core::RawPosition;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum XliffVersion {
+    V1_2,
+    V2_0,
+}
+
+/// The translated content of a unit.
+#[derive(Debug)]
+pub struct XliffTarget {
+    /// The text of the target, with inline placeholders replaced by the ICU syntax they protect.
+    pub value: String,
+    /// Position where the content of the target starts.
+    pub position: RawPosition,
+    /// The content of the target as written in the file, if it is plain text, so that positions
+    /// within the value can be mapped back to the file.
+    pub literal: Option<String>,
+    /// The `state` of the target in XLIFF 1.2, or of the segment in XLIFF 2.0.
+    pub state: Option<String>,
+}
+
+impl XliffTarget {
+    /// Whether the state of the target says that it still needs to be translated or reviewed.
+    pub fn needs_review(&self, version: XliffVersion) -> bool {
+        let Some(state) = &self.state else {
+            return false;
+        };
+        match version {
+            XliffVersion::V1_2 => state == "new" || state.starts_with("needs-"),
+            XliffVersion::V2_0 => state == "initial",
+        }
+    }
+}
+
+/// A single translatable unit, which is a `<trans-unit>` in XLIFF 1.2 or a `<unit>` in XLIFF 2.0.
+#[derive(Debug)]
+pub struct XliffUnit {
+    /// The message key of the unit, from its `resname` (1.2) or `name` (2.0), or otherwise its
+    /// `id`.
+    pub key: String,
+    pub source: String,
+    pub target: Option<XliffTarget>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct XliffDocument {
+    pub version: XliffVersion,
+    pub units: Vec<XliffUnit>,
+}
+
+/// Parse the content of an XLIFF 1.2 or 2.0 document into all of the units from every `<file>`
+/// within it.
+pub fn parse_xliff(content: &str) -> Result<XliffDocument, String> {
+    let document = Document::parse(content).map_err(|error| error.to_string())?;
+    let root = document.root_element();
+    if root.tag_name().name() != "xliff" {
+        return Err("Expected the root element to be <xliff>".into());
+    }
+    let version = match root.attribute("version") {
+        Some("1.2") => XliffVersion::V1_2,
+        Some(version) if version.starts_with("2.") => XliffVersion::V2_0,
+        Some(version) => return Err(format!("Unsupported XLIFF version {version}")),
+        None => return Err("The <xliff> element is missing a version".into()),
+    };
+
+    let units = match version {
+        XliffVersion::V1_2 => root
+            .descendants()
+            .filter(|node| is_element(node, "trans-unit"))
+            .map(|node| read_trans_unit(&document, node))
+            .collect::<Result<_, _>>()?,
+        XliffVersion::V2_0 => root
+            .descendants()
+            .filter(|node| is_element(node, "unit"))
+            .map(|node| read_unit(&document, node))
+            .collect::<Result<_, _>>()?,
+    };
+    Ok(XliffDocument { version, units })
+}
+
+fn is_element(node: &Node, name: &str) -> bool {
+    node.is_element() && node.tag_name().name() == name
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(move |child| is_element(child, name))
+}
+
+fn unit_key(node: Node, name_attribute: &str) -> Result<String, String> {
+    node.attribute(name_attribute)
+        .or_else(|| node.attribute("id"))
+        .map(String::from)
+        .ok_or_else(|| format!("<{}> is missing an id", node.tag_name().name()))
+}
+
+fn read_trans_unit(document: &Document, node: Node) -> Result<XliffUnit, String> {
+    let no_data = HashMap::new();
+    let target = children(node, "target")
+        .next()
+        .map(|target| read_target(document, &[target], target.attribute("state"), &no_data));
+    Ok(XliffUnit {
+        key: unit_key(node, "resname")?,
+        source: children(node, "source")
+            .map(|source| inline_text(source, &no_data))
+            .collect(),
+        target,
+        notes: children(node, "note")
+            .map(|note| note.text().unwrap_or_default().to_string())
+            .collect(),
+    })
+}
+
+fn read_unit(document: &Document, node: Node) -> Result<XliffUnit, String> {
+    let original_data: HashMap<&str, String> = children(node, "originalData")
+        .flat_map(|data| children(data, "data"))
+        .filter_map(|data| Some((data.attribute("id")?, inline_text(data, &HashMap::new()))))
+        .collect();
+    // Segments and ignorables are joined together in order to form the whole message.
+    let parts: Vec<Node> = node
+        .children()
+        .filter(|child| is_element(child, "segment") || is_element(child, "ignorable"))
+        .collect();
+    let targets: Vec<Node> = parts
+        .iter()
+        .filter_map(|part| children(*part, "target").next())
+        .collect();
+    let state = parts
+        .iter()
+        .find(|part| is_element(part, "segment"))
+        .and_then(|segment| segment.attribute("state"));
+
+    Ok(XliffUnit {
+        key: unit_key(node, "name")?,
+        source: parts
+            .iter()
+            .filter_map(|part| children(*part, "source").next())
+            .map(|source| inline_text(source, &original_data))
+            .collect(),
+        target: match targets.is_empty() {
+            true => None,
+            false => Some(read_target(document, &targets, state, &original_data)),
+        },
+        notes: children(node, "notes")
+            .flat_map(|notes| children(notes, "note"))
+            .map(|note| note.text().unwrap_or_default().to_string())
+            .collect(),
+    })
+}
+
+fn read_target(
+    document: &Document,
+    targets: &[Node],
+    state: Option<&str>,
+    original_data: &HashMap<&str, String>,
+) -> XliffTarget {
+    let value: String = targets
+        .iter()
+        .map(|target| inline_text(*target, original_data))
+        .collect();
+    let first = targets[0];
+    let content_start = first
+        .first_child()
+        .map_or(first.range().end, |child| child.range().start);
+    let text_position = document.text_pos_at(content_start);
+    let position = RawPosition {
+        line: text_position.row,
+        col: text_position.col - 1,
+    };
+    // Only plain text can be mapped back to the file, since inline elements and entities don't
+    // appear in the value as they are written.
+    let literal = match (targets, first.first_child()) {
+        ([_], Some(child)) if child.is_text() && child.next_sibling().is_none() => {
+            Some(document.input_text()[child.range()].to_string())
+        }
+        _ => None,
+    };
+
+    XliffTarget {
+        value,
+        position,
+        literal,
+        state: state.map(String::from),
+    }
+}
+
+/// Return the text content of `node`, replacing inline placeholder elements with the original
+/// content that they represent.
+fn inline_text(node: Node, original_data: &HashMap<&str, String>) -> String {
+    let mut result = String::new();
+    for child in node.children() {
+        if child.is_text() {
+            result.push_str(child.text().unwrap_or_default());
+            continue;
+        }
+        if !child.is_element() {
+            continue;
+        }
+        match child.tag_name().name() {
+            "ph" if child.has_children() => result.push_str(&inline_text(child, original_data)),
+            "ph" => {
+                let data = child
+                    .attribute("dataRef")
+                    .and_then(|id| original_data.get(id))
+                    .map(String::as_str)
+                    .or_else(|| child.attribute("equiv"))
+                    .or_else(|| child.attribute("disp"))
+                    .unwrap_or_default();
+                result.push_str(data);
+            }
+            "x" | "bx" | "ex" => {
+                result.push_str(child.attribute("equiv-text").unwrap_or_default());
+            }
+            _ => result.push_str(&inline_text(child, original_data)),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_xliff, XliffVersion};
+
+    #[test]
+    fn xliff_1_2() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file original="Feature.messages.js" source-language="en-US" target-language="fr" datatype="plaintext">
+    <body>
+      <trans-unit id="GREETING">
+        <source>Hello <ph id="1">{name}</ph></source>
+        <target state="translated">Bonjour <ph id="1">{name}</ph></target>
+        <note>Greets the user</note>
+      </trans-unit>
+      <trans-unit id="1" resname="PLAIN">
+        <source>Plain &amp; simple</source>
+        <target state="needs-review-translation">Simple</target>
+      </trans-unit>
+      <trans-unit id="UNTRANSLATED">
+        <source>Untranslated</source>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"#;
+        let document = parse_xliff(content).unwrap();
+        assert_eq!(document.version, XliffVersion::V1_2);
+        let units = &document.units;
+        assert_eq!(units.len(), 3);
+        assert_eq!(units[0].key, "GREETING");
+        assert_eq!(units[0].source, "Hello {name}");
+        assert_eq!(units[0].notes, vec!["Greets the user"]);
+        let target = units[0].target.as_ref().unwrap();
+        assert_eq!(target.value, "Bonjour {name}");
+        assert!(target.literal.is_none());
+        assert!(!target.needs_review(document.version));
+
+        assert_eq!(units[1].key, "PLAIN");
+        assert_eq!(units[1].source, "Plain & simple");
+        let target = units[1].target.as_ref().unwrap();
+        assert!(target.needs_review(document.version));
+        assert_eq!(target.literal.as_deref(), Some("Simple"));
+        assert_eq!((target.position.line, target.position.col), (12, 49));
+        assert!(units[2].target.is_none());
+    }
+
+    #[test]
+    fn xliff_2_0() {
+        let content = r#"<xliff version="2.0" xmlns="urn:oasis:names:tc:xliff:document:2.0" srcLang="en-US" trgLang="fr">
+  <file id="f1">
+    <unit id="GREETING">
+      <notes><note>Greets the user</note></notes>
+      <originalData><data id="d1">{name}</data></originalData>
+      <segment state="initial">
+        <source>Hello <ph id="1" dataRef="d1"/></source>
+        <target>Bonjour <ph id="1" dataRef="d1"/></target>
+      </segment>
+      <ignorable><source>!</source><target>&#160;!</target></ignorable>
+    </unit>
+  </file>
+</xliff>"#;
+        let document = parse_xliff(content).unwrap();
+        assert_eq!(document.version, XliffVersion::V2_0);
+        let unit = &document.units[0];
+        assert_eq!(unit.source, "Hello {name}!");
+        assert_eq!(unit.notes, vec!["Greets the user"]);
+        let target = unit.target.as_ref().unwrap();
+        assert_eq!(target.value, "Bonjour {name}\u{a0}!");
+        assert!(target.needs_review(document.version));
+    }
+
+    #[test]
+    fn invalid_documents() {
+        assert!(parse_xliff("<xliff version=\"1.2\">").is_err());
+        assert!(parse_xliff("<root/>").is_err());
+        assert!(parse_xliff("<xliff version=\"3.0\"/>").is_err());
+        assert!(parse_xliff("<xliff version=\"1.2\"><trans-unit/></xliff>").is_err());
+    }
+}
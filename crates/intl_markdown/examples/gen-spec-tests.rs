@@ -14,9 +14,19 @@ struct ExampleDef {
     should_skip: Option<bool>,
 }
 
-fn check_should_skip_test_case(section: &str, content: &str, output: &str) -> bool {
-    // Skip anything with lists.
-    if output.contains("<li>") {
+/// Examples from supported sections that rely on behavior which isn't implemented yet.
+#[rustfmt::skip]
+const UNSUPPORTED_EXAMPLES: [usize; 6] = [
+    // Tabs that only partially count towards the indentation of a list item.
+    5, 6, 7, 9,
+    // Lazy continuation lines that become a setext heading underline inside a block quote.
+    93,
+    // Blank lines inside of a block quote that is nested in a tight list.
+    320,
+];
+
+fn check_should_skip_test_case(number: usize, section: &str, content: &str) -> bool {
+    if UNSUPPORTED_EXAMPLES.contains(&number) {
         return true;
     }
 
@@ -41,11 +51,6 @@ fn check_should_skip_test_case(section: &str, content: &str, output: &str) -> bo
     //     return true;
     // }
 
-    // Skipping hard breaks from br tags and blockquotes
-    if output.contains("<blockquote>") {
-        return true;
-    }
-
     let allowed = vec![
         "autolinks",
         "backslash_escapes",
@@ -66,6 +71,9 @@ fn check_should_skip_test_case(section: &str, content: &str, output: &str) -> bo
         "emphasis_and_strong_emphasis",
         "entity_and_numeric_character_references",
         "blank_lines",
+        "block_quotes",
+        "list_items",
+        "lists",
     ];
 
     return !allowed.contains(&section);
@@ -105,7 +113,7 @@ fn main() {
         .expect("Failed to parse spec tests");
 
     for ExampleDef {
-        number: _,
+        number,
         section,
         input,
         output,
@@ -115,7 +123,7 @@ fn main() {
         *section = sluggify(section);
         *input = input.trim_end_matches('\n').into();
         *output = output.trim_end_matches('\n').into();
-        *should_skip = Some(check_should_skip_test_case(&section, &input, &output));
+        *should_skip = Some(check_should_skip_test_case(*number, &section, &input));
     }
 
     // Output the test contents into test case folders to read as individual
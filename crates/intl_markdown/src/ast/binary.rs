@@ -0,0 +1,445 @@
+//! Binary encoding for Documents, used to persist parsed messages without having to parse their
+//! content again. Every node is written exactly as it exists in memory, including source spans and
+//! diagnostics, so a decoded Document is indistinguishable from the one that was encoded.
+//!
+//! Number and date skeletons are stored as their original text and parsed again when decoding,
+//! since they are small and their parsed form is entirely determined by that text.
+use intl_message_utils::binary::{
+    BinaryDecode, BinaryEncode, BinaryReadResult, BinaryReader, BinaryWriter,
+};
+
+use crate::diagnostic::{SyntaxDiagnostic, SyntaxDiagnosticKind};
+
+use super::{
+    BlockNode, BlockQuote, CodeBlock, CodeBlockKind, CodeSpan, Document, Emphasis, Heading,
+    HeadingKind, Hook, Icu, IcuDate, IcuDateTimeStyle, IcuNumber, IcuNumberStyle, IcuPlural,
+    IcuPluralArm, IcuPluralKind, IcuSelect, IcuTime, IcuVariable, InlineContent, Link,
+    LinkDestination, LinkKind, List, ListItem, ListKind, Paragraph, Strikethrough, Strong, Table,
+    TableAlignment, TableCell, TableRow,
+};
+
+/// Implement encoding for enums without any data by writing each variant as its index in the
+/// given list.
+macro_rules! unit_enum_encoding {
+    ($name:ident, [$($variant:ident),+ $(,)?]) => {
+        impl BinaryEncode for $name {
+            fn encode(&self, writer: &mut BinaryWriter) {
+                const VARIANTS: &[$name] = &[$($name::$variant),+];
+                let index = VARIANTS.iter().position(|variant| variant == self).unwrap();
+                writer.write_u8(index as u8);
+            }
+        }
+        impl BinaryDecode for $name {
+            fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+                const VARIANTS: &[$name] = &[$($name::$variant),+];
+                let tag = reader.read_tag()?;
+                VARIANTS
+                    .get(tag as usize)
+                    .copied()
+                    .ok_or_else(|| reader.invalid_tag(stringify!($name), tag))
+            }
+        }
+    };
+}
+
+/// Implement encoding for single-field tuple structs by encoding their only field.
+macro_rules! newtype_encoding {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            impl BinaryEncode for $name {
+                fn encode(&self, writer: &mut BinaryWriter) {
+                    self.0.encode(writer);
+                }
+            }
+            impl BinaryDecode for $name {
+                fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+                    Ok(Self(reader.read()?))
+                }
+            }
+        )+
+    };
+}
+
+unit_enum_encoding!(HeadingKind, [Atx, Setext]);
+unit_enum_encoding!(CodeBlockKind, [Indented, Fenced]);
+unit_enum_encoding!(ListKind, [Bullet, Ordered]);
+unit_enum_encoding!(TableAlignment, [None, Left, Center, Right]);
+unit_enum_encoding!(LinkKind, [Link, Image, Autolink, Email]);
+unit_enum_encoding!(IcuPluralKind, [Plural, SelectOrdinal]);
+unit_enum_encoding!(
+    SyntaxDiagnosticKind,
+    [
+        UnclosedIcuPlaceholder,
+        UnclosedUnsafePlaceholder,
+        UnknownIcuFormat,
+        ExpectedPluralOptions,
+        InvalidPluralOffset,
+        InvalidPluralArm,
+        UnterminatedPluralArm,
+        InvalidHookName,
+        UnknownNumberSkeletonToken,
+        InvalidNumberSkeletonOptions,
+        InvalidDateTimeSkeletonField,
+        UnsupportedDateTimeSkeletonField,
+    ]
+);
+
+newtype_encoding!(
+    Paragraph,
+    BlockQuote,
+    ListItem,
+    TableRow,
+    TableCell,
+    Emphasis,
+    Strong,
+    Strikethrough,
+    CodeSpan,
+);
+
+impl BinaryEncode for Document {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write(&self.blocks);
+        writer.write(&self.diagnostics);
+    }
+}
+impl BinaryDecode for Document {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        Ok(Self {
+            blocks: reader.read()?,
+            diagnostics: reader.read()?,
+        })
+    }
+}
+
+impl BinaryEncode for SyntaxDiagnostic {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write(&self.kind());
+        writer.write(self.span());
+    }
+}
+impl BinaryDecode for SyntaxDiagnostic {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        Ok(SyntaxDiagnostic::new(reader.read()?, reader.read()?))
+    }
+}
+
+impl BinaryEncode for BlockNode {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        match self {
+            BlockNode::Paragraph(paragraph) => {
+                writer.write_u8(0);
+                writer.write(paragraph);
+            }
+            BlockNode::Heading(heading) => {
+                writer.write_u8(1);
+                writer.write(&heading.kind);
+                writer.write_u8(heading.level);
+                writer.write(&heading.content);
+            }
+            BlockNode::CodeBlock(code_block) => {
+                writer.write_u8(2);
+                writer.write(&code_block.kind);
+                writer.write(&code_block.language);
+                writer.write(&code_block.info_string);
+                writer.write(&code_block.content);
+            }
+            BlockNode::ThematicBreak => writer.write_u8(3),
+            BlockNode::InlineContent(content) => {
+                writer.write_u8(4);
+                writer.write(content);
+            }
+            BlockNode::BlockQuote(block_quote) => {
+                writer.write_u8(5);
+                writer.write(block_quote);
+            }
+            BlockNode::List(list) => {
+                writer.write_u8(6);
+                writer.write(&list.kind);
+                writer.write(&list.start);
+                writer.write_bool(list.is_tight);
+                writer.write(&list.items);
+            }
+            BlockNode::Table(table) => {
+                writer.write_u8(7);
+                writer.write(&table.alignments);
+                writer.write(&table.header);
+                writer.write(&table.rows);
+            }
+        }
+    }
+}
+impl BinaryDecode for BlockNode {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        let node = match reader.read_tag()? {
+            0 => BlockNode::Paragraph(reader.read()?),
+            1 => BlockNode::Heading(Heading {
+                kind: reader.read()?,
+                level: reader.read_u8()?,
+                content: reader.read()?,
+            }),
+            2 => BlockNode::CodeBlock(CodeBlock {
+                kind: reader.read()?,
+                language: reader.read()?,
+                info_string: reader.read()?,
+                content: reader.read()?,
+            }),
+            3 => BlockNode::ThematicBreak,
+            4 => BlockNode::InlineContent(reader.read()?),
+            5 => BlockNode::BlockQuote(reader.read()?),
+            6 => BlockNode::List(List {
+                kind: reader.read()?,
+                start: reader.read()?,
+                is_tight: reader.read_bool()?,
+                items: reader.read()?,
+            }),
+            // Tables are built directly rather than through `Table::new`, since the rows were
+            // already normalized to the column count before being encoded.
+            7 => BlockNode::Table(Table {
+                alignments: reader.read()?,
+                header: reader.read()?,
+                rows: reader.read()?,
+            }),
+            tag => return Err(reader.invalid_tag("BlockNode", tag)),
+        };
+        Ok(node)
+    }
+}
+
+impl BinaryEncode for InlineContent {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        match self {
+            InlineContent::Text(text) => {
+                writer.write_u8(0);
+                writer.write(text);
+            }
+            InlineContent::Emphasis(emphasis) => {
+                writer.write_u8(1);
+                writer.write(emphasis);
+            }
+            InlineContent::Strong(strong) => {
+                writer.write_u8(2);
+                writer.write(strong);
+            }
+            InlineContent::Link(link) => {
+                writer.write_u8(3);
+                writer.write(&link.kind);
+                writer.write(&link.label);
+                writer.write(&link.destination);
+                writer.write(&link.title);
+                writer.write(&link.span);
+            }
+            InlineContent::CodeSpan(code_span) => {
+                writer.write_u8(4);
+                writer.write(code_span);
+            }
+            InlineContent::HardLineBreak => writer.write_u8(5),
+            InlineContent::Hook(hook) => {
+                writer.write_u8(6);
+                writer.write(&hook.content);
+                writer.write(&hook.name);
+                writer.write(&hook.span);
+            }
+            InlineContent::Strikethrough(strikethrough) => {
+                writer.write_u8(7);
+                writer.write(strikethrough);
+            }
+            InlineContent::Icu(icu) => {
+                writer.write_u8(8);
+                writer.write(icu);
+            }
+            InlineContent::IcuPound => writer.write_u8(9),
+        }
+    }
+}
+impl BinaryDecode for InlineContent {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        let node = match reader.read_tag()? {
+            0 => InlineContent::Text(reader.read()?),
+            1 => InlineContent::Emphasis(reader.read()?),
+            2 => InlineContent::Strong(reader.read()?),
+            3 => InlineContent::Link(Link {
+                kind: reader.read()?,
+                label: reader.read()?,
+                destination: reader.read()?,
+                title: reader.read()?,
+                span: reader.read()?,
+            }),
+            4 => InlineContent::CodeSpan(reader.read()?),
+            5 => InlineContent::HardLineBreak,
+            6 => InlineContent::Hook(Hook {
+                content: reader.read()?,
+                name: reader.read()?,
+                span: reader.read()?,
+            }),
+            7 => InlineContent::Strikethrough(reader.read()?),
+            8 => InlineContent::Icu(reader.read()?),
+            9 => InlineContent::IcuPound,
+            tag => return Err(reader.invalid_tag("InlineContent", tag)),
+        };
+        Ok(node)
+    }
+}
+
+impl BinaryEncode for LinkDestination {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        match self {
+            LinkDestination::Text(text) => {
+                writer.write_u8(0);
+                writer.write(text);
+            }
+            LinkDestination::Placeholder(icu) => {
+                writer.write_u8(1);
+                writer.write(icu);
+            }
+            LinkDestination::Handler(name) => {
+                writer.write_u8(2);
+                writer.write(name);
+            }
+        }
+    }
+}
+impl BinaryDecode for LinkDestination {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        match reader.read_tag()? {
+            0 => Ok(LinkDestination::Text(reader.read()?)),
+            1 => Ok(LinkDestination::Placeholder(reader.read()?)),
+            2 => Ok(LinkDestination::Handler(reader.read()?)),
+            tag => Err(reader.invalid_tag("LinkDestination", tag)),
+        }
+    }
+}
+
+impl BinaryEncode for Icu {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        match self {
+            Icu::IcuVariable(variable) => {
+                writer.write_u8(0);
+                writer.write(variable);
+            }
+            Icu::IcuPlural(plural) => {
+                writer.write_u8(1);
+                writer.write(&plural.variable);
+                writer.write(&plural.kind);
+                writer.write_usize(plural.offset);
+                writer.write(&plural.arms);
+                writer.write_bool(plural.is_unsafe);
+            }
+            Icu::IcuSelect(select) => {
+                writer.write_u8(2);
+                writer.write(&select.variable);
+                writer.write(&select.arms);
+                writer.write_bool(select.is_unsafe);
+            }
+            Icu::IcuDate(date) => {
+                writer.write_u8(3);
+                writer.write(&date.variable);
+                writer.write(&date.style);
+                writer.write_bool(date.is_unsafe);
+            }
+            Icu::IcuTime(time) => {
+                writer.write_u8(4);
+                writer.write(&time.variable);
+                writer.write(&time.style);
+                writer.write_bool(time.is_unsafe);
+            }
+            Icu::IcuNumber(number) => {
+                writer.write_u8(5);
+                writer.write(&number.variable);
+                writer.write(&number.style);
+                writer.write_bool(number.is_unsafe);
+            }
+        }
+    }
+}
+impl BinaryDecode for Icu {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        let icu = match reader.read_tag()? {
+            0 => Icu::IcuVariable(reader.read()?),
+            1 => Icu::IcuPlural(IcuPlural {
+                variable: reader.read()?,
+                kind: reader.read()?,
+                offset: reader.read_usize()?,
+                arms: reader.read()?,
+                is_unsafe: reader.read_bool()?,
+            }),
+            2 => Icu::IcuSelect(IcuSelect {
+                variable: reader.read()?,
+                arms: reader.read()?,
+                is_unsafe: reader.read_bool()?,
+            }),
+            3 => Icu::IcuDate(IcuDate {
+                variable: reader.read()?,
+                style: reader.read()?,
+                is_unsafe: reader.read_bool()?,
+            }),
+            4 => Icu::IcuTime(IcuTime {
+                variable: reader.read()?,
+                style: reader.read()?,
+                is_unsafe: reader.read_bool()?,
+            }),
+            5 => Icu::IcuNumber(IcuNumber {
+                variable: reader.read()?,
+                style: reader.read()?,
+                is_unsafe: reader.read_bool()?,
+            }),
+            tag => return Err(reader.invalid_tag("Icu", tag)),
+        };
+        Ok(icu)
+    }
+}
+
+impl BinaryEncode for IcuVariable {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write(&self.name);
+        writer.write_bool(self.is_unsafe);
+        writer.write(&self.span);
+    }
+}
+impl BinaryDecode for IcuVariable {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        Ok(Self {
+            name: reader.read()?,
+            is_unsafe: reader.read_bool()?,
+            span: reader.read()?,
+        })
+    }
+}
+
+impl BinaryEncode for IcuPluralArm {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write(&self.selector);
+        writer.write(&self.selector_span);
+        writer.write(&self.content);
+    }
+}
+impl BinaryDecode for IcuPluralArm {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        Ok(Self {
+            selector: reader.read()?,
+            selector_span: reader.read()?,
+            content: reader.read()?,
+        })
+    }
+}
+
+impl BinaryEncode for IcuDateTimeStyle {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write(&self.text);
+    }
+}
+impl BinaryDecode for IcuDateTimeStyle {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        Ok(IcuDateTimeStyle::new(reader.read()?))
+    }
+}
+
+impl BinaryEncode for IcuNumberStyle {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write(&self.text);
+    }
+}
+impl BinaryDecode for IcuNumberStyle {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        Ok(IcuNumberStyle::new(reader.read()?))
+    }
+}
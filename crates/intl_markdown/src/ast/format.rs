@@ -1,10 +1,10 @@
 use std::fmt::Write;
 
 use crate::ast::{
-    BlockNode, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
+    BlockNode, BlockQuote, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
     IcuDateTimeStyle, IcuNumber, IcuNumberStyle, IcuPlural, IcuPluralArm, IcuPluralKind, IcuSelect,
-    IcuTime, IcuVariable, InlineContent, Link, LinkKind, Paragraph, Strikethrough, Strong,
-    LinkDestination,
+    IcuTime, IcuVariable, InlineContent, Link, LinkDestination, LinkKind, List, ListKind,
+    Paragraph, Strikethrough, Strong, Table, TableAlignment, TableRow,
 };
 
 use super::util::{escape_body_text, escape_href, format_plain_text};
@@ -83,22 +83,107 @@ impl<T: FormatHtml> FormatHtml for [T] {
 
 pub fn format_ast(document: &Document) -> FormatResult<String> {
     let mut f = String::new();
+    format_blocks(&mut f, document.blocks(), false)?;
+    // Blocks are separated by line breaks, but the output has no trailing line break.
+    if f.ends_with('\n') {
+        f.pop();
+    }
+
+    Ok(f)
+}
+
+/// Start a new line in the output unless it is empty or already at the start of a line. Block
+/// elements are written on their own lines this way, the same as the CommonMark reference
+/// renderer.
+fn start_line(f: &mut String) {
+    if !f.is_empty() && !f.ends_with('\n') {
+        f.push('\n');
+    }
+}
 
-    for (index, block) in document.blocks().iter().enumerate() {
-        if index > 0 {
-            f.push('\n');
+/// Format each of the given blocks on their own lines. Paragraphs directly inside the items of
+/// tight lists are written without surrounding `<p>` tags or line breaks.
+fn format_blocks(mut f: &mut String, blocks: &[BlockNode], is_tight: bool) -> FormatResult<()> {
+    for block in blocks {
+        if let BlockNode::Paragraph(paragraph) = block {
+            if is_tight {
+                write!(f, [paragraph.content()])?;
+                continue;
+            }
         }
 
+        start_line(f);
         match block {
             BlockNode::Paragraph(paragraph) => write!(f, [paragraph])?,
             BlockNode::Heading(heading) => write!(f, [heading])?,
             BlockNode::CodeBlock(code_block) => write!(f, [code_block])?,
             BlockNode::ThematicBreak => write!(f, ["<hr />"])?,
             BlockNode::InlineContent(content) => write!(f, [content])?,
+            BlockNode::BlockQuote(block_quote) => format_block_quote(f, block_quote)?,
+            BlockNode::List(list) => format_list(f, list)?,
+            BlockNode::Table(table) => format_table(f, table)?,
         }
+        start_line(f);
     }
 
-    Ok(f)
+    Ok(())
+}
+
+fn format_block_quote(mut f: &mut String, block_quote: &BlockQuote) -> FormatResult<()> {
+    write!(f, ["<blockquote>\n"])?;
+    format_blocks(f, block_quote.content(), false)?;
+    start_line(f);
+    write!(f, ["</blockquote>"])
+}
+
+fn format_list(mut f: &mut String, list: &List) -> FormatResult<()> {
+    let tag = match list.kind() {
+        ListKind::Bullet => "ul",
+        ListKind::Ordered => "ol",
+    };
+    match list.start() {
+        Some(start) if start != "1" => std::writeln!(f, "<{tag} start=\"{start}\">")?,
+        _ => std::writeln!(f, "<{tag}>")?,
+    }
+    for item in list.items() {
+        write!(f, ["<li>"])?;
+        format_blocks(f, item.content(), list.is_tight())?;
+        write!(f, ["</li>\n"])?;
+    }
+    std::write!(f, "</{tag}>")
+}
+
+fn format_table(mut f: &mut String, table: &Table) -> FormatResult<()> {
+    write!(f, ["<table>\n<thead>\n"])?;
+    format_table_row(f, table.header(), table.alignments(), "th")?;
+    write!(f, ["</thead>\n"])?;
+    // Tables without any data rows are written without a body.
+    if !table.rows().is_empty() {
+        write!(f, ["<tbody>\n"])?;
+        for row in table.rows() {
+            format_table_row(f, row, table.alignments(), "td")?;
+        }
+        write!(f, ["</tbody>\n"])?;
+    }
+    write!(f, ["</table>"])
+}
+
+fn format_table_row(
+    mut f: &mut String,
+    row: &TableRow,
+    alignments: &[TableAlignment],
+    tag: &str,
+) -> FormatResult<()> {
+    write!(f, ["<tr>\n"])?;
+    for (cell, alignment) in row.cells().iter().zip(alignments) {
+        match alignment.as_str() {
+            Some(align) => std::write!(f, "<{tag} align=\"{align}\">")?,
+            None => std::write!(f, "<{tag}>")?,
+        }
+        write!(f, [cell.content()])?;
+        std::writeln!(f, "</{tag}>")?;
+    }
+    write!(f, ["</tr>\n"])
 }
 
 impl FormatHtml for Paragraph {
@@ -253,7 +338,11 @@ impl FormatHtml for IcuPlural {
             IcuPluralKind::SelectOrdinal => "selectordinal",
         };
 
-        write!(f, [self.name(), ", ", kind_str, ",", self.arms()])
+        write!(f, [self.name(), ", ", kind_str, ","])?;
+        if self.offset() > 0 {
+            write!(f, [" offset:", &self.offset().to_string()])?;
+        }
+        write!(f, [self.arms()])
     }
 }
 
@@ -13,7 +13,21 @@
 //! removed and replaced with simple String values (such as LinkTitle, LinkResource, etc.), and
 //! other nodes merged into single representations, like AtxHeading and SetextHeading becoming a
 //! single Heading struct with a `kind` property indicating which it came from.
+//!
+//! Every node can also be created directly with its `new` constructor and modified in place
+//! through its `*_mut` accessors and `set_*` setters, which is how transforms like codemods
+//! rewrite a parsed message. Nodes created this way have no position in any source, so their
+//! spans are empty.
+
+use std::ops::Range;
+
+use crate::diagnostic::SyntaxDiagnostic;
+use crate::icu::skeleton::{
+    parse_date_time_style, parse_number_style, DateTimeSkeleton, NamedDateTimeStyle,
+    NamedNumberStyle, NumberSkeleton,
+};
 
+mod binary;
 pub mod format;
 pub mod process;
 pub mod util;
@@ -21,8 +35,15 @@ pub mod util;
 #[derive(Clone, Debug)]
 pub struct Document {
     blocks: Vec<BlockNode>,
+    diagnostics: Vec<SyntaxDiagnostic>,
 }
 impl Document {
+    pub fn new(blocks: Vec<BlockNode>) -> Self {
+        Self {
+            blocks,
+            diagnostics: vec![],
+        }
+    }
     /// Return a new Document with the given content as the only value, treated as a raw string with
     /// no parsing or semantics applied.
     pub fn from_literal(content: &str) -> Self {
@@ -30,11 +51,27 @@ impl Document {
             blocks: vec![BlockNode::InlineContent(vec![InlineContent::Text(
                 content.into(),
             )])],
+            diagnostics: vec![],
         }
     }
+    /// Add the given diagnostics to the ones already found in this document, keeping them ordered
+    /// by where they appear in the source.
+    pub fn with_diagnostics(mut self, diagnostics: Vec<SyntaxDiagnostic>) -> Self {
+        self.diagnostics.extend(diagnostics);
+        self.diagnostics
+            .sort_by_key(|diagnostic| diagnostic.span().start);
+        self
+    }
     pub fn blocks(&self) -> &Vec<BlockNode> {
         &self.blocks
     }
+    pub fn blocks_mut(&mut self) -> &mut Vec<BlockNode> {
+        &mut self.blocks
+    }
+    /// Syntax errors that were recovered from while parsing the content of this document.
+    pub fn diagnostics(&self) -> &Vec<SyntaxDiagnostic> {
+        &self.diagnostics
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -46,15 +83,24 @@ pub enum BlockNode {
     /// Inline content directly added to a Document, generally only in the case of using inline
     /// mode, where the content is intentionally _not_ placed inside a paragraph.
     InlineContent(Vec<InlineContent>),
+    BlockQuote(BlockQuote),
+    List(List),
+    Table(Table),
 }
 
 #[derive(Clone, Debug)]
 #[repr(transparent)]
 pub struct Paragraph(Vec<InlineContent>);
 impl Paragraph {
+    pub fn new(content: Vec<InlineContent>) -> Self {
+        Self(content)
+    }
     pub fn content(&self) -> &Vec<InlineContent> {
         &self.0
     }
+    pub fn content_mut(&mut self) -> &mut Vec<InlineContent> {
+        &mut self.0
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -70,6 +116,14 @@ pub struct Heading {
     content: Vec<InlineContent>,
 }
 impl Heading {
+    pub fn new(kind: HeadingKind, level: u8, content: Vec<InlineContent>) -> Self {
+        Self {
+            kind,
+            level,
+            content,
+        }
+    }
+
     pub fn kind(&self) -> &HeadingKind {
         &self.kind
     }
@@ -78,9 +132,17 @@ impl Heading {
         self.level
     }
 
+    pub fn set_level(&mut self, level: u8) {
+        self.level = level;
+    }
+
     pub fn content(&self) -> &Vec<InlineContent> {
         &self.content
     }
+
+    pub fn content_mut(&mut self) -> &mut Vec<InlineContent> {
+        &mut self.content
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -97,6 +159,19 @@ pub struct CodeBlock {
     content: String,
 }
 impl CodeBlock {
+    pub fn new(
+        kind: CodeBlockKind,
+        language: Option<String>,
+        info_string: Option<String>,
+        content: String,
+    ) -> Self {
+        Self {
+            kind,
+            language,
+            info_string,
+            content,
+        }
+    }
     pub fn kind(&self) -> &CodeBlockKind {
         &self.kind
     }
@@ -109,6 +184,185 @@ impl CodeBlock {
     pub fn content(&self) -> &String {
         &self.content
     }
+    pub fn content_mut(&mut self) -> &mut String {
+        &mut self.content
+    }
+}
+
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct BlockQuote(Vec<BlockNode>);
+impl BlockQuote {
+    pub fn new(content: Vec<BlockNode>) -> Self {
+        Self(content)
+    }
+    pub fn content(&self) -> &Vec<BlockNode> {
+        &self.0
+    }
+    pub fn content_mut(&mut self) -> &mut Vec<BlockNode> {
+        &mut self.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListKind {
+    Bullet,
+    Ordered,
+}
+
+#[derive(Clone, Debug)]
+pub struct List {
+    kind: ListKind,
+    start: Option<String>,
+    is_tight: bool,
+    items: Vec<ListItem>,
+}
+impl List {
+    pub fn new(
+        kind: ListKind,
+        start: Option<String>,
+        is_tight: bool,
+        items: Vec<ListItem>,
+    ) -> Self {
+        Self {
+            kind,
+            start,
+            is_tight,
+            items,
+        }
+    }
+    pub fn kind(&self) -> &ListKind {
+        &self.kind
+    }
+    /// The number of the first item of an ordered list, without any leading zeros. This is
+    /// always None for bullet lists.
+    pub fn start(&self) -> Option<&str> {
+        self.start.as_deref()
+    }
+    /// True if no items of the list are separated by blank lines and no item contains blocks
+    /// separated by blank lines. Paragraphs in the items of tight lists are rendered without
+    /// their own paragraph tags.
+    pub fn is_tight(&self) -> bool {
+        self.is_tight
+    }
+    pub fn set_tight(&mut self, is_tight: bool) {
+        self.is_tight = is_tight;
+    }
+    pub fn items(&self) -> &Vec<ListItem> {
+        &self.items
+    }
+    pub fn items_mut(&mut self) -> &mut Vec<ListItem> {
+        &mut self.items
+    }
+}
+
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct ListItem(Vec<BlockNode>);
+impl ListItem {
+    pub fn new(content: Vec<BlockNode>) -> Self {
+        Self(content)
+    }
+    pub fn content(&self) -> &Vec<BlockNode> {
+        &self.0
+    }
+    pub fn content_mut(&mut self) -> &mut Vec<BlockNode> {
+        &mut self.0
+    }
+}
+
+/// The alignment of a table column, set by colons in the table's delimiter row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableAlignment {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl TableAlignment {
+    /// The name of this alignment as used by the `align` attribute of a table cell, or None if the
+    /// column has no alignment.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            TableAlignment::None => None,
+            TableAlignment::Left => Some("left"),
+            TableAlignment::Center => Some("center"),
+            TableAlignment::Right => Some("right"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Table {
+    alignments: Vec<TableAlignment>,
+    header: TableRow,
+    rows: Vec<TableRow>,
+}
+impl Table {
+    /// Create a table with one column for each of the given alignments. Rows with more cells than
+    /// there are columns are truncated, and rows with fewer are filled with empty cells.
+    pub fn new(alignments: Vec<TableAlignment>, header: TableRow, rows: Vec<TableRow>) -> Self {
+        let mut table = Self {
+            alignments,
+            header,
+            rows,
+        };
+        let column_count = table.alignments.len();
+        for row in std::iter::once(&mut table.header).chain(&mut table.rows) {
+            row.0.resize_with(column_count, || TableCell(vec![]));
+        }
+        table
+    }
+    /// The alignment of every column of the table. Every row of the table has exactly one cell
+    /// for each of these columns.
+    pub fn alignments(&self) -> &Vec<TableAlignment> {
+        &self.alignments
+    }
+    pub fn header(&self) -> &TableRow {
+        &self.header
+    }
+    pub fn header_mut(&mut self) -> &mut TableRow {
+        &mut self.header
+    }
+    pub fn rows(&self) -> &Vec<TableRow> {
+        &self.rows
+    }
+    /// Mutable access to the body rows of the table. Cells beyond the table's columns are ignored
+    /// when the table is rendered, so rows added here should match the columns of the header.
+    pub fn rows_mut(&mut self) -> &mut Vec<TableRow> {
+        &mut self.rows
+    }
+}
+
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct TableRow(Vec<TableCell>);
+impl TableRow {
+    pub fn new(cells: Vec<TableCell>) -> Self {
+        Self(cells)
+    }
+    pub fn cells(&self) -> &Vec<TableCell> {
+        &self.0
+    }
+    pub fn cells_mut(&mut self) -> &mut Vec<TableCell> {
+        &mut self.0
+    }
+}
+
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+pub struct TableCell(Vec<InlineContent>);
+impl TableCell {
+    pub fn new(content: Vec<InlineContent>) -> Self {
+        Self(content)
+    }
+    pub fn content(&self) -> &Vec<InlineContent> {
+        &self.0
+    }
+    pub fn content_mut(&mut self) -> &mut Vec<InlineContent> {
+        &mut self.0
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -138,18 +392,30 @@ pub enum InlineContent {
 #[repr(transparent)]
 pub struct Emphasis(Vec<InlineContent>);
 impl Emphasis {
+    pub fn new(content: Vec<InlineContent>) -> Self {
+        Self(content)
+    }
     pub fn content(&self) -> &Vec<InlineContent> {
         &self.0
     }
+    pub fn content_mut(&mut self) -> &mut Vec<InlineContent> {
+        &mut self.0
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct Strong(Vec<InlineContent>);
 impl Strong {
+    pub fn new(content: Vec<InlineContent>) -> Self {
+        Self(content)
+    }
     pub fn content(&self) -> &Vec<InlineContent> {
         &self.0
     }
+    pub fn content_mut(&mut self) -> &mut Vec<InlineContent> {
+        &mut self.0
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -191,54 +457,113 @@ pub struct Link {
     label: Vec<InlineContent>,
     destination: LinkDestination,
     title: Option<String>,
+    span: Range<usize>,
 }
 
 impl Link {
+    pub fn new(
+        kind: LinkKind,
+        label: Vec<InlineContent>,
+        destination: LinkDestination,
+        title: Option<String>,
+    ) -> Self {
+        Self {
+            kind,
+            label,
+            destination,
+            title,
+            span: Range::default(),
+        }
+    }
     pub fn kind(&self) -> LinkKind {
         self.kind
     }
+    /// Byte range of the entire link in the original message source.
+    pub fn span(&self) -> &Range<usize> {
+        &self.span
+    }
     pub fn label(&self) -> &Vec<InlineContent> {
         &self.label
     }
+    pub fn label_mut(&mut self) -> &mut Vec<InlineContent> {
+        &mut self.label
+    }
     pub fn destination(&self) -> &LinkDestination {
         &self.destination
     }
+    pub fn destination_mut(&mut self) -> &mut LinkDestination {
+        &mut self.destination
+    }
     pub fn title(&self) -> &Option<String> {
         &self.title
     }
+    pub fn set_title(&mut self, title: Option<String>) {
+        self.title = title;
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Hook {
     content: Vec<InlineContent>,
     name: String,
+    span: Range<usize>,
 }
 
 impl Hook {
+    pub fn new(name: String, content: Vec<InlineContent>) -> Self {
+        Self {
+            content,
+            name,
+            span: Range::default(),
+        }
+    }
     pub fn name(&self) -> &String {
         &self.name
     }
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+    /// Byte range of the entire hook, from the `$` through the closing paren of its name, in the
+    /// original message source.
+    pub fn span(&self) -> &Range<usize> {
+        &self.span
+    }
     pub fn content(&self) -> &Vec<InlineContent> {
         &self.content
     }
+    pub fn content_mut(&mut self) -> &mut Vec<InlineContent> {
+        &mut self.content
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct Strikethrough(Vec<InlineContent>);
 impl Strikethrough {
+    pub fn new(content: Vec<InlineContent>) -> Self {
+        Self(content)
+    }
     pub fn content(&self) -> &Vec<InlineContent> {
         &self.0
     }
+    pub fn content_mut(&mut self) -> &mut Vec<InlineContent> {
+        &mut self.0
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct CodeSpan(String);
 impl CodeSpan {
+    pub fn new(content: String) -> Self {
+        Self(content)
+    }
     pub fn content(&self) -> &String {
         &self.0
     }
+    pub fn content_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -269,12 +594,30 @@ impl Icu {
 pub struct IcuVariable {
     name: String,
     is_unsafe: bool,
+    span: Range<usize>,
 }
 impl IcuVariable {
+    pub fn new(name: String, is_unsafe: bool) -> Self {
+        Self {
+            name,
+            is_unsafe,
+            span: Range::default(),
+        }
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
 
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    /// Byte range of the variable's name in the original message source.
+    pub fn span(&self) -> &Range<usize> {
+        &self.span
+    }
+
     pub fn is_unsafe(&self) -> bool {
         self.is_unsafe
     }
@@ -290,14 +633,35 @@ pub enum IcuPluralKind {
 pub struct IcuPlural {
     variable: IcuVariable,
     kind: IcuPluralKind,
+    offset: usize,
     arms: Vec<IcuPluralArm>,
     is_unsafe: bool,
 }
 impl IcuPlural {
+    /// Create a plural that is unsafe if the given variable is.
+    pub fn new(
+        variable: IcuVariable,
+        kind: IcuPluralKind,
+        offset: usize,
+        arms: Vec<IcuPluralArm>,
+    ) -> Self {
+        Self {
+            is_unsafe: variable.is_unsafe,
+            variable,
+            kind,
+            offset,
+            arms,
+        }
+    }
+
     pub fn variable(&self) -> &IcuVariable {
         &self.variable
     }
 
+    pub fn variable_mut(&mut self) -> &mut IcuVariable {
+        &mut self.variable
+    }
+
     pub fn name(&self) -> &String {
         self.variable.name()
     }
@@ -306,10 +670,24 @@ impl IcuPlural {
         &self.kind
     }
 
+    /// The amount subtracted from the value before selecting a plural category and formatting `#`,
+    /// written as `offset:N` before the arms. Exact selectors still match the original value.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
     pub fn arms(&self) -> &Vec<IcuPluralArm> {
         &self.arms
     }
 
+    pub fn arms_mut(&mut self) -> &mut Vec<IcuPluralArm> {
+        &mut self.arms
+    }
+
     pub fn is_unsafe(&self) -> bool {
         self.is_unsafe
     }
@@ -322,10 +700,23 @@ pub struct IcuSelect {
     is_unsafe: bool,
 }
 impl IcuSelect {
+    /// Create a select that is unsafe if the given variable is.
+    pub fn new(variable: IcuVariable, arms: Vec<IcuPluralArm>) -> Self {
+        Self {
+            is_unsafe: variable.is_unsafe,
+            variable,
+            arms,
+        }
+    }
+
     pub fn variable(&self) -> &IcuVariable {
         &self.variable
     }
 
+    pub fn variable_mut(&mut self) -> &mut IcuVariable {
+        &mut self.variable
+    }
+
     pub fn name(&self) -> &String {
         self.variable.name()
     }
@@ -334,6 +725,10 @@ impl IcuSelect {
         &self.arms
     }
 
+    pub fn arms_mut(&mut self) -> &mut Vec<IcuPluralArm> {
+        &mut self.arms
+    }
+
     pub fn is_unsafe(&self) -> bool {
         self.is_unsafe
     }
@@ -342,16 +737,38 @@ impl IcuSelect {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct IcuPluralArm {
     selector: String,
+    selector_span: Range<usize>,
     content: Vec<InlineContent>,
 }
 impl IcuPluralArm {
+    pub fn new(selector: String, content: Vec<InlineContent>) -> Self {
+        Self {
+            selector,
+            selector_span: Range::default(),
+            content,
+        }
+    }
+
     pub fn selector(&self) -> &String {
         &self.selector
     }
 
+    pub fn set_selector(&mut self, selector: String) {
+        self.selector = selector;
+    }
+
+    /// Byte range of the arm's selector in the original message source.
+    pub fn selector_span(&self) -> &Range<usize> {
+        &self.selector_span
+    }
+
     pub fn content(&self) -> &Vec<InlineContent> {
         &self.content
     }
+
+    pub fn content_mut(&mut self) -> &mut Vec<InlineContent> {
+        &mut self.content
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -361,15 +778,29 @@ pub struct IcuDate {
     is_unsafe: bool,
 }
 impl IcuDate {
+    /// Create a date format that is unsafe if the given variable is.
+    pub fn new(variable: IcuVariable, style: Option<IcuDateTimeStyle>) -> Self {
+        Self {
+            is_unsafe: variable.is_unsafe,
+            variable,
+            style,
+        }
+    }
     pub fn variable(&self) -> &IcuVariable {
         &self.variable
     }
+    pub fn variable_mut(&mut self) -> &mut IcuVariable {
+        &mut self.variable
+    }
     pub fn name(&self) -> &String {
         self.variable.name()
     }
     pub fn style(&self) -> &Option<IcuDateTimeStyle> {
         &self.style
     }
+    pub fn style_mut(&mut self) -> &mut Option<IcuDateTimeStyle> {
+        &mut self.style
+    }
     pub fn is_unsafe(&self) -> bool {
         self.is_unsafe
     }
@@ -382,15 +813,29 @@ pub struct IcuTime {
     is_unsafe: bool,
 }
 impl IcuTime {
+    /// Create a time format that is unsafe if the given variable is.
+    pub fn new(variable: IcuVariable, style: Option<IcuDateTimeStyle>) -> Self {
+        Self {
+            is_unsafe: variable.is_unsafe,
+            variable,
+            style,
+        }
+    }
     pub fn variable(&self) -> &IcuVariable {
         &self.variable
     }
+    pub fn variable_mut(&mut self) -> &mut IcuVariable {
+        &mut self.variable
+    }
     pub fn name(&self) -> &String {
         self.variable.name()
     }
     pub fn style(&self) -> &Option<IcuDateTimeStyle> {
         &self.style
     }
+    pub fn style_mut(&mut self) -> &mut Option<IcuDateTimeStyle> {
+        &mut self.style
+    }
     pub fn is_unsafe(&self) -> bool {
         self.is_unsafe
     }
@@ -399,11 +844,31 @@ impl IcuTime {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct IcuDateTimeStyle {
     text: String,
+    kind: IcuDateTimeStyleKind,
 }
 impl IcuDateTimeStyle {
+    /// Create a style from its text, like `short` or `::yyyyMMMd`. Text that isn't a valid style
+    /// creates an Invalid style.
+    pub fn new(text: String) -> Self {
+        let kind = parse_date_time_style(&text).unwrap_or(IcuDateTimeStyleKind::Invalid);
+        Self { text, kind }
+    }
     pub fn text(&self) -> &String {
         &self.text
     }
+    pub fn kind(&self) -> &IcuDateTimeStyleKind {
+        &self.kind
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IcuDateTimeStyleKind {
+    /// A predefined style, like `short`.
+    Named(NamedDateTimeStyle),
+    /// A skeleton describing the fields to format, like `::yyyyMMMd`.
+    Skeleton(Box<DateTimeSkeleton>),
+    /// A skeleton that couldn't be parsed. The errors are reported on the containing Document.
+    Invalid,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -413,15 +878,29 @@ pub struct IcuNumber {
     is_unsafe: bool,
 }
 impl IcuNumber {
+    /// Create a number format that is unsafe if the given variable is.
+    pub fn new(variable: IcuVariable, style: Option<IcuNumberStyle>) -> Self {
+        Self {
+            is_unsafe: variable.is_unsafe,
+            variable,
+            style,
+        }
+    }
     pub fn variable(&self) -> &IcuVariable {
         &self.variable
     }
+    pub fn variable_mut(&mut self) -> &mut IcuVariable {
+        &mut self.variable
+    }
     pub fn name(&self) -> &String {
         self.variable.name()
     }
     pub fn style(&self) -> &Option<IcuNumberStyle> {
         &self.style
     }
+    pub fn style_mut(&mut self) -> &mut Option<IcuNumberStyle> {
+        &mut self.style
+    }
     pub fn is_unsafe(&self) -> bool {
         self.is_unsafe
     }
@@ -430,9 +909,29 @@ impl IcuNumber {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct IcuNumberStyle {
     text: String,
+    kind: IcuNumberStyleKind,
 }
 impl IcuNumberStyle {
+    /// Create a style from its text, like `percent` or `::currency/EUR`. Text that isn't a valid
+    /// style creates an Invalid style.
+    pub fn new(text: String) -> Self {
+        let kind = parse_number_style(&text).unwrap_or(IcuNumberStyleKind::Invalid);
+        Self { text, kind }
+    }
     pub fn text(&self) -> &String {
         &self.text
     }
+    pub fn kind(&self) -> &IcuNumberStyleKind {
+        &self.kind
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IcuNumberStyleKind {
+    /// A predefined style, like `percent`.
+    Named(NamedNumberStyle),
+    /// A skeleton describing the format in detail, like `::currency/EUR .00`.
+    Skeleton(Box<NumberSkeleton>),
+    /// A skeleton that couldn't be parsed. The errors are reported on the containing Document.
+    Invalid,
 }
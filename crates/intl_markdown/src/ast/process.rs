@@ -1,7 +1,9 @@
 use std::borrow::Cow;
 
 use crate::ast::{CodeBlockKind, HeadingKind, IcuPluralKind, LinkDestination, LinkKind};
+use crate::diagnostic::SyntaxDiagnostic;
 use crate::html_entities::get_html_entity;
+use crate::icu::skeleton::{offset_diagnostics, parse_date_time_style, parse_number_style};
 use crate::token::{SourceText, Token};
 use crate::tree_builder::{cst, TokenSpan};
 use crate::util::unescape_cow;
@@ -14,6 +16,8 @@ pub struct AstProcessingContext {
     source: SourceText,
     allow_hard_line_breaks: bool,
     allow_icu_pound: bool,
+    is_in_table_cell: bool,
+    diagnostics: Vec<SyntaxDiagnostic>,
 }
 
 impl AstProcessingContext {
@@ -22,9 +26,17 @@ impl AstProcessingContext {
             source,
             allow_hard_line_breaks: false,
             allow_icu_pound: false,
+            is_in_table_cell: false,
+            diagnostics: vec![],
         }
     }
 
+    /// Record diagnostics found in a part of the source, with spans relative to `offset`.
+    fn add_diagnostics(&mut self, diagnostics: Vec<SyntaxDiagnostic>, offset: usize) {
+        self.diagnostics
+            .extend(offset_diagnostics(diagnostics, offset));
+    }
+
     fn with_context<M, F, T>(&mut self, mut mutator: M, func: F) -> T
     where
         M: FnMut(&mut Self),
@@ -32,9 +44,11 @@ impl AstProcessingContext {
     {
         let old_allow_hard_line_breaks = self.allow_hard_line_breaks;
         let old_allow_icu_pound = self.allow_icu_pound;
+        let old_is_in_table_cell = self.is_in_table_cell;
         mutator(self);
 
         let result = func(self);
+        self.is_in_table_cell = old_is_in_table_cell;
         self.allow_icu_pound = old_allow_icu_pound;
         self.allow_hard_line_breaks = old_allow_hard_line_breaks;
         result
@@ -43,8 +57,20 @@ impl AstProcessingContext {
 
 pub fn process_cst_to_ast(source: SourceText, cst: &cst::Document) -> ast::Document {
     let mut context = AstProcessingContext::new(source);
+    let blocks = process_blocks(&mut context, cst.children());
+
+    ast::Document {
+        blocks,
+        diagnostics: context.diagnostics,
+    }
+}
+
+pub fn process_blocks(
+    context: &mut AstProcessingContext,
+    children: &Vec<cst::NodeOrToken>,
+) -> Vec<ast::BlockNode> {
     let mut blocks = vec![];
-    for node in cst.children() {
+    for node in children {
         match node {
             // Top-level tokens can't mean anything in a document, so this is ignored.
             cst::NodeOrToken::Token(_) => {}
@@ -52,23 +78,28 @@ pub fn process_cst_to_ast(source: SourceText, cst: &cst::Document) -> ast::Docum
                 let ast_node = match node {
                     cst::Node::ThematicBreak(_) => ast::BlockNode::ThematicBreak,
                     cst::Node::InlineContent(content) => {
-                        ast::BlockNode::InlineContent(process_inline_content(&mut context, content))
+                        ast::BlockNode::InlineContent(process_inline_content(context, content))
                     }
                     cst::Node::Paragraph(paragraph) => {
-                        ast::BlockNode::Paragraph(process_paragraph(&mut context, paragraph))
+                        ast::BlockNode::Paragraph(process_paragraph(context, paragraph))
                     }
                     cst::Node::AtxHeading(atx_heading) => {
-                        ast::BlockNode::Heading(process_atx_heading(&mut context, atx_heading))
+                        ast::BlockNode::Heading(process_atx_heading(context, atx_heading))
                     }
-                    cst::Node::SetextHeading(setext_heading) => ast::BlockNode::Heading(
-                        process_setext_heading(&mut context, setext_heading),
-                    ),
-                    cst::Node::IndentedCodeBlock(code_block) => ast::BlockNode::CodeBlock(
-                        process_indented_code_block(&mut context, code_block),
-                    ),
-                    cst::Node::FencedCodeBlock(code_block) => ast::BlockNode::CodeBlock(
-                        process_fenced_code_block(&mut context, code_block),
-                    ),
+                    cst::Node::SetextHeading(setext_heading) => {
+                        ast::BlockNode::Heading(process_setext_heading(context, setext_heading))
+                    }
+                    cst::Node::IndentedCodeBlock(code_block) => {
+                        ast::BlockNode::CodeBlock(process_indented_code_block(context, code_block))
+                    }
+                    cst::Node::FencedCodeBlock(code_block) => {
+                        ast::BlockNode::CodeBlock(process_fenced_code_block(context, code_block))
+                    }
+                    cst::Node::BlockQuote(block_quote) => {
+                        ast::BlockNode::BlockQuote(process_block_quote(context, block_quote))
+                    }
+                    cst::Node::List(list) => ast::BlockNode::List(process_list(context, list)),
+                    cst::Node::Table(table) => ast::BlockNode::Table(process_table(context, table)),
                     node => unreachable!(
                         "Inline nodes can't appear directly under a document. Found:\n{:#?}",
                         node
@@ -79,7 +110,132 @@ pub fn process_cst_to_ast(source: SourceText, cst: &cst::Document) -> ast::Docum
         }
     }
 
-    ast::Document { blocks }
+    blocks
+}
+
+pub fn process_block_quote(
+    context: &mut AstProcessingContext,
+    block_quote: &cst::BlockQuote,
+) -> ast::BlockQuote {
+    ast::BlockQuote(process_blocks(context, block_quote.children()))
+}
+
+pub fn process_list(context: &mut AstProcessingContext, list: &cst::List) -> ast::List {
+    let first_marker = &list.items[0].marker;
+    let (kind, start) = match first_marker.kind() {
+        SyntaxKind::ORDERED_LIST_MARKER => (
+            ast::ListKind::Ordered,
+            Some(
+                first_marker
+                    .text()
+                    .trim_end_matches(['.', ')'])
+                    .parse::<u32>()
+                    .map_or_else(|_| "1".into(), |start| start.to_string()),
+            ),
+        ),
+        _ => (ast::ListKind::Bullet, None),
+    };
+
+    // "A list is loose if any of its constituent list items are separated by blank lines, or if
+    // any of its constituent list items directly contain two block-level elements with a blank
+    // line between them. Otherwise a list is tight."
+    let is_tight = !list
+        .items
+        .windows(2)
+        .any(|items| is_separated_by_blank_line(context, &items[0], &items[1]))
+        && !list.items.iter().any(|item| {
+            item.children
+                .windows(2)
+                .any(|blocks| is_separated_by_blank_line(context, &blocks[0], &blocks[1]))
+        });
+
+    let items = list
+        .items
+        .iter()
+        .map(|item| ast::ListItem(process_blocks(context, &item.children)))
+        .collect();
+
+    ast::List {
+        kind,
+        start,
+        is_tight,
+        items,
+    }
+}
+
+pub fn process_table(context: &mut AstProcessingContext, table: &cst::Table) -> ast::Table {
+    let delimiter_text: String = table
+        .delimiter_row
+        .children()
+        .iter()
+        .map(|token| token.text())
+        .collect();
+    let alignments: Vec<_> = delimiter_text
+        .split('|')
+        .filter(|cell| !cell.is_empty())
+        .map(|cell| match (cell.starts_with(':'), cell.ends_with(':')) {
+            (true, true) => ast::TableAlignment::Center,
+            (true, false) => ast::TableAlignment::Left,
+            (false, true) => ast::TableAlignment::Right,
+            (false, false) => ast::TableAlignment::None,
+        })
+        .collect();
+
+    let header = process_table_row(context, &table.header, alignments.len());
+    let rows = table
+        .rows
+        .iter()
+        .map(|row| process_table_row(context, row, alignments.len()))
+        .collect();
+
+    ast::Table {
+        alignments,
+        header,
+        rows,
+    }
+}
+
+/// "The remainder of the table's rows may vary in the number of cells. If a number of cells fewer
+/// than the number of cells in the header row, empty cells are inserted. If greater, the excess
+/// is ignored."
+fn process_table_row(
+    context: &mut AstProcessingContext,
+    row: &cst::TableRow,
+    column_count: usize,
+) -> ast::TableRow {
+    let mut cells: Vec<_> = row
+        .children()
+        .iter()
+        .filter_map(|child| match child.as_node() {
+            Some(cst::Node::TableCell(cell)) => Some(cell),
+            _ => None,
+        })
+        .take(column_count)
+        .map(|cell| {
+            context.with_context(
+                |context| context.is_in_table_cell = true,
+                |context| ast::TableCell(process_inline_content(context, &cell.content)),
+            )
+        })
+        .collect();
+    cells.resize_with(column_count, || ast::TableCell(vec![]));
+    ast::TableRow(cells)
+}
+
+/// Returns true if a blank line appears in the source between the end of `before` and the start
+/// of `after`.
+fn is_separated_by_blank_line(
+    context: &AstProcessingContext,
+    before: &impl TokenSpan,
+    after: &impl TokenSpan,
+) -> bool {
+    let (Some(before), Some(after)) = (before.last_token(), after.first_token()) else {
+        return false;
+    };
+    let between = &context.source[before.range().end as usize..after.range().start as usize];
+    // Verbatim lines in code blocks include their own line ending.
+    let line_ending_count = between.matches('\n').count() + before.text().ends_with('\n') as usize;
+    line_ending_count >= 2
 }
 
 pub fn process_paragraph(
@@ -118,11 +274,20 @@ pub fn process_indented_code_block(
     context: &mut AstProcessingContext,
     code_block: &cst::IndentedCodeBlock,
 ) -> ast::CodeBlock {
+    let mut content = process_code_block_content(context, &code_block.content);
+    // Blank lines following an indented code block are not part of its content.
+    if let Some(last_content) = content.rfind(|c| !matches!(c, ' ' | '\t' | '\n')) {
+        let line_end = content[last_content..]
+            .find('\n')
+            .map_or(content.len(), |offset| last_content + offset + 1);
+        content.truncate(line_end);
+    }
+
     ast::CodeBlock {
         kind: CodeBlockKind::Indented,
         language: None,
         info_string: None,
-        content: process_code_block_content(context, &code_block.content),
+        content,
     }
 }
 
@@ -143,8 +308,8 @@ pub fn process_fenced_code_block(
         let mut past_first_line_ending = false;
         for piece in trivia {
             match piece.kind() {
-                // Leading whitespace is always skipped
-                SyntaxKind::LEADING_WHITESPACE => continue,
+                // Leading whitespace and container prefixes are always skipped
+                SyntaxKind::LEADING_WHITESPACE | SyntaxKind::BLOCK_PREFIX => continue,
                 // Skip everything up until the first line ending
                 SyntaxKind::LINE_ENDING if !past_first_line_ending => {
                     past_first_line_ending = true;
@@ -156,6 +321,19 @@ pub fn process_fenced_code_block(
         }
     }
     content.push_str(&process_code_block_content(context, &code_block.content));
+    // Inside of a container block, blank lines at the end of the content follow the container's
+    // prefix, so they become leading trivia of the closing delimiter instead.
+    if let Some(token) = code_block
+        .closing_sequence
+        .as_ref()
+        .and_then(|closing_sequence| closing_sequence.first_token())
+    {
+        for trivia in token.leading_trivia() {
+            if trivia.kind() == SyntaxKind::BLANK_LINE {
+                content.push_str(trivia.text());
+            }
+        }
+    }
 
     ast::CodeBlock {
         kind: CodeBlockKind::Fenced,
@@ -170,13 +348,36 @@ pub fn process_code_block_content(
     content: &cst::CodeBlockContent,
 ) -> String {
     let mut buffer = String::with_capacity(content.children().len() * 10);
-    for child in content.children() {
+    for (index, child) in content.children().iter().enumerate() {
         // The only leading trivia that a token in a code block can have is a LEADING_WHITESPACE
         // trivia, which is intentionally left out of the content of a code block anyway, so we
         // can always skip all leading trivia of these tokens safely.
+        //
+        // Inside a container block, though, blank lines in the code follow the container's prefix
+        // and become leading trivia of the next line. Blank lines before the first token are
+        // handled by the containing code block.
+        if index > 0 {
+            for trivia in child.leading_trivia() {
+                if trivia.kind() == SyntaxKind::BLANK_LINE {
+                    buffer.push_str(trivia.text());
+                }
+            }
+        }
         let range = child.text_with_trailing_trivia_range();
         buffer.push_str(&context.source[range.start as usize..range.end as usize]);
     }
+    // Line endings after the last line are lexed once the code block has already ended, like the
+    // blank lines between this block and the next one, so they aren't part of the content.
+    if let Some(last) = content.children().last() {
+        let trailing_line_endings = last
+            .trailing_trivia()
+            .iter()
+            .rev()
+            .take_while(|trivia| trivia.kind() == SyntaxKind::LINE_ENDING)
+            .map(|trivia| trivia.text().len())
+            .sum::<usize>();
+        buffer.truncate(buffer.len() - trailing_line_endings);
+    }
 
     // Code blocks always have a trailing newline, so add it if it isn't already present.
     if !buffer.is_empty() && !buffer.ends_with('\n') {
@@ -422,6 +623,7 @@ pub fn process_link(context: &mut AstProcessingContext, link: &cst::Link) -> ast
         label,
         destination,
         title,
+        span: link.l_square.range().start as usize..link.resource.r_paren.range().end as usize,
     }
 }
 
@@ -435,6 +637,7 @@ pub fn process_image(context: &mut AstProcessingContext, image: &cst::Image) ->
         label,
         destination,
         title,
+        span: image.exclaim.range().start as usize..image.resource.r_paren.range().end as usize,
     }
 }
 
@@ -457,6 +660,7 @@ pub fn process_autolink(_: &mut AstProcessingContext, image: &cst::Autolink) ->
         label,
         destination: LinkDestination::Text(destination),
         title: None,
+        span: image.uri.range_usize(),
     }
 }
 
@@ -527,6 +731,11 @@ pub fn process_code_span(
         text.push('\\');
     }
 
+    // "It is possible to include a pipe in a cell's content by escaping it, including inside other
+    // inline spans", so escaped pipes in a table cell are unescaped even inside of code spans.
+    if context.is_in_table_cell {
+        text = text.replace("\\|", "|");
+    }
     // Line endings within a code span are converted to single spaces.
     let mut text = text.replace('\n', " ");
     // Then, _after_ replacing, if each side ends with a space but the content is not entirely made
@@ -546,6 +755,7 @@ pub fn process_hook(context: &mut AstProcessingContext, hook: &cst::Hook) -> ast
     ast::Hook {
         content: process_inline_content(context, &hook.content),
         name: process_hook_name(context, &hook.name),
+        span: hook.dollar.range().start as usize..hook.name.r_paren.range().end as usize,
     }
 }
 
@@ -573,6 +783,7 @@ pub fn process_icu(context: &mut AstProcessingContext, icu: &cst::Icu) -> ast::I
         cst::IcuPlaceholder::IcuPlural(plural) => ast::Icu::IcuPlural(process_icu_plural(
             context,
             &plural.variable,
+            &plural.offset,
             &plural.arms,
             IcuPluralKind::Plural,
             is_unsafe,
@@ -580,6 +791,7 @@ pub fn process_icu(context: &mut AstProcessingContext, icu: &cst::Icu) -> ast::I
         cst::IcuPlaceholder::IcuSelectOrdinal(select) => ast::Icu::IcuPlural(process_icu_plural(
             context,
             &select.variable,
+            &select.offset,
             &select.arms,
             IcuPluralKind::SelectOrdinal,
             is_unsafe,
@@ -604,6 +816,7 @@ pub fn process_icu_variable(
     ast::IcuVariable {
         name: variable.ident.text().to_owned(),
         is_unsafe,
+        span: variable.ident.range_usize(),
     }
 }
 
@@ -614,7 +827,10 @@ pub fn process_icu_date(
 ) -> ast::IcuDate {
     ast::IcuDate {
         variable: process_icu_variable(context, &date.variable, is_unsafe),
-        style: date.style.as_ref().map(process_icu_date_time_style),
+        style: date
+            .style
+            .as_ref()
+            .map(|style| process_icu_date_time_style(context, style)),
         is_unsafe,
     }
 }
@@ -626,14 +842,26 @@ pub fn process_icu_time(
 ) -> ast::IcuTime {
     ast::IcuTime {
         variable: process_icu_variable(context, &time.variable, is_unsafe),
-        style: time.style.as_ref().map(process_icu_date_time_style),
+        style: time
+            .style
+            .as_ref()
+            .map(|style| process_icu_date_time_style(context, style)),
         is_unsafe,
     }
 }
 
-pub fn process_icu_date_time_style(style: &cst::IcuDateTimeStyle) -> ast::IcuDateTimeStyle {
+pub fn process_icu_date_time_style(
+    context: &mut AstProcessingContext,
+    style: &cst::IcuDateTimeStyle,
+) -> ast::IcuDateTimeStyle {
+    let (text, offset) = trimmed_style_text(&style.style_text);
+    let kind = parse_date_time_style(text).unwrap_or_else(|diagnostics| {
+        context.add_diagnostics(diagnostics, offset);
+        ast::IcuDateTimeStyleKind::Invalid
+    });
     ast::IcuDateTimeStyle {
-        text: style.style_text.text().trim().into(),
+        text: text.into(),
+        kind,
     }
 }
 
@@ -644,20 +872,42 @@ pub fn process_icu_number(
 ) -> ast::IcuNumber {
     ast::IcuNumber {
         variable: process_icu_variable(context, &number.variable, is_unsafe),
-        style: number.style.as_ref().map(process_icu_number_style),
+        style: number
+            .style
+            .as_ref()
+            .map(|style| process_icu_number_style(context, style)),
         is_unsafe,
     }
 }
 
-pub fn process_icu_number_style(style: &cst::IcuNumberStyle) -> ast::IcuNumberStyle {
+pub fn process_icu_number_style(
+    context: &mut AstProcessingContext,
+    style: &cst::IcuNumberStyle,
+) -> ast::IcuNumberStyle {
+    let (text, offset) = trimmed_style_text(&style.style_text);
+    let kind = parse_number_style(text).unwrap_or_else(|diagnostics| {
+        context.add_diagnostics(diagnostics, offset);
+        ast::IcuNumberStyleKind::Invalid
+    });
     ast::IcuNumberStyle {
-        text: style.style_text.text().trim().into(),
+        text: text.into(),
+        kind,
     }
 }
 
+/// Returns the text of a style argument without surrounding whitespace, along with the offset in
+/// the source where that trimmed text starts.
+fn trimmed_style_text(style_text: &Token) -> (&str, usize) {
+    let text = style_text.text();
+    let trimmed = text.trim_start();
+    let offset = style_text.range_usize().start + text.len() - trimmed.len();
+    (trimmed.trim_end(), offset)
+}
+
 pub fn process_icu_plural(
     context: &mut AstProcessingContext,
     variable: &cst::IcuVariable,
+    offset: &Option<cst::IcuPluralOffset>,
     arms: &Vec<cst::IcuPluralArm>,
     kind: IcuPluralKind,
     is_unsafe: bool,
@@ -666,9 +916,14 @@ pub fn process_icu_plural(
         .iter()
         .map(|arm| process_plural_arm(context, arm))
         .collect();
+    // The parser only accepts offsets that fit, so this can't fail on a parsed document.
+    let offset = offset
+        .as_ref()
+        .map_or(0, |offset| offset.value.text().parse().unwrap_or_default());
     ast::IcuPlural {
         variable: process_icu_variable(context, &variable, is_unsafe),
         kind,
+        offset,
         arms,
         is_unsafe,
     }
@@ -698,6 +953,7 @@ pub fn process_plural_arm(
         |context| context.allow_icu_pound = true,
         |context| ast::IcuPluralArm {
             selector: arm.selector.text().into(),
+            selector_span: arm.selector.range_usize(),
             content: process_inline_content(context, &arm.value.content),
         },
     )
@@ -13,6 +13,10 @@ pub(crate) enum BlockBound {
     // outside of these bounds will be parsed as block syntax only.
     InlineStart(usize, SyntaxKind),
     InlineEnd(usize, SyntaxKind),
+    // Container syntax at the start of a line, like a block quote marker or a
+    // list item marker, is lexed directly as a single token of the given kind
+    // spanning from the start position to the end position.
+    Token(usize, usize, SyntaxKind),
 }
 
 impl BlockBound {
@@ -22,6 +26,7 @@ impl BlockBound {
             BlockBound::End(position, _) => position,
             BlockBound::InlineStart(position, _) => position,
             BlockBound::InlineEnd(position, _) => position,
+            BlockBound::Token(position, _, _) => position,
         }
     }
 
@@ -31,6 +36,7 @@ impl BlockBound {
             BlockBound::End(_, kind) => *kind,
             BlockBound::InlineStart(_, kind) => *kind,
             BlockBound::InlineEnd(_, kind) => *kind,
+            BlockBound::Token(_, _, kind) => *kind,
         }
     }
 }
@@ -62,7 +68,6 @@ impl Line {
         self.offset + self.leading_offset
     }
 
-    #[allow(unused)]
     fn get_from<'a>(&self, text: &'a str) -> &'a str {
         &text[self.offset..self.end_offset()]
     }
@@ -71,6 +76,70 @@ impl Line {
         &text[self.content_offset()..self.end_offset()]
     }
 
+    /// Returns the remainder of this line after the first `length` bytes, like the content of a
+    /// line inside a container block once the container's prefix is removed. The leading
+    /// whitespace of the remainder is measured again from its new start.
+    fn strip_prefix(&self, text: &str, length: usize) -> Line {
+        let offset = self.offset + length;
+        let line_length = self.line_length - length;
+        let mut leading_offset = 0;
+        let mut leading_spaces = 0;
+        for byte in text[offset..offset + line_length].bytes() {
+            match byte {
+                b' ' => leading_spaces += 1,
+                b'\t' => leading_spaces += 4 - (leading_spaces % 4),
+                _ => break,
+            }
+            leading_offset += 1;
+        }
+
+        Line {
+            offset,
+            leading_offset,
+            line_length,
+            leading_spaces,
+            ..*self
+        }
+    }
+
+    /// Returns the number of bytes that make up the first `columns` columns of indentation on
+    /// this line, or all of its indentation if there is less than that.
+    fn indentation_length(&self, text: &str, columns: usize) -> usize {
+        let mut length = 0;
+        let mut current_columns = 0;
+        for byte in self.get_from(text).bytes() {
+            if current_columns >= columns {
+                break;
+            }
+            match byte {
+                b' ' => current_columns += 1,
+                b'\t' => current_columns += 4 - (current_columns % 4),
+                _ => break,
+            }
+            length += 1;
+        }
+
+        length
+    }
+
+    /// Returns the content of this line inside of all the container markers it starts with, like
+    /// `foo` from `> - foo`.
+    fn innermost_content(&self, text: &str) -> Line {
+        let mut line = *self;
+        loop {
+            if let Some(length) = line.block_quote_marker_length(text) {
+                line = line.strip_prefix(text, length);
+            } else if let Some(marker) = line
+                .list_item_marker(text)
+                .filter(|_| !line.is_thematic_break(text))
+            {
+                line = line.strip_prefix(text, marker.content_start);
+            } else {
+                return line;
+            }
+        }
+    }
+
     /// Returns true if this line starts with content that is part of an ICU control section, where
     /// Markdown rules won't apply. If the line does not start in an ICU context, it may be
     /// considered for block semantics, but if a line _ends_ with an ICU context, then the following
@@ -128,6 +197,17 @@ impl Line {
         true
     }
 
+    /// Returns the fence character and the length of the opening fence for a line that starts a
+    /// fenced code block.
+    fn code_fence(&self, text: &str) -> (char, usize) {
+        let content = self.get_content(text);
+        let count = content
+            .find(|c| !matches!(c, '~' | '`'))
+            .unwrap_or(content.len());
+
+        (content.as_bytes()[0] as char, count)
+    }
+
     fn is_fenced_code_block_ending(
         &self,
         text: &str,
@@ -249,6 +329,82 @@ impl Line {
         count >= 3
     }
 
+    /// Returns the length in bytes of the block quote marker starting this line, including its
+    /// indentation and the optional space after the `>`, or None if the line doesn't start a
+    /// block quote.
+    fn block_quote_marker_length(&self, text: &str) -> Option<usize> {
+        if self.leading_spaces >= 4 || !self.get_content(text).starts_with('>') {
+            return None;
+        }
+
+        let length = self.leading_offset + 1;
+        match text.as_bytes().get(self.offset + length) {
+            Some(b' ' | b'\t') if length < self.line_length => Some(length + 1),
+            _ => Some(length),
+        }
+    }
+
+    /// Returns the list item marker starting this line, or None if the line doesn't start a list
+    /// item. This does not check for thematic breaks, which take precedence over list items when
+    /// a line could be either.
+    fn list_item_marker(&self, text: &str) -> Option<ListItemMarker> {
+        if self.leading_spaces >= 4 {
+            return None;
+        }
+
+        let bytes = self.get_content(text).as_bytes();
+        let (delimiter, number, marker_length) = match bytes.first()? {
+            b'-' | b'+' | b'*' => (bytes[0], None, 1),
+            b'0'..=b'9' => {
+                let digits = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+                if digits > 9 {
+                    return None;
+                }
+                let delimiter = *bytes.get(digits)?;
+                if !matches!(delimiter, b'.' | b')') {
+                    return None;
+                }
+                // Nine digits always fit in a u32.
+                let number = std::str::from_utf8(&bytes[..digits]).ok()?.parse().ok()?;
+                (delimiter, Some(number), digits + 1)
+            }
+            _ => return None,
+        };
+
+        // The marker must be followed by whitespace or the end of the line.
+        let rest = &bytes[marker_length..];
+        let spaces = rest
+            .iter()
+            .take_while(|b| matches!(b, b' ' | b'\t'))
+            .count();
+        if spaces == 0 && !rest.is_empty() {
+            return None;
+        }
+
+        let is_empty = spaces == rest.len();
+        // When the item starts with a blank line or with an indented code block (five or more
+        // spaces after the marker), its content begins one space after the marker.
+        let padding = if is_empty || spaces > 4 { 1 } else { spaces };
+        let marker_start = self.leading_offset;
+        let marker_end = marker_start + marker_length;
+
+        Some(ListItemMarker {
+            delimiter,
+            number,
+            marker_start,
+            marker_end,
+            content_start: std::cmp::min(marker_end + padding, self.line_length),
+            content_indent: self.leading_spaces + marker_length + padding,
+            is_empty,
+        })
+    }
+
+    /// Returns true if this line starts a container block, either a block quote or a list item.
+    fn starts_container(&self, text: &str) -> bool {
+        self.block_quote_marker_length(text).is_some()
+            || (self.list_item_marker(text).is_some() && !self.is_thematic_break(text))
+    }
+
     /// Returns true if the line represents a new block that can interrupt a
     /// paragraph.
     fn can_interrupt_paragraph(&self, text: &str) -> bool {
@@ -256,11 +412,175 @@ impl Line {
             || self.is_thematic_break(text)
             || self.is_atx_heading(text)
             || self.is_blank()
+            || self.block_quote_marker_length(text).is_some()
+            || self
+                .list_item_marker(text)
+                .is_some_and(|marker| marker.can_interrupt_paragraph())
+    }
+
+    /// Returns true if this line can continue a paragraph inside a container block even without
+    /// the container's prefix, making it a lazy continuation line.
+    fn is_lazy_continuation(&self, text: &str) -> bool {
+        !self.is_blank() && !self.can_interrupt_paragraph(text) && !self.starts_container(text)
+    }
+
+    /// Splits the content of this line into the pipes and cells of a table row. Pipes inside of
+    /// ICU content or escaped with a backslash don't separate cells, and the leading and trailing
+    /// pipes of a row are optional, so they don't create empty cells of their own.
+    fn table_row(&self, text: &str) -> TableRowLayout {
+        let content = self.get_content(text).trim_end_matches([' ', '\t']);
+        let content_start = self.content_offset();
+        let content_end = content_start + content.len();
+        let bytes = content.as_bytes();
+
+        let mut pipes = vec![];
+        let mut icu_brace_balance: usize = 0;
+        let mut index = 0;
+        while index < bytes.len() {
+            match bytes[index] {
+                b'\\' => index += 1,
+                b'\'' if matches!(bytes.get(index + 1), Some(b'{' | b'}')) => index += 1,
+                b'{' => icu_brace_balance += 1,
+                b'}' => icu_brace_balance = icu_brace_balance.saturating_sub(1),
+                b'|' if icu_brace_balance == 0 => pipes.push(content_start + index),
+                _ => {}
+            }
+            index += 1;
+        }
+
+        let mut cells = vec![];
+        let mut segment_start = content_start;
+        for segment_end in pipes.iter().copied().chain([content_end]) {
+            let segment = &text[segment_start..segment_end];
+            let start = segment_end - segment.trim_start_matches([' ', '\t']).len();
+            let end = segment_start + segment.trim_end_matches([' ', '\t']).len();
+            // Empty cells are placed at the end of their segment, right before the next pipe.
+            cells.push(if start < end {
+                (start, end)
+            } else {
+                (segment_end, segment_end)
+            });
+            segment_start = segment_end + 1;
+        }
+        if pipes.first() == Some(&content_start) {
+            cells.remove(0);
+        }
+        if pipes.last().is_some_and(|pipe| pipe + 1 == content_end) {
+            cells.pop();
+        }
+
+        TableRowLayout { pipes, cells }
+    }
+
+    /// Returns the number of columns of the table if this line is a table delimiter row, where
+    /// every cell is made of hyphens, optionally with a colon on either side to set the alignment
+    /// of the column, like `| :--- | :---: | ---: |`.
+    fn table_delimiter_row_columns(&self, text: &str) -> Option<usize> {
+        let row = self.table_row(text);
+        if self.is_blank() || row.pipes.is_empty() {
+            return None;
+        }
+
+        let is_delimiter_cell = |&(start, end): &(usize, usize)| {
+            let cell = &text[start..end];
+            let cell = cell.strip_prefix(':').unwrap_or(cell);
+            let cell = cell.strip_suffix(':').unwrap_or(cell);
+            !cell.is_empty() && cell.bytes().all(|byte| byte == b'-')
+        };
+        row.cells
+            .iter()
+            .all(is_delimiter_cell)
+            .then_some(row.cells.len())
     }
 
     //#endregion
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ListItemMarker {
+    /// The bullet character of a bullet list item, or the `.` or `)` following the number of an
+    /// ordered list item.
+    delimiter: u8,
+    /// The number of an ordered list item.
+    number: Option<u32>,
+    /// Byte offsets of the marker from the start of the line.
+    marker_start: usize,
+    marker_end: usize,
+    /// Byte offset of the item's content from the start of the line.
+    content_start: usize,
+    /// The number of columns that following lines must be indented by to continue the item.
+    content_indent: usize,
+    /// True if nothing follows the marker on its line.
+    is_empty: bool,
+}
+
+impl ListItemMarker {
+    fn kind(&self) -> SyntaxKind {
+        match self.number {
+            Some(_) => SyntaxKind::ORDERED_LIST_MARKER,
+            None => SyntaxKind::BULLET_LIST_MARKER,
+        }
+    }
+
+    /// Returns true if an item with this marker belongs to the same list as an item with the
+    /// `other` marker, meaning they use the same bullet character or the same delimiter.
+    fn continues_list(&self, other: &ListItemMarker) -> bool {
+        self.delimiter == other.delimiter
+    }
+
+    /// "In order for a sequence of lines to constitute a list item, the sequence must... when
+    /// the first list item in a list interrupts a paragraph...the list item must not be empty
+    /// and ordered lists must start with 1."
+    fn can_interrupt_paragraph(&self) -> bool {
+        !self.is_empty && self.number.map_or(true, |number| number == 1)
+    }
+}
+
+/// The positions of the pipes and the trimmed content of each cell in a single row of a table.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct TableRowLayout {
+    pipes: Vec<usize>,
+    cells: Vec<(usize, usize)>,
+}
+
+/// Tracks what the content of a container block ends with while its lines are being collected,
+/// to determine whether a following line without the container's prefix can still belong to it
+/// as a lazy paragraph continuation.
+#[derive(Clone, Copy, Debug, Default)]
+struct LazyContinuationState {
+    is_in_paragraph: bool,
+    open_fence: Option<(char, usize)>,
+}
+
+impl LazyContinuationState {
+    fn push_line(&mut self, text: &str, line: Line) {
+        let line = line.innermost_content(text);
+        if let Some((expected, opening_count)) = self.open_fence {
+            if line.is_fenced_code_block_ending(text, expected, opening_count) {
+                self.open_fence = None;
+            }
+            return;
+        }
+
+        if line.is_fenced_code_block(text) {
+            self.open_fence = Some(line.code_fence(text));
+            self.is_in_paragraph = false;
+        } else if self.is_in_paragraph {
+            self.is_in_paragraph =
+                !line.is_setext_heading_underline(text) && !line.can_interrupt_paragraph(text);
+        } else {
+            self.is_in_paragraph = !line.is_blank()
+                && !line.is_indented_code_block()
+                && !line.is_thematic_break(text)
+                && !line.is_atx_heading(text);
+        }
+    }
+
+    fn allows(&self, text: &str, line: &Line) -> bool {
+        self.is_in_paragraph && line.is_lazy_continuation(text)
+    }
+}
+
 /// A one-shot parser to build a structure of block elements from a Markdown
 /// source text. The result is a list of indices in the text representing block
 /// boundaries, which the full parser is then able to use as delimiters when
@@ -274,10 +594,16 @@ pub(crate) struct BlockParser<'a> {
 
 impl<'a> BlockParser<'a> {
     pub(crate) fn new(text: &'a str) -> Self {
+        Self::from_lines(text, create_lines(text))
+    }
+
+    /// Create a parser for only the given lines of the text, like the content of a container
+    /// block with the container's prefixes stripped from each line.
+    fn from_lines(text: &'a str, lines: VecDeque<Line>) -> Self {
         Self {
             text,
             bounds: vec![],
-            lines: create_lines(text),
+            lines,
             previous_line: None,
         }
     }
@@ -304,6 +630,11 @@ impl<'a> BlockParser<'a> {
                     self.consume_line_as(SyntaxKind::THEMATIC_BREAK)
                 }
                 line if line.is_atx_heading(self.text) => self.consume_atx_heading(),
+                line if line.block_quote_marker_length(self.text).is_some() => {
+                    self.consume_block_quote()
+                }
+                line if line.list_item_marker(self.text).is_some() => self.consume_list(),
+                _ if self.is_at_table_start() => self.consume_table(),
                 // A sequence of non-blank lines that cannot be interpreted as
                 // other kinds of blocks forms a paragraph.
                 _ => self.consume_paragraph_or_setext_heading(),
@@ -320,6 +651,81 @@ impl<'a> BlockParser<'a> {
         self.push_end(kind);
     }
 
+    /// Returns true if the current line is the header row of a table, meaning it contains at least
+    /// one pipe and is followed by a delimiter row with the same number of cells. The header row
+    /// must be a single line, so it can't start or end inside of ICU content.
+    fn is_at_table_start(&self) -> bool {
+        let (Some(header), Some(delimiter_row)) = (self.lines.front(), self.lines.get(1)) else {
+            return false;
+        };
+        if header.starting_icu_brace_balance > 0 || header.ends_inside_icu_context() {
+            return false;
+        }
+
+        let header_row = header.table_row(self.text);
+        !header_row.pipes.is_empty()
+            && delimiter_row.table_delimiter_row_columns(self.text) == Some(header_row.cells.len())
+    }
+
+    /// Consume a table from the input, starting with its header and delimiter rows. Every
+    /// following line is a data row of the table until a blank line, the start of another block,
+    /// or a line that starts or ends inside of ICU content, since rows can't span multiple lines.
+    ///
+    /// Unlike GFM, a table can't interrupt a paragraph, so its header row must be the first line
+    /// of the block.
+    fn consume_table(&mut self) {
+        self.push_start(SyntaxKind::TABLE);
+        let header = *self.current_line();
+        self.push_table_row(header);
+        self.advance();
+        self.consume_line_as(SyntaxKind::TABLE_DELIMITER_ROW);
+
+        while !self.is_eof() {
+            let line = *self.current_line();
+            if line.is_blank()
+                || line.starting_icu_brace_balance > 0
+                || line.ends_inside_icu_context()
+                || line.can_interrupt_paragraph(self.text)
+                || line.starts_container(self.text)
+            {
+                break;
+            }
+            self.push_table_row(line);
+            self.advance();
+        }
+
+        self.push_end(SyntaxKind::TABLE);
+    }
+
+    /// Push the bounds for a single row of a table, with a token for every pipe on the line and an
+    /// inline content segment for every cell.
+    fn push_table_row(&mut self, line: Line) {
+        let row = line.table_row(self.text);
+        self.push_start_at(SyntaxKind::TABLE_ROW, line.offset);
+        let mut pipes = row.pipes.into_iter().peekable();
+        for (start, end) in row.cells {
+            while let Some(pipe) = pipes.next_if(|pipe| *pipe < start) {
+                self.push_table_cell_delimiter(pipe);
+            }
+            self.push_start_at(SyntaxKind::TABLE_CELL, start);
+            self.push_inline_start(start);
+            self.push_inline_end(end);
+            self.push_end_at(SyntaxKind::TABLE_CELL, end);
+        }
+        for pipe in pipes {
+            self.push_table_cell_delimiter(pipe);
+        }
+        self.push_end_at(SyntaxKind::TABLE_ROW, line.end_offset());
+    }
+
+    fn push_table_cell_delimiter(&mut self, index: usize) {
+        self.bounds.push(BlockBound::Token(
+            index,
+            index + 1,
+            SyntaxKind::TABLE_CELL_DELIMITER,
+        ));
+    }
+
     /// Consume a paragraph from the input, eating lines so long as they are valid continuations.
     /// This method checks whether the following line is able to interrupt the paragraph to assert
     /// that the continuation is valid or not.
@@ -333,11 +739,12 @@ impl<'a> BlockParser<'a> {
         let start_offset = self.current_line().offset;
         let mut block_kind = SyntaxKind::PARAGRAPH;
         self.eat_lines_while(|line| {
-            // Ending in an ICU context forces the paragraph to continue until the ICU content
-            // ends, even if there are blank lines. This check can safely happen first, since a
-            // setext heading underline cannot contain extra characters that would allow an ICU
-            // content segment to start or end on that line.
-            if line.ends_inside_icu_context() {
+            // Starting or ending in an ICU context forces the paragraph to continue until the ICU
+            // content ends, even if there are blank lines or the line looks like the start of
+            // another block. This check can safely happen first, since a setext heading underline
+            // cannot contain extra characters that would allow an ICU content segment to start or
+            // end on that line.
+            if line.starting_icu_brace_balance > 0 || line.ends_inside_icu_context() {
                 return true;
             }
 
@@ -455,13 +862,8 @@ impl<'a> BlockParser<'a> {
     /// Fenced code blocks also ignore ICU context since they also treat all content within them as
     /// literal text.
     fn consume_fenced_code_block(&mut self) {
-        let opening_content = self.current_line().get_content(self.text);
         // Find how many characters will be needed to close the block
-        let opening_count = opening_content
-            .find(|c| !matches!(c, '~' | '`'))
-            .unwrap_or(opening_content.len());
-
-        let expected = self.current_line().get_content(self.text).as_bytes()[0] as char;
+        let (expected, opening_count) = self.current_line().code_fence(self.text);
 
         self.push_start(SyntaxKind::FENCED_CODE_BLOCK);
         self.advance();
@@ -483,8 +885,12 @@ impl<'a> BlockParser<'a> {
             // so the inline and block ends just appear at the end of the input.
             if self.is_eof() {
                 let last_line = self.previous_line.unwrap();
-                self.push_inline_end(last_line.end_offset());
-                break;
+                // Inside of a container, the last line still has a trailing newline, which is
+                // part of the code content.
+                let end = last_line.end_offset() + !last_line.is_last_line as usize;
+                self.push_inline_end(end);
+                self.push_end_at(SyntaxKind::FENCED_CODE_BLOCK, end);
+                return;
             }
 
             // If this line is a valid code block ending, then mark the end of the inline content
@@ -504,6 +910,192 @@ impl<'a> BlockParser<'a> {
         self.push_end(SyntaxKind::FENCED_CODE_BLOCK);
     }
 
+    /// Consume a block quote from the input, collecting every line that starts with a block
+    /// quote marker or lazily continues a paragraph inside of it. The content of those lines is
+    /// then parsed as its own set of blocks, and the markers are added as prefix tokens.
+    ///
+    /// Like paragraphs, a line ending inside an ICU context forces the next line to be part of
+    /// the block quote. If that line doesn't start with a marker, it is kept exactly as written,
+    /// since Markdown syntax doesn't apply within ICU content.
+    fn consume_block_quote(&mut self) {
+        let start_offset = self.current_line().offset;
+        let mut prefixes = vec![];
+        let mut content_lines = VecDeque::new();
+        let mut lazy_state = LazyContinuationState::default();
+
+        while !self.is_eof() {
+            let line = *self.current_line();
+            if let Some(length) = line.block_quote_marker_length(self.text) {
+                prefixes.extend(self.prefix_bound(&line, length));
+                let content = line.strip_prefix(self.text, length);
+                lazy_state.push_line(self.text, content);
+                content_lines.push_back(content);
+            } else if (self
+                .previous_line
+                .is_some_and(|previous| previous.ends_inside_icu_context())
+                && !content_lines.is_empty())
+                || lazy_state.allows(self.text, &line)
+            {
+                content_lines.push_back(line);
+            } else {
+                break;
+            }
+            self.advance();
+        }
+
+        let last_line = self.previous_line.unwrap();
+        self.push_start_at(SyntaxKind::BLOCK_QUOTE, start_offset);
+        self.push_container_content(SyntaxKind::BLOCK_QUOTE, prefixes, content_lines, last_line);
+    }
+
+    /// Consume a list from the input, which is a sequence of list items that all use the same
+    /// kind of marker, optionally separated by blank lines.
+    fn consume_list(&mut self) {
+        let first_marker = self.current_line().list_item_marker(self.text).unwrap();
+        self.push_start(SyntaxKind::LIST);
+
+        loop {
+            let line = *self.current_line();
+            let marker = line.list_item_marker(self.text).unwrap();
+            self.consume_list_item(line, marker);
+
+            // Blank lines between items don't end the list, so the list continues if the next
+            // contentful line is another item of the same kind.
+            let next_contentful_line = self.lines.iter().find(|line| !line.is_blank());
+            let continues_list = next_contentful_line.is_some_and(|next| {
+                !next.is_thematic_break(self.text)
+                    && next
+                        .list_item_marker(self.text)
+                        .is_some_and(|next_marker| next_marker.continues_list(&first_marker))
+            });
+            if !continues_list {
+                break;
+            }
+            while self.current_line().is_blank() {
+                self.advance();
+            }
+        }
+
+        let end = self.bounds.last().map_or(0, |bound| *bound.position());
+        self.push_end_at(SyntaxKind::LIST, end);
+    }
+
+    /// Consume a single list item starting on the given line. Following lines belong to the item
+    /// when they are indented at least as far as the item's content, are blank, lazily continue
+    /// a paragraph, or continue ICU content from the previous line. Blank lines at the end of the
+    /// item are not included in it.
+    fn consume_list_item(&mut self, line: Line, marker: ListItemMarker) {
+        self.push_start(SyntaxKind::LIST_ITEM);
+        let mut prefixes = vec![BlockBound::Token(
+            line.offset + marker.marker_start,
+            line.offset + marker.marker_end,
+            marker.kind(),
+        )];
+        if marker.content_start > marker.marker_end {
+            prefixes.push(BlockBound::Token(
+                line.offset + marker.marker_end,
+                line.offset + marker.content_start,
+                SyntaxKind::BLOCK_PREFIX,
+            ));
+        }
+
+        let first_content = line.strip_prefix(self.text, marker.content_start);
+        let mut content_lines = VecDeque::from([first_content]);
+        let mut lazy_state = LazyContinuationState::default();
+        lazy_state.push_line(self.text, first_content);
+        let mut last_line = line;
+        // Blank lines only become part of the item once a contentful line follows them.
+        let mut pending_blank_lines = vec![];
+        self.advance();
+
+        while !self.is_eof() {
+            let line = *self.current_line();
+            let is_inside_icu = self.previous_line.unwrap().ends_inside_icu_context();
+            let (content, prefix) = if line.is_blank() && !is_inside_icu {
+                // A list item can begin with at most one blank line.
+                if marker.is_empty && content_lines.len() == 1 {
+                    break;
+                }
+                let length = line.indentation_length(self.text, marker.content_indent);
+                pending_blank_lines.push((
+                    line.strip_prefix(self.text, length),
+                    self.prefix_bound(&line, length),
+                ));
+                self.advance();
+                continue;
+            } else if line.leading_spaces >= marker.content_indent {
+                let length = line.indentation_length(self.text, marker.content_indent);
+                (
+                    line.strip_prefix(self.text, length),
+                    self.prefix_bound(&line, length),
+                )
+            } else if is_inside_icu
+                || (pending_blank_lines.is_empty() && lazy_state.allows(self.text, &line))
+            {
+                // Lines inside of ICU content are kept exactly as written when they aren't
+                // indented as part of the item.
+                (line, None)
+            } else {
+                break;
+            };
+
+            for (blank_line, blank_prefix) in pending_blank_lines.drain(..) {
+                lazy_state.push_line(self.text, blank_line);
+                content_lines.push_back(blank_line);
+                prefixes.extend(blank_prefix);
+            }
+            prefixes.extend(prefix);
+            lazy_state.push_line(self.text, content);
+            content_lines.push_back(content);
+            last_line = line;
+            self.advance();
+        }
+
+        self.push_container_content(SyntaxKind::LIST_ITEM, prefixes, content_lines, last_line);
+    }
+
+    /// Returns a prefix token bound covering the first `length` bytes of the given line, or None
+    /// if there is no prefix to cover.
+    fn prefix_bound(&self, line: &Line, length: usize) -> Option<BlockBound> {
+        (length > 0).then_some(BlockBound::Token(
+            line.offset,
+            line.offset + length,
+            SyntaxKind::BLOCK_PREFIX,
+        ))
+    }
+
+    /// Parse the given content lines of a container block as their own set of blocks, then add
+    /// the resulting bounds together with the container's prefix tokens, followed by the end of
+    /// the container after `last_line`.
+    fn push_container_content(
+        &mut self,
+        kind: SyntaxKind,
+        prefixes: Vec<BlockBound>,
+        content_lines: VecDeque<Line>,
+        last_line: Line,
+    ) {
+        let children = BlockParser::from_lines(self.text, content_lines).parse_into_block_bounds();
+
+        // Both sets of bounds are already ordered, so they can be merged by position. Child
+        // bounds come first when they share a position with a prefix, since those can only be
+        // the ends of blocks from the previous line.
+        let mut children = children.into_iter().peekable();
+        for prefix in prefixes {
+            while let Some(child) = children.next_if(|child| child.position() <= prefix.position())
+            {
+                self.bounds.push(child);
+            }
+            self.bounds.push(prefix);
+        }
+        self.bounds.extend(children);
+
+        let end = std::cmp::max(
+            last_line.end_offset(),
+            self.bounds.last().map_or(0, |bound| *bound.position()),
+        );
+        self.push_end_at(kind, end);
+    }
+
     fn current_line(&self) -> &Line {
         self.lines
             .front()
@@ -571,6 +1163,11 @@ impl<'a> BlockParser<'a> {
             .push(BlockBound::End(previous.end_offset(), kind));
     }
 
+    /// Push an end bound for the given kind at the given offset.
+    fn push_end_at(&mut self, kind: SyntaxKind, index: usize) {
+        self.bounds.push(BlockBound::End(index, kind));
+    }
+
     /// Push an end bound for the given kind, where the bound is written at the
     /// start of the following line, rather than at the end of the previous
     /// line. This is useful for blocks that include trailing line endings, like
@@ -739,4 +1336,85 @@ mod test {
     fn indented_code_blocks(text: &str, bounds: &[(usize, usize, SyntaxKind)]) {
         block_bounds_test(text, bounds);
     }
+
+    #[test]
+    fn block_quote_with_lazy_continuation() {
+        let bounds = BlockParser::new("> quoted\n> text\nlazy").parse_into_block_bounds();
+
+        assert_eq!(
+            bounds,
+            vec![
+                BlockBound::Start(0, SyntaxKind::BLOCK_QUOTE),
+                BlockBound::Token(0, 2, SyntaxKind::BLOCK_PREFIX),
+                BlockBound::Start(2, SyntaxKind::PARAGRAPH),
+                BlockBound::Token(9, 11, SyntaxKind::BLOCK_PREFIX),
+                BlockBound::End(20, SyntaxKind::PARAGRAPH),
+                BlockBound::End(20, SyntaxKind::BLOCK_QUOTE),
+            ]
+        );
+    }
+
+    #[test]
+    fn list_items_with_indented_content() {
+        let bounds = BlockParser::new("1. one\n\n   more\n2) two").parse_into_block_bounds();
+
+        assert_eq!(
+            bounds,
+            vec![
+                BlockBound::Start(0, SyntaxKind::LIST),
+                BlockBound::Start(0, SyntaxKind::LIST_ITEM),
+                BlockBound::Token(0, 2, SyntaxKind::ORDERED_LIST_MARKER),
+                BlockBound::Token(2, 3, SyntaxKind::BLOCK_PREFIX),
+                BlockBound::Start(3, SyntaxKind::PARAGRAPH),
+                BlockBound::End(6, SyntaxKind::PARAGRAPH),
+                BlockBound::Token(8, 11, SyntaxKind::BLOCK_PREFIX),
+                BlockBound::Start(11, SyntaxKind::PARAGRAPH),
+                BlockBound::End(15, SyntaxKind::PARAGRAPH),
+                BlockBound::End(15, SyntaxKind::LIST_ITEM),
+                BlockBound::End(15, SyntaxKind::LIST),
+                BlockBound::Start(16, SyntaxKind::LIST),
+                BlockBound::Start(16, SyntaxKind::LIST_ITEM),
+                BlockBound::Token(16, 18, SyntaxKind::ORDERED_LIST_MARKER),
+                BlockBound::Token(18, 19, SyntaxKind::BLOCK_PREFIX),
+                BlockBound::Start(19, SyntaxKind::PARAGRAPH),
+                BlockBound::End(22, SyntaxKind::PARAGRAPH),
+                BlockBound::End(22, SyntaxKind::LIST_ITEM),
+                BlockBound::End(22, SyntaxKind::LIST),
+            ]
+        );
+    }
+
+    #[test]
+    fn table_rows_with_cells_and_pipes() {
+        let bounds = BlockParser::new("a | b\n--|:-\n|c|").parse_into_block_bounds();
+
+        assert_eq!(
+            bounds,
+            vec![
+                BlockBound::Start(0, SyntaxKind::TABLE),
+                BlockBound::Start(0, SyntaxKind::TABLE_ROW),
+                BlockBound::Start(0, SyntaxKind::TABLE_CELL),
+                BlockBound::InlineStart(0, SyntaxKind::INLINE_START),
+                BlockBound::InlineEnd(1, SyntaxKind::INLINE_END),
+                BlockBound::End(1, SyntaxKind::TABLE_CELL),
+                BlockBound::Token(2, 3, SyntaxKind::TABLE_CELL_DELIMITER),
+                BlockBound::Start(4, SyntaxKind::TABLE_CELL),
+                BlockBound::InlineStart(4, SyntaxKind::INLINE_START),
+                BlockBound::InlineEnd(5, SyntaxKind::INLINE_END),
+                BlockBound::End(5, SyntaxKind::TABLE_CELL),
+                BlockBound::End(5, SyntaxKind::TABLE_ROW),
+                BlockBound::Start(6, SyntaxKind::TABLE_DELIMITER_ROW),
+                BlockBound::End(11, SyntaxKind::TABLE_DELIMITER_ROW),
+                BlockBound::Start(12, SyntaxKind::TABLE_ROW),
+                BlockBound::Token(12, 13, SyntaxKind::TABLE_CELL_DELIMITER),
+                BlockBound::Start(13, SyntaxKind::TABLE_CELL),
+                BlockBound::InlineStart(13, SyntaxKind::INLINE_START),
+                BlockBound::InlineEnd(14, SyntaxKind::INLINE_END),
+                BlockBound::End(14, SyntaxKind::TABLE_CELL),
+                BlockBound::Token(14, 15, SyntaxKind::TABLE_CELL_DELIMITER),
+                BlockBound::End(15, SyntaxKind::TABLE_ROW),
+                BlockBound::End(15, SyntaxKind::TABLE),
+            ]
+        );
+    }
 }
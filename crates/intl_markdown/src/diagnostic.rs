@@ -0,0 +1,80 @@
+use std::ops::Range;
+
+/// The kinds of syntax errors that the parser is able to recover from. Recovery always means
+/// treating the malformed syntax as plain text, so the message can still be rendered, but likely
+/// not in the way the author intended.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SyntaxDiagnosticKind {
+    /// An ICU placeholder like `{name` that never reaches its closing `}`.
+    UnclosedIcuPlaceholder,
+    /// An unsafe ICU placeholder like `!!{name}` that is closed with `}` instead of `}!!`.
+    UnclosedUnsafePlaceholder,
+    /// A complex placeholder with a format type that ICU doesn't know, like `{name, color}`.
+    UnknownIcuFormat,
+    /// A plural or select placeholder like `{count, plural}` with no options following it.
+    ExpectedPluralOptions,
+    /// A plural offset like `offset:` or `offset:-1` that isn't followed by a whole number.
+    InvalidPluralOffset,
+    /// A plural option selector that isn't followed by a `{value}`, like `{count, plural, one}`.
+    InvalidPluralArm,
+    /// A plural option value like `one {value` that never reaches its closing `}`.
+    UnterminatedPluralArm,
+    /// A hook whose target is not a plain name, like `$[text]({target})` or `$[text](name`.
+    InvalidHookName,
+    /// A number skeleton token that isn't a known stem, like `fancy` in `{n, number, ::fancy}`.
+    UnknownNumberSkeletonToken,
+    /// A number skeleton stem with missing or invalid options, like `currency` without a
+    /// currency code, or `percent/x`.
+    InvalidNumberSkeletonOptions,
+    /// A date skeleton field that isn't a known letter, or is repeated too many times, like `ddd`.
+    InvalidDateTimeSkeletonField,
+    /// A date skeleton field that ICU knows, but that can't be formatted with
+    /// `Intl.DateTimeFormat`, like the quarter field `Q`.
+    UnsupportedDateTimeSkeletonField,
+}
+
+impl SyntaxDiagnosticKind {
+    /// A short, human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            SyntaxDiagnosticKind::UnclosedIcuPlaceholder => "ICU placeholder is missing its closing '}'",
+            SyntaxDiagnosticKind::UnclosedUnsafePlaceholder => "Unsafe ICU placeholder opened with '!!{' must be closed with '}!!'",
+            SyntaxDiagnosticKind::UnknownIcuFormat => "Unknown ICU format type. Expected one of 'plural', 'select', 'selectordinal', 'number', 'date', or 'time'",
+            SyntaxDiagnosticKind::ExpectedPluralOptions => "Expected a ',' followed by options for this placeholder",
+            SyntaxDiagnosticKind::InvalidPluralOffset => "Plural offsets must be a whole number, like 'offset:1'",
+            SyntaxDiagnosticKind::InvalidPluralArm => "Plural options must be followed by a value wrapped in '{}'",
+            SyntaxDiagnosticKind::UnterminatedPluralArm => "Plural option value is missing its closing '}'",
+            SyntaxDiagnosticKind::InvalidHookName => "Hook targets must be a plain name, like '$[text](hookName)'",
+            SyntaxDiagnosticKind::UnknownNumberSkeletonToken => "Unknown number skeleton token. See the ICU number skeleton documentation for the supported stems",
+            SyntaxDiagnosticKind::InvalidNumberSkeletonOptions => "Number skeleton token has missing or invalid options, like 'currency' without a currency code",
+            SyntaxDiagnosticKind::InvalidDateTimeSkeletonField => "Unknown date skeleton field, or the field is repeated too many times",
+            SyntaxDiagnosticKind::UnsupportedDateTimeSkeletonField => "Date skeleton field is valid in ICU, but can't be formatted with Intl.DateTimeFormat",
+        }
+    }
+}
+
+/// A syntax error found while parsing a message, along with the byte range of the message content
+/// that it applies to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SyntaxDiagnostic {
+    kind: SyntaxDiagnosticKind,
+    span: Range<usize>,
+}
+
+impl SyntaxDiagnostic {
+    pub fn new(kind: SyntaxDiagnosticKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    pub fn kind(&self) -> SyntaxDiagnosticKind {
+        self.kind
+    }
+
+    pub fn span(&self) -> &Range<usize> {
+        &self.span
+    }
+
+    pub fn message(&self) -> &'static str {
+        self.kind.message()
+    }
+}
@@ -4,14 +4,19 @@
 //! serialized to JSON. However, this format also allows more compact representations like keyless
 //! JSON or even binary formats, and includes extensions to support additional features like link
 //! attributes.
+use std::borrow::Cow;
+
 use serde::ser::SerializeMap;
 use serde::{self, Serialize, Serializer};
 
 use crate::ast::{
-    BlockNode, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate, IcuNumber,
+    BlockNode, BlockQuote, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
+    IcuDateTimeStyle, IcuDateTimeStyleKind, IcuNumber, IcuNumberStyle, IcuNumberStyleKind,
     IcuPlural, IcuPluralArm, IcuPluralKind, IcuSelect, IcuTime, IcuVariable, InlineContent, Link,
-    LinkDestination, Paragraph, Strikethrough, Strong,
+    LinkDestination, List, ListKind, Paragraph, Strikethrough, Strong, Table, TableAlignment,
+    TableRow,
 };
+use crate::icu::skeleton::{DateTimeSkeleton, NumberSkeleton};
 use crate::icu::tags::DEFAULT_TAG_NAMES;
 
 /// Enum matching a type of element to it's FormatJS type number. The order defines the numbering.
@@ -35,13 +40,15 @@ pub fn compile_to_format_js(document: &Document) -> FormatJsNode {
     FormatJsNode::from(document)
 }
 
-/// A mono-morphed type capable of representing any node in an ICU tree following the FormatJS JSON
-/// structure. The ordering of these fields is explicitly done to match FormatJS's serialization and
-/// allow for minified, structured serialization without field names.
+/// A mono-morphed type capable of representing any node in an ICU tree.
+///
+/// Nodes follow the FormatJS JSON structure. The ordering of these fields is explicitly done to
+/// match FormatJS's serialization and allow for minified, structured serialization without field
+/// names.
 #[derive(Debug, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum FormatJsNode<'a> {
-    Literal(&'a str),
+    Literal(Cow<'a, str>),
     SingleNode(FormatJsSingleNode<'a>),
     ListNode(Vec<FormatJsNode<'a>>),
 }
@@ -52,7 +59,7 @@ impl<'a> FormatJsNode<'a> {
     }
 
     fn literal(value: &'a str) -> Self {
-        Self::Literal(value)
+        Self::Literal(Cow::Borrowed(value))
     }
 }
 
@@ -61,7 +68,7 @@ pub struct FormatJsSingleNode<'a> {
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub ty: Option<FormatJsElementType>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<&'a str>,
+    pub value: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Box<FormatJsNode<'a>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -74,11 +81,12 @@ pub struct FormatJsSingleNode<'a> {
     /// "visible" and what is "hidden". By adding this `control` type, the distinction is always
     /// trivial to process.
     ///
-    /// This value may have other uses in the future but for now is simply reserved for links.
+    /// Ordered lists that don't start at 1 also use `control` to hold their starting number, and
+    /// the cells of aligned table columns use it to hold their alignment.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub control: Option<Box<FormatJsNode<'a>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub style: Option<&'a str>,
+    pub style: Option<FormatJsStyle<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<usize>,
     #[serde(rename = "pluralType", skip_serializing_if = "Option::is_none")]
@@ -104,7 +112,7 @@ impl<'a> FormatJsSingleNode<'a> {
     }
 
     fn with_value(mut self, value: &'a str) -> Self {
-        self.value = Some(value);
+        self.value = Some(Cow::Borrowed(value));
         self
     }
 
@@ -131,8 +139,8 @@ impl<'a> FormatJsSingleNode<'a> {
         self
     }
 
-    fn with_style(mut self, style: &'a str) -> Self {
-        self.style = Some(style);
+    fn with_style(mut self, style: impl Into<FormatJsStyle<'a>>) -> Self {
+        self.style = Some(style.into());
         self
     }
 
@@ -147,6 +155,47 @@ impl<'a> FormatJsSingleNode<'a> {
     }
 }
 
+/// The style of a number, date, or time element. Named styles are kept as plain strings, while
+/// skeletons are compiled into the same parsed objects that FormatJS produces, so that runtimes
+/// can use their options directly without parsing the skeleton again.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum FormatJsStyle<'a> {
+    Text(Cow<'a, str>),
+    NumberSkeleton(Cow<'a, NumberSkeleton>),
+    DateTimeSkeleton(Cow<'a, DateTimeSkeleton>),
+}
+
+impl<'a> From<&'a str> for FormatJsStyle<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Text(Cow::Borrowed(value))
+    }
+}
+
+impl<'a> From<&'a IcuNumberStyle> for FormatJsStyle<'a> {
+    fn from(value: &'a IcuNumberStyle) -> Self {
+        match value.kind() {
+            IcuNumberStyleKind::Skeleton(skeleton) => Self::NumberSkeleton(Cow::Borrowed(skeleton)),
+            IcuNumberStyleKind::Named(_) | IcuNumberStyleKind::Invalid => {
+                Self::from(value.text().as_str())
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a IcuDateTimeStyle> for FormatJsStyle<'a> {
+    fn from(value: &'a IcuDateTimeStyle) -> Self {
+        match value.kind() {
+            IcuDateTimeStyleKind::Skeleton(skeleton) => {
+                Self::DateTimeSkeleton(Cow::Borrowed(skeleton))
+            }
+            IcuDateTimeStyleKind::Named(_) | IcuDateTimeStyleKind::Invalid => {
+                Self::from(value.text().as_str())
+            }
+        }
+    }
+}
+
 impl<'a> From<FormatJsSingleNode<'a>> for FormatJsNode<'a> {
     fn from(value: FormatJsSingleNode<'a>) -> Self {
         FormatJsNode::SingleNode(value)
@@ -161,32 +210,45 @@ impl<'a> From<Vec<FormatJsNode<'a>>> for FormatJsNode<'a> {
 
 //#region Serialization
 
+/// The arms of a plural or select element, as pairs of each selector and its content, in the
+/// order they were written.
 #[derive(Debug, Eq, PartialEq)]
-pub struct FormatJsNodeOptions<'a>(&'a Vec<IcuPluralArm>);
+pub struct FormatJsNodeOptions<'a>(pub Vec<(Cow<'a, str>, FormatJsNode<'a>)>);
 impl Serialize for FormatJsNodeOptions<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         let mut arms = serializer.serialize_map(Some(self.0.len()))?;
-        for arm in self.0 {
-            arms.serialize_entry(arm.selector(), &FormatJsNode::from(arm.content()))?;
+        for (selector, content) in &self.0 {
+            arms.serialize_entry(selector, content)?;
         }
         arms.end()
     }
 }
 
+impl<'a> From<&'a Vec<IcuPluralArm>> for FormatJsNodeOptions<'a> {
+    fn from(value: &'a Vec<IcuPluralArm>) -> Self {
+        Self(
+            value
+                .iter()
+                .map(|arm| (Cow::Borrowed(arm.selector().as_str()), arm.content().into()))
+                .collect(),
+        )
+    }
+}
+
 //#endregion
 
 //#region AST to Node conversions
 impl<'a> From<&'a str> for FormatJsNode<'a> {
     fn from(value: &'a str) -> Self {
-        FormatJsNode::Literal(value)
+        FormatJsNode::literal(value)
     }
 }
 impl<'a> From<&'a String> for FormatJsNode<'a> {
     fn from(value: &'a String) -> Self {
-        FormatJsNode::Literal(value)
+        FormatJsNode::literal(value)
     }
 }
 
@@ -242,12 +304,21 @@ macro_rules! impl_from_for_tag_node {
     };
 }
 
-impl_from_for_tag_node!(CodeBlock, DEFAULT_TAG_NAMES.code_block(), content);
 impl_from_for_tag_node!(Paragraph, DEFAULT_TAG_NAMES.paragraph(), content);
 impl_from_for_tag_node!(Emphasis, DEFAULT_TAG_NAMES.emphasis(), content);
 impl_from_for_tag_node!(Strong, DEFAULT_TAG_NAMES.strong(), content);
 impl_from_for_tag_node!(Strikethrough, DEFAULT_TAG_NAMES.strike_through(), content);
 
+impl<'a> From<&'a CodeBlock> for FormatJsNode<'a> {
+    fn from(value: &'a CodeBlock) -> Self {
+        FormatJsSingleNode::tag(DEFAULT_TAG_NAMES.code_block())
+            .with_children(FormatJsNode::list(vec![FormatJsNode::literal(
+                value.content(),
+            )]))
+            .into()
+    }
+}
+
 impl<'a> From<&'a CodeSpan> for FormatJsNode<'a> {
     fn from(value: &'a CodeSpan) -> Self {
         FormatJsSingleNode::tag(DEFAULT_TAG_NAMES.code())
@@ -300,10 +371,114 @@ impl<'a> From<&'a BlockNode> for FormatJsNode<'a> {
             BlockNode::ThematicBreak => FormatJsSingleNode::tag(DEFAULT_TAG_NAMES.hr())
                 .with_children(FormatJsNode::list(vec![]))
                 .into(),
+            BlockNode::BlockQuote(block_quote) => FormatJsNode::from(block_quote),
+            BlockNode::List(list) => FormatJsNode::from(list),
+            BlockNode::Table(table) => FormatJsNode::from(table),
+        }
+    }
+}
+
+/// Compile the blocks inside of a container. Paragraphs in the items of a tight list are unwrapped
+/// into their inline content, the same way they are rendered without `<p>` tags in HTML.
+fn compile_container_children(blocks: &Vec<BlockNode>, is_tight: bool) -> FormatJsNode {
+    let mut children = Vec::with_capacity(blocks.len());
+    for block in blocks {
+        match block {
+            BlockNode::Paragraph(paragraph) if is_tight => {
+                children.extend(paragraph.content().iter().map(FormatJsNode::from))
+            }
+            _ => children.push(FormatJsNode::from(block)),
+        }
+    }
+    FormatJsNode::list(children)
+}
+
+impl<'a> From<&'a BlockQuote> for FormatJsNode<'a> {
+    fn from(value: &'a BlockQuote) -> Self {
+        FormatJsSingleNode::tag(DEFAULT_TAG_NAMES.block_quote())
+            .with_children(compile_container_children(value.content(), false))
+            .into()
+    }
+}
+
+impl<'a> From<&'a List> for FormatJsNode<'a> {
+    fn from(value: &'a List) -> Self {
+        let tag_name = match value.kind() {
+            ListKind::Bullet => DEFAULT_TAG_NAMES.unordered_list(),
+            ListKind::Ordered => DEFAULT_TAG_NAMES.ordered_list(),
+        };
+        let items = value
+            .items()
+            .iter()
+            .map(|item| {
+                FormatJsSingleNode::tag(DEFAULT_TAG_NAMES.list_item())
+                    .with_children(compile_container_children(item.content(), value.is_tight()))
+                    .into()
+            })
+            .collect();
+
+        let mut node = FormatJsSingleNode::tag(tag_name).with_children(FormatJsNode::list(items));
+        if let Some(start) = value.start().filter(|start| *start != "1") {
+            node = node.with_control(FormatJsNode::list(vec![FormatJsNode::literal(start)]));
         }
+        node.into()
     }
 }
 
+impl<'a> From<&'a Table> for FormatJsNode<'a> {
+    fn from(value: &'a Table) -> Self {
+        let mut sections = vec![FormatJsSingleNode::tag(DEFAULT_TAG_NAMES.table_head())
+            .with_children(FormatJsNode::list(vec![compile_table_row(
+                value.header(),
+                value.alignments(),
+                DEFAULT_TAG_NAMES.table_header_cell(),
+            )]))
+            .into()];
+        if !value.rows().is_empty() {
+            let rows = value
+                .rows()
+                .iter()
+                .map(|row| {
+                    compile_table_row(row, value.alignments(), DEFAULT_TAG_NAMES.table_cell())
+                })
+                .collect();
+            sections.push(
+                FormatJsSingleNode::tag(DEFAULT_TAG_NAMES.table_body())
+                    .with_children(FormatJsNode::list(rows))
+                    .into(),
+            );
+        }
+
+        FormatJsSingleNode::tag(DEFAULT_TAG_NAMES.table())
+            .with_children(FormatJsNode::list(sections))
+            .into()
+    }
+}
+
+fn compile_table_row<'a>(
+    row: &'a TableRow,
+    alignments: &[TableAlignment],
+    cell_tag_name: &'a str,
+) -> FormatJsNode<'a> {
+    let cells = row
+        .cells()
+        .iter()
+        .zip(alignments)
+        .map(|(cell, alignment)| {
+            let mut node = FormatJsSingleNode::tag(cell_tag_name)
+                .with_children(FormatJsNode::from(cell.content()));
+            if let Some(align) = alignment.as_str() {
+                node = node.with_control(FormatJsNode::list(vec![FormatJsNode::literal(align)]));
+            }
+            node.into()
+        })
+        .collect();
+
+    FormatJsSingleNode::tag(DEFAULT_TAG_NAMES.table_row())
+        .with_children(FormatJsNode::list(cells))
+        .into()
+}
+
 impl<'a> From<&'a Hook> for FormatJsNode<'a> {
     fn from(value: &'a Hook) -> Self {
         FormatJsSingleNode::tag(value.name())
@@ -332,8 +507,8 @@ macro_rules! impl_from_for_icu_type {
                 let mut node = FormatJsSingleNode::default()
                     .with_type($ty)
                     .with_value(value.name());
-                if value.style().is_some() {
-                    node = node.with_style(value.style().as_ref().unwrap().text());
+                if let Some(style) = value.style() {
+                    node = node.with_style(style);
                 }
                 node.into()
             }
@@ -350,9 +525,8 @@ impl<'a> From<&'a IcuPlural> for FormatJsNode<'a> {
         FormatJsSingleNode::default()
             .with_type(FormatJsElementType::Plural)
             .with_value(value.name())
-            .with_options(FormatJsNodeOptions(value.arms()))
-            // TODO: Implement offset in parsing
-            .with_offset(0)
+            .with_options(value.arms().into())
+            .with_offset(value.offset())
             .with_plural_type(*value.kind())
             .into()
     }
@@ -363,7 +537,7 @@ impl<'a> From<&'a IcuSelect> for FormatJsNode<'a> {
         FormatJsSingleNode::default()
             .with_type(FormatJsElementType::Select)
             .with_value(value.name())
-            .with_options(FormatJsNodeOptions(value.arms()))
+            .with_options(value.arms().into())
             .into()
     }
 }
@@ -378,10 +552,16 @@ impl<'a> From<&'a IcuVariable> for FormatJsNode<'a> {
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
+    use crate::ast::{IcuDateTimeStyleKind, IcuNumberStyleKind};
+    use crate::icu::skeleton::{parse_date_time_style, parse_number_style};
     use crate::icu::tags::DEFAULT_TAG_NAMES;
     use crate::parse_intl_message;
 
-    use super::{compile_to_format_js, FormatJsElementType, FormatJsNode, FormatJsSingleNode};
+    use super::{
+        compile_to_format_js, FormatJsElementType, FormatJsNode, FormatJsSingleNode, FormatJsStyle,
+    };
 
     fn assert_formatjs_with_blocks(
         input_str: &str,
@@ -409,7 +589,7 @@ mod tests {
 
     macro_rules! lit {
         ($name:literal) => {
-            FormatJsNode::Literal($name)
+            FormatJsNode::literal($name)
         };
     }
 
@@ -462,6 +642,96 @@ mod tests {
         )
     }
 
+    #[test]
+    fn lists() {
+        assert_formatjs_with_blocks(
+            "- {count} items\n- *more*",
+            &list!(tag!(
+                DEFAULT_TAG_NAMES.unordered_list(),
+                [
+                    tag!(
+                        DEFAULT_TAG_NAMES.list_item(),
+                        [var!("count"), lit!(" items")]
+                    ),
+                    tag!(
+                        DEFAULT_TAG_NAMES.list_item(),
+                        [tag!(DEFAULT_TAG_NAMES.emphasis(), [lit!("more")])]
+                    ),
+                ]
+            )),
+            true,
+        );
+        // Loose items keep their paragraphs, and the starting number is given as a control.
+        assert_formatjs_with_blocks(
+            "2. first\n\n3. second",
+            &list!(tag!(
+                DEFAULT_TAG_NAMES.ordered_list(),
+                [
+                    tag!(
+                        DEFAULT_TAG_NAMES.list_item(),
+                        [tag!(DEFAULT_TAG_NAMES.paragraph(), [lit!("first")])]
+                    ),
+                    tag!(
+                        DEFAULT_TAG_NAMES.list_item(),
+                        [tag!(DEFAULT_TAG_NAMES.paragraph(), [lit!("second")])]
+                    ),
+                ]
+            )
+            .with_control(list!(lit!("2")))),
+            true,
+        );
+    }
+
+    #[test]
+    fn block_quotes() {
+        assert_formatjs_with_blocks(
+            "> quoted\n> text",
+            &list!(tag!(
+                DEFAULT_TAG_NAMES.block_quote(),
+                [tag!(DEFAULT_TAG_NAMES.paragraph(), [lit!("quoted\ntext")])]
+            )),
+            true,
+        );
+    }
+
+    #[test]
+    fn tables() {
+        assert_formatjs_with_blocks(
+            "| name | count |\n| --- | ---: |\n| {name} | *{count}* |",
+            &list!(tag!(
+                DEFAULT_TAG_NAMES.table(),
+                [
+                    tag!(
+                        DEFAULT_TAG_NAMES.table_head(),
+                        [tag!(
+                            DEFAULT_TAG_NAMES.table_row(),
+                            [
+                                tag!(DEFAULT_TAG_NAMES.table_header_cell(), [lit!("name")]),
+                                tag!(DEFAULT_TAG_NAMES.table_header_cell(), [lit!("count")])
+                                    .with_control(list!(lit!("right"))),
+                            ]
+                        )]
+                    ),
+                    tag!(
+                        DEFAULT_TAG_NAMES.table_body(),
+                        [tag!(
+                            DEFAULT_TAG_NAMES.table_row(),
+                            [
+                                tag!(DEFAULT_TAG_NAMES.table_cell(), [var!("name")]),
+                                tag!(
+                                    DEFAULT_TAG_NAMES.table_cell(),
+                                    [tag!(DEFAULT_TAG_NAMES.emphasis(), [var!("count")])]
+                                )
+                                .with_control(list!(lit!("right"))),
+                            ]
+                        )]
+                    ),
+                ]
+            )),
+            true,
+        );
+    }
+
     #[test]
     fn icu_variables() {
         assert_formatjs("{username}", &list!(var!("username")));
@@ -471,15 +741,42 @@ mod tests {
             &list!(var!("startDate", Date).with_style("medium")),
         );
         assert_formatjs("{postedAt, time}", &list!(var!("postedAt", Time)));
+        let IcuDateTimeStyleKind::Skeleton(time_skeleton) =
+            parse_date_time_style("::hmsGy").unwrap()
+        else {
+            unreachable!()
+        };
         assert_formatjs(
             "{postedAt, time, ::hmsGy  }",
-            &list!(var!("postedAt", Time).with_style("::hmsGy")),
+            &list!(
+                var!("postedAt", Time).with_style(FormatJsStyle::DateTimeSkeleton(Cow::Borrowed(
+                    &time_skeleton
+                )))
+            ),
         );
 
         assert_formatjs("{price, number}", &list!(var!("price", Number)));
+        assert_formatjs(
+            "{price, number, percent}",
+            &list!(var!("price", Number).with_style("percent")),
+        );
+        let IcuNumberStyleKind::Skeleton(number_skeleton) =
+            parse_number_style("::.## sign-always currency/USD").unwrap()
+        else {
+            unreachable!()
+        };
         assert_formatjs(
             "{price, number,   ::.## sign-always currency/USD }",
-            &list!(var!("price", Number).with_style("::.## sign-always currency/USD")),
+            &list!(
+                var!("price", Number).with_style(FormatJsStyle::NumberSkeleton(Cow::Borrowed(
+                    &number_skeleton
+                )))
+            ),
+        );
+        // Invalid skeletons are left as their original text.
+        assert_formatjs(
+            "{price, number, ::fancy}",
+            &list!(var!("price", Number).with_style("::fancy")),
         );
     }
 
@@ -0,0 +1,368 @@
+//! Decompiling turns a [FormatJsNode] tree back into a [Document], reversing
+//! [crate::compile_to_format_js]. Combined with [crate::icu::deserialize], this allows reading
+//! precompiled bundles and messages compiled by other FormatJS tools.
+//!
+//! Compiling is lossy, so decompiled Documents are equivalent to their originals but not always
+//! identical. Notably:
+//!
+//! - Variables are never unsafe.
+//! - Link handlers become placeholders, since both compile to the same argument node.
+//! - Headings are ATX headings, code blocks are fenced, and images become links.
+//! - Nodes have no source spans.
+use crate::ast::{
+    BlockNode, BlockQuote, CodeBlock, CodeBlockKind, CodeSpan, Document, Emphasis, Heading,
+    HeadingKind, Hook, Icu, IcuDate, IcuDateTimeStyle, IcuNumber, IcuNumberStyle, IcuPlural,
+    IcuPluralArm, IcuPluralKind, IcuSelect, IcuTime, IcuVariable, InlineContent, Link,
+    LinkDestination, LinkKind, List, ListItem, ListKind, Paragraph, Strikethrough, Strong, Table,
+    TableAlignment, TableCell, TableRow,
+};
+use crate::icu::compile::{
+    FormatJsElementType, FormatJsNode, FormatJsNodeOptions, FormatJsSingleNode, FormatJsStyle,
+};
+use crate::icu::tags::DEFAULT_TAG_NAMES;
+
+/// Decompile a FormatJS node tree into a Document. See the module documentation for what is lost
+/// when a message is compiled.
+pub fn decompile_format_js(node: &FormatJsNode) -> Document {
+    Document::new(decompile_blocks(as_list(node), false))
+}
+
+impl From<&FormatJsNode<'_>> for Document {
+    fn from(value: &FormatJsNode<'_>) -> Self {
+        decompile_format_js(value)
+    }
+}
+
+/// Treat a node as a list, so that single nodes and lists can be handled the same way.
+fn as_list<'n, 'a>(node: &'n FormatJsNode<'a>) -> &'n [FormatJsNode<'a>] {
+    match node {
+        FormatJsNode::ListNode(list) => list,
+        node => std::slice::from_ref(node),
+    }
+}
+
+fn children<'n, 'a>(node: &'n FormatJsSingleNode<'a>) -> &'n [FormatJsNode<'a>] {
+    node.children.as_deref().map_or(&[], as_list)
+}
+
+fn control<'n, 'a>(node: &'n FormatJsSingleNode<'a>) -> &'n [FormatJsNode<'a>] {
+    node.control.as_deref().map_or(&[], as_list)
+}
+
+fn value(node: &FormatJsSingleNode) -> String {
+    node.value.as_deref().unwrap_or_default().into()
+}
+
+fn tag_name<'n>(node: &'n FormatJsNode) -> Option<&'n str> {
+    match node {
+        FormatJsNode::SingleNode(node) if node.ty == Some(FormatJsElementType::Tag) => {
+            node.value.as_deref()
+        }
+        _ => None,
+    }
+}
+
+/// The concatenated text of all the literals in the given nodes.
+fn literal_text(nodes: &[FormatJsNode]) -> String {
+    nodes
+        .iter()
+        .filter_map(|node| match node {
+            FormatJsNode::Literal(text) => Some(text.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+//#region Blocks
+
+fn heading_level(name: &str) -> Option<u8> {
+    (1..=6).find(|level| DEFAULT_TAG_NAMES.heading(*level) == name)
+}
+
+fn is_block_tag(name: &str) -> bool {
+    name == DEFAULT_TAG_NAMES.paragraph()
+        || name == DEFAULT_TAG_NAMES.code_block()
+        || name == DEFAULT_TAG_NAMES.hr()
+        || name == DEFAULT_TAG_NAMES.block_quote()
+        || name == DEFAULT_TAG_NAMES.unordered_list()
+        || name == DEFAULT_TAG_NAMES.ordered_list()
+        || name == DEFAULT_TAG_NAMES.table()
+        || heading_level(name).is_some()
+}
+
+/// Decompile the content of a document or container. Runs of inline nodes between blocks become
+/// a single block, which is a paragraph in the items of tight lists, where paragraphs are compiled
+/// without their tags.
+fn decompile_blocks(nodes: &[FormatJsNode], is_tight: bool) -> Vec<BlockNode> {
+    let mut blocks = vec![];
+    let mut inline = vec![];
+    let flush_inline = |blocks: &mut Vec<BlockNode>, inline: &mut Vec<InlineContent>| {
+        if !inline.is_empty() {
+            let content = std::mem::take(inline);
+            blocks.push(match is_tight {
+                true => BlockNode::Paragraph(Paragraph::new(content)),
+                false => BlockNode::InlineContent(content),
+            });
+        }
+    };
+
+    // Serialized Documents write thematic breaks as literal HTML between their other blocks.
+    let has_blocks = nodes.iter().any(|node| {
+        matches!(node, FormatJsNode::ListNode(_)) || tag_name(node).is_some_and(is_block_tag)
+    });
+    for node in nodes {
+        match node {
+            FormatJsNode::Literal(text) if has_blocks && text == "<hr />" => {
+                flush_inline(&mut blocks, &mut inline);
+                blocks.push(BlockNode::ThematicBreak);
+            }
+            FormatJsNode::SingleNode(single) if tag_name(node).is_some_and(is_block_tag) => {
+                flush_inline(&mut blocks, &mut inline);
+                blocks.push(decompile_block(single));
+            }
+            FormatJsNode::ListNode(list) => {
+                flush_inline(&mut blocks, &mut inline);
+                blocks.push(BlockNode::InlineContent(decompile_inline(list)));
+            }
+            node => inline.extend(decompile_inline_node(node)),
+        }
+    }
+    flush_inline(&mut blocks, &mut inline);
+    blocks
+}
+
+fn decompile_block(node: &FormatJsSingleNode) -> BlockNode {
+    let name = node.value.as_deref().unwrap_or_default();
+    let tags = &DEFAULT_TAG_NAMES;
+    if let Some(level) = heading_level(name) {
+        BlockNode::Heading(Heading::new(
+            HeadingKind::Atx,
+            level,
+            decompile_inline(children(node)),
+        ))
+    } else if name == tags.paragraph() {
+        BlockNode::Paragraph(Paragraph::new(decompile_inline(children(node))))
+    } else if name == tags.code_block() {
+        BlockNode::CodeBlock(CodeBlock::new(
+            CodeBlockKind::Fenced,
+            None,
+            None,
+            literal_text(children(node)),
+        ))
+    } else if name == tags.hr() {
+        BlockNode::ThematicBreak
+    } else if name == tags.block_quote() {
+        BlockNode::BlockQuote(BlockQuote::new(decompile_blocks(children(node), false)))
+    } else if name == tags.table() {
+        BlockNode::Table(decompile_table(node))
+    } else {
+        BlockNode::List(decompile_list(node))
+    }
+}
+
+fn decompile_list(node: &FormatJsSingleNode) -> List {
+    let kind = match node.value.as_deref() == Some(DEFAULT_TAG_NAMES.ordered_list()) {
+        true => ListKind::Ordered,
+        false => ListKind::Bullet,
+    };
+    // Lists starting at 1 are compiled without a control.
+    let start = (kind == ListKind::Ordered).then(|| match literal_text(control(node)) {
+        start if start.is_empty() => "1".into(),
+        start => start,
+    });
+
+    let items: Vec<&[FormatJsNode]> = children(node)
+        .iter()
+        .filter_map(|item| match item {
+            FormatJsNode::SingleNode(item) => Some(children(item)),
+            _ => None,
+        })
+        .collect();
+    // Paragraphs only keep their tags in loose lists.
+    let is_tight = !items.iter().any(|content| {
+        content
+            .iter()
+            .any(|node| tag_name(node) == Some(DEFAULT_TAG_NAMES.paragraph()))
+    });
+    let items = items
+        .into_iter()
+        .map(|content| ListItem::new(decompile_blocks(content, is_tight)))
+        .collect();
+    List::new(kind, start, is_tight, items)
+}
+
+fn decompile_table(node: &FormatJsSingleNode) -> Table {
+    let mut header = None;
+    let mut rows = vec![];
+    for section in children(node) {
+        let FormatJsNode::SingleNode(section) = section else {
+            continue;
+        };
+        let section_rows = children(section).iter().filter_map(|row| match row {
+            FormatJsNode::SingleNode(row) => Some(children(row)),
+            _ => None,
+        });
+        if section.value.as_deref() == Some(DEFAULT_TAG_NAMES.table_head()) {
+            header = header.or(section_rows.into_iter().next());
+        } else {
+            rows.extend(section_rows);
+        }
+    }
+
+    let header = header.unwrap_or_default();
+    let alignments = header
+        .iter()
+        .map(|cell| {
+            let align = match cell {
+                FormatJsNode::SingleNode(cell) => literal_text(control(cell)),
+                _ => String::new(),
+            };
+            match align.as_str() {
+                "left" => TableAlignment::Left,
+                "center" => TableAlignment::Center,
+                "right" => TableAlignment::Right,
+                _ => TableAlignment::None,
+            }
+        })
+        .collect();
+    Table::new(
+        alignments,
+        decompile_table_row(header),
+        rows.into_iter().map(decompile_table_row).collect(),
+    )
+}
+
+fn decompile_table_row(cells: &[FormatJsNode]) -> TableRow {
+    TableRow::new(
+        cells
+            .iter()
+            .map(|cell| match cell {
+                FormatJsNode::SingleNode(cell) => TableCell::new(decompile_inline(children(cell))),
+                cell => TableCell::new(decompile_inline_node(cell)),
+            })
+            .collect(),
+    )
+}
+
+//#endregion
+
+//#region Inline content
+
+fn decompile_inline(nodes: &[FormatJsNode]) -> Vec<InlineContent> {
+    nodes.iter().flat_map(decompile_inline_node).collect()
+}
+
+/// Decompile a single inline node. Lists are flattened into their items, so this may return any
+/// number of nodes.
+fn decompile_inline_node(node: &FormatJsNode) -> Vec<InlineContent> {
+    let node = match node {
+        FormatJsNode::Literal(text) => return vec![InlineContent::Text(text.to_string())],
+        FormatJsNode::ListNode(list) => return decompile_inline(list),
+        FormatJsNode::SingleNode(node) => node,
+    };
+
+    let content = match node.ty {
+        Some(FormatJsElementType::Tag) => decompile_tag(node),
+        Some(FormatJsElementType::Pound) => InlineContent::IcuPound,
+        Some(FormatJsElementType::Literal) => InlineContent::Text(value(node)),
+        _ => match decompile_icu(node) {
+            Some(icu) => InlineContent::Icu(icu),
+            None => return vec![],
+        },
+    };
+    vec![content]
+}
+
+fn decompile_tag(node: &FormatJsSingleNode) -> InlineContent {
+    let name = node.value.as_deref().unwrap_or_default();
+    let tags = &DEFAULT_TAG_NAMES;
+    let content = || decompile_inline(children(node));
+    if name == tags.strong() {
+        InlineContent::Strong(Strong::new(content()))
+    } else if name == tags.emphasis() {
+        InlineContent::Emphasis(Emphasis::new(content()))
+    } else if name == tags.strike_through() {
+        InlineContent::Strikethrough(Strikethrough::new(content()))
+    } else if name == tags.code() {
+        InlineContent::CodeSpan(CodeSpan::new(literal_text(children(node))))
+    } else if name == tags.br() {
+        InlineContent::HardLineBreak
+    } else if name == tags.link() {
+        InlineContent::Link(Link::new(
+            LinkKind::Link,
+            content(),
+            decompile_link_destination(control(node)),
+            None,
+        ))
+    } else {
+        InlineContent::Hook(Hook::new(name.into(), content()))
+    }
+}
+
+fn decompile_link_destination(control: &[FormatJsNode]) -> LinkDestination {
+    match control.first() {
+        Some(FormatJsNode::SingleNode(node)) => match decompile_icu(node) {
+            Some(icu) => LinkDestination::Placeholder(icu),
+            None => LinkDestination::Text(String::new()),
+        },
+        _ => LinkDestination::Text(literal_text(control)),
+    }
+}
+
+//#endregion
+
+//#region ICU
+
+fn decompile_icu(node: &FormatJsSingleNode) -> Option<Icu> {
+    let variable = IcuVariable::new(value(node), false);
+    let style_text = || {
+        node.style.as_ref().map(|style| match style {
+            FormatJsStyle::Text(text) => text.to_string(),
+            FormatJsStyle::NumberSkeleton(skeleton) => skeleton.source_text(),
+            FormatJsStyle::DateTimeSkeleton(skeleton) => skeleton.source_text(),
+        })
+    };
+
+    let icu = match node.ty? {
+        FormatJsElementType::Argument => Icu::IcuVariable(variable),
+        FormatJsElementType::Number => Icu::IcuNumber(IcuNumber::new(
+            variable,
+            style_text().map(IcuNumberStyle::new),
+        )),
+        FormatJsElementType::Date => Icu::IcuDate(IcuDate::new(
+            variable,
+            style_text().map(IcuDateTimeStyle::new),
+        )),
+        FormatJsElementType::Time => Icu::IcuTime(IcuTime::new(
+            variable,
+            style_text().map(IcuDateTimeStyle::new),
+        )),
+        FormatJsElementType::Select => {
+            Icu::IcuSelect(IcuSelect::new(variable, decompile_arms(&node.options)))
+        }
+        FormatJsElementType::Plural => Icu::IcuPlural(IcuPlural::new(
+            variable,
+            node.plural_type.unwrap_or(IcuPluralKind::Plural),
+            node.offset.unwrap_or_default(),
+            decompile_arms(&node.options),
+        )),
+        FormatJsElementType::Literal | FormatJsElementType::Pound | FormatJsElementType::Tag => {
+            return None
+        }
+    };
+    Some(icu)
+}
+
+fn decompile_arms(options: &Option<FormatJsNodeOptions>) -> Vec<IcuPluralArm> {
+    options.as_ref().map_or(vec![], |options| {
+        options
+            .0
+            .iter()
+            .map(|(selector, content)| {
+                IcuPluralArm::new(selector.to_string(), decompile_inline(as_list(content)))
+            })
+            .collect()
+    })
+}
+
+//#endregion
@@ -0,0 +1,465 @@
+//! Deserialization of compiled messages back into [FormatJsNode] trees. Both of the formats that
+//! compiled nodes are written in are accepted:
+//!
+//! - Keyless JSON, as written by the bundler, where literals are bare strings and every other node
+//!   is an array of its field values in order, starting with its type number.
+//! - FormatJS JSON, as written by `@formatjs/cli compile --ast` or [crate::icu::serialize], where
+//!   every node is an object with named fields. Fields that aren't part of the node, like
+//!   `location`, are ignored.
+//!
+//! Strings are borrowed from the input whenever the deserializer allows it.
+use std::borrow::Cow;
+use std::fmt::Formatter;
+
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use crate::ast::{IcuDateTimeStyleKind, IcuNumberStyleKind, IcuPluralKind};
+use crate::icu::compile::{
+    FormatJsElementType, FormatJsNode, FormatJsNodeOptions, FormatJsSingleNode, FormatJsStyle,
+};
+use crate::icu::skeleton::{
+    number_skeleton_token_text, parse_date_time_style, parse_number_style, DATE_TIME_SKELETON_TYPE,
+};
+
+/// A string that is borrowed from the input when possible.
+struct CowStr<'de>(Cow<'de, str>);
+
+impl<'de> Deserialize<'de> for CowStr<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CowStrVisitor;
+        impl<'de> Visitor<'de> for CowStrVisitor {
+            type Value = CowStr<'de>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Borrowed(v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Owned(v.into())))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Owned(v)))
+            }
+        }
+
+        deserializer.deserialize_str(CowStrVisitor)
+    }
+}
+
+//#region Element types
+
+impl FormatJsElementType {
+    fn from_index(index: u64) -> Option<Self> {
+        Some(match index {
+            0 => Self::Literal,
+            1 => Self::Argument,
+            2 => Self::Number,
+            3 => Self::Date,
+            4 => Self::Time,
+            5 => Self::Select,
+            6 => Self::Plural,
+            7 => Self::Pound,
+            8 => Self::Tag,
+            _ => return None,
+        })
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "Literal" => Self::Literal,
+            "Argument" => Self::Argument,
+            "Number" => Self::Number,
+            "Date" => Self::Date,
+            "Time" => Self::Time,
+            "Select" => Self::Select,
+            "Plural" => Self::Plural,
+            "Pound" => Self::Pound,
+            "Tag" => Self::Tag,
+            _ => return None,
+        })
+    }
+}
+
+struct ElementTypeVisitor;
+impl Visitor<'_> for ElementTypeVisitor {
+    type Value = FormatJsElementType;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a FormatJS element type")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        FormatJsElementType::from_index(v)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        u64::try_from(v)
+            .ok()
+            .and_then(FormatJsElementType::from_index)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        FormatJsElementType::from_name(v)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for FormatJsElementType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ElementTypeVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for IcuPluralKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match CowStr::deserialize(deserializer)?.0.as_ref() {
+            "cardinal" => Ok(IcuPluralKind::Plural),
+            "ordinal" => Ok(IcuPluralKind::SelectOrdinal),
+            other => Err(de::Error::unknown_variant(other, &["cardinal", "ordinal"])),
+        }
+    }
+}
+
+//#endregion
+
+//#region Nodes
+
+impl<'de> Deserialize<'de> for FormatJsNode<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = FormatJsNode<'de>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a FormatJS node, list of nodes, or literal string")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(FormatJsNode::Literal(Cow::Borrowed(v)))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(FormatJsNode::Literal(Cow::Owned(v.into())))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(FormatJsNode::Literal(Cow::Owned(v)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // Lists only ever contain nodes, so an array starting with a type number is a single node
+        // written without keys.
+        let node = match seq.next_element::<SeqHead>()? {
+            None => FormatJsNode::ListNode(vec![]),
+            Some(SeqHead::Type(ty)) => visit_keyless_node(ty, &mut seq)?,
+            Some(SeqHead::Node(first)) => {
+                let mut list = vec![first];
+                while let Some(node) = seq.next_element()? {
+                    list.push(node);
+                }
+                FormatJsNode::ListNode(list)
+            }
+        };
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(node)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut node = FormatJsSingleNode::default();
+        while let Some(key) = map.next_key::<CowStr>()? {
+            match key.0.as_ref() {
+                "type" => node.ty = Some(map.next_value()?),
+                "value" => node.value = Some(map.next_value::<CowStr>()?.0),
+                "children" => node.children = map.next_value::<Option<_>>()?.map(Box::new),
+                "options" => node.options = Some(map.next_value()?),
+                "control" => node.control = map.next_value::<Option<_>>()?.map(Box::new),
+                "style" => node.style = map.next_value()?,
+                "offset" => node.offset = Some(map.next_value()?),
+                "pluralType" => node.plural_type = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        match node.ty {
+            Some(FormatJsElementType::Literal) => Ok(FormatJsNode::Literal(
+                node.value
+                    .ok_or_else(|| de::Error::missing_field("value"))?,
+            )),
+            Some(_) => Ok(normalize_node(node).into()),
+            None => Err(de::Error::missing_field("type")),
+        }
+    }
+}
+
+/// The first element of an array, which is either the type of a keyless node or the first node of
+/// a list.
+enum SeqHead<'de> {
+    Type(FormatJsElementType),
+    Node(FormatJsNode<'de>),
+}
+
+impl<'de> Deserialize<'de> for SeqHead<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SeqHeadVisitor;
+        impl<'de> Visitor<'de> for SeqHeadVisitor {
+            type Value = SeqHead<'de>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a FormatJS element type or node")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                ElementTypeVisitor.visit_u64(v).map(SeqHead::Type)
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                ElementTypeVisitor.visit_i64(v).map(SeqHead::Type)
+            }
+
+            fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                NodeVisitor.visit_borrowed_str(v).map(SeqHead::Node)
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                NodeVisitor.visit_str(v).map(SeqHead::Node)
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                NodeVisitor.visit_string(v).map(SeqHead::Node)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+                NodeVisitor.visit_seq(seq).map(SeqHead::Node)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                NodeVisitor.visit_map(map).map(SeqHead::Node)
+            }
+        }
+
+        deserializer.deserialize_any(SeqHeadVisitor)
+    }
+}
+
+/// Read the remaining fields of a keyless node. Fields are written in their declared order, and
+/// only the fields that each type of node uses are present.
+fn visit_keyless_node<'de, A: SeqAccess<'de>>(
+    ty: FormatJsElementType,
+    seq: &mut A,
+) -> Result<FormatJsNode<'de>, A::Error> {
+    fn required<'de, T: Deserialize<'de>, A: SeqAccess<'de>>(
+        seq: &mut A,
+        index: usize,
+    ) -> Result<T, A::Error> {
+        seq.next_element()?
+            .ok_or_else(|| de::Error::invalid_length(index, &"a complete keyless node"))
+    }
+
+    let mut node = FormatJsSingleNode {
+        ty: Some(ty),
+        ..Default::default()
+    };
+    if ty != FormatJsElementType::Pound {
+        node.value = Some(required::<CowStr, _>(seq, 1)?.0);
+    }
+    match ty {
+        FormatJsElementType::Literal => {
+            return Ok(FormatJsNode::Literal(node.value.unwrap_or_default()));
+        }
+        FormatJsElementType::Number | FormatJsElementType::Date | FormatJsElementType::Time => {
+            node.style = seq.next_element::<Option<_>>()?.flatten();
+        }
+        FormatJsElementType::Select => {
+            node.options = Some(required(seq, 2)?);
+        }
+        FormatJsElementType::Plural => {
+            node.options = Some(required(seq, 2)?);
+            node.offset = Some(required(seq, 3)?);
+            node.plural_type = Some(required(seq, 4)?);
+        }
+        FormatJsElementType::Tag => {
+            node.children = Some(Box::new(required(seq, 2)?));
+            node.control = seq.next_element::<Option<_>>()?.flatten().map(Box::new);
+        }
+        FormatJsElementType::Argument | FormatJsElementType::Pound => {}
+    }
+    Ok(normalize_node(node).into())
+}
+
+/// Make the children and control of the node lists, the same as they are when compiled. FormatJS
+/// JSON allows `null` children and a single node as the control of a link.
+fn normalize_node(mut node: FormatJsSingleNode) -> FormatJsSingleNode {
+    fn into_list(node: FormatJsNode) -> FormatJsNode {
+        match node {
+            FormatJsNode::ListNode(_) => node,
+            node => FormatJsNode::ListNode(vec![node]),
+        }
+    }
+
+    if node.ty == Some(FormatJsElementType::Tag) && node.children.is_none() {
+        node.children = Some(Box::new(FormatJsNode::ListNode(vec![])));
+    }
+    node.children = node.children.map(|children| Box::new(into_list(*children)));
+    node.control = node.control.map(|control| Box::new(into_list(*control)));
+    node
+}
+
+//#endregion
+
+//#region Options
+
+impl<'de> Deserialize<'de> for FormatJsNodeOptions<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OptionsVisitor;
+        impl<'de> Visitor<'de> for OptionsVisitor {
+            type Value = FormatJsNodeOptions<'de>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a map of selectors to their content")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut arms = vec![];
+                while let Some(selector) = map.next_key::<CowStr>()? {
+                    arms.push((selector.0, map.next_value_seed(ArmContentSeed)?));
+                }
+                Ok(FormatJsNodeOptions(arms))
+            }
+        }
+
+        deserializer.deserialize_map(OptionsVisitor)
+    }
+}
+
+/// The content of a plural or select arm. Keyless JSON writes the list of nodes directly, while
+/// FormatJS wraps it in an object as `{"value": [...]}`.
+struct ArmContentSeed;
+impl<'de> DeserializeSeed<'de> for ArmContentSeed {
+    type Value = FormatJsNode<'de>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ArmContentSeed {
+    type Value = FormatJsNode<'de>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a list of nodes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        NodeVisitor.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut content = None;
+        while let Some(key) = map.next_key::<CowStr>()? {
+            if key.0 == "value" {
+                content = Some(map.next_value()?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        content.ok_or_else(|| de::Error::missing_field("value"))
+    }
+}
+
+//#endregion
+
+//#region Styles
+
+#[derive(Deserialize)]
+struct SkeletonToken {
+    stem: String,
+    #[serde(default)]
+    options: Vec<String>,
+}
+
+/// The fields of a compiled skeleton that are needed to parse it again. The parsed options are
+/// always recreated from the skeleton itself.
+#[derive(Deserialize)]
+struct Skeleton {
+    #[serde(rename = "type")]
+    ty: u8,
+    #[serde(default)]
+    tokens: Vec<SkeletonToken>,
+    #[serde(default)]
+    pattern: String,
+}
+
+impl<'de> Deserialize<'de> for FormatJsStyle<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StyleVisitor;
+        impl<'de> Visitor<'de> for StyleVisitor {
+            type Value = FormatJsStyle<'de>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a named style or a skeleton")
+            }
+
+            fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(FormatJsStyle::Text(Cow::Borrowed(v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(FormatJsStyle::Text(Cow::Owned(v.into())))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(FormatJsStyle::Text(Cow::Owned(v)))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                let skeleton = Skeleton::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                Ok(style_from_skeleton(skeleton))
+            }
+        }
+
+        deserializer.deserialize_any(StyleVisitor)
+    }
+}
+
+/// Parse the skeleton again from its text. Skeletons that can't be parsed are kept as text, the
+/// same as invalid styles in a message.
+fn style_from_skeleton<'de>(skeleton: Skeleton) -> FormatJsStyle<'de> {
+    if skeleton.ty == DATE_TIME_SKELETON_TYPE {
+        let text = format!("::{}", skeleton.pattern);
+        return match parse_date_time_style(&text) {
+            Ok(IcuDateTimeStyleKind::Skeleton(parsed)) => {
+                FormatJsStyle::DateTimeSkeleton(Cow::Owned(*parsed))
+            }
+            _ => FormatJsStyle::Text(Cow::Owned(text)),
+        };
+    }
+
+    let tokens: Vec<String> = skeleton
+        .tokens
+        .iter()
+        .map(|token| number_skeleton_token_text(&token.stem, &token.options))
+        .collect();
+    let text = format!("::{}", tokens.join(" "));
+    match parse_number_style(&text) {
+        Ok(IcuNumberStyleKind::Skeleton(parsed)) => {
+            FormatJsStyle::NumberSkeleton(Cow::Owned(*parsed))
+        }
+        _ => FormatJsStyle::Text(Cow::Owned(text)),
+    }
+}
+
+//#endregion
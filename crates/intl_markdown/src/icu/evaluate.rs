@@ -0,0 +1,735 @@
+//! Evaluating a message means rendering a parsed ICU-Markdown document with a set of argument
+//! values into its final, visible form. This is the same work that the `@discord/intl` runtime does
+//! in JS, but implemented natively so that messages can be previewed and rendered without a JS
+//! runtime, like in server-side renderers, snapshot tests, or tooling.
+//!
+//! The result of evaluation is a list of [FormattedSegment]s, which are either plain text or rich
+//! tags (Markdown formatting, hooks, links, and blocks) containing more segments. Callers that only
+//! care about the visible text can use [format_message_to_string] to flatten the result directly.
+//!
+//! Number, date, and time values are formatted with simple, locale-independent representations.
+//! The locale given to the evaluator is only used for resolving CLDR plural categories.
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use intl_plural_rules::PluralRuleType;
+use thiserror::Error;
+
+use crate::ast::{
+    BlockNode, BlockQuote, Document, Icu, IcuDate, IcuNumber, IcuNumberStyle, IcuNumberStyleKind,
+    IcuPlural, IcuPluralArm, IcuPluralKind, IcuSelect, IcuTime, InlineContent, Link,
+    LinkDestination, List, ListKind, Table, TableAlignment, TableRow,
+};
+use crate::icu::skeleton::NamedNumberStyle;
+use crate::icu::tags::DEFAULT_TAG_NAMES;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum FormatMessageError {
+    #[error("No value was provided for the argument '{0}'")]
+    MissingArgument(String),
+    #[error("Argument '{name}' was expected to be a {expected}, but the provided value is not")]
+    InvalidArgumentType {
+        name: String,
+        expected: &'static str,
+    },
+    #[error(
+        "No arm of '{0}' matches the provided value, and there is no 'other' arm to fall back to"
+    )]
+    NoMatchingArm(String),
+}
+
+pub type FormatMessageResult<T> = Result<T, FormatMessageError>;
+
+/// A value provided for a single argument when evaluating a message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormatArgument {
+    String(String),
+    Number(f64),
+    /// A point in time, represented as the number of milliseconds since the Unix epoch, matching
+    /// the representation of a JS `Date`.
+    Date(f64),
+}
+
+impl From<&str> for FormatArgument {
+    fn from(value: &str) -> Self {
+        FormatArgument::String(value.into())
+    }
+}
+impl From<String> for FormatArgument {
+    fn from(value: String) -> Self {
+        FormatArgument::String(value)
+    }
+}
+impl From<f64> for FormatArgument {
+    fn from(value: f64) -> Self {
+        FormatArgument::Number(value)
+    }
+}
+impl From<i64> for FormatArgument {
+    fn from(value: i64) -> Self {
+        FormatArgument::Number(value as f64)
+    }
+}
+
+pub type FormatArguments = HashMap<String, FormatArgument>;
+
+/// A rich tag in the formatted output of a message, like a Markdown style, a hook, a link, or a
+/// block element.
+///
+/// Tags use the same names as the compiled FormatJS output (e.g., `$b` for strong
+/// text or the hook name for hooks), so renderers can share handling between both formats.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormattedTag {
+    pub name: String,
+    pub children: Vec<FormattedSegment>,
+    /// Non-visible content controlling the behavior of the tag, like the destination of a link.
+    /// This mirrors the `control` extension of the compiled FormatJS output.
+    pub control: Vec<FormattedSegment>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormattedSegment {
+    Text(String),
+    Tag(FormattedTag),
+    /// A reference to a handler function, like the click handler of `[click me](onClick)`. The
+    /// handler has no visible content and only appears within the control of a tag.
+    Handler(String),
+}
+
+impl FormattedSegment {
+    /// Write the visible text of all the given segments into a single string, discarding any
+    /// formatting tags and control content.
+    pub fn to_plain_text(segments: &[FormattedSegment]) -> String {
+        let mut buffer = String::new();
+        write_plain_text(&mut buffer, segments);
+        buffer
+    }
+}
+
+fn write_plain_text(buffer: &mut String, segments: &[FormattedSegment]) {
+    for segment in segments {
+        match segment {
+            FormattedSegment::Text(text) => buffer.push_str(text),
+            FormattedSegment::Tag(tag) if tag.name == DEFAULT_TAG_NAMES.br() => buffer.push('\n'),
+            FormattedSegment::Tag(tag) => write_plain_text(buffer, &tag.children),
+            FormattedSegment::Handler(_) => {}
+        }
+    }
+}
+
+/// Evaluate the given `document` using the given `arguments`, returning the list of rich segments
+/// that make up the rendered message.
+pub fn format_message(
+    document: &Document,
+    locale: &str,
+    arguments: &FormatArguments,
+) -> FormatMessageResult<Vec<FormattedSegment>> {
+    MessageEvaluator::new(locale, arguments).evaluate_document(document)
+}
+
+/// Evaluate the given `document` using the given `arguments`, returning only the visible text of
+/// the message.
+pub fn format_message_to_string(
+    document: &Document,
+    locale: &str,
+    arguments: &FormatArguments,
+) -> FormatMessageResult<String> {
+    let segments = format_message(document, locale, arguments)?;
+    Ok(FormattedSegment::to_plain_text(&segments))
+}
+
+struct MessageEvaluator<'a> {
+    locale: &'a str,
+    arguments: &'a FormatArguments,
+    /// Stack of values for the plural expressions currently being evaluated, used to resolve
+    /// `#` placeholders to the innermost plural value.
+    plural_values: Vec<f64>,
+}
+
+impl<'a> MessageEvaluator<'a> {
+    fn new(locale: &'a str, arguments: &'a FormatArguments) -> Self {
+        Self {
+            locale,
+            arguments,
+            plural_values: vec![],
+        }
+    }
+
+    fn evaluate_document(
+        &mut self,
+        document: &Document,
+    ) -> FormatMessageResult<Vec<FormattedSegment>> {
+        let mut result = Vec::with_capacity(document.blocks().len());
+        for block in document.blocks() {
+            match block {
+                BlockNode::InlineContent(content) => {
+                    result.extend(self.evaluate_inline_content(content)?)
+                }
+                block => result.push(self.evaluate_block(block)?),
+            }
+        }
+        Ok(result)
+    }
+
+    fn evaluate_block(&mut self, block: &BlockNode) -> FormatMessageResult<FormattedSegment> {
+        let segment = match block {
+            BlockNode::Paragraph(paragraph) => tag(
+                DEFAULT_TAG_NAMES.paragraph(),
+                self.evaluate_inline_content(paragraph.content())?,
+            ),
+            BlockNode::Heading(heading) => tag(
+                DEFAULT_TAG_NAMES.heading(heading.level()),
+                self.evaluate_inline_content(heading.content())?,
+            ),
+            BlockNode::CodeBlock(code_block) => tag(
+                DEFAULT_TAG_NAMES.code_block(),
+                vec![FormattedSegment::Text(code_block.content().clone())],
+            ),
+            BlockNode::ThematicBreak => tag(DEFAULT_TAG_NAMES.hr(), vec![]),
+            BlockNode::BlockQuote(block_quote) => self.evaluate_block_quote(block_quote)?,
+            BlockNode::List(list) => self.evaluate_list(list)?,
+            BlockNode::Table(table) => self.evaluate_table(table)?,
+            BlockNode::InlineContent(content) => {
+                unreachable!(
+                    "InlineContent blocks are flattened into the document. Found: {content:?}"
+                )
+            }
+        };
+        Ok(segment)
+    }
+
+    /// Evaluate the blocks inside of a container. Paragraphs in the items of a tight list are
+    /// unwrapped into their inline content, matching how they are compiled for the runtime.
+    fn evaluate_container_children(
+        &mut self,
+        blocks: &[BlockNode],
+        is_tight: bool,
+    ) -> FormatMessageResult<Vec<FormattedSegment>> {
+        let mut result = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            match block {
+                BlockNode::Paragraph(paragraph) if is_tight => {
+                    for segment in self.evaluate_inline_content(paragraph.content())? {
+                        push_segment(&mut result, segment);
+                    }
+                }
+                block => result.push(self.evaluate_block(block)?),
+            }
+        }
+        Ok(result)
+    }
+
+    fn evaluate_block_quote(
+        &mut self,
+        block_quote: &BlockQuote,
+    ) -> FormatMessageResult<FormattedSegment> {
+        Ok(tag(
+            DEFAULT_TAG_NAMES.block_quote(),
+            self.evaluate_container_children(block_quote.content(), false)?,
+        ))
+    }
+
+    fn evaluate_list(&mut self, list: &List) -> FormatMessageResult<FormattedSegment> {
+        let name = match list.kind() {
+            ListKind::Bullet => DEFAULT_TAG_NAMES.unordered_list(),
+            ListKind::Ordered => DEFAULT_TAG_NAMES.ordered_list(),
+        };
+        let mut items = Vec::with_capacity(list.items().len());
+        for item in list.items() {
+            items.push(tag(
+                DEFAULT_TAG_NAMES.list_item(),
+                self.evaluate_container_children(item.content(), list.is_tight())?,
+            ));
+        }
+        let control = match list.start() {
+            Some(start) if start != "1" => vec![FormattedSegment::Text(start.into())],
+            _ => vec![],
+        };
+        Ok(FormattedSegment::Tag(FormattedTag {
+            name: name.into(),
+            children: items,
+            control,
+        }))
+    }
+
+    fn evaluate_table(&mut self, table: &Table) -> FormatMessageResult<FormattedSegment> {
+        let header = self.evaluate_table_row(
+            table.header(),
+            table.alignments(),
+            DEFAULT_TAG_NAMES.table_header_cell(),
+        )?;
+        let mut sections = vec![tag(DEFAULT_TAG_NAMES.table_head(), vec![header])];
+        if !table.rows().is_empty() {
+            let mut rows = Vec::with_capacity(table.rows().len());
+            for row in table.rows() {
+                rows.push(self.evaluate_table_row(
+                    row,
+                    table.alignments(),
+                    DEFAULT_TAG_NAMES.table_cell(),
+                )?);
+            }
+            sections.push(tag(DEFAULT_TAG_NAMES.table_body(), rows));
+        }
+        Ok(tag(DEFAULT_TAG_NAMES.table(), sections))
+    }
+
+    fn evaluate_table_row(
+        &mut self,
+        row: &TableRow,
+        alignments: &[TableAlignment],
+        cell_name: &str,
+    ) -> FormatMessageResult<FormattedSegment> {
+        let mut cells = Vec::with_capacity(row.cells().len());
+        for (cell, alignment) in row.cells().iter().zip(alignments) {
+            cells.push(FormattedSegment::Tag(FormattedTag {
+                name: cell_name.into(),
+                children: self.evaluate_inline_content(cell.content())?,
+                control: alignment
+                    .as_str()
+                    .map(|align| FormattedSegment::Text(align.into()))
+                    .into_iter()
+                    .collect(),
+            }));
+        }
+        Ok(tag(DEFAULT_TAG_NAMES.table_row(), cells))
+    }
+
+    fn evaluate_inline_content(
+        &mut self,
+        content: &[InlineContent],
+    ) -> FormatMessageResult<Vec<FormattedSegment>> {
+        let mut result: Vec<FormattedSegment> = Vec::with_capacity(content.len());
+        for element in content {
+            let segment = match element {
+                InlineContent::Text(text) => FormattedSegment::Text(text.clone()),
+                InlineContent::Emphasis(emphasis) => tag(
+                    DEFAULT_TAG_NAMES.emphasis(),
+                    self.evaluate_inline_content(emphasis.content())?,
+                ),
+                InlineContent::Strong(strong) => tag(
+                    DEFAULT_TAG_NAMES.strong(),
+                    self.evaluate_inline_content(strong.content())?,
+                ),
+                InlineContent::Link(link) => self.evaluate_link(link)?,
+                InlineContent::CodeSpan(code_span) => tag(
+                    DEFAULT_TAG_NAMES.code(),
+                    vec![FormattedSegment::Text(code_span.content().clone())],
+                ),
+                InlineContent::HardLineBreak => tag(DEFAULT_TAG_NAMES.br(), vec![]),
+                InlineContent::Hook(hook) => {
+                    tag(hook.name(), self.evaluate_inline_content(hook.content())?)
+                }
+                InlineContent::Strikethrough(strikethrough) => tag(
+                    DEFAULT_TAG_NAMES.strike_through(),
+                    self.evaluate_inline_content(strikethrough.content())?,
+                ),
+                InlineContent::Icu(icu) => {
+                    // Plurals and selects evaluate to a list of segments that get inlined into
+                    // the surrounding content, rather than being wrapped in another tag.
+                    for segment in self.evaluate_icu(icu)? {
+                        push_segment(&mut result, segment);
+                    }
+                    continue;
+                }
+                InlineContent::IcuPound => FormattedSegment::Text(self.evaluate_icu_pound()),
+            };
+            push_segment(&mut result, segment);
+        }
+        Ok(result)
+    }
+
+    fn evaluate_link(&mut self, link: &Link) -> FormatMessageResult<FormattedSegment> {
+        let control = match link.destination() {
+            LinkDestination::Text(text) => vec![FormattedSegment::Text(text.clone())],
+            LinkDestination::Placeholder(icu) => self.evaluate_icu(icu)?,
+            LinkDestination::Handler(name) => vec![FormattedSegment::Handler(name.clone())],
+        };
+        Ok(FormattedSegment::Tag(FormattedTag {
+            name: DEFAULT_TAG_NAMES.link().into(),
+            children: self.evaluate_inline_content(link.label())?,
+            control,
+        }))
+    }
+
+    fn evaluate_icu(&mut self, icu: &Icu) -> FormatMessageResult<Vec<FormattedSegment>> {
+        let text = match icu {
+            Icu::IcuVariable(variable) => {
+                format_argument_value(self.get_argument(variable.name())?)
+            }
+            Icu::IcuPlural(plural) => return self.evaluate_icu_plural(plural),
+            Icu::IcuSelect(select) => return self.evaluate_icu_select(select),
+            Icu::IcuDate(date) => self.evaluate_icu_date(date)?,
+            Icu::IcuTime(time) => self.evaluate_icu_time(time)?,
+            Icu::IcuNumber(number) => self.evaluate_icu_number(number)?,
+        };
+        Ok(vec![FormattedSegment::Text(text)])
+    }
+
+    fn evaluate_icu_plural(
+        &mut self,
+        plural: &IcuPlural,
+    ) -> FormatMessageResult<Vec<FormattedSegment>> {
+        let value = self.get_number_argument(plural.name())?;
+        // Exact selectors match the value as given, but everything else, including `#`, uses the
+        // value after the offset is applied.
+        let offset_value = value - plural.offset() as f64;
+        let category = select_plural_category(self.locale, *plural.kind(), offset_value);
+        let arm = find_exact_plural_arm(plural.arms(), value)
+            .or_else(|| find_arm(plural.arms(), category))
+            .or_else(|| find_arm(plural.arms(), "other"))
+            .ok_or_else(|| FormatMessageError::NoMatchingArm(plural.name().clone()))?;
+
+        self.plural_values.push(offset_value);
+        let result = self.evaluate_inline_content(arm.content());
+        self.plural_values.pop();
+        result
+    }
+
+    fn evaluate_icu_select(
+        &mut self,
+        select: &IcuSelect,
+    ) -> FormatMessageResult<Vec<FormattedSegment>> {
+        let value = format_argument_value(self.get_argument(select.name())?);
+        let arm = find_arm(select.arms(), &value)
+            .or_else(|| find_arm(select.arms(), "other"))
+            .ok_or_else(|| FormatMessageError::NoMatchingArm(select.name().clone()))?;
+        self.evaluate_inline_content(arm.content())
+    }
+
+    fn evaluate_icu_date(&mut self, date: &IcuDate) -> FormatMessageResult<String> {
+        let timestamp = self.get_date_argument(date.name())?;
+        let (year, month, day) = civil_from_timestamp(timestamp);
+        Ok(format!("{year:04}-{month:02}-{day:02}"))
+    }
+
+    fn evaluate_icu_time(&mut self, time: &IcuTime) -> FormatMessageResult<String> {
+        let timestamp = self.get_date_argument(time.name())?;
+        let seconds_of_day = (timestamp / 1000.0).floor().rem_euclid(86400.0) as u32;
+        Ok(format!(
+            "{:02}:{:02}:{:02}",
+            seconds_of_day / 3600,
+            seconds_of_day / 60 % 60,
+            seconds_of_day % 60
+        ))
+    }
+
+    fn evaluate_icu_number(&mut self, number: &IcuNumber) -> FormatMessageResult<String> {
+        let value = self.get_number_argument(number.name())?;
+        let (is_percent, is_integer) = match number.style().as_ref().map(IcuNumberStyle::kind) {
+            Some(IcuNumberStyleKind::Named(NamedNumberStyle::Percent)) => (true, false),
+            Some(IcuNumberStyleKind::Named(NamedNumberStyle::Integer)) => (false, true),
+            Some(IcuNumberStyleKind::Skeleton(skeleton)) => {
+                let options = skeleton.options();
+                (
+                    options.style == Some("percent"),
+                    options.maximum_fraction_digits == Some(0),
+                )
+            }
+            _ => (false, false),
+        };
+        let value = if is_percent { value * 100.0 } else { value };
+        let value = if is_integer { value.round() } else { value };
+        Ok(if is_percent {
+            format!("{}%", format_number(value))
+        } else {
+            format_number(value)
+        })
+    }
+
+    fn evaluate_icu_pound(&self) -> String {
+        // `#` is only parsed inside of plural arms, so there is always a value on the stack when
+        // evaluating a parsed document.
+        self.plural_values
+            .last()
+            .map_or_else(|| "#".into(), |value| format_number(*value))
+    }
+
+    fn get_argument(&self, name: &str) -> FormatMessageResult<&FormatArgument> {
+        self.arguments
+            .get(name)
+            .ok_or_else(|| FormatMessageError::MissingArgument(name.into()))
+    }
+
+    fn get_number_argument(&self, name: &str) -> FormatMessageResult<f64> {
+        match self.get_argument(name)? {
+            FormatArgument::Number(value) => Ok(*value),
+            // Strings are coerced the same way that the JS runtime would.
+            FormatArgument::String(value) => {
+                value
+                    .trim()
+                    .parse()
+                    .map_err(|_| FormatMessageError::InvalidArgumentType {
+                        name: name.into(),
+                        expected: "number",
+                    })
+            }
+            FormatArgument::Date(_) => Err(FormatMessageError::InvalidArgumentType {
+                name: name.into(),
+                expected: "number",
+            }),
+        }
+    }
+
+    fn get_date_argument(&self, name: &str) -> FormatMessageResult<f64> {
+        match self.get_argument(name)? {
+            FormatArgument::Date(value) | FormatArgument::Number(value) => Ok(*value),
+            FormatArgument::String(_) => Err(FormatMessageError::InvalidArgumentType {
+                name: name.into(),
+                expected: "date",
+            }),
+        }
+    }
+}
+
+#[inline(always)]
+fn tag(name: &str, children: Vec<FormattedSegment>) -> FormattedSegment {
+    FormattedSegment::Tag(FormattedTag {
+        name: name.into(),
+        children,
+        control: vec![],
+    })
+}
+
+/// Push `segment` onto the end of `segments`, merging adjacent text segments together.
+fn push_segment(segments: &mut Vec<FormattedSegment>, segment: FormattedSegment) {
+    if let FormattedSegment::Text(text) = &segment {
+        if let Some(FormattedSegment::Text(previous)) = segments.last_mut() {
+            previous.push_str(text);
+            return;
+        }
+    }
+    segments.push(segment);
+}
+
+fn find_arm<'a>(arms: &'a [IcuPluralArm], selector: &str) -> Option<&'a IcuPluralArm> {
+    arms.iter().find(|arm| arm.selector() == selector)
+}
+
+/// Exact selectors like `=0` take precedence over plural categories.
+fn find_exact_plural_arm(arms: &[IcuPluralArm], value: f64) -> Option<&IcuPluralArm> {
+    arms.iter().find(|arm| {
+        arm.selector()
+            .strip_prefix('=')
+            .and_then(|exact| exact.parse::<f64>().ok())
+            .is_some_and(|exact| exact == value)
+    })
+}
+
+/// Resolve the plural category that `value` belongs to in `locale`, using the CLDR rules for the
+/// kind of plural being evaluated.
+fn select_plural_category(locale: &str, kind: IcuPluralKind, value: f64) -> &'static str {
+    let rule_type = match kind {
+        IcuPluralKind::Plural => PluralRuleType::Cardinal,
+        IcuPluralKind::SelectOrdinal => PluralRuleType::Ordinal,
+    };
+    intl_plural_rules::select_plural_category(locale, rule_type, value).as_str()
+}
+
+fn format_argument_value(value: &FormatArgument) -> String {
+    match value {
+        FormatArgument::String(value) => value.clone(),
+        FormatArgument::Number(value) | FormatArgument::Date(value) => format_number(*value),
+    }
+}
+
+fn format_number(value: f64) -> String {
+    let mut buffer = String::new();
+    if value.fract() == 0.0 && value.abs() < 1e16 {
+        let _ = write!(buffer, "{}", value as i64);
+    } else {
+        let _ = write!(buffer, "{}", value);
+    }
+    buffer
+}
+
+/// Convert a millisecond timestamp into a (year, month, day) date in UTC. Adapted from Howard
+/// Hinnant's `civil_from_days` algorithm.
+fn civil_from_timestamp(timestamp: f64) -> (i64, u32, u32) {
+    let days = (timestamp / 86_400_000.0).floor() as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::icu::tags::DEFAULT_TAG_NAMES;
+    use crate::parse_intl_message;
+
+    use super::{
+        format_message, format_message_to_string, FormatArgument, FormatArguments,
+        FormatMessageError, FormattedSegment, FormattedTag,
+    };
+
+    macro_rules! args {
+        ($($name:literal => $value:expr),* $(,)?) => {{
+            #[allow(unused_mut)]
+            let mut arguments = FormatArguments::new();
+            $(arguments.insert($name.into(), FormatArgument::from($value));)*
+            arguments
+        }};
+    }
+
+    fn format(input: &str, arguments: &FormatArguments) -> String {
+        let document = parse_intl_message(input, false);
+        format_message_to_string(&document, "en-US", arguments).unwrap()
+    }
+
+    #[test]
+    fn static_text() {
+        assert_eq!(format("hello world", &args!()), "hello world");
+    }
+
+    #[test]
+    fn variables() {
+        assert_eq!(
+            format("hello {username}!", &args!("username" => "faulty")),
+            "hello faulty!"
+        );
+    }
+
+    #[test]
+    fn missing_variable() {
+        let document = parse_intl_message("hello {username}", false);
+        assert_eq!(
+            format_message(&document, "en-US", &args!()),
+            Err(FormatMessageError::MissingArgument("username".into()))
+        );
+    }
+
+    #[test]
+    fn plurals() {
+        let message = "{count, plural, =0 {none} one {# item} other {# items}}";
+        assert_eq!(format(message, &args!("count" => 0i64)), "none");
+        assert_eq!(format(message, &args!("count" => 1i64)), "1 item");
+        assert_eq!(format(message, &args!("count" => 5i64)), "5 items");
+    }
+
+    #[test]
+    fn locale_plural_rules() {
+        let message = "{count, plural, one {one} few {few} many {many} other {other}}";
+        let document = parse_intl_message(message, false);
+        let format_pl = |count: i64| {
+            format_message_to_string(&document, "pl", &args!("count" => count)).unwrap()
+        };
+        assert_eq!(format_pl(1), "one");
+        assert_eq!(format_pl(3), "few");
+        assert_eq!(format_pl(5), "many");
+
+        let ordinal = "{place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}";
+        assert_eq!(format(ordinal, &args!("place" => 22i64)), "22nd");
+        assert_eq!(format(ordinal, &args!("place" => 13i64)), "13th");
+    }
+
+    #[test]
+    fn plural_offset() {
+        let message = "{count, plural, offset:1 =0 {nobody} =1 {you} one {you and # other} other {you and # others}}";
+        assert_eq!(format(message, &args!("count" => 0i64)), "nobody");
+        assert_eq!(format(message, &args!("count" => 1i64)), "you");
+        assert_eq!(format(message, &args!("count" => 2i64)), "you and 1 other");
+        assert_eq!(format(message, &args!("count" => 4i64)), "you and 3 others");
+    }
+
+    #[test]
+    fn nested_plural_pound() {
+        let message = "{a, plural, other {# outer and {b, plural, other {# inner}}}}";
+        assert_eq!(
+            format(message, &args!("a" => 2i64, "b" => 3i64)),
+            "2 outer and 3 inner"
+        );
+    }
+
+    #[test]
+    fn selects() {
+        let message = "{color, select, orange {fluffy} other {plain}}";
+        assert_eq!(format(message, &args!("color" => "orange")), "fluffy");
+        assert_eq!(format(message, &args!("color" => "blue")), "plain");
+    }
+
+    #[test]
+    fn numbers_and_dates() {
+        assert_eq!(
+            format("{ratio, number, percent}", &args!("ratio" => 0.25)),
+            "25%"
+        );
+        assert_eq!(
+            format(
+                "{ratio, number, ::percent .} {count, number, ::.}",
+                &args!("ratio" => 0.256, "count" => 2.5)
+            ),
+            "26% 3"
+        );
+        assert_eq!(
+            format(
+                "{at, date} {at, time}",
+                &args!("at" => FormatArgument::Date(1_700_000_000_000.0))
+            ),
+            "2023-11-14 22:13:20"
+        );
+    }
+
+    #[test]
+    fn rich_segments() {
+        let document = parse_intl_message("**hi** $[{name}](mention) [go](onClick)", false);
+        let segments = format_message(&document, "en-US", &args!("name" => "faulty")).unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                FormattedSegment::Tag(FormattedTag {
+                    name: DEFAULT_TAG_NAMES.strong().into(),
+                    children: vec![FormattedSegment::Text("hi".into())],
+                    control: vec![],
+                }),
+                FormattedSegment::Text(" ".into()),
+                FormattedSegment::Tag(FormattedTag {
+                    name: "mention".into(),
+                    children: vec![FormattedSegment::Text("faulty".into())],
+                    control: vec![],
+                }),
+                FormattedSegment::Text(" ".into()),
+                FormattedSegment::Tag(FormattedTag {
+                    name: DEFAULT_TAG_NAMES.link().into(),
+                    children: vec![FormattedSegment::Text("go".into())],
+                    control: vec![FormattedSegment::Handler("onClick".into())],
+                }),
+            ]
+        );
+        assert_eq!(FormattedSegment::to_plain_text(&segments), "hi faulty go");
+    }
+
+    #[test]
+    fn list_segments() {
+        let document = parse_intl_message("3. {count} left\n4. done", true);
+        let segments = format_message(&document, "en-US", &args!("count" => 2i64)).unwrap();
+        assert_eq!(
+            segments,
+            vec![FormattedSegment::Tag(FormattedTag {
+                name: DEFAULT_TAG_NAMES.ordered_list().into(),
+                children: vec![
+                    FormattedSegment::Tag(FormattedTag {
+                        name: DEFAULT_TAG_NAMES.list_item().into(),
+                        children: vec![FormattedSegment::Text("2 left".into())],
+                        control: vec![],
+                    }),
+                    FormattedSegment::Tag(FormattedTag {
+                        name: DEFAULT_TAG_NAMES.list_item().into(),
+                        children: vec![FormattedSegment::Text("done".into())],
+                        control: vec![],
+                    }),
+                ],
+                control: vec![FormattedSegment::Text("3".into())],
+            })]
+        );
+    }
+}
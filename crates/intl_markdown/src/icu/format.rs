@@ -2,10 +2,10 @@ use std::fmt::Write;
 
 use crate::ast::util::{escape_body_text, escape_href};
 use crate::ast::{
-    BlockNode, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
+    BlockNode, BlockQuote, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
     IcuDateTimeStyle, IcuNumber, IcuNumberStyle, IcuPlural, IcuPluralArm, IcuPluralKind, IcuSelect,
-    IcuTime, IcuVariable, InlineContent, Link, LinkDestination, LinkKind, Paragraph, Strikethrough,
-    Strong,
+    IcuTime, IcuVariable, InlineContent, Link, LinkDestination, LinkKind, List, ListKind,
+    Paragraph, Strikethrough, Strong, Table, TableAlignment, TableRow,
 };
 
 macro_rules! write {
@@ -83,21 +83,127 @@ impl<T: FormatIcuString> FormatIcuString for [T] {
 pub fn format_icu_string(document: &Document) -> FormatResult<String> {
     let mut f = String::new();
 
-    for (index, block) in document.blocks().iter().enumerate() {
-        if index > 0 {
-            f.push('\n');
+    write!(f, [format_blocks(document.blocks(), false)])?;
+
+    Ok(f)
+}
+
+impl FormatIcuString for BlockNode {
+    fn fmt(&self, mut f: &mut dyn Write) -> FormatResult<()> {
+        match self {
+            BlockNode::Paragraph(paragraph) => write!(f, [paragraph]),
+            BlockNode::Heading(heading) => write!(f, [heading]),
+            BlockNode::CodeBlock(code_block) => write!(f, [code_block]),
+            BlockNode::ThematicBreak => write!(f, ["<hr />"]),
+            BlockNode::InlineContent(content) => write!(f, [content]),
+            BlockNode::BlockQuote(block_quote) => write!(f, [block_quote]),
+            BlockNode::List(list) => write!(f, [list]),
+            BlockNode::Table(table) => write!(f, [table]),
         }
+    }
+}
+
+fn format_blocks(blocks: &[BlockNode], is_tight: bool) -> FormatBlocks {
+    FormatBlocks { blocks, is_tight }
+}
+/// A sequence of blocks, separated by newlines. Paragraphs in the items of a tight list are
+/// written without their wrapping tag, the same as they are compiled for the runtime.
+struct FormatBlocks<'a> {
+    blocks: &'a [BlockNode],
+    is_tight: bool,
+}
+impl FormatIcuString for FormatBlocks<'_> {
+    fn fmt(&self, mut f: &mut dyn Write) -> FormatResult<()> {
+        for (index, block) in self.blocks.iter().enumerate() {
+            if index > 0 {
+                f.write_char('\n')?;
+            }
 
-        match block {
-            BlockNode::Paragraph(paragraph) => write!(f, [paragraph])?,
-            BlockNode::Heading(heading) => write!(f, [heading])?,
-            BlockNode::CodeBlock(code_block) => write!(f, [code_block])?,
-            BlockNode::ThematicBreak => write!(f, ["<hr />"])?,
-            BlockNode::InlineContent(content) => write!(f, [content])?,
+            match block {
+                BlockNode::Paragraph(paragraph) if self.is_tight => {
+                    write!(f, [paragraph.content()])?
+                }
+                block => write!(f, [block])?,
+            }
         }
+
+        Ok(())
     }
+}
 
-    Ok(f)
+impl FormatIcuString for BlockQuote {
+    fn fmt(&self, mut f: &mut dyn Write) -> FormatResult<()> {
+        write!(
+            f,
+            [
+                "<blockquote>",
+                format_blocks(self.content(), false),
+                "</blockquote>"
+            ]
+        )
+    }
+}
+
+impl FormatIcuString for List {
+    fn fmt(&self, mut f: &mut dyn Write) -> FormatResult<()> {
+        let tag_name = match self.kind() {
+            ListKind::Bullet => "ul",
+            ListKind::Ordered => "ol",
+        };
+        std::write!(f, "<{tag_name}>")?;
+        // Like link destinations, the starting number of an ordered list is written as the first
+        // child of the tag, followed by a delimiter to keep it separate from the items.
+        if let Some(start) = self.start().filter(|start| *start != "1") {
+            write!(f, [start, "{_}"])?;
+        }
+        for item in self.items() {
+            write!(
+                f,
+                [
+                    "<li>",
+                    format_blocks(item.content(), self.is_tight()),
+                    "</li>"
+                ]
+            )?;
+        }
+        std::write!(f, "</{tag_name}>")
+    }
+}
+
+impl FormatIcuString for Table {
+    fn fmt(&self, mut f: &mut dyn Write) -> FormatResult<()> {
+        write!(f, ["<table><thead>"])?;
+        format_table_row(f, self.header(), self.alignments(), "th")?;
+        write!(f, ["</thead>"])?;
+        if !self.rows().is_empty() {
+            write!(f, ["<tbody>"])?;
+            for row in self.rows() {
+                format_table_row(f, row, self.alignments(), "td")?;
+            }
+            write!(f, ["</tbody>"])?;
+        }
+        write!(f, ["</table>"])
+    }
+}
+
+fn format_table_row(
+    mut f: &mut dyn Write,
+    row: &TableRow,
+    alignments: &[TableAlignment],
+    tag_name: &str,
+) -> FormatResult<()> {
+    write!(f, ["<tr>"])?;
+    for (cell, alignment) in row.cells().iter().zip(alignments) {
+        std::write!(f, "<{tag_name}>")?;
+        // The alignment of a cell is written as its first child, the same as the starting number
+        // of an ordered list.
+        if let Some(align) = alignment.as_str() {
+            write!(f, [align, "{_}"])?;
+        }
+        write!(f, [cell.content()])?;
+        std::write!(f, "</{tag_name}>")?;
+    }
+    write!(f, ["</tr>"])
 }
 
 impl FormatIcuString for Paragraph {
@@ -247,7 +353,11 @@ impl FormatIcuString for IcuPlural {
             IcuPluralKind::SelectOrdinal => "selectordinal",
         };
 
-        write!(f, [self.name(), ", ", kind_str, ",", self.arms()])
+        write!(f, [self.name(), ", ", kind_str, ","])?;
+        if self.offset() > 0 {
+            write!(f, [" offset:", &self.offset().to_string()])?;
+        }
+        write!(f, [self.arms()])
     }
 }
 
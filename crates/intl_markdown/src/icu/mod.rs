@@ -1,4 +1,8 @@
 pub mod compile;
+pub mod decompile;
+pub mod deserialize;
+pub mod evaluate;
 pub mod format;
 pub mod serialize;
+pub mod skeleton;
 pub mod tags;
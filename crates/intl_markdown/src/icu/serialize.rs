@@ -2,9 +2,11 @@ use serde::ser::{SerializeMap, SerializeSeq, SerializeStruct};
 use serde::{Serialize, Serializer};
 
 use crate::ast::{
-    BlockNode, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate, IcuNumber,
+    BlockNode, BlockQuote, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
+    IcuDateTimeStyle, IcuDateTimeStyleKind, IcuNumber, IcuNumberStyle, IcuNumberStyleKind,
     IcuPlural, IcuPluralArm, IcuPluralKind, IcuSelect, IcuTime, IcuVariable, InlineContent, Link,
-    LinkDestination, Paragraph, Strikethrough, Strong,
+    LinkDestination, List, ListKind, Paragraph, Strikethrough, Strong, Table, TableAlignment,
+    TableCell, TableRow,
 };
 use crate::icu::tags::DEFAULT_TAG_NAMES;
 
@@ -99,13 +101,34 @@ impl Serialize for Document {
     where
         S: Serializer,
     {
-        let mut root = serializer.serialize_seq(Some(self.blocks().len()))?;
-        for block in self.blocks() {
+        SerializeBlocks(self.blocks(), false).serialize(serializer)
+    }
+}
+
+/// A sequence of blocks serialized as a flat list of elements. Inline content is spread into the
+/// list directly, as are the paragraphs in the items of a tight list when the second field is set.
+struct SerializeBlocks<'a>(&'a Vec<BlockNode>, bool);
+impl Serialize for SerializeBlocks<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let SerializeBlocks(blocks, is_tight) = *self;
+        let mut root = serializer.serialize_seq(Some(blocks.len()))?;
+        for block in blocks {
             match block {
+                BlockNode::Paragraph(paragraph) if is_tight => {
+                    for element in paragraph.content() {
+                        root.serialize_element(&element)?
+                    }
+                }
                 BlockNode::Paragraph(paragraph) => root.serialize_element(&paragraph)?,
                 BlockNode::Heading(heading) => root.serialize_element(&heading)?,
                 BlockNode::CodeBlock(code_block) => root.serialize_element(&code_block)?,
                 BlockNode::ThematicBreak => root.serialize_element(&"<hr />")?,
+                BlockNode::BlockQuote(block_quote) => root.serialize_element(&block_quote)?,
+                BlockNode::List(list) => root.serialize_element(&list)?,
+                BlockNode::Table(table) => root.serialize_element(&table)?,
                 BlockNode::InlineContent(content) => {
                     for element in content {
                         root.serialize_element(&element)?
@@ -118,6 +141,178 @@ impl Serialize for Document {
     }
 }
 
+impl Serialize for BlockQuote {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_tag(
+            serializer,
+            DEFAULT_TAG_NAMES.block_quote(),
+            &SerializeBlocks(self.content(), false),
+        )
+    }
+}
+
+struct SerializeListItems<'a>(&'a List);
+impl Serialize for SerializeListItems<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut items = serializer.serialize_seq(Some(self.0.items().len()))?;
+        for item in self.0.items() {
+            items.serialize_element(&SerializeListItem(SerializeBlocks(
+                item.content(),
+                self.0.is_tight(),
+            )))?;
+        }
+        items.end()
+    }
+}
+
+struct SerializeListItem<'a>(SerializeBlocks<'a>);
+impl Serialize for SerializeListItem<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_tag(serializer, DEFAULT_TAG_NAMES.list_item(), &self.0)
+    }
+}
+
+impl Serialize for List {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let tag_name = match self.kind() {
+            ListKind::Bullet => DEFAULT_TAG_NAMES.unordered_list(),
+            ListKind::Ordered => DEFAULT_TAG_NAMES.ordered_list(),
+        };
+        let start = self.start().filter(|start| *start != "1");
+
+        let mut list = serializer.serialize_struct("List", 3 + start.is_some() as usize)?;
+        list.serialize_field(fjs_types::TYPE, &FormatJsElementType::Tag)?;
+        list.serialize_field(fjs_types::VALUE, tag_name)?;
+        list.serialize_field(fjs_types::CHILDREN, &SerializeListItems(self))?;
+        // Ordered lists that don't start at 1 carry their starting number as a control element.
+        if let Some(start) = start {
+            list.serialize_field(fjs_types::CONTROL, &[InlineContent::Text(start.into())])?;
+        }
+        list.end()
+    }
+}
+
+impl Serialize for Table {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_tag(
+            serializer,
+            DEFAULT_TAG_NAMES.table(),
+            &SerializeTableSections(self),
+        )
+    }
+}
+
+/// The head of a table, followed by its body if the table has any data rows.
+struct SerializeTableSections<'a>(&'a Table);
+impl Serialize for SerializeTableSections<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let table = self.0;
+        let has_body = !table.rows().is_empty();
+        let mut sections = serializer.serialize_seq(Some(1 + has_body as usize))?;
+        sections.serialize_element(&SerializeTableSection(
+            DEFAULT_TAG_NAMES.table_head(),
+            std::slice::from_ref(table.header()),
+            table.alignments(),
+            DEFAULT_TAG_NAMES.table_header_cell(),
+        ))?;
+        if has_body {
+            sections.serialize_element(&SerializeTableSection(
+                DEFAULT_TAG_NAMES.table_body(),
+                table.rows(),
+                table.alignments(),
+                DEFAULT_TAG_NAMES.table_cell(),
+            ))?;
+        }
+        sections.end()
+    }
+}
+
+/// A section of a table with the given tag name, containing rows whose cells use `cell_name`.
+struct SerializeTableSection<'a>(&'a str, &'a [TableRow], &'a [TableAlignment], &'a str);
+impl Serialize for SerializeTableSection<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_tag(
+            serializer,
+            self.0,
+            &SerializeTableRows(self.1, self.2, self.3),
+        )
+    }
+}
+
+struct SerializeTableRows<'a>(&'a [TableRow], &'a [TableAlignment], &'a str);
+impl Serialize for SerializeTableRows<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let SerializeTableRows(rows, alignments, cell_name) = *self;
+        let mut seq = serializer.serialize_seq(Some(rows.len()))?;
+        for row in rows {
+            seq.serialize_element(&SerializeTableRow(row, alignments, cell_name))?;
+        }
+        seq.end()
+    }
+}
+
+struct SerializeTableRow<'a>(&'a TableRow, &'a [TableAlignment], &'a str);
+impl Serialize for SerializeTableRow<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let SerializeTableRow(row, alignments, cell_name) = *self;
+        let cells: Vec<_> = row
+            .cells()
+            .iter()
+            .zip(alignments)
+            .map(|(cell, alignment)| SerializeTableCell(cell, *alignment, cell_name))
+            .collect();
+        serialize_tag(serializer, DEFAULT_TAG_NAMES.table_row(), &cells)
+    }
+}
+
+struct SerializeTableCell<'a>(&'a TableCell, TableAlignment, &'a str);
+impl Serialize for SerializeTableCell<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let SerializeTableCell(cell, alignment, cell_name) = *self;
+        let align = alignment.as_str();
+
+        let mut tag = serializer.serialize_struct("Tag", 3 + align.is_some() as usize)?;
+        tag.serialize_field(fjs_types::TYPE, &FormatJsElementType::Tag)?;
+        tag.serialize_field(fjs_types::VALUE, cell_name)?;
+        tag.serialize_field(fjs_types::CHILDREN, cell.content())?;
+        // Cells of aligned columns carry their alignment as a control element.
+        if let Some(align) = align {
+            tag.serialize_field(fjs_types::CONTROL, &[InlineContent::Text(align.into())])?;
+        }
+        tag.end()
+    }
+}
+
 macro_rules! tag_serializer {
     ($struct:ident, $tag:expr, $method:ident) => {
         impl Serialize for $struct {
@@ -291,8 +486,7 @@ impl Serialize for IcuPlural {
         plural.serialize_field(fjs_types::TYPE, &FormatJsElementType::Plural)?;
         plural.serialize_field(fjs_types::VALUE, self.name())?;
         plural.serialize_field(fjs_types::OPTIONS, &SerializePluralArms(self.arms()))?;
-        // TODO: Implement offset in parsing
-        plural.serialize_field(fjs_types::OFFSET, &0)?;
+        plural.serialize_field(fjs_types::OFFSET, &self.offset())?;
         plural.serialize_field(fjs_types::PLURAL_TYPE, self.kind())?;
         plural.end()
     }
@@ -322,7 +516,7 @@ impl Serialize for IcuDate {
         date.serialize_field(fjs_types::TYPE, &FormatJsElementType::Date)?;
         date.serialize_field(fjs_types::VALUE, self.name())?;
         if let Some(style) = self.style() {
-            date.serialize_field(fjs_types::STYLE, style.text())?;
+            date.serialize_field(fjs_types::STYLE, style)?;
         }
         date.end()
     }
@@ -339,12 +533,26 @@ impl Serialize for IcuTime {
         time.serialize_field(fjs_types::TYPE, &FormatJsElementType::Time)?;
         time.serialize_field(fjs_types::VALUE, self.name())?;
         if let Some(style) = self.style() {
-            time.serialize_field(fjs_types::STYLE, style.text())?;
+            time.serialize_field(fjs_types::STYLE, style)?;
         }
         time.end()
     }
 }
 
+/// Skeletons are serialized as their parsed FormatJS objects, while named and invalid styles are
+/// left as the original text.
+impl Serialize for IcuDateTimeStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.kind() {
+            IcuDateTimeStyleKind::Skeleton(skeleton) => skeleton.serialize(serializer),
+            _ => serializer.serialize_str(self.text()),
+        }
+    }
+}
+
 impl Serialize for IcuNumber {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -356,8 +564,20 @@ impl Serialize for IcuNumber {
         number.serialize_field(fjs_types::TYPE, &FormatJsElementType::Number)?;
         number.serialize_field(fjs_types::VALUE, self.name())?;
         if let Some(style) = self.style() {
-            number.serialize_field(fjs_types::STYLE, style.text())?;
+            number.serialize_field(fjs_types::STYLE, style)?;
         }
         number.end()
     }
 }
+
+impl Serialize for IcuNumberStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.kind() {
+            IcuNumberStyleKind::Skeleton(skeleton) => skeleton.serialize(serializer),
+            _ => serializer.serialize_str(self.text()),
+        }
+    }
+}
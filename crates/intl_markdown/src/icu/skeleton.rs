@@ -0,0 +1,907 @@
+//! Parsing for the style arguments of number, date, and time placeholders. Styles are either a
+//! predefined name, like `{count, number, percent}`, or an ICU skeleton describing the format in
+//! detail, like `{count, number, ::currency/EUR .00}` or `{today, date, ::yyyyMMMd}`.
+//!
+//! Skeletons are interpreted the same way as FormatJS' `@formatjs/icu-skeleton-parser`, producing
+//! options that can be passed directly to `Intl.NumberFormat` and `Intl.DateTimeFormat`. Unlike
+//! FormatJS, tokens that can't be understood are reported as errors rather than silently ignored.
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+use crate::ast::{IcuDateTimeStyleKind, IcuNumberStyleKind};
+use crate::diagnostic::{SyntaxDiagnostic, SyntaxDiagnosticKind};
+
+/// Values of FormatJS' `SKELETON_TYPE` enum, used as the `type` of a serialized skeleton.
+pub(crate) const NUMBER_SKELETON_TYPE: u8 = 0;
+pub(crate) const DATE_TIME_SKELETON_TYPE: u8 = 1;
+
+/// The predefined number styles that can be referenced by name, like `{count, number, percent}`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NamedNumberStyle {
+    Integer,
+    Currency,
+    Percent,
+}
+
+impl NamedNumberStyle {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "integer" => Some(NamedNumberStyle::Integer),
+            "currency" => Some(NamedNumberStyle::Currency),
+            "percent" => Some(NamedNumberStyle::Percent),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NamedNumberStyle::Integer => "integer",
+            NamedNumberStyle::Currency => "currency",
+            NamedNumberStyle::Percent => "percent",
+        }
+    }
+}
+
+/// The predefined date and time styles that can be referenced by name, like
+/// `{today, date, short}`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NamedDateTimeStyle {
+    Short,
+    Medium,
+    Long,
+    Full,
+}
+
+impl NamedDateTimeStyle {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "short" => Some(NamedDateTimeStyle::Short),
+            "medium" => Some(NamedDateTimeStyle::Medium),
+            "long" => Some(NamedDateTimeStyle::Long),
+            "full" => Some(NamedDateTimeStyle::Full),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NamedDateTimeStyle::Short => "short",
+            NamedDateTimeStyle::Medium => "medium",
+            NamedDateTimeStyle::Long => "long",
+            NamedDateTimeStyle::Full => "full",
+        }
+    }
+}
+
+//#region Number skeletons
+
+/// A single whitespace-separated token of a number skeleton, like `currency/EUR`, where `currency`
+/// is the stem and `EUR` is its only option.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NumberSkeletonToken {
+    stem: String,
+    options: Vec<String>,
+}
+
+impl NumberSkeletonToken {
+    pub fn stem(&self) -> &String {
+        &self.stem
+    }
+
+    pub fn options(&self) -> &Vec<String> {
+        &self.options
+    }
+}
+
+/// Write a number skeleton token as it appears in a skeleton, like `currency/EUR`.
+pub(crate) fn number_skeleton_token_text(stem: &str, options: &[String]) -> String {
+    std::iter::once(stem)
+        .chain(options.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Options for `Intl.NumberFormat` described by a number skeleton. Only the options that the
+/// skeleton sets are present.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NumberFormatOptions {
+    pub style: Option<&'static str>,
+    pub currency: Option<String>,
+    pub unit: Option<String>,
+    pub use_grouping: Option<bool>,
+    pub minimum_integer_digits: Option<u32>,
+    pub minimum_fraction_digits: Option<u32>,
+    pub maximum_fraction_digits: Option<u32>,
+    pub minimum_significant_digits: Option<u32>,
+    pub maximum_significant_digits: Option<u32>,
+    pub notation: Option<&'static str>,
+    pub compact_display: Option<&'static str>,
+    pub currency_display: Option<&'static str>,
+    pub unit_display: Option<&'static str>,
+    pub currency_sign: Option<&'static str>,
+    pub sign_display: Option<&'static str>,
+    pub rounding_mode: Option<&'static str>,
+    pub rounding_priority: Option<&'static str>,
+    pub trailing_zero_display: Option<&'static str>,
+    /// FormatJS Extension: a multiplier applied to the value before it is formatted. Kept as the
+    /// text from the skeleton, which is always a valid number, so that the options remain `Eq`.
+    pub scale: Option<String>,
+}
+
+/// A parsed ICU number skeleton, like `::currency/EUR .00`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NumberSkeleton {
+    tokens: Vec<NumberSkeletonToken>,
+    options: NumberFormatOptions,
+}
+
+impl NumberSkeleton {
+    pub fn tokens(&self) -> &Vec<NumberSkeletonToken> {
+        &self.tokens
+    }
+
+    pub fn options(&self) -> &NumberFormatOptions {
+        &self.options
+    }
+
+    /// The text of this skeleton as it would be written in a message, like `::currency/EUR .00`.
+    pub fn source_text(&self) -> String {
+        let tokens: Vec<String> = self
+            .tokens
+            .iter()
+            .map(|token| number_skeleton_token_text(&token.stem, &token.options))
+            .collect();
+        format!("::{}", tokens.join(" "))
+    }
+}
+
+/// Parse the style of a number placeholder. Styles that aren't a predefined name are treated as
+/// skeletons, even without the leading `::`, matching how the runtime formats them.
+///
+/// When the skeleton is invalid, the returned diagnostics have spans relative to `text`.
+pub(crate) fn parse_number_style(text: &str) -> Result<IcuNumberStyleKind, Vec<SyntaxDiagnostic>> {
+    if let Some(named) = NamedNumberStyle::from_name(text) {
+        return Ok(IcuNumberStyleKind::Named(named));
+    }
+    let (skeleton, offset) = strip_skeleton_prefix(text);
+    parse_number_skeleton(skeleton)
+        .map(|skeleton| IcuNumberStyleKind::Skeleton(Box::new(skeleton)))
+        .map_err(|errors| offset_diagnostics(errors, offset))
+}
+
+fn parse_number_skeleton(text: &str) -> Result<NumberSkeleton, Vec<SyntaxDiagnostic>> {
+    let mut tokens = vec![];
+    let mut options = NumberFormatOptions::default();
+    let mut errors = vec![];
+
+    for (start, token) in split_whitespace_with_offsets(text) {
+        let mut parts = token.split('/');
+        let stem = parts.next().unwrap_or_default();
+        let token_options: Vec<String> = parts.map(String::from).collect();
+
+        let result = if token_options.iter().any(String::is_empty) {
+            Err(SyntaxDiagnosticKind::InvalidNumberSkeletonOptions)
+        } else {
+            apply_number_stem(&mut options, stem, &token_options)
+        };
+        if let Err(kind) = result {
+            errors.push(SyntaxDiagnostic::new(kind, start..start + token.len()));
+        }
+
+        tokens.push(NumberSkeletonToken {
+            stem: stem.into(),
+            options: token_options,
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(NumberSkeleton { tokens, options })
+    } else {
+        Err(errors)
+    }
+}
+
+type StemResult = Result<(), SyntaxDiagnosticKind>;
+
+fn apply_number_stem(options: &mut NumberFormatOptions, stem: &str, args: &[String]) -> StemResult {
+    match stem {
+        "percent" | "%" => {
+            no_options(args)?;
+            options.style = Some("percent");
+        }
+        "%x100" => {
+            no_options(args)?;
+            options.style = Some("percent");
+            options.scale = Some("100".into());
+        }
+        "permille" => {
+            no_options(args)?;
+            options.style = Some("percent");
+            options.scale = Some("1000".into());
+        }
+        "currency" => {
+            options.style = Some("currency");
+            options.currency = Some(single_option(args)?.clone());
+        }
+        "measure-unit" => {
+            options.style = Some("unit");
+            // ICU units are prefixed with their type, like `length-meter`, but Intl only wants
+            // the unit itself.
+            let unit = single_option(args)?;
+            let unit = unit.split_once('-').map_or(unit.as_str(), |(_, unit)| unit);
+            options.unit = Some(unit.into());
+        }
+        "unit" => {
+            options.style = Some("unit");
+            options.unit = Some(single_option(args)?.clone());
+        }
+        "group-off" | ",_" => {
+            no_options(args)?;
+            options.use_grouping = Some(false);
+        }
+        "precision-integer" | "." => {
+            no_options(args)?;
+            options.maximum_fraction_digits = Some(0);
+        }
+        "compact-short" | "K" => {
+            no_options(args)?;
+            options.notation = Some("compact");
+            options.compact_display = Some("short");
+        }
+        "compact-long" | "KK" => {
+            no_options(args)?;
+            options.notation = Some("compact");
+            options.compact_display = Some("long");
+        }
+        "scientific" | "engineering" => {
+            options.notation = Some(if stem == "scientific" {
+                "scientific"
+            } else {
+                "engineering"
+            });
+            for arg in args {
+                let (sign_display, _) =
+                    parse_sign(arg).ok_or(SyntaxDiagnosticKind::InvalidNumberSkeletonOptions)?;
+                options.sign_display = sign_display;
+            }
+        }
+        "notation-simple" => {
+            no_options(args)?;
+            options.notation = Some("standard");
+        }
+        "unit-width-narrow" => {
+            no_options(args)?;
+            options.currency_display = Some("narrowSymbol");
+            options.unit_display = Some("narrow");
+        }
+        "unit-width-short" => {
+            no_options(args)?;
+            options.currency_display = Some("code");
+            options.unit_display = Some("short");
+        }
+        "unit-width-full-name" => {
+            no_options(args)?;
+            options.currency_display = Some("name");
+            options.unit_display = Some("long");
+        }
+        "unit-width-iso-code" => {
+            no_options(args)?;
+            options.currency_display = Some("symbol");
+        }
+        "scale" => {
+            let scale = single_option(args)?;
+            if !scale.parse::<f64>().is_ok_and(f64::is_finite) {
+                return Err(SyntaxDiagnosticKind::InvalidNumberSkeletonOptions);
+            }
+            options.scale = Some(scale.clone());
+        }
+        "integer-width" => {
+            options.minimum_integer_digits = Some(parse_integer_width(single_option(args)?)?);
+        }
+        "rounding-mode-ceiling" => options.rounding_mode = Some(no_options(args).map(|_| "ceil")?),
+        "rounding-mode-floor" => options.rounding_mode = Some(no_options(args).map(|_| "floor")?),
+        "rounding-mode-down" => options.rounding_mode = Some(no_options(args).map(|_| "trunc")?),
+        "rounding-mode-up" => options.rounding_mode = Some(no_options(args).map(|_| "expand")?),
+        "rounding-mode-half-even" => {
+            options.rounding_mode = Some(no_options(args).map(|_| "halfEven")?)
+        }
+        "rounding-mode-half-down" => {
+            options.rounding_mode = Some(no_options(args).map(|_| "halfTrunc")?)
+        }
+        "rounding-mode-half-up" => {
+            options.rounding_mode = Some(no_options(args).map(|_| "halfExpand")?)
+        }
+        // These are valid ICU stems, but have no equivalent in `Intl.NumberFormat`, so they are
+        // accepted without affecting the options.
+        "precision-unlimited"
+        | "precision-currency-standard"
+        | "precision-currency-cash"
+        | "precision-increment"
+        | "decimal-auto"
+        | "decimal-always"
+        | "unit-width-hidden"
+        | "integer-width-trunc"
+        | "base-unit"
+        | "latin"
+        | "numbering-system" => {}
+        _ => apply_number_pattern_stem(options, stem, args)?,
+    }
+    Ok(())
+}
+
+/// Apply the stems that are patterns rather than keywords, like `.00`, `@@#`, `E0`, or `+!`.
+fn apply_number_pattern_stem(
+    options: &mut NumberFormatOptions,
+    stem: &str,
+    args: &[String],
+) -> StemResult {
+    if let Some((minimum, maximum)) = parse_fraction_precision(stem) {
+        options.minimum_fraction_digits = Some(minimum);
+        options.maximum_fraction_digits = maximum;
+        for arg in args {
+            if arg == "w" {
+                options.trailing_zero_display = Some("stripIfInteger");
+                continue;
+            }
+            // A significant digits option, like `.##/@@@r`, lets the rounding use whichever of
+            // the two precisions is more (`r`) or less (`s`) precise.
+            let (significant, priority) = match arg.strip_suffix('r') {
+                Some(significant) => (significant, "morePrecision"),
+                None => match arg.strip_suffix('s') {
+                    Some(significant) => (significant, "lessPrecision"),
+                    None => (arg.as_str(), "auto"),
+                },
+            };
+            let (minimum, maximum) = parse_significant_precision(significant)
+                .ok_or(SyntaxDiagnosticKind::InvalidNumberSkeletonOptions)?;
+            options.minimum_significant_digits = Some(minimum);
+            options.maximum_significant_digits = maximum;
+            options.rounding_priority = Some(priority);
+        }
+        return Ok(());
+    }
+
+    if let Some((minimum, maximum)) = parse_significant_precision(stem) {
+        no_options(args)?;
+        options.minimum_significant_digits = Some(minimum);
+        options.maximum_significant_digits = maximum;
+        return Ok(());
+    }
+
+    // Concise integer width, like `000`.
+    if !stem.is_empty() && stem.bytes().all(|byte| byte == b'0') {
+        no_options(args)?;
+        options.minimum_integer_digits = Some(stem.len() as u32);
+        return Ok(());
+    }
+
+    // Concise scientific notation, like `E0`, `EE+!00`.
+    if let Some(rest) = stem.strip_prefix('E') {
+        no_options(args)?;
+        let (notation, rest) = match rest.strip_prefix('E') {
+            Some(rest) => ("engineering", rest),
+            None => ("scientific", rest),
+        };
+        let (sign_display, rest) = match rest.get(..2) {
+            Some("+!") => (Some("always"), &rest[2..]),
+            Some("+?") => (Some("exceptZero"), &rest[2..]),
+            _ => (None, rest),
+        };
+        if rest.is_empty() || !rest.bytes().all(|byte| byte == b'0') {
+            return Err(SyntaxDiagnosticKind::UnknownNumberSkeletonToken);
+        }
+        options.notation = Some(notation);
+        options.minimum_integer_digits = Some(rest.len() as u32);
+        if sign_display.is_some() {
+            options.sign_display = sign_display;
+        }
+        return Ok(());
+    }
+
+    if let Some((sign_display, currency_sign)) = parse_sign(stem) {
+        no_options(args)?;
+        options.sign_display = sign_display.or(options.sign_display);
+        options.currency_sign = currency_sign.or(options.currency_sign);
+        return Ok(());
+    }
+
+    Err(SyntaxDiagnosticKind::UnknownNumberSkeletonToken)
+}
+
+/// Parse a fraction precision stem, like `.00`, `.0#`, `.##`, or `.00+`, into its minimum and
+/// maximum number of fraction digits.
+fn parse_fraction_precision(stem: &str) -> Option<(u32, Option<u32>)> {
+    let digits = stem.strip_prefix('.')?;
+    let zeros = digits.bytes().take_while(|byte| *byte == b'0').count();
+    let rest = &digits[zeros..];
+    let minimum = zeros as u32;
+    match rest {
+        "" if zeros > 0 => Some((minimum, Some(minimum))),
+        "*" | "+" if zeros > 0 => Some((minimum, None)),
+        _ if !rest.is_empty() && rest.bytes().all(|byte| byte == b'#') => {
+            Some((minimum, Some(minimum + rest.len() as u32)))
+        }
+        _ => None,
+    }
+}
+
+/// Parse a significant digits stem, like `@@@`, `@@#`, or `@@+`, into its minimum and maximum
+/// number of significant digits.
+fn parse_significant_precision(stem: &str) -> Option<(u32, Option<u32>)> {
+    let ats = stem.bytes().take_while(|byte| *byte == b'@').count();
+    if ats == 0 {
+        return None;
+    }
+    let rest = &stem[ats..];
+    let minimum = ats as u32;
+    match rest {
+        "" => Some((minimum, Some(minimum))),
+        "*" | "+" => Some((minimum, None)),
+        _ if rest.bytes().all(|byte| byte == b'#') => {
+            Some((minimum, Some(minimum + rest.len() as u32)))
+        }
+        _ => None,
+    }
+}
+
+/// Parse the option of an `integer-width` stem. Only a minimum width, like `*000` or `+000`, can
+/// be represented in `Intl.NumberFormat`.
+fn parse_integer_width(option: &str) -> Result<u32, SyntaxDiagnosticKind> {
+    option
+        .strip_prefix(['*', '+'])
+        .filter(|zeros| !zeros.is_empty() && zeros.bytes().all(|byte| byte == b'0'))
+        .map(|zeros| zeros.len() as u32)
+        .ok_or(SyntaxDiagnosticKind::InvalidNumberSkeletonOptions)
+}
+
+/// Parse a sign display stem, like `sign-always` or its concise form `+!`, into the `signDisplay`
+/// and `currencySign` options that it sets.
+fn parse_sign(stem: &str) -> Option<(Option<&'static str>, Option<&'static str>)> {
+    let result = match stem {
+        "sign-auto" => (Some("auto"), None),
+        "sign-accounting" | "()" => (None, Some("accounting")),
+        "sign-always" | "+!" => (Some("always"), None),
+        "sign-accounting-always" | "()!" => (Some("always"), Some("accounting")),
+        "sign-except-zero" | "+?" => (Some("exceptZero"), None),
+        "sign-accounting-except-zero" | "()?" => (Some("exceptZero"), Some("accounting")),
+        "sign-never" | "+_" => (Some("never"), None),
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn no_options(args: &[String]) -> StemResult {
+    if args.is_empty() {
+        Ok(())
+    } else {
+        Err(SyntaxDiagnosticKind::InvalidNumberSkeletonOptions)
+    }
+}
+
+fn single_option(args: &[String]) -> Result<&String, SyntaxDiagnosticKind> {
+    match args {
+        [option] => Ok(option),
+        _ => Err(SyntaxDiagnosticKind::InvalidNumberSkeletonOptions),
+    }
+}
+//#endregion
+
+//#region Date and time skeletons
+
+/// Options for `Intl.DateTimeFormat` described by a date or time skeleton. Only the options that
+/// the skeleton sets are present.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DateTimeFormatOptions {
+    pub era: Option<&'static str>,
+    pub year: Option<&'static str>,
+    pub month: Option<&'static str>,
+    pub day: Option<&'static str>,
+    pub weekday: Option<&'static str>,
+    pub hour12: Option<bool>,
+    pub hour_cycle: Option<&'static str>,
+    pub hour: Option<&'static str>,
+    pub minute: Option<&'static str>,
+    pub second: Option<&'static str>,
+    pub time_zone_name: Option<&'static str>,
+}
+
+/// A parsed ICU date or time skeleton, like `::yyyyMMMd`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DateTimeSkeleton {
+    pattern: String,
+    options: DateTimeFormatOptions,
+}
+
+impl DateTimeSkeleton {
+    pub fn pattern(&self) -> &String {
+        &self.pattern
+    }
+
+    pub fn options(&self) -> &DateTimeFormatOptions {
+        &self.options
+    }
+
+    /// The text of this skeleton as it would be written in a message, like `::yyyyMMMd`.
+    pub fn source_text(&self) -> String {
+        format!("::{}", self.pattern)
+    }
+}
+
+/// Parse the style of a date or time placeholder. Styles that aren't a predefined name are treated
+/// as skeletons, even without the leading `::`, matching how the runtime formats them.
+///
+/// When the skeleton is invalid, the returned diagnostics have spans relative to `text`.
+pub(crate) fn parse_date_time_style(
+    text: &str,
+) -> Result<IcuDateTimeStyleKind, Vec<SyntaxDiagnostic>> {
+    if let Some(named) = NamedDateTimeStyle::from_name(text) {
+        return Ok(IcuDateTimeStyleKind::Named(named));
+    }
+    let (skeleton, offset) = strip_skeleton_prefix(text);
+    parse_date_time_skeleton(skeleton)
+        .map(|skeleton| IcuDateTimeStyleKind::Skeleton(Box::new(skeleton)))
+        .map_err(|errors| offset_diagnostics(errors, offset))
+}
+
+fn parse_date_time_skeleton(text: &str) -> Result<DateTimeSkeleton, Vec<SyntaxDiagnostic>> {
+    let mut options = DateTimeFormatOptions::default();
+    let mut errors = vec![];
+
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        // Quoted text is a literal, even if it contains field letters.
+        if c == '\'' {
+            for (_, c) in chars.by_ref() {
+                if c == '\'' {
+                    break;
+                }
+            }
+            continue;
+        }
+        // Anything else that isn't a letter, like punctuation, is also a literal.
+        if !c.is_ascii_alphabetic() {
+            continue;
+        }
+
+        let mut length = 1;
+        while chars.next_if(|(_, next)| *next == c).is_some() {
+            length += 1;
+        }
+        if let Err(kind) = apply_date_time_field(&mut options, c, length) {
+            errors.push(SyntaxDiagnostic::new(kind, start..start + length));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(DateTimeSkeleton {
+            pattern: text.into(),
+            options,
+        })
+    } else {
+        Err(errors)
+    }
+}
+
+/// Apply a field of a date skeleton, which is a letter repeated `length` times, like `MMM`.
+fn apply_date_time_field(
+    options: &mut DateTimeFormatOptions,
+    field: char,
+    length: usize,
+) -> StemResult {
+    let pick = |values: &[&'static str]| {
+        values
+            .get(length - 1)
+            .copied()
+            .ok_or(SyntaxDiagnosticKind::InvalidDateTimeSkeletonField)
+    };
+    match field {
+        'G' => options.era = Some(pick(&["short", "short", "short", "long", "narrow"])?),
+        'y' => options.year = Some(if length == 2 { "2-digit" } else { "numeric" }),
+        'M' | 'L' => {
+            options.month = Some(pick(&["numeric", "2-digit", "short", "long", "narrow"])?)
+        }
+        'd' => options.day = Some(pick(&["numeric", "2-digit"])?),
+        'E' => {
+            options.weekday = Some(pick(&[
+                "short", "short", "short", "long", "narrow", "short",
+            ])?)
+        }
+        // The numeric forms of local weekdays (`e` through `eee`) can't be represented.
+        'e' | 'c' if length < 4 => {
+            return Err(SyntaxDiagnosticKind::UnsupportedDateTimeSkeletonField)
+        }
+        'e' | 'c' => options.weekday = Some(pick(&["", "", "", "short", "long", "narrow"])?),
+        'a' => {
+            pick(&["", "", "", "", ""])?;
+            options.hour12 = Some(true);
+        }
+        'h' | 'H' | 'K' | 'k' => {
+            options.hour = Some(pick(&["numeric", "2-digit"])?);
+            options.hour_cycle = Some(match field {
+                'h' => "h12",
+                'H' => "h23",
+                'K' => "h11",
+                _ => "h24",
+            });
+        }
+        'm' => options.minute = Some(pick(&["numeric", "2-digit"])?),
+        's' => options.second = Some(pick(&["numeric", "2-digit"])?),
+        'z' => options.time_zone_name = Some(pick(&["short", "short", "short", "long"])?),
+        // These are valid ICU fields, but have no equivalent in `Intl.DateTimeFormat`.
+        'Y' | 'u' | 'U' | 'r' | 'q' | 'Q' | 'w' | 'W' | 'D' | 'F' | 'g' | 'b' | 'B' | 'j' | 'J'
+        | 'C' | 'S' | 'A' | 'Z' | 'O' | 'v' | 'V' | 'X' | 'x' => {
+            return Err(SyntaxDiagnosticKind::UnsupportedDateTimeSkeletonField)
+        }
+        _ => return Err(SyntaxDiagnosticKind::InvalidDateTimeSkeletonField),
+    }
+    Ok(())
+}
+//#endregion
+
+//#region Utilities
+
+/// Skeletons can be explicitly marked with a leading `::`. Returns the skeleton without that
+/// prefix, along with the number of bytes that were removed from the start of `text`.
+fn strip_skeleton_prefix(text: &str) -> (&str, usize) {
+    match text.strip_prefix("::") {
+        Some(skeleton) => {
+            let trimmed = skeleton.trim_start();
+            (trimmed, text.len() - trimmed.len())
+        }
+        None => (text, 0),
+    }
+}
+
+/// Shift the spans of the given diagnostics by `offset` bytes.
+pub(crate) fn offset_diagnostics(
+    diagnostics: Vec<SyntaxDiagnostic>,
+    offset: usize,
+) -> Vec<SyntaxDiagnostic> {
+    diagnostics
+        .into_iter()
+        .map(|diagnostic| {
+            let span = diagnostic.span();
+            SyntaxDiagnostic::new(diagnostic.kind(), span.start + offset..span.end + offset)
+        })
+        .collect()
+}
+
+/// Like `str::split_whitespace`, but also returns the byte offset of each part in `text`.
+fn split_whitespace_with_offsets(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(|c: char| c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(move |part| (part.as_ptr() as usize - text.as_ptr() as usize, part))
+}
+//#endregion
+
+//#region Serialization
+
+/// Serialize each of the given fields as a map entry, skipping the ones that aren't present.
+macro_rules! serialize_present_fields {
+    ($map:ident, $($key:literal => $value:expr),+ $(,)?) => {
+        $(
+            if let Some(value) = &$value {
+                $map.serialize_entry($key, value)?;
+            }
+        )+
+    };
+}
+
+impl Serialize for NumberSkeletonToken {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut token = serializer.serialize_map(Some(2))?;
+        token.serialize_entry("stem", &self.stem)?;
+        token.serialize_entry("options", &self.options)?;
+        token.end()
+    }
+}
+
+impl Serialize for NumberFormatOptions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut options = serializer.serialize_map(None)?;
+        serialize_present_fields!(options,
+            "style" => self.style,
+            "currency" => self.currency,
+            "unit" => self.unit,
+            "useGrouping" => self.use_grouping,
+            "minimumIntegerDigits" => self.minimum_integer_digits,
+            "minimumFractionDigits" => self.minimum_fraction_digits,
+            "maximumFractionDigits" => self.maximum_fraction_digits,
+            "minimumSignificantDigits" => self.minimum_significant_digits,
+            "maximumSignificantDigits" => self.maximum_significant_digits,
+            "notation" => self.notation,
+            "compactDisplay" => self.compact_display,
+            "currencyDisplay" => self.currency_display,
+            "unitDisplay" => self.unit_display,
+            "currencySign" => self.currency_sign,
+            "signDisplay" => self.sign_display,
+            "roundingMode" => self.rounding_mode,
+            "roundingPriority" => self.rounding_priority,
+            "trailingZeroDisplay" => self.trailing_zero_display,
+        );
+        if let Some(scale) = &self.scale {
+            // Scales are validated as numbers when parsed.
+            options.serialize_entry("scale", &scale.parse::<f64>().unwrap_or(1.0))?;
+        }
+        options.end()
+    }
+}
+
+/// Number skeletons are serialized like FormatJS' `NumberSkeleton` objects.
+impl Serialize for NumberSkeleton {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut skeleton = serializer.serialize_map(Some(3))?;
+        skeleton.serialize_entry("type", &NUMBER_SKELETON_TYPE)?;
+        skeleton.serialize_entry("tokens", &self.tokens)?;
+        skeleton.serialize_entry("parsedOptions", &self.options)?;
+        skeleton.end()
+    }
+}
+
+impl Serialize for DateTimeFormatOptions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut options = serializer.serialize_map(None)?;
+        serialize_present_fields!(options,
+            "era" => self.era,
+            "year" => self.year,
+            "month" => self.month,
+            "day" => self.day,
+            "weekday" => self.weekday,
+            "hour12" => self.hour12,
+            "hourCycle" => self.hour_cycle,
+            "hour" => self.hour,
+            "minute" => self.minute,
+            "second" => self.second,
+            "timeZoneName" => self.time_zone_name,
+        );
+        options.end()
+    }
+}
+
+/// Date and time skeletons are serialized like FormatJS' `DateTimeSkeleton` objects.
+impl Serialize for DateTimeSkeleton {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut skeleton = serializer.serialize_map(Some(3))?;
+        skeleton.serialize_entry("type", &DATE_TIME_SKELETON_TYPE)?;
+        skeleton.serialize_entry("pattern", &self.pattern)?;
+        skeleton.serialize_entry("parsedOptions", &self.options)?;
+        skeleton.end()
+    }
+}
+//#endregion
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{IcuDateTimeStyleKind, IcuNumberStyleKind};
+    use crate::diagnostic::SyntaxDiagnosticKind;
+
+    use super::{parse_date_time_style, parse_number_style, NamedNumberStyle};
+
+    fn number_options(text: &str) -> String {
+        match parse_number_style(text) {
+            Ok(IcuNumberStyleKind::Skeleton(skeleton)) => {
+                serde_json::to_string(skeleton.options()).unwrap()
+            }
+            result => panic!("{text} was not a valid skeleton: {result:?}"),
+        }
+    }
+
+    fn date_options(text: &str) -> String {
+        match parse_date_time_style(text) {
+            Ok(IcuDateTimeStyleKind::Skeleton(skeleton)) => {
+                serde_json::to_string(skeleton.options()).unwrap()
+            }
+            result => panic!("{text} was not a valid skeleton: {result:?}"),
+        }
+    }
+
+    fn errors(
+        result: Result<impl std::fmt::Debug, Vec<crate::SyntaxDiagnostic>>,
+        text: &str,
+    ) -> Vec<(SyntaxDiagnosticKind, &str)> {
+        result
+            .expect_err(text)
+            .iter()
+            .map(|diagnostic| (diagnostic.kind(), &text[diagnostic.span().clone()]))
+            .collect()
+    }
+
+    #[test]
+    fn named_styles() {
+        assert_eq!(
+            parse_number_style("percent"),
+            Ok(IcuNumberStyleKind::Named(NamedNumberStyle::Percent))
+        );
+        assert!(matches!(
+            parse_number_style("::percent"),
+            Ok(IcuNumberStyleKind::Skeleton(_))
+        ));
+        assert!(matches!(
+            parse_date_time_style("short"),
+            Ok(IcuDateTimeStyleKind::Named(_))
+        ));
+    }
+
+    #[test]
+    fn number_skeletons() {
+        assert_eq!(
+            number_options("::currency/EUR .00"),
+            r#"{"style":"currency","currency":"EUR","minimumFractionDigits":2,"maximumFractionDigits":2}"#
+        );
+        assert_eq!(
+            number_options("sign-always compact-short"),
+            r#"{"notation":"compact","compactDisplay":"short","signDisplay":"always"}"#
+        );
+        assert_eq!(
+            number_options(":: +! K ,_ .0## @@#"),
+            r#"{"useGrouping":false,"minimumFractionDigits":1,"maximumFractionDigits":3,"minimumSignificantDigits":2,"maximumSignificantDigits":3,"notation":"compact","compactDisplay":"short","signDisplay":"always"}"#
+        );
+        assert_eq!(
+            number_options("::measure-unit/length-meter unit-width-full-name"),
+            r#"{"style":"unit","unit":"meter","currencyDisplay":"name","unitDisplay":"long"}"#
+        );
+        assert_eq!(
+            number_options("::%x100 integer-width/*000 EE+?0"),
+            r#"{"style":"percent","minimumIntegerDigits":1,"notation":"engineering","signDisplay":"exceptZero","scale":100.0}"#
+        );
+        assert_eq!(
+            number_options("::.##/@@@r scale/0.5"),
+            r#"{"minimumFractionDigits":0,"maximumFractionDigits":2,"minimumSignificantDigits":3,"maximumSignificantDigits":3,"roundingPriority":"morePrecision","scale":0.5}"#
+        );
+    }
+
+    #[test]
+    fn invalid_number_skeletons() {
+        let text = "::currency/EUR fancy .00/x currency";
+        assert_eq!(
+            errors(parse_number_style(text), text),
+            vec![
+                (SyntaxDiagnosticKind::UnknownNumberSkeletonToken, "fancy"),
+                (SyntaxDiagnosticKind::InvalidNumberSkeletonOptions, ".00/x"),
+                (
+                    SyntaxDiagnosticKind::InvalidNumberSkeletonOptions,
+                    "currency"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn date_time_skeletons() {
+        assert_eq!(
+            date_options("::yyyyMMMd"),
+            r#"{"year":"numeric","month":"short","day":"numeric"}"#
+        );
+        assert_eq!(
+            date_options("EEEE, hh:mm a 'at' z"),
+            r#"{"weekday":"long","hour12":true,"hourCycle":"h12","hour":"2-digit","minute":"2-digit","timeZoneName":"short"}"#
+        );
+    }
+
+    #[test]
+    fn invalid_date_time_skeletons() {
+        let text = "::yyyy ddd QQ";
+        assert_eq!(
+            errors(parse_date_time_style(text), text),
+            vec![
+                (SyntaxDiagnosticKind::InvalidDateTimeSkeletonField, "ddd"),
+                (SyntaxDiagnosticKind::UnsupportedDateTimeSkeletonField, "QQ"),
+            ]
+        );
+    }
+}
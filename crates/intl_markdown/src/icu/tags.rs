@@ -8,6 +8,16 @@ pub struct TagNames<'a> {
     code_block: &'a str,
     br: &'a str,
     hr: &'a str,
+    unordered_list: &'a str,
+    ordered_list: &'a str,
+    list_item: &'a str,
+    block_quote: &'a str,
+    table: &'a str,
+    table_head: &'a str,
+    table_body: &'a str,
+    table_row: &'a str,
+    table_header_cell: &'a str,
+    table_cell: &'a str,
     h1: &'a str,
     h2: &'a str,
     h3: &'a str,
@@ -44,6 +54,36 @@ impl<'a> TagNames<'a> {
     pub const fn hr(&self) -> &'a str {
         &self.hr
     }
+    pub const fn unordered_list(&self) -> &'a str {
+        self.unordered_list
+    }
+    pub const fn ordered_list(&self) -> &'a str {
+        self.ordered_list
+    }
+    pub const fn list_item(&self) -> &'a str {
+        self.list_item
+    }
+    pub const fn block_quote(&self) -> &'a str {
+        self.block_quote
+    }
+    pub const fn table(&self) -> &'a str {
+        self.table
+    }
+    pub const fn table_head(&self) -> &'a str {
+        self.table_head
+    }
+    pub const fn table_body(&self) -> &'a str {
+        self.table_body
+    }
+    pub const fn table_row(&self) -> &'a str {
+        self.table_row
+    }
+    pub const fn table_header_cell(&self) -> &'a str {
+        self.table_header_cell
+    }
+    pub const fn table_cell(&self) -> &'a str {
+        self.table_cell
+    }
 
     pub fn heading(&self, level: u8) -> &'a str {
         match level {
@@ -68,6 +108,16 @@ pub static DEFAULT_TAG_NAMES: TagNames<'static> = TagNames {
     code_block: "$codeBlock",
     br: "$br",
     hr: "$hr",
+    unordered_list: "$ul",
+    ordered_list: "$ol",
+    list_item: "$li",
+    block_quote: "$blockquote",
+    table: "$table",
+    table_head: "$thead",
+    table_body: "$tbody",
+    table_row: "$tr",
+    table_header_cell: "$th",
+    table_cell: "$td",
     h1: "$h1",
     h2: "$h2",
     h3: "$h3",
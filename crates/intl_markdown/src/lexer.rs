@@ -135,6 +135,12 @@ impl<'source> Lexer<'source> {
         //
         // But if this is the first byte of the input, then we can just assume
         // a truly-default state instead.
+        // Container prefixes and list markers come directly from the block bounds and can't be
+        // interpreted any other way.
+        if self.is_at_consumed_token_bound() {
+            return self.current_kind;
+        }
+
         self.position = self.last_position;
         self.get_state_from_previous_character();
         self.current_flags = TokenFlags::default();
@@ -493,18 +499,53 @@ impl<'source> Lexer<'source> {
     /// consumes that block bound and returns a matching SyntaxKind for it,
     /// representing a zero-width internal token that the parser uses to branch
     /// its parsing appropriately.
+    ///
+    /// Token bounds are the exception, since they represent real text. They are consumed entirely
+    /// as a token of their given kind, and the lexer moves on to the next block bound.
     fn consume_block_bound(&mut self) -> SyntaxKind {
-        self.current_kind = match self.block_bounds.get(self.state.block_bound_index) {
+        self.current_kind = match self.block_bounds.get(self.state.block_bound_index).copied() {
             Some(BlockBound::Start(_, _)) => SyntaxKind::BLOCK_START,
             Some(BlockBound::End(_, _)) => SyntaxKind::BLOCK_END,
             Some(BlockBound::InlineStart(_, _)) => SyntaxKind::INLINE_START,
             Some(BlockBound::InlineEnd(_, _)) => SyntaxKind::INLINE_END,
+            Some(BlockBound::Token(_, end, kind)) => {
+                self.position = end;
+                self.advance_block_bound();
+                kind
+            }
             _ => unreachable!(),
         };
 
         return self.current_kind;
     }
 
+    /// Returns true if the current token was lexed from a Token block bound.
+    fn is_at_consumed_token_bound(&self) -> bool {
+        let Some(index) = self.state.block_bound_index.checked_sub(1) else {
+            return false;
+        };
+        matches!(
+            self.block_bounds[index],
+            BlockBound::Token(start, end, _) if start == self.last_position && end == self.position
+        )
+    }
+
+    /// Returns true if the current position is directly after a container prefix, like the `> `
+    /// of a block quote. The rest of the line after a prefix is lexed as if it were the start of
+    /// a new line.
+    fn is_after_block_prefix(&self) -> bool {
+        self.block_bounds[..self.state.block_bound_index]
+            .iter()
+            .rev()
+            // Zero-width bounds at the same position are consumed after the prefix before it.
+            .find(|bound| {
+                matches!(bound, BlockBound::Token(..)) || *bound.position() != self.position
+            })
+            .is_some_and(|bound| {
+                matches!(bound, BlockBound::Token(_, end, SyntaxKind::BLOCK_PREFIX) if *end == self.position)
+            })
+    }
+
     /// Return the current block boundary that the lexer is in. This method
     /// checks that the lexer is currently positioned at a block boundary
     /// before returning.
@@ -824,8 +865,10 @@ impl<'source> Lexer<'source> {
                 self.advance_n_bytes(2);
                 self.consume_byte(SyntaxKind::ICU_DOUBLE_COLON)
             }
+            b':' => self.consume_byte(SyntaxKind::COLON),
             b'=' => self.consume_icu_plural_exact(),
             b'}' => self.consume_maybe_icu_unsafe_rcurly(),
+            c if c.is_ascii_digit() => self.consume_icu_integer(),
             // Whitespace is insignificant when inside an ICU block.
             c if c.is_ascii_whitespace() => self.consume_whitespace(LexContext::Icu),
             _ => self.consume_icu_keyword_or_ident(),
@@ -841,16 +884,22 @@ impl<'source> Lexer<'source> {
         }
 
         let mut open_brace_count = 0;
-        loop {
+        // An unclosed style argument runs until the end of the input, and the parser is left to
+        // report the missing brace.
+        while !self.is_eof() {
             match self.current() {
                 // Apostrophes count as quoting characters in ICU syntax, so anything within them
                 // will be treated as a string until the second apostrophe closes it, even opening
                 // and closing braces.
                 // NOTE: This does _not_ deal with "escaped escapes", but that's fine for now.
                 b'\'' => {
-                    while self.current() != b'\'' {
+                    self.advance();
+                    while !self.is_eof() && self.current() != b'\'' {
                         self.advance()
                     }
+                    if !self.is_eof() {
+                        self.advance();
+                    }
                 }
                 b'}' if open_brace_count == 0 => break,
                 b'}' => {
@@ -880,16 +929,30 @@ impl<'source> Lexer<'source> {
             "date" => SyntaxKind::ICU_DATE_KW,
             "time" => SyntaxKind::ICU_TIME_KW,
             "number" => SyntaxKind::ICU_NUMBER_KW,
+            // `offset` is only a keyword as part of `offset:N` in a plural, so that it can still
+            // be used as a variable name or select option everywhere else.
+            "offset" if !self.is_eof() && self.current() == b':' => SyntaxKind::ICU_OFFSET_KW,
             _ => SyntaxKind::ICU_IDENT,
         }
     }
 
+    fn consume_icu_integer(&mut self) -> SyntaxKind {
+        while !self.is_eof() && self.current().is_ascii_digit() {
+            self.advance();
+        }
+
+        SyntaxKind::ICU_INTEGER
+    }
+
     fn consume_icu_plural_exact(&mut self) -> SyntaxKind {
         // An exact value must be an = followed immediately by at least one digit.
         if !self.advance_if(b'=') {
             return SyntaxKind::EQUAL;
         }
 
+        if self.is_eof() {
+            return SyntaxKind::EQUAL;
+        }
         if !self.current().is_ascii_digit() {
             // Allow negative numbers using a `-` prefix.
             if self.current() == b'-' && self.peek().is_some_and(u8::is_ascii_digit) {
@@ -899,7 +962,7 @@ impl<'source> Lexer<'source> {
             }
         }
 
-        while self.current().is_ascii_digit() {
+        while !self.is_eof() && self.current().is_ascii_digit() {
             self.advance();
         }
 
@@ -919,7 +982,7 @@ impl<'source> Lexer<'source> {
             return SyntaxKind::TEXT;
         }
 
-        while is_unicode_identifier_continue(self.current_char()) {
+        while !self.is_eof() && is_unicode_identifier_continue(self.current_char()) {
             self.advance();
         }
 
@@ -1029,7 +1092,7 @@ impl<'source> Lexer<'source> {
     /// Calculate properties for the LexerState by examining backwards in the
     /// source.
     fn get_state_from_previous_character(&mut self) {
-        if self.position == 0 {
+        if self.position == 0 || self.is_after_block_prefix() {
             self.state.set_initial_conditions();
             return;
         }
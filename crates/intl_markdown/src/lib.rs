@@ -3,18 +3,30 @@ extern crate core;
 pub use ast::format::format_ast;
 pub use ast::process::process_cst_to_ast;
 pub use ast::*;
-pub use icu::compile::compile_to_format_js;
+pub use diagnostic::{SyntaxDiagnostic, SyntaxDiagnosticKind};
+pub use icu::compile::{compile_to_format_js, FormatJsNode};
+pub use icu::decompile::decompile_format_js;
+pub use icu::evaluate::{
+    format_message, format_message_to_string, FormatArgument, FormatArguments, FormatMessageError,
+    FormatMessageResult, FormattedSegment, FormattedTag,
+};
 pub use icu::format::format_icu_string;
+pub use icu::skeleton::{
+    DateTimeFormatOptions, DateTimeSkeleton, NamedDateTimeStyle, NamedNumberStyle,
+    NumberFormatOptions, NumberSkeleton, NumberSkeletonToken,
+};
 pub use icu::tags::DEFAULT_TAG_NAMES;
 pub use parser::ICUMarkdownParser;
 pub use syntax::SyntaxKind;
 pub use token::SyntaxToken;
 pub use tree_builder::cst::Document as CstDocument;
+pub use tree_builder::format::format_source;
 
 pub mod ast;
 mod block_parser;
 mod byte_lookup;
 mod delimiter;
+mod diagnostic;
 mod event;
 mod html_entities;
 mod icu;
@@ -24,13 +36,15 @@ mod syntax;
 mod token;
 mod tree_builder;
 
-/// Parse an intl message into a final AST representing the semantics of the message.
+/// Parse an intl message into a final AST representing the semantics of the message. Any syntax
+/// errors the parser recovered from are available from [Document::diagnostics].
 pub fn parse_intl_message(content: &str, include_blocks: bool) -> Document {
     let mut parser = ICUMarkdownParser::new(content, include_blocks);
     let source = parser.source().clone();
     parser.parse();
+    let diagnostics = parser.take_diagnostics();
     let cst = parser.into_cst();
-    process_cst_to_ast(source, &cst)
+    process_cst_to_ast(source, &cst).with_diagnostics(diagnostics)
 }
 
 /// Return a new Document with the given content as the only value, treated as a raw string with
@@ -39,6 +53,18 @@ pub fn raw_string_to_document(content: &str) -> Document {
     Document::from_literal(content)
 }
 
+/// Parse an intl message and format its source text, normalizing the ICU expressions it contains
+/// while keeping all of its other content exactly as written. See [format_source].
+pub fn format_message_source(
+    content: &str,
+    include_blocks: bool,
+) -> Result<String, std::fmt::Error> {
+    let mut parser = ICUMarkdownParser::new(content, include_blocks);
+    parser.parse();
+    let cst = parser.into_cst();
+    format_source(&cst)
+}
+
 pub fn format_to_icu_string(document: &Document) -> Result<String, std::fmt::Error> {
     format_icu_string(document)
 }
@@ -1,4 +1,4 @@
-use crate::{lexer::LexContext, SyntaxKind};
+use crate::{event::Event, lexer::LexContext, SyntaxKind};
 
 use super::{ICUMarkdownParser, inline::parse_inline};
 
@@ -12,6 +12,13 @@ pub(super) fn parse_block(p: &mut ICUMarkdownParser, kind: SyntaxKind) -> Option
         SyntaxKind::INDENTED_CODE_BLOCK => parse_code_block(p),
         SyntaxKind::FENCED_CODE_BLOCK => parse_fenced_code_block(p),
         SyntaxKind::THEMATIC_BREAK => parse_thematic_break(p),
+        // The content of container blocks is parsed as regular blocks by the caller.
+        SyntaxKind::BLOCK_QUOTE | SyntaxKind::LIST => Some(()),
+        SyntaxKind::LIST_ITEM => parse_list_item(p),
+        // The rows of a table are parsed individually by the caller.
+        SyntaxKind::TABLE => Some(()),
+        SyntaxKind::TABLE_ROW => parse_table_row(p),
+        SyntaxKind::TABLE_DELIMITER_ROW => parse_remainder_as_token_list(p),
         _ => parse_paragraph(p),
     };
 
@@ -23,6 +30,48 @@ fn parse_paragraph(p: &mut ICUMarkdownParser) -> Option<()> {
     Some(())
 }
 
+fn parse_list_item(p: &mut ICUMarkdownParser) -> Option<()> {
+    p.skip_whitespace_as_trivia();
+    // The block parser has already asserted that every list item starts with a marker.
+    if !matches!(
+        p.current(),
+        SyntaxKind::BULLET_LIST_MARKER | SyntaxKind::ORDERED_LIST_MARKER
+    ) {
+        return None;
+    }
+    p.bump();
+    Some(())
+}
+
+/// Parse the pipes and cells of a single table row. The block parser has already determined the
+/// bounds of every cell, so each one is parsed as its own inline segment, with the pipes between
+/// them as plain tokens of the row.
+fn parse_table_row(p: &mut ICUMarkdownParser) -> Option<()> {
+    loop {
+        p.skip_whitespace_as_trivia();
+        match p.current() {
+            SyntaxKind::TABLE_CELL_DELIMITER => p.bump(),
+            SyntaxKind::BLOCK_START if p.at_block_bound(SyntaxKind::TABLE_CELL) => {
+                parse_table_cell(p)?
+            }
+            _ => return Some(()),
+        }
+    }
+}
+
+fn parse_table_cell(p: &mut ICUMarkdownParser) -> Option<()> {
+    let kind = p.eat_block_bound();
+    p.push_event(Event::Start(kind));
+    p.expect_block_bound(SyntaxKind::INLINE_START)?;
+    parse_inline(p, false);
+    p.expect_block_bound(SyntaxKind::INLINE_END)?;
+    p.expect_block_bound(SyntaxKind::TABLE_CELL)?;
+    p.push_event(Event::Finish(kind));
+    // Inline syntax like emphasis can't span across the cells of a row.
+    p.reset_inline_state();
+    Some(())
+}
+
 fn parse_remainder_as_token_list(p: &mut ICUMarkdownParser) -> Option<()> {
     while !matches!(
         p.current(),
@@ -1,9 +1,19 @@
+use crate::diagnostic::{SyntaxDiagnostic, SyntaxDiagnosticKind};
 use crate::lexer::LexContext;
 use crate::parser::inline::parse_inline;
 use crate::SyntaxKind;
 
 use super::ICUMarkdownParser;
 
+/// The result of parsing some part of an ICU placeholder. When parsing fails, the error is the
+/// diagnostic explaining why, or `None` if the content simply isn't ICU syntax and should be
+/// treated as plain text without any complaint (like a `{` that isn't followed by a name).
+type IcuParseResult<T> = Result<T, Option<SyntaxDiagnostic>>;
+
+fn error_at(kind: SyntaxDiagnosticKind, span: std::ops::Range<usize>) -> Option<SyntaxDiagnostic> {
+    Some(SyntaxDiagnostic::new(kind, span))
+}
+
 pub(super) fn is_at_normal_icu(p: &mut ICUMarkdownParser) -> bool {
     (p.at(SyntaxKind::LCURLY) || p.at(SyntaxKind::UNSAFE_LCURLY)) && !p.current_flags().is_escaped()
 }
@@ -15,24 +25,40 @@ pub(super) fn parse_icu(p: &mut ICUMarkdownParser) -> Option<()> {
         _ => return None,
     };
 
+    let icu_start = p.current_span().start;
     let icu_mark = p.mark();
     p.bump();
     // Mark a checkpoint after the opening curly brace in case any part of the ICU content fails.
     // This will be the rewind point to let the parser retry all the content as plain markdown.
     let checkpoint = p.checkpoint();
 
-    parse_icu_inner(p)
-        .and_then(|_| {
-            p.expect(end_kind)?;
-            icu_mark.complete(p, SyntaxKind::ICU)
-        })
-        .or_else(|| {
+    let result = parse_icu_inner(p).and_then(|_| {
+        if p.expect(end_kind).is_none() {
+            // `!!{name}` is a common mistake, so it gets a more specific error than just being
+            // unclosed.
+            let kind = if end_kind == SyntaxKind::UNSAFE_RCURLY && p.at(SyntaxKind::RCURLY) {
+                SyntaxDiagnosticKind::UnclosedUnsafePlaceholder
+            } else {
+                SyntaxDiagnosticKind::UnclosedIcuPlaceholder
+            };
+            return Err(error_at(kind, icu_start..p.current_span().start));
+        }
+        icu_mark.complete(p, SyntaxKind::ICU).ok_or(None)
+    });
+
+    match result {
+        Ok(()) => Some(()),
+        Err(diagnostic) => {
             p.rewind(checkpoint);
+            if let Some(diagnostic) = diagnostic {
+                p.push_diagnostic(diagnostic);
+            }
             None
-        })
+        }
+    }
 }
 
-fn parse_icu_inner(p: &mut ICUMarkdownParser) -> Option<()> {
+fn parse_icu_inner(p: &mut ICUMarkdownParser) -> IcuParseResult<()> {
     p.relex_with_context(LexContext::Icu);
     p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
 
@@ -40,9 +66,11 @@ fn parse_icu_inner(p: &mut ICUMarkdownParser) -> Option<()> {
     if p.at(SyntaxKind::ICU_IDENT) || p.current().is_icu_keyword() {
         let var_start = p.mark();
         p.bump_as(SyntaxKind::ICU_IDENT, LexContext::Icu);
-        var_start.complete(p, SyntaxKind::ICU_VARIABLE)?;
+        var_start
+            .complete(p, SyntaxKind::ICU_VARIABLE)
+            .ok_or(None)?;
     } else {
-        return None;
+        return Err(None);
     }
 
     p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
@@ -50,14 +78,14 @@ fn parse_icu_inner(p: &mut ICUMarkdownParser) -> Option<()> {
         p.bump_with_context(LexContext::Icu);
         p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
         let completed_kind = parse_complex_icu_placeholder(p)?;
-        outer_mark.complete(p, completed_kind)?;
+        outer_mark.complete(p, completed_kind).ok_or(None)?;
     }
 
     p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
-    Some(())
+    Ok(())
 }
 
-fn parse_complex_icu_placeholder(p: &mut ICUMarkdownParser) -> Option<SyntaxKind> {
+fn parse_complex_icu_placeholder(p: &mut ICUMarkdownParser) -> IcuParseResult<SyntaxKind> {
     match p.current() {
         SyntaxKind::ICU_DATE_KW => parse_icu_date(p),
         SyntaxKind::ICU_TIME_KW => parse_icu_time(p),
@@ -73,29 +101,35 @@ fn parse_complex_icu_placeholder(p: &mut ICUMarkdownParser) -> Option<SyntaxKind
             SyntaxKind::ICU_SELECT_ORDINAL_KW,
             SyntaxKind::ICU_SELECT_ORDINAL,
         ),
-        _ => None,
+        _ => Err(error_at(
+            SyntaxDiagnosticKind::UnknownIcuFormat,
+            p.current_span(),
+        )),
     }
 }
 
-fn parse_icu_date(p: &mut ICUMarkdownParser) -> Option<SyntaxKind> {
-    p.expect_with_context(SyntaxKind::ICU_DATE_KW, LexContext::Icu)?;
+fn parse_icu_date(p: &mut ICUMarkdownParser) -> IcuParseResult<SyntaxKind> {
+    p.expect_with_context(SyntaxKind::ICU_DATE_KW, LexContext::Icu)
+        .ok_or(None)?;
     p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
     parse_optional_icu_style_argument(p, SyntaxKind::ICU_DATE);
-    Some(SyntaxKind::ICU_DATE)
+    Ok(SyntaxKind::ICU_DATE)
 }
 
-fn parse_icu_time(p: &mut ICUMarkdownParser) -> Option<SyntaxKind> {
-    p.expect_with_context(SyntaxKind::ICU_TIME_KW, LexContext::Icu)?;
+fn parse_icu_time(p: &mut ICUMarkdownParser) -> IcuParseResult<SyntaxKind> {
+    p.expect_with_context(SyntaxKind::ICU_TIME_KW, LexContext::Icu)
+        .ok_or(None)?;
     p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
     parse_optional_icu_style_argument(p, SyntaxKind::ICU_TIME);
-    Some(SyntaxKind::ICU_TIME)
+    Ok(SyntaxKind::ICU_TIME)
 }
 
-fn parse_icu_number(p: &mut ICUMarkdownParser) -> Option<SyntaxKind> {
-    p.expect_with_context(SyntaxKind::ICU_NUMBER_KW, LexContext::Icu)?;
+fn parse_icu_number(p: &mut ICUMarkdownParser) -> IcuParseResult<SyntaxKind> {
+    p.expect_with_context(SyntaxKind::ICU_NUMBER_KW, LexContext::Icu)
+        .ok_or(None)?;
     p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
     parse_optional_icu_style_argument(p, SyntaxKind::ICU_NUMBER);
-    Some(SyntaxKind::ICU_NUMBER)
+    Ok(SyntaxKind::ICU_NUMBER)
 }
 
 /// FormatJS's interpretation of the style argument is _very_ loose. It can be completely invalid
@@ -141,33 +175,80 @@ fn parse_icu_plural(
     p: &mut ICUMarkdownParser,
     keyword_kind: SyntaxKind,
     kind: SyntaxKind,
-) -> Option<SyntaxKind> {
-    p.expect_with_context(keyword_kind, LexContext::Icu)?;
+) -> IcuParseResult<SyntaxKind> {
+    let keyword_span = p.current_span();
+    p.expect_with_context(keyword_kind, LexContext::Icu)
+        .ok_or(None)?;
     p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
-    p.expect_with_context(SyntaxKind::COMMA, LexContext::Icu)?;
+    p.expect_with_context(SyntaxKind::COMMA, LexContext::Icu)
+        .ok_or_else(|| error_at(SyntaxDiagnosticKind::ExpectedPluralOptions, keyword_span))?;
     p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
+    // Selects match on exact values, so only plurals can have an offset.
+    if keyword_kind != SyntaxKind::ICU_SELECT_KW && p.at(SyntaxKind::ICU_OFFSET_KW) {
+        parse_icu_plural_offset(p)?;
+    }
 
     loop {
         if !p.at(SyntaxKind::ICU_IDENT) && !p.at(SyntaxKind::ICU_PLURAL_EXACT) {
             break;
         }
 
+        let selector_span = p.current_span();
         let arm_mark = p.mark();
         p.bump_with_context(LexContext::Icu);
         p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
         // Using Regular context here because we're entering a value section where the content
         // is expected to be regular markdown.
-        p.expect(SyntaxKind::LCURLY)?;
+        let value_start = p.current_span().start;
+        p.expect(SyntaxKind::LCURLY)
+            .ok_or_else(|| error_at(SyntaxDiagnosticKind::InvalidPluralArm, selector_span))?;
         let value_mark = p.mark();
         parse_inline(p, true);
-        value_mark.complete(p, SyntaxKind::ICU_PLURAL_VALUE)?;
+        value_mark
+            .complete(p, SyntaxKind::ICU_PLURAL_VALUE)
+            .ok_or(None)?;
         // The closing curly needs to be lexed in the Icu context, though, since we're now back in
         // the ICU control section.
-        p.expect_with_context(SyntaxKind::RCURLY, LexContext::Icu)?;
+        if p.expect_with_context(SyntaxKind::RCURLY, LexContext::Icu)
+            .is_none()
+        {
+            return Err(error_at(
+                SyntaxDiagnosticKind::UnterminatedPluralArm,
+                value_start..p.current_span().start,
+            ));
+        }
 
         p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
-        arm_mark.complete(p, SyntaxKind::ICU_PLURAL_ARM)?;
+        arm_mark
+            .complete(p, SyntaxKind::ICU_PLURAL_ARM)
+            .ok_or(None)?;
     }
 
-    Some(kind)
+    Ok(kind)
+}
+
+/// Parse the `offset:N` that can precede the arms of a plural.
+fn parse_icu_plural_offset(p: &mut ICUMarkdownParser) -> IcuParseResult<()> {
+    let offset_start = p.current_span().start;
+    let offset_mark = p.mark();
+    p.expect_with_context(SyntaxKind::ICU_OFFSET_KW, LexContext::Icu)
+        .ok_or(None)?;
+    p.expect_with_context(SyntaxKind::COLON, LexContext::Icu)
+        .ok_or(None)?;
+    p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
+    let value_span = p.current_span();
+    let is_valid_value =
+        p.at(SyntaxKind::ICU_INTEGER) && p.source()[value_span.clone()].parse::<usize>().is_ok();
+    if !is_valid_value {
+        return Err(error_at(
+            SyntaxDiagnosticKind::InvalidPluralOffset,
+            offset_start..value_span.end,
+        ));
+    }
+    p.bump_with_context(LexContext::Icu);
+    offset_mark
+        .complete(p, SyntaxKind::ICU_PLURAL_OFFSET)
+        .ok_or(None)?;
+    p.skip_whitespace_as_trivia_with_context(LexContext::Icu);
+    Ok(())
 }
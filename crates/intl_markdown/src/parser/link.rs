@@ -1,3 +1,4 @@
+use crate::diagnostic::{SyntaxDiagnostic, SyntaxDiagnosticKind};
 use crate::lexer::LexContext;
 use crate::parser::icu::{is_at_normal_icu, parse_icu};
 use crate::{
@@ -91,11 +92,14 @@ pub(super) fn parse_link_like_close(p: &mut ICUMarkdownParser) -> Option<()> {
     // parser gets rewound to the closing brace, and it's treated as plain text.
     let checkpoint = p.checkpoint();
     let resource = parse_link_or_hook_resource(p, opening_kind);
-    if resource.is_none() {
+    if let Err(diagnostic) = resource {
         // Since there was a matching opener, it becomes a balanced pair, and
         // the opener gets deactivated to avoid matching future braces.
         p.deactivate_delimiter(opener_index);
         p.rewind(checkpoint);
+        if let Some(diagnostic) = diagnostic {
+            p.push_diagnostic(diagnostic);
+        }
         return None;
     }
 
@@ -141,20 +145,33 @@ pub(super) fn complete_link_like(
     );
 }
 
-fn parse_link_or_hook_resource(p: &mut ICUMarkdownParser, kind: SyntaxKind) -> Option<()> {
+/// Parse the resource following the content of a link-like element. When parsing fails, the error
+/// is a diagnostic explaining why, or `None` if the content just isn't a resource.
+fn parse_link_or_hook_resource(
+    p: &mut ICUMarkdownParser,
+    kind: SyntaxKind,
+) -> Result<(), Option<SyntaxDiagnostic>> {
     match kind {
-        SyntaxKind::LINK | SyntaxKind::IMAGE => parse_link_resource(p),
+        SyntaxKind::LINK | SyntaxKind::IMAGE => parse_link_resource(p).ok_or(None),
         SyntaxKind::HOOK => parse_hook_name(p),
         _ => unreachable!("parse_link_or_hook_resource can only be called with a known link type"),
     }
 }
 
-fn parse_hook_name(p: &mut ICUMarkdownParser) -> Option<()> {
+fn parse_hook_name(p: &mut ICUMarkdownParser) -> Result<(), Option<SyntaxDiagnostic>> {
     let name_mark = p.mark();
-    p.expect(SyntaxKind::LPAREN)?;
-    p.expect(SyntaxKind::TEXT)?;
-    p.expect(SyntaxKind::RPAREN)?;
-    name_mark.complete(p, SyntaxKind::HOOK_NAME)
+    let name_start = p.current_span().start;
+    // Without a parenthesis, this is just some bracketed text after a dollar sign, which is fine.
+    p.expect(SyntaxKind::LPAREN).ok_or(None)?;
+    // But once the parenthesis is present, this is clearly meant to be a hook, so anything other
+    // than a plain name is an error.
+    if p.expect(SyntaxKind::TEXT).is_none() || p.expect(SyntaxKind::RPAREN).is_none() {
+        return Err(Some(SyntaxDiagnostic::new(
+            SyntaxDiagnosticKind::InvalidHookName,
+            name_start..p.current_span().end,
+        )));
+    }
+    name_mark.complete(p, SyntaxKind::HOOK_NAME).ok_or(None)
 }
 
 fn parse_link_resource(p: &mut ICUMarkdownParser) -> Option<()> {
@@ -1,17 +1,20 @@
+use std::ops::Range;
+
+use crate::diagnostic::SyntaxDiagnostic;
+use crate::token::{SourceText, TriviaList};
 use crate::{
     lexer::{LexContext, LexerState},
     token::Trivia,
 };
-use crate::token::{SourceText, TriviaList};
 
 use super::{
     block_parser::BlockParser,
     delimiter::{AnyDelimiter, Delimiter},
     event::{Event, Marker},
     lexer::{Lexer, LexerCheckpoint},
-    SyntaxKind,
-    SyntaxToken,
-    token::TokenFlags, tree_builder::cst::{Document, parser_events_to_cst},
+    token::TokenFlags,
+    tree_builder::cst::{parser_events_to_cst, Document},
+    SyntaxKind, SyntaxToken,
 };
 
 use self::{block::parse_block, inline::parse_inline};
@@ -35,6 +38,7 @@ pub(super) struct ParserCheckpoint {
     buffer_index: usize,
     trivia_index: usize,
     delimiter_stack_length: usize,
+    diagnostics_length: usize,
     state: ParserState,
 }
 
@@ -72,6 +76,10 @@ pub struct ICUMarkdownParser<'source> {
     /// up failing to match, since that inner delimiter is at a different node level than the
     /// container, causing an invalid event buffer order.
     delimiter_stacks: Vec<Vec<AnyDelimiter>>,
+    /// Syntax errors that the parser recovered from. Diagnostics are added at the point where the
+    /// parser gives up on some syntax and falls back to treating it as text, and are discarded
+    /// when rewinding past them, since the rewound content will be parsed again.
+    diagnostics: Vec<SyntaxDiagnostic>,
     state: ParserState,
 
     // Configuration
@@ -98,6 +106,7 @@ impl<'source> ICUMarkdownParser<'source> {
             // extra allocations for simple sources.
             trivia_list: TriviaList::new(),
             delimiter_stacks: vec![],
+            diagnostics: vec![],
             state: ParserState::default(),
             include_blocks,
         }
@@ -182,6 +191,16 @@ impl<'source> ICUMarkdownParser<'source> {
         self.push_event(Event::Finish(SyntaxKind::DOCUMENT));
     }
 
+    /// Syntax errors encountered while parsing. This is only complete after `parse` has finished.
+    pub fn diagnostics(&self) -> &Vec<SyntaxDiagnostic> {
+        &self.diagnostics
+    }
+
+    /// Take ownership of the diagnostics collected while parsing, leaving the parser with none.
+    pub fn take_diagnostics(&mut self) -> Vec<SyntaxDiagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+
     /// Consume this parser, interpreting its events into a constructed,
     /// lossless syntax tree. The return value is the root Node of that tree,
     /// a Document.
@@ -219,6 +238,27 @@ impl<'source> ICUMarkdownParser<'source> {
         self.lexer.current_flags()
     }
 
+    /// Returns the byte range of the current token in the source text.
+    pub(super) fn current_span(&self) -> Range<usize> {
+        let span = self.lexer.current_byte_span();
+        span.start as usize..span.end as usize
+    }
+
+    /// Record a diagnostic. When recovering from an error causes some content to be parsed again
+    /// as text, the same syntax can fail a second time, so only the first diagnostic starting at
+    /// any position is kept.
+    pub(super) fn push_diagnostic(&mut self, diagnostic: SyntaxDiagnostic) {
+        let start = diagnostic.span().start;
+        if self
+            .diagnostics
+            .iter()
+            .any(|existing| existing.span().start == start)
+        {
+            return;
+        }
+        self.diagnostics.push(diagnostic);
+    }
+
     /// Advances by 1 if the current token matches the given kind and returns
     /// that token. Otherwise, returns None indicating no bump was made.
     #[inline]
@@ -322,7 +362,13 @@ impl<'source> ICUMarkdownParser<'source> {
             //
             // However, if this trivia is at the very start of the input, then
             // it can't be trailing, so it gets forced as leading trivia, too.
-            token.span_start() > 0 && token.kind() != SyntaxKind::LEADING_WHITESPACE,
+            //
+            // Container prefixes also start a line, so they are leading as well.
+            token.span_start() > 0
+                && !matches!(
+                    token.kind(),
+                    SyntaxKind::LEADING_WHITESPACE | SyntaxKind::BLOCK_PREFIX
+                ),
         )
     }
 
@@ -367,6 +413,7 @@ impl<'source> ICUMarkdownParser<'source> {
             buffer_index: self.buffer_index(),
             trivia_index: self.trivia_list.len(),
             delimiter_stack_length: self.delimiter_stack_length(),
+            diagnostics_length: self.diagnostics.len(),
             state: self.state,
         }
     }
@@ -375,6 +422,7 @@ impl<'source> ICUMarkdownParser<'source> {
         self.lexer.rewind(checkpoint.lexer_checkpoint);
         self.buffer.truncate(checkpoint.buffer_index);
         self.trivia_list.truncate(checkpoint.trivia_index);
+        self.diagnostics.truncate(checkpoint.diagnostics_length);
         self.delimiter_stack()
             .truncate(checkpoint.delimiter_stack_length);
         self.state = checkpoint.state;
@@ -449,8 +497,8 @@ impl<'source> ICUMarkdownParser<'source> {
 
 #[cfg(test)]
 mod test {
-    use crate::{format_ast, process_cst_to_ast};
     use crate::event::DebugEventBuffer;
+    use crate::{format_ast, process_cst_to_ast};
 
     use super::ICUMarkdownParser;
 
@@ -10,12 +10,18 @@ pub enum SyntaxKind {
     LINE_ENDING,        // \n, \r, or \r\n
     LEADING_WHITESPACE, // ASCII whitespace occurring at the start of a line matching an expected line depth.
     BLANK_LINE,         // A complete line containing only whitespace and a line ending.
-    ESCAPED,            // Any valid, backslash-escaped character.
+    BLOCK_PREFIX, // Container syntax starting a line, like `> ` in a block quote or a list item's indent.
+    ESCAPED,      // Any valid, backslash-escaped character.
     // Block Bounds
     BLOCK_START,  // A zero-width marker of the start of a block element.
     BLOCK_END,    // A zero-width representing the end of a block element.
     INLINE_START, // A zero-width marker of the start of inline content.
     INLINE_END,   // A zero-width marker of the end of inline content.
+    // Container Markers
+    BULLET_LIST_MARKER,  // `-`, `+`, or `*` starting a list item.
+    ORDERED_LIST_MARKER, // 1–9 digits followed by `.` or `)` starting a list item.
+    // Table Markers
+    TABLE_CELL_DELIMITER, // `|` separating the cells of a table row.
     // Tokens
     TEXT,             // Any string of contiguous plain text.
     HARD_LINE_ENDING, // A line ending preceded immediately by two or more spaces.
@@ -142,14 +148,29 @@ pub enum SyntaxKind {
     /// An ordered list marker is a sequence of 1–9 arabic digits (0-9),
     /// followed by either a . character or a ) character.
     LIST_ITEM,
-    BULLET_LIST_MARKER,
-    ORDERED_LIST_MARKER,
     /// 5.3 Lists
     ///
     /// A list is a sequence of one or more list items of the same type. The
     /// list items may be separated by any number of blank lines.
     LIST,
 
+    // GitHub-flavored Markdown block nodes
+    /// 4.10 Tables (extension)
+    ///
+    /// A table is an arrangement of data with rows and columns, consisting of
+    /// a single header row, a delimiter row separating the header from the
+    /// data, and zero or more data rows. Each row consists of cells containing
+    /// arbitrary text, in which inlines are parsed, separated by pipes (|).
+    TABLE,
+    /// A header or data row of a table, containing the cells and the pipes
+    /// that separate them.
+    TABLE_ROW,
+    /// The row between the header and the data of a table, which determines
+    /// the alignment of each column.
+    TABLE_DELIMITER_ROW,
+    /// A single cell of a table row, containing inline content.
+    TABLE_CELL,
+
     // Everything above this point is a Block-level node. Everything below here
     // is an Inline-level node.
 
@@ -191,12 +212,14 @@ pub enum SyntaxKind {
     ICU_SELECT_KW,         // select
     ICU_SELECT_ORDINAL_KW, // selectordinal
     ICU_PLURAL_KW,         // plural
+    ICU_OFFSET_KW,         // offset, only when followed by `:` in a plural.
     // ICU tokens
     ICU_DOUBLE_COLON, // ::
     // ICU literals
     ICU_IDENT,           // Any user-created identifier, used for variable names.
     ICU_PLURAL_CATEGORY, // `one`, `zero`, `other`, etc. in a plural or select ordinal.
     ICU_PLURAL_EXACT,    // Exact value match in a plural block, like `=0`.
+    ICU_INTEGER,         // A plain, unsigned integer, like the value of a plural offset.
     ICU_STYLE_ARGUMENT,  // Any third argument to a number, date, or time variable.
     ICU_STYLE_TEXT,      // The text token of the ICU_STYLE_ARGUMENT node above.
     ICU_DATE_TIME_STYLE, // Either a keyword like `short` or a skeleton like `::hmsGy`
@@ -210,6 +233,7 @@ pub enum SyntaxKind {
     ICU_SELECT,         // {var, select, ...}
     ICU_SELECT_ORDINAL, // {var, selectordinal, ...}
     ICU_VARIABLE,       // `var` in `{var}` or `{var, plural}` and so on.
+    ICU_PLURAL_OFFSET,  // The `offset:1` in `{var, plural, offset:1 ...}`
     // ICU_PLURAL_ARMS,  // The list of arms in a plural or select node.
     ICU_PLURAL_ARM,   // The `one {inner}` in `{var, plural, one {inner}}`
     ICU_PLURAL_VALUE, // The `inner` in `{var, plural, one {inner}}`
@@ -236,6 +260,7 @@ impl SyntaxKind {
             self,
             SyntaxKind::BLANK_LINE
                 | SyntaxKind::LEADING_WHITESPACE
+                | SyntaxKind::BLOCK_PREFIX
                 | SyntaxKind::WHITESPACE
                 | SyntaxKind::LINE_ENDING
         )
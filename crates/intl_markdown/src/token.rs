@@ -137,9 +137,11 @@ impl TriviaList {
     ) -> (u32, u16, u16) {
         let available_trivia = &self.trivia_list[*starting_cursor..];
         let token_start = token.span_start();
+        // Trailing trivia that doesn't directly follow the previous token, like a blank line
+        // after a container prefix, is leading trivia of this token instead.
         let leading_count = available_trivia
             .iter()
-            .position(|trivia| trivia.is_trailing() || trivia.span_start() >= token_start)
+            .position(|trivia| trivia.span_start() >= token_start)
             .unwrap_or(available_trivia.len());
 
         *starting_cursor += leading_count;
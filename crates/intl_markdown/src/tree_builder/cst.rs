@@ -188,6 +188,34 @@ pub struct SetextHeading {
 
 cst_token_list!(SetextHeadingUnderline);
 
+cst_block_node!(BlockQuote);
+
+#[derive(Debug, ReadFromEvents)]
+pub struct List {
+    pub items: Vec<ListItem>,
+}
+
+#[derive(Debug, ReadFromEvents)]
+pub struct ListItem {
+    pub marker: Token,
+    pub children: Vec<NodeOrToken>,
+}
+
+#[derive(Debug, ReadFromEvents)]
+pub struct Table {
+    pub header: TableRow,
+    pub delimiter_row: TableDelimiterRow,
+    pub rows: Vec<TableRow>,
+}
+
+cst_block_node!(TableRow);
+cst_token_list!(TableDelimiterRow);
+
+#[derive(Debug, ReadFromEvents)]
+pub struct TableCell {
+    pub content: InlineContent,
+}
+
 impl SetextHeadingUnderline {
     /// Returns the heading level (1 or 2) that this heading should have
     /// according to the type of underline.
@@ -365,6 +393,7 @@ pub struct IcuPlural {
     pub variable_comma: Token,
     pub format_token: Token,
     pub format_comma: Token,
+    pub offset: Option<IcuPluralOffset>,
     pub arms: Vec<IcuPluralArm>,
 }
 
@@ -383,9 +412,17 @@ pub struct IcuSelectOrdinal {
     pub variable_comma: Token,
     pub format_token: Token,
     pub format_comma: Token,
+    pub offset: Option<IcuPluralOffset>,
     pub arms: Vec<IcuPluralArm>,
 }
 
+#[derive(Debug, ReadFromEvents)]
+pub struct IcuPluralOffset {
+    pub offset_kw: Token,
+    pub colon: Token,
+    pub value: Token,
+}
+
 #[derive(Debug, ReadFromEvents)]
 pub struct IcuPluralArm {
     pub selector: Token,
@@ -443,6 +480,10 @@ pub enum Node {
     SetextHeading(SetextHeading),
     IndentedCodeBlock(IndentedCodeBlock),
     FencedCodeBlock(FencedCodeBlock),
+    BlockQuote(BlockQuote),
+    List(List),
+    Table(Table),
+    TableCell(TableCell),
     InlineContent(InlineContent),
     Emphasis(Emphasis),
     Strong(Strong),
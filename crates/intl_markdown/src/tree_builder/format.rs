@@ -0,0 +1,356 @@
+//! Formatting for message source text, working from the lossless CST rather than the semantic
+//! AST. Everything outside of ICU expressions is written exactly as the author wrote it, so
+//! Markdown syntax like `**strong**` or `__strong__`, escapes, and line breaks are all preserved.
+//!
+//! ICU expressions are written canonically:
+//! - Whitespace in the control sections is normalized, like `{ count ,plural,one {…}}` becoming
+//!   `{count, plural, one {…}}`.
+//! - Plurals and selects that were written across multiple lines put each arm on its own line,
+//!   indented two spaces past the line that the expression starts on, and nested expressions are
+//!   indented further for each level. Expressions written on a single line stay that way, since
+//!   some blocks, like table rows and ATX headings, can't span multiple lines.
+//! - The content of each arm is kept as written, other than any ICU expressions it contains.
+//!
+//! Expressions with anything other than whitespace in their control sections, like the `>`
+//! prefixes of a block quote that the expression continues through, are left as written. Since
+//! the output of formatting parses to the same structure and is already canonical, formatting is
+//! idempotent.
+use crate::token::Token;
+use crate::tree_builder::cst::{
+    Document, Icu, IcuPlaceholder, IcuPluralArm, IcuPluralOffset, InlineContent, LinkDestination,
+    LinkResource, Node, NodeOrToken,
+};
+use crate::tree_builder::TokenSpan;
+
+pub type FormatResult<T> = Result<T, std::fmt::Error>;
+
+const INDENT: &str = "  ";
+
+/// Format the source text of the given document, writing every ICU expression canonically and
+/// keeping all other content exactly as it was written.
+pub fn format_source(document: &Document) -> FormatResult<String> {
+    let Some(first_token) = document.first_token() else {
+        return Ok(String::new());
+    };
+    let source = first_token.parent_text();
+    let mut formatter = SourceFormatter::new(source);
+    formatter.format_children(document.children());
+    formatter.finish()
+}
+
+struct SourceFormatter<'a> {
+    source: &'a str,
+    output: String,
+    /// The position in the source up to which content has been written to the output.
+    position: usize,
+    /// The indentation of the innermost plural arm currently being written, or None when outside
+    /// of any multi-line ICU expression.
+    indent: Option<String>,
+}
+
+impl<'a> SourceFormatter<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            output: String::with_capacity(source.len()),
+            position: 0,
+            indent: None,
+        }
+    }
+
+    fn finish(mut self) -> FormatResult<String> {
+        self.write_verbatim_until(self.source.len());
+        Ok(self.output)
+    }
+
+    /// Write the source text between the current position and `end` exactly as written.
+    fn write_verbatim_until(&mut self, end: usize) {
+        if end > self.position {
+            self.output.push_str(&self.source[self.position..end]);
+            self.position = end;
+        }
+    }
+
+    fn format_children(&mut self, children: &[NodeOrToken]) {
+        for child in children {
+            if let NodeOrToken::Node(node) = child {
+                self.format_node(node);
+            }
+        }
+    }
+
+    fn format_inline_content(&mut self, content: &InlineContent) {
+        self.format_children(content.children());
+    }
+
+    /// Find and format every ICU expression within the given node. Nodes that can't contain ICU
+    /// expressions, like code spans and code blocks, are skipped and written as they are.
+    fn format_node(&mut self, node: &Node) {
+        match node {
+            Node::Paragraph(paragraph) => self.format_inline_content(&paragraph.children),
+            Node::AtxHeading(heading) => self.format_inline_content(&heading.children),
+            Node::SetextHeading(heading) => self.format_inline_content(&heading.children),
+            Node::BlockQuote(block_quote) => self.format_children(block_quote.children()),
+            Node::List(list) => {
+                for item in &list.items {
+                    self.format_children(&item.children);
+                }
+            }
+            Node::Table(table) => {
+                self.format_children(table.header.children());
+                for row in &table.rows {
+                    self.format_children(row.children());
+                }
+            }
+            Node::TableCell(cell) => self.format_inline_content(&cell.content),
+            Node::InlineContent(content) => self.format_inline_content(content),
+            Node::Emphasis(emphasis) => self.format_inline_content(&emphasis.children),
+            Node::Strong(strong) => self.format_inline_content(&strong.children),
+            Node::Link(link) => {
+                self.format_inline_content(&link.content);
+                self.format_link_resource(&link.resource);
+            }
+            Node::Image(image) => {
+                self.format_inline_content(&image.content);
+                self.format_link_resource(&image.resource);
+            }
+            Node::Hook(hook) => self.format_inline_content(&hook.content),
+            Node::Strikethrough(strikethrough) => {
+                self.format_inline_content(&strikethrough.content)
+            }
+            Node::Icu(icu) => self.format_icu(icu),
+            Node::ThematicBreak(_)
+            | Node::IndentedCodeBlock(_)
+            | Node::FencedCodeBlock(_)
+            | Node::Autolink(_)
+            | Node::CodeSpan(_) => {}
+        }
+    }
+
+    fn format_link_resource(&mut self, resource: &LinkResource) {
+        if let Some(LinkDestination::DynamicLinkDestination(destination)) = &resource.destination {
+            self.format_icu(&destination.url);
+        }
+    }
+
+    /// Write the given ICU expression canonically, or leave it to be written as-is if its control
+    /// sections contain anything other than whitespace.
+    fn format_icu(&mut self, icu: &Icu) {
+        self.write_verbatim_until(icu.l_curly.range_usize().start);
+
+        let output_length = self.output.len();
+        let position = self.position;
+        let indent = self.indent.clone();
+        if self.write_icu(icu).is_none() {
+            self.output.truncate(output_length);
+            self.position = position;
+        }
+        self.indent = indent;
+    }
+
+    fn write_icu(&mut self, icu: &Icu) -> Option<()> {
+        self.output.push_str(icu.l_curly.text());
+        match &icu.value {
+            IcuPlaceholder::IcuVariable(variable) => {
+                self.expect_gap(&icu.l_curly, &variable.ident)?;
+                self.output.push_str(variable.ident.text());
+                self.expect_gap(&variable.ident, &icu.r_curly)?;
+            }
+            IcuPlaceholder::IcuDate(date) => {
+                let style = date
+                    .style
+                    .as_ref()
+                    .map(|style| (&style.leading_comma, &style.style_text));
+                self.write_formatted_argument(
+                    icu,
+                    &date.variable.ident,
+                    &date.variable_comma,
+                    &date.format_token,
+                    style,
+                )?;
+            }
+            IcuPlaceholder::IcuTime(time) => {
+                let style = time
+                    .style
+                    .as_ref()
+                    .map(|style| (&style.leading_comma, &style.style_text));
+                self.write_formatted_argument(
+                    icu,
+                    &time.variable.ident,
+                    &time.variable_comma,
+                    &time.format_token,
+                    style,
+                )?;
+            }
+            IcuPlaceholder::IcuNumber(number) => {
+                let style = number
+                    .style
+                    .as_ref()
+                    .map(|style| (&style.leading_comma, &style.style_text));
+                self.write_formatted_argument(
+                    icu,
+                    &number.variable.ident,
+                    &number.variable_comma,
+                    &number.format_token,
+                    style,
+                )?;
+            }
+            IcuPlaceholder::IcuPlural(plural) => self.write_plural(
+                icu,
+                [
+                    &plural.variable.ident,
+                    &plural.variable_comma,
+                    &plural.format_token,
+                    &plural.format_comma,
+                ],
+                plural.offset.as_ref(),
+                &plural.arms,
+            )?,
+            IcuPlaceholder::IcuSelectOrdinal(plural) => self.write_plural(
+                icu,
+                [
+                    &plural.variable.ident,
+                    &plural.variable_comma,
+                    &plural.format_token,
+                    &plural.format_comma,
+                ],
+                plural.offset.as_ref(),
+                &plural.arms,
+            )?,
+            IcuPlaceholder::IcuSelect(select) => self.write_plural(
+                icu,
+                [
+                    &select.variable.ident,
+                    &select.variable_comma,
+                    &select.format_token,
+                    &select.format_comma,
+                ],
+                None,
+                &select.arms,
+            )?,
+        }
+        self.output.push_str(icu.r_curly.text());
+        self.position = icu.r_curly.range_usize().end;
+        Some(())
+    }
+
+    /// Write an argument with a format and an optional style, like `{count, number, percent}`.
+    /// The style is written as its trimmed text, since FormatJS treats all of it as the style.
+    fn write_formatted_argument(
+        &mut self,
+        icu: &Icu,
+        ident: &Token,
+        variable_comma: &Token,
+        format_token: &Token,
+        style: Option<(&Token, &Token)>,
+    ) -> Option<()> {
+        self.expect_gap(&icu.l_curly, ident)?;
+        self.expect_gap(ident, variable_comma)?;
+        self.expect_gap(variable_comma, format_token)?;
+        self.output.push_str(ident.text());
+        self.output.push_str(", ");
+        self.output.push_str(format_token.text());
+
+        let last_token = match style {
+            Some((leading_comma, style_text)) => {
+                self.expect_gap(format_token, leading_comma)?;
+                self.expect_gap(leading_comma, style_text)?;
+                self.output.push_str(", ");
+                self.output.push_str(style_text.text().trim());
+                style_text
+            }
+            None => format_token,
+        };
+        self.expect_gap(last_token, &icu.r_curly).map(|_| ())
+    }
+
+    /// Write a plural, select, or selectordinal expression, like `{count, plural, one {…}}`. The
+    /// arms go on their own lines if any of them were separated by a line break in the source.
+    fn write_plural(
+        &mut self,
+        icu: &Icu,
+        [ident, variable_comma, format_token, format_comma]: [&Token; 4],
+        offset: Option<&IcuPluralOffset>,
+        arms: &[IcuPluralArm],
+    ) -> Option<()> {
+        self.expect_gap(&icu.l_curly, ident)?;
+        self.expect_gap(ident, variable_comma)?;
+        self.expect_gap(variable_comma, format_token)?;
+        self.expect_gap(format_token, format_comma)?;
+        self.output.push_str(ident.text());
+        self.output.push_str(", ");
+        self.output.push_str(format_token.text());
+        self.output.push(',');
+
+        let mut previous = format_comma;
+        if let Some(offset) = offset {
+            self.expect_gap(previous, &offset.offset_kw)?;
+            self.expect_gap(&offset.offset_kw, &offset.colon)?;
+            self.expect_gap(&offset.colon, &offset.value)?;
+            self.output.push(' ');
+            self.output.push_str(offset.offset_kw.text());
+            self.output.push(':');
+            self.output.push_str(offset.value.text());
+            previous = &offset.value;
+        }
+
+        // The gaps around each arm decide whether the expression is written across multiple lines.
+        let mut is_multiline = false;
+        for arm in arms {
+            is_multiline |= self.expect_gap(previous, &arm.selector)?.contains('\n');
+            self.expect_gap(&arm.selector, &arm.l_curly)?;
+            previous = &arm.r_curly;
+        }
+        is_multiline |= self.expect_gap(previous, &icu.r_curly)?.contains('\n');
+        let is_multiline = is_multiline && !arms.is_empty();
+
+        let outer_indent = match &self.indent {
+            Some(indent) => indent.clone(),
+            None => self
+                .line_indentation(icu.l_curly.range_usize().start)
+                .to_string(),
+        };
+        let arm_indent = format!("{outer_indent}{INDENT}");
+
+        for arm in arms {
+            if is_multiline {
+                self.output.push('\n');
+                self.output.push_str(&arm_indent);
+            } else {
+                self.output.push(' ');
+            }
+            self.output.push_str(arm.selector.text());
+            self.output.push(' ');
+            self.output.push_str(arm.l_curly.text());
+
+            // The content of the arm is written as-is, other than any nested expressions.
+            self.position = arm.l_curly.range_usize().end;
+            self.indent = Some(arm_indent.clone());
+            self.format_inline_content(&arm.value.content);
+            self.write_verbatim_until(arm.r_curly.range_usize().start);
+            self.output.push_str(arm.r_curly.text());
+        }
+
+        if is_multiline {
+            self.output.push('\n');
+            self.output.push_str(&outer_indent);
+        }
+        Some(())
+    }
+
+    /// Returns the text between two tokens of an ICU control section, or None if it contains
+    /// anything other than whitespace.
+    fn expect_gap(&self, before: &Token, after: &Token) -> Option<&'a str> {
+        let gap = &self.source[before.range_usize().end..after.range_usize().start];
+        gap.trim().is_empty().then_some(gap)
+    }
+
+    /// Returns the whitespace at the start of the line containing `position`.
+    fn line_indentation(&self, position: usize) -> &'a str {
+        let line_start = self.source[..position]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line = &self.source[line_start..position];
+        &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+    }
+}
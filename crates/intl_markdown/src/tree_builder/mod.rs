@@ -5,6 +5,7 @@ use crate::{
 };
 
 pub mod cst;
+pub mod format;
 
 /// General trait allowing callers to access the first and last tokens of any kind of node, even if
 /// the exact token isn't referenced until multiple levels down.
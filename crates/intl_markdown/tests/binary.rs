@@ -0,0 +1,70 @@
+//! Tests for persisting Documents with the binary encoding and reading them back.
+
+use intl_markdown::{parse_intl_message, Document};
+use intl_message_utils::binary::{BinaryReader, BinaryWriter};
+
+fn round_trip(document: &Document) -> Document {
+    let mut writer = BinaryWriter::new();
+    writer.write(document);
+    let data = writer.into_inner();
+
+    let mut reader = BinaryReader::new(&data);
+    let decoded = reader.read().unwrap();
+    assert!(reader.is_empty());
+    decoded
+}
+
+fn assert_round_trips(input: &str, include_blocks: bool) {
+    let document = parse_intl_message(input, include_blocks);
+    assert_eq!(
+        format!("{document:?}"),
+        format!("{:?}", round_trip(&document)),
+        "input: {input}"
+    );
+}
+
+#[test]
+fn inline_content() {
+    assert_round_trips("", false);
+    assert_round_trips("**strong** *emphasis* ~~gone~~ `code`  \nbreak", false);
+    assert_round_trips(
+        "[text](./somewhere) [{name}]({url}) [click](onClick)",
+        false,
+    );
+    assert_round_trips("<https://example.com> $[hook **content**](myHook)", false);
+}
+
+#[test]
+fn icu_content() {
+    assert_round_trips(
+        "{count, plural, offset:1 =0 {none} one {# item} other {# items}}",
+        false,
+    );
+    assert_round_trips("{gender, select, male {he} other {!!{they}!!}}", false);
+    assert_round_trips(
+        "{n, number, ::currency/EUR .00} {d, date, short} {t, time, ::Hm}",
+        false,
+    );
+}
+
+#[test]
+fn blocks() {
+    assert_round_trips(
+        "# Title\n\nSetext\n---\n\n***\n\n```js\ncode\n```\n\n> quote\n\n3. three\n4. four\n\n- a\n\n- b",
+        true,
+    );
+    assert_round_trips("| a | b |\n| :- | -: |\n| 1 | {two} | extra |", true);
+}
+
+#[test]
+fn diagnostics() {
+    let document = parse_intl_message("{count, plural, one {# item} other {# items", false);
+    assert!(!document.diagnostics().is_empty());
+    assert_eq!(document.diagnostics(), round_trip(&document).diagnostics());
+}
+
+#[test]
+fn invalid_data() {
+    let mut reader = BinaryReader::new(&[1, 0, 0, 0, 42]);
+    assert!(reader.read::<Document>().is_err());
+}
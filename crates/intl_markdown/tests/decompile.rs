@@ -0,0 +1,149 @@
+//! Tests for reading compiled messages back into Documents, both from the keyless JSON written by
+//! the bundler and from FormatJS JSON.
+
+use intl_markdown::{
+    compile_to_format_js, decompile_format_js, format_to_icu_string, parse_intl_message,
+    FormatJsNode,
+};
+
+fn icu_string(input: &str, include_blocks: bool) -> String {
+    format_to_icu_string(&parse_intl_message(input, include_blocks)).unwrap()
+}
+
+fn decompile_keyless(json: &str) -> String {
+    let node: FormatJsNode = keyless_json::from_str(json).unwrap();
+    format_to_icu_string(&decompile_format_js(&node)).unwrap()
+}
+
+fn decompile_json(json: &str) -> String {
+    let node: FormatJsNode = serde_json::from_str(json).unwrap();
+    format_to_icu_string(&decompile_format_js(&node)).unwrap()
+}
+
+/// Compile the input to every supported format and check that decompiling each of them gives
+/// back the same message.
+fn assert_round_trips(input: &str, include_blocks: bool) {
+    let document = parse_intl_message(input, include_blocks);
+    let expected = icu_string(input, include_blocks);
+
+    let keyless = keyless_json::to_string(&compile_to_format_js(&document)).unwrap();
+    assert_eq!(expected, decompile_keyless(&keyless), "keyless: {keyless}");
+
+    let compiled = serde_json::to_string(&compile_to_format_js(&document)).unwrap();
+    assert_eq!(expected, decompile_json(&compiled), "compiled: {compiled}");
+
+    let serialized = serde_json::to_string(&document).unwrap();
+    assert_eq!(
+        expected,
+        decompile_json(&serialized),
+        "serialized: {serialized}"
+    );
+}
+
+#[test]
+fn inline_content() {
+    assert_round_trips("", false);
+    assert_round_trips("plain text", false);
+    assert_round_trips("**strong** and *emphasis* and ~~gone~~ with `code`", false);
+    assert_round_trips("a $[hook with **content**](myHook) here", false);
+    assert_round_trips("line  \nbreak", false);
+}
+
+#[test]
+fn links() {
+    assert_round_trips("[text](./somewhere) and [{name}]({url})", false);
+    // Handlers and placeholders compile to the same argument node.
+    let keyless = r#"[[8,"$link",["click me"],[[1,"onClick"]]]]"#;
+    assert_eq!("<link>{onClick}click me</link>", decompile_keyless(keyless));
+}
+
+#[test]
+fn icu_elements() {
+    assert_round_trips("hello {name}", false);
+    assert_round_trips(
+        "{count, plural, offset:1 =0 {none} one {# **item**} other {# items}}",
+        false,
+    );
+    assert_round_trips("{place, selectordinal, one {#st} other {#th}}", false);
+    assert_round_trips(
+        "{gender, select, male {he} female {she} other {they}}",
+        false,
+    );
+    assert_round_trips(
+        "{n, number} {n, number, percent} {n, number, ::currency/EUR .00}",
+        false,
+    );
+    assert_round_trips(
+        "{d, date, short} {d, date, ::yyyyMMMd} {t, time, ::Hm}",
+        false,
+    );
+}
+
+#[test]
+fn blocks() {
+    assert_round_trips(
+        "# Title\n\nA paragraph\n\n---\n\n```\ncode {here}\n```",
+        true,
+    );
+    assert_round_trips("> quoted\n> text", true);
+    assert_round_trips("- one\n- **two**\n  - nested", true);
+    assert_round_trips("3. three\n4. four\n\n5. five", true);
+    assert_round_trips("| a | b |\n| :- | -: |\n| 1 | {two} |", true);
+}
+
+#[test]
+fn keyless_nodes() {
+    assert_eq!(
+        "hi <b>{name}</b>, {count, plural, one {# item} other {# items}}",
+        decompile_keyless(
+            r#"["hi ",[8,"$b",[[1,"name"]]],", ",[6,"count",{"one":[[7]," item"],"other":[[7]," items"]},0,"cardinal"]]"#
+        )
+    );
+    assert_eq!("just text", decompile_keyless(r#""just text""#));
+}
+
+#[test]
+fn formatjs_ast() {
+    // Output of `@formatjs/cli compile --ast`, including locations that aren't needed.
+    let json = r#"[
+        {"type": 0, "value": "You have "},
+        {
+            "type": 6,
+            "value": "count",
+            "options": {
+                "one": {"value": [{"type": 7, "location": {}}, {"type": 0, "value": " message"}]},
+                "other": {"value": [{"type": 7}, {"type": 0, "value": " messages"}]}
+            },
+            "offset": 0,
+            "pluralType": "cardinal",
+            "location": {"start": {"offset": 9, "line": 1, "column": 10}}
+        },
+        {"type": 0, "value": " from "},
+        {"type": 8, "value": "link", "children": [{"type": 1, "value": "sender"}]},
+        {"type": 0, "value": " since "},
+        {
+            "type": 3,
+            "value": "since",
+            "style": {
+                "type": 1,
+                "pattern": "yyyyMMMd",
+                "location": {},
+                "parsedOptions": {"year": "numeric", "month": "short", "day": "numeric"}
+            }
+        },
+        {
+            "type": 2,
+            "value": "cost",
+            "style": {
+                "type": 0,
+                "tokens": [{"stem": "currency", "options": ["USD"]}],
+                "parsedOptions": {"style": "currency", "currency": "USD"}
+            }
+        }
+    ]"#;
+    assert_eq!(
+        "You have {count, plural, one {# message} other {# messages}} from <link>{sender}</link> \
+         since {since, date, ::yyyyMMMd}{cost, number, ::currency/USD}",
+        decompile_json(json)
+    );
+}
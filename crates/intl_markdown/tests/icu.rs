@@ -46,6 +46,16 @@ mod icu_variable_formats {
         "{count, number, currency/USD}",
         "{count, number, currency/USD}"
     );
+    icu_string_test!(
+        plural_offset,
+        "{count, plural, offset:1 =0 {nobody} other {# others}}",
+        "{count, plural, offset:1 =0 {nobody} other {# others}}"
+    );
+    icu_string_test!(
+        plural_offset_whitespace,
+        "{count, selectordinal,offset:  2\n other {#}}",
+        "{count, selectordinal, offset:2 other {#}}"
+    );
 }
 
 mod icu_markdown_blocks {
@@ -76,6 +86,46 @@ mod icu_markdown_blocks {
         "```\n{\nnovariable\n}\n```",
         "<codeBlock>{\nnovariable\n}\n</codeBlock>"
     );
+    icu_block_string_test!(
+        tight_bullet_list,
+        "- one\n- two",
+        "<ul><li>one</li><li>two</li></ul>"
+    );
+    icu_block_string_test!(
+        loose_ordered_list_start,
+        "3. one\n\n4. two",
+        "<ol>3{_}<li><p>one</p></li><li><p>two</p></li></ol>"
+    );
+    icu_block_string_test!(
+        block_quote,
+        "> # Heading\n> quoted *text*",
+        "<blockquote><h1>Heading</h1>\n<p>quoted <i>text</i></p></blockquote>"
+    );
+}
+
+mod icu_in_containers {
+    use crate::harness::icu_block_string_test;
+
+    icu_block_string_test!(
+        list_item_variable,
+        "- {count} items\n- {other}",
+        "<ul><li>{count} items</li><li>{other}</li></ul>"
+    );
+    icu_block_string_test!(
+        list_item_multiline_plural,
+        "- {count, plural,\n  one {# item}\n\n  other {# items}}\n- after",
+        "<ul><li>{count, plural, one {# item} other {# items}}</li><li>after</li></ul>"
+    );
+    icu_block_string_test!(
+        list_item_unindented_plural,
+        "1. {count, plural,\none {# *item*}\nother {# items}} left\n2. after",
+        "<ol><li>{count, plural, one {# <i>item</i>} other {# items}} left</li><li>after</li></ol>"
+    );
+    icu_block_string_test!(
+        block_quote_multiline_plural,
+        "> {count, plural,\n> one {# item}\n\nother {# items}}",
+        "<blockquote><p>{count, plural, one {# item} other {# items}}</p></blockquote>"
+    );
 }
 
 mod icu_blocks {
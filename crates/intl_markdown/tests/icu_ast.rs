@@ -9,12 +9,12 @@ mod icu_formatjs_types {
     ast_test!(
         number_style,
         "{count, number, sign-always currency/USD}",
-        r#"[[2,"count","sign-always currency/USD"]]"#
+        r#"[[2,"count",{"type":0,"tokens":[{"stem":"sign-always","options":[]},{"stem":"currency","options":["USD"]}],"parsedOptions":{"style":"currency","currency":"USD","signDisplay":"always"}}]]"#
     );
     ast_test!(
         number_style_shorthand,
         "{count, number, +! K currency/GBP }",
-        r#"[[2,"count","+! K currency/GBP"]]"#
+        r#"[[2,"count",{"type":0,"tokens":[{"stem":"+!","options":[]},{"stem":"K","options":[]},{"stem":"currency","options":["GBP"]}],"parsedOptions":{"style":"currency","currency":"GBP","notation":"compact","compactDisplay":"short","signDisplay":"always"}}]]"#
     );
     ast_test!(date, "{today, date}", r#"[[3,"today"]]"#);
     ast_test!(
@@ -25,7 +25,7 @@ mod icu_formatjs_types {
     ast_test!(
         date_skeleton,
         "{today, date,  ::hhmsyG }",
-        r#"[[3,"today","::hhmsyG"]]"#
+        r#"[[3,"today",{"type":1,"pattern":"hhmsyG","parsedOptions":{"era":"short","year":"numeric","hourCycle":"h12","hour":"2-digit","minute":"numeric","second":"numeric"}}]]"#
     );
     ast_test!(time, "{rightNow, time}", r#"[[4,"rightNow"]]"#);
     ast_test!(
@@ -35,9 +35,20 @@ mod icu_formatjs_types {
     );
     ast_test!(
         time_skeleton,
+        "{rightNow, time, ::Hmm}",
+        r#"[[4,"rightNow",{"type":1,"pattern":"Hmm","parsedOptions":{"hourCycle":"h23","hour":"numeric","minute":"2-digit"}}]]"#
+    );
+    // Skeletons that can't be parsed are reported as diagnostics and left as the original text.
+    ast_test!(
+        time_skeleton_invalid,
         "{rightNow, time, ::GMDY}",
         r#"[[4,"rightNow","::GMDY"]]"#
     );
+    ast_test!(
+        number_style_named,
+        "{count, number, percent}",
+        r#"[[2,"count","percent"]]"#
+    );
     ast_test!(
         plural,
         "{count, plural, one {#}}",
@@ -48,6 +59,27 @@ mod icu_formatjs_types {
         "{count, plural, =-1 {#} =5 {five}}",
         r#"[[6,"count",{"=-1":[[7]],"=5":["five"]},0,"cardinal"]]"#
     );
+    ast_test!(
+        plural_pound_in_text,
+        "{count, plural, other {you and # others}}",
+        r#"[[6,"count",{"other":["you and ",[7]," others"]},0,"cardinal"]]"#
+    );
+    ast_test!(hash_outside_plural, "issue # 5", r#"["issue # 5"]"#);
+    ast_test!(
+        plural_offset,
+        "{count, plural, offset:1 =0 {nobody} other {#}}",
+        r#"[[6,"count",{"=0":["nobody"],"other":[[7]]},1,"cardinal"]]"#
+    );
+    ast_test!(
+        selectordinal_offset,
+        "{count, selectordinal, offset: 2 other {#}}",
+        r#"[[6,"count",{"other":[[7]]},2,"ordinal"]]"#
+    );
+    ast_test!(
+        offset_as_name,
+        "{offset, select, offset {x}}",
+        r#"[[5,"offset",{"offset":["x"]}]]"#
+    );
     ast_test!(
         selectordinal,
         "{count, selectordinal, one {#}}",
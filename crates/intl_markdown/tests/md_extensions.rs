@@ -1,5 +1,5 @@
 //! Tests for Markdown syntax extensions, specifically hooks (`$[]()`), unsafe variables (`!!{}!!`),
-//! and strikethroughs (a la GFM, `~~deleted~~`).
+//! strikethroughs (a la GFM, `~~deleted~~`), and tables (also a la GFM).
 
 mod harness;
 
@@ -146,3 +146,84 @@ mod strikethrough {
         "flanked punctuation single~!~"
     );
 }
+
+mod tables {
+    use crate::harness::{icu_block_string_test, run_icu_ast_test, run_spec_test};
+
+    #[test]
+    fn basic_table() {
+        run_spec_test(
+            "| foo | bar |\n| --- | --- |\n| baz | bim |",
+            "<table>\n<thead>\n<tr>\n<th>foo</th>\n<th>bar</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>baz</td>\n<td>bim</td>\n</tr>\n</tbody>\n</table>",
+        );
+    }
+
+    #[test]
+    fn aligned_columns() {
+        run_spec_test(
+            "| abc | defghi |\n:-: | -----------:\nbar | baz",
+            "<table>\n<thead>\n<tr>\n<th align=\"center\">abc</th>\n<th align=\"right\">defghi</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td align=\"center\">bar</td>\n<td align=\"right\">baz</td>\n</tr>\n</tbody>\n</table>",
+        );
+    }
+
+    #[test]
+    fn escaped_pipes() {
+        run_spec_test(
+            "| f\\|oo  |\n| ------ |\n| b `\\|` az |\n| b **\\|** im |",
+            "<table>\n<thead>\n<tr>\n<th>f|oo</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b <code>|</code> az</td>\n</tr>\n<tr>\n<td>b <strong>|</strong> im</td>\n</tr>\n</tbody>\n</table>",
+        );
+    }
+
+    #[test]
+    fn ended_by_another_block() {
+        run_spec_test(
+            "| abc | def |\n| --- | --- |\n| bar | baz |\n> bar",
+            "<table>\n<thead>\n<tr>\n<th>abc</th>\n<th>def</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>bar</td>\n<td>baz</td>\n</tr>\n</tbody>\n</table>\n<blockquote>\n<p>bar</p>\n</blockquote>",
+        );
+    }
+
+    #[test]
+    fn mismatched_delimiter_row() {
+        run_spec_test(
+            "| abc | def |\n| --- |\n| bar |",
+            "<p>| abc | def |\n| --- |\n| bar |</p>",
+        );
+    }
+
+    #[test]
+    fn uneven_rows() {
+        run_spec_test(
+            "| abc | def |\n| --- | --- |\n| bar |\n| bar | baz | boo |",
+            "<table>\n<thead>\n<tr>\n<th>abc</th>\n<th>def</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>bar</td>\n<td></td>\n</tr>\n<tr>\n<td>bar</td>\n<td>baz</td>\n</tr>\n</tbody>\n</table>",
+        );
+    }
+
+    #[test]
+    fn header_only() {
+        run_spec_test(
+            "| abc | def |\n| --- | --- |",
+            "<table>\n<thead>\n<tr>\n<th>abc</th>\n<th>def</th>\n</tr>\n</thead>\n</table>",
+        );
+    }
+
+    icu_block_string_test!(
+        icu_in_cells,
+        "| name | items |\n| --- | :-: |\n| *{name}* | {count, plural, one {# item} other {# items}} |",
+        "<table><thead><tr><th>name</th><th>center{_}items</th></tr></thead><tbody><tr><td><i>{name}</i></td><td>center{_}{count, plural, one {# item} other {# items}}</td></tr></tbody></table>"
+    );
+    icu_block_string_test!(
+        pipes_inside_icu,
+        "a | {kind, select, x {b | c} other {d}}\n- | -",
+        "<table><thead><tr><th>a</th><th>{kind, select, x {b | c} other {d}}</th></tr></thead></table>"
+    );
+    icu_block_string_test!(setext_heading_without_pipes, "Foo\n---", "<h2>Foo</h2>");
+
+    #[test]
+    fn alignment_as_control() {
+        run_icu_ast_test(
+            "a | b\n:- | -\nc | d",
+            r#"[[8,"$table",[[8,"$thead",[[8,"$tr",[[8,"$th",["a"],["left"]],[8,"$th",["b"]]]]]],[8,"$tbody",[[8,"$tr",[[8,"$td",["c"],["left"]],[8,"$td",["d"]]]]]]]]]"#,
+            true,
+        );
+    }
+}
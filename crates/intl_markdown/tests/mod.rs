@@ -73,32 +73,31 @@ fn blank_lines(input: &str, output: &str) {
     run_spec_test(input, output);
 }
 
-#[allow(unused)]
-// #[test_case("> # Foo\n> bar\n> baz", "<blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>"; "example_228")]
-// #[test_case("># Foo\n>bar\n> baz", "<blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>"; "example_229")]
-// #[test_case("   > # Foo\n   > bar\n > baz", "<blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>"; "example_230")]
-// #[test_case("    > # Foo\n    > bar\n    > baz", "<pre><code>&gt; # Foo\n&gt; bar\n&gt; baz\n</code></pre>"; "example_231")]
-// #[test_case("> # Foo\n> bar\nbaz", "<blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>"; "example_232")]
-// #[test_case("> bar\nbaz\n> foo", "<blockquote>\n<p>bar\nbaz\nfoo</p>\n</blockquote>"; "example_233")]
-// #[test_case("> foo\n---", "<blockquote>\n<p>foo</p>\n</blockquote>\n<hr />"; "example_234")]
-// #[test_case("> - foo\n- bar", "<blockquote>\n<ul>\n<li>foo</li>\n</ul>\n</blockquote>\n<ul>\n<li>bar</li>\n</ul>"; "example_235")]
-// #[test_case(">     foo\n    bar", "<blockquote>\n<pre><code>foo\n</code></pre>\n</blockquote>\n<pre><code>bar\n</code></pre>"; "example_236")]
-// #[test_case("> ```\nfoo\n```", "<blockquote>\n<pre><code></code></pre>\n</blockquote>\n<p>foo</p>\n<pre><code></code></pre>"; "example_237")]
-// #[test_case("> foo\n    - bar", "<blockquote>\n<p>foo\n- bar</p>\n</blockquote>"; "example_238")]
-// #[test_case(">", "<blockquote>\n</blockquote>"; "example_239")]
-// #[test_case(">\n>  \n> ", "<blockquote>\n</blockquote>"; "example_240")]
-// #[test_case(">\n> foo\n>  ", "<blockquote>\n<p>foo</p>\n</blockquote>"; "example_241")]
-// #[test_case("> foo\n\n> bar", "<blockquote>\n<p>foo</p>\n</blockquote>\n<blockquote>\n<p>bar</p>\n</blockquote>"; "example_242")]
-// #[test_case("> foo\n> bar", "<blockquote>\n<p>foo\nbar</p>\n</blockquote>"; "example_243")]
-// #[test_case("> foo\n>\n> bar", "<blockquote>\n<p>foo</p>\n<p>bar</p>\n</blockquote>"; "example_244")]
-// #[test_case("foo\n> bar", "<p>foo</p>\n<blockquote>\n<p>bar</p>\n</blockquote>"; "example_245")]
-// #[test_case("> aaa\n***\n> bbb", "<blockquote>\n<p>aaa</p>\n</blockquote>\n<hr />\n<blockquote>\n<p>bbb</p>\n</blockquote>"; "example_246")]
-// #[test_case("> bar\nbaz", "<blockquote>\n<p>bar\nbaz</p>\n</blockquote>"; "example_247")]
-// #[test_case("> bar\n\nbaz", "<blockquote>\n<p>bar</p>\n</blockquote>\n<p>baz</p>"; "example_248")]
-// #[test_case("> bar\n>\nbaz", "<blockquote>\n<p>bar</p>\n</blockquote>\n<p>baz</p>"; "example_249")]
-// #[test_case("> > > foo\nbar", "<blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar</p>\n</blockquote>\n</blockquote>\n</blockquote>"; "example_250")]
-// #[test_case(">>> foo\n> bar\n>>baz", "<blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar\nbaz</p>\n</blockquote>\n</blockquote>\n</blockquote>"; "example_251")]
-// #[test_case(">     code\n\n>    not code", "<blockquote>\n<pre><code>code\n</code></pre>\n</blockquote>\n<blockquote>\n<p>not code</p>\n</blockquote>"; "example_252")]
+#[test_case("> # Foo\n> bar\n> baz", "<blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>"; "example_228")]
+#[test_case("># Foo\n>bar\n> baz", "<blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>"; "example_229")]
+#[test_case("   > # Foo\n   > bar\n > baz", "<blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>"; "example_230")]
+#[test_case("    > # Foo\n    > bar\n    > baz", "<pre><code>&gt; # Foo\n&gt; bar\n&gt; baz\n</code></pre>"; "example_231")]
+#[test_case("> # Foo\n> bar\nbaz", "<blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>"; "example_232")]
+#[test_case("> bar\nbaz\n> foo", "<blockquote>\n<p>bar\nbaz\nfoo</p>\n</blockquote>"; "example_233")]
+#[test_case("> foo\n---", "<blockquote>\n<p>foo</p>\n</blockquote>\n<hr />"; "example_234")]
+#[test_case("> - foo\n- bar", "<blockquote>\n<ul>\n<li>foo</li>\n</ul>\n</blockquote>\n<ul>\n<li>bar</li>\n</ul>"; "example_235")]
+#[test_case(">     foo\n    bar", "<blockquote>\n<pre><code>foo\n</code></pre>\n</blockquote>\n<pre><code>bar\n</code></pre>"; "example_236")]
+#[test_case("> ```\nfoo\n```", "<blockquote>\n<pre><code></code></pre>\n</blockquote>\n<p>foo</p>\n<pre><code></code></pre>"; "example_237")]
+#[test_case("> foo\n    - bar", "<blockquote>\n<p>foo\n- bar</p>\n</blockquote>"; "example_238")]
+#[test_case(">", "<blockquote>\n</blockquote>"; "example_239")]
+#[test_case(">\n>  \n> ", "<blockquote>\n</blockquote>"; "example_240")]
+#[test_case(">\n> foo\n>  ", "<blockquote>\n<p>foo</p>\n</blockquote>"; "example_241")]
+#[test_case("> foo\n\n> bar", "<blockquote>\n<p>foo</p>\n</blockquote>\n<blockquote>\n<p>bar</p>\n</blockquote>"; "example_242")]
+#[test_case("> foo\n> bar", "<blockquote>\n<p>foo\nbar</p>\n</blockquote>"; "example_243")]
+#[test_case("> foo\n>\n> bar", "<blockquote>\n<p>foo</p>\n<p>bar</p>\n</blockquote>"; "example_244")]
+#[test_case("foo\n> bar", "<p>foo</p>\n<blockquote>\n<p>bar</p>\n</blockquote>"; "example_245")]
+#[test_case("> aaa\n***\n> bbb", "<blockquote>\n<p>aaa</p>\n</blockquote>\n<hr />\n<blockquote>\n<p>bbb</p>\n</blockquote>"; "example_246")]
+#[test_case("> bar\nbaz", "<blockquote>\n<p>bar\nbaz</p>\n</blockquote>"; "example_247")]
+#[test_case("> bar\n\nbaz", "<blockquote>\n<p>bar</p>\n</blockquote>\n<p>baz</p>"; "example_248")]
+#[test_case("> bar\n>\nbaz", "<blockquote>\n<p>bar</p>\n</blockquote>\n<p>baz</p>"; "example_249")]
+#[test_case("> > > foo\nbar", "<blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar</p>\n</blockquote>\n</blockquote>\n</blockquote>"; "example_250")]
+#[test_case(">>> foo\n> bar\n>>baz", "<blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar\nbaz</p>\n</blockquote>\n</blockquote>\n</blockquote>"; "example_251")]
+#[test_case(">     code\n\n>    not code", "<blockquote>\n<pre><code>code\n</code></pre>\n</blockquote>\n<blockquote>\n<p>not code</p>\n</blockquote>"; "example_252")]
 fn block_quotes(input: &str, output: &str) {
     run_spec_test(input, output);
 }
@@ -278,7 +277,7 @@ fn emphasis_and_strong_emphasis(input: &str, output: &str) {
 #[test_case("`f&ouml;&ouml;`", "<p><code>f&amp;ouml;&amp;ouml;</code></p>"; "example_35")]
 #[test_case("    f&ouml;f&ouml;", "<pre><code>f&amp;ouml;f&amp;ouml;\n</code></pre>"; "example_36")]
 #[test_case("&#42;foo&#42;\n*foo*", "<p>*foo*\n<em>foo</em></p>"; "example_37")]
-// #[test_case("&#42; foo\n\n* foo", "<p>* foo</p>\n<ul>\n<li>foo</li>\n</ul>"; "example_38")]
+#[test_case("&#42; foo\n\n* foo", "<p>* foo</p>\n<ul>\n<li>foo</li>\n</ul>"; "example_38")]
 #[test_case("foo&#10;&#10;bar", "<p>foo\n\nbar</p>"; "example_39")]
 #[test_case("&#9;foo", "<p>\tfoo</p>"; "example_40")]
 #[test_case("[a](url &quot;tit&quot;)", "<p>[a](url &quot;tit&quot;)</p>"; "example_41")]
@@ -295,7 +294,7 @@ fn entity_and_numeric_character_references(input: &str, output: &str) {
 #[test_case("~~~~\naaa\n~~~\n~~~~", "<pre><code>aaa\n~~~\n</code></pre>"; "example_125")]
 #[test_case("```", "<pre><code></code></pre>"; "example_126")]
 #[test_case("`````\n\n```\naaa", "<pre><code>\n```\naaa\n</code></pre>"; "example_127")]
-// #[test_case("> ```\n> aaa\n\nbbb", "<blockquote>\n<pre><code>aaa\n</code></pre>\n</blockquote>\n<p>bbb</p>"; "example_128")]
+#[test_case("> ```\n> aaa\n\nbbb", "<blockquote>\n<pre><code>aaa\n</code></pre>\n</blockquote>\n<p>bbb</p>"; "example_128")]
 #[test_case("```\n\n  \n```", "<pre><code>\n  \n</code></pre>"; "example_129")]
 #[test_case("```\n```", "<pre><code></code></pre>"; "example_130")]
 #[test_case(" ```\n aaa\naaa\n```", "<pre><code>aaa\naaa\n</code></pre>"; "example_131")]
@@ -414,8 +413,8 @@ fn images(input: &str, output: &str) {
 }
 
 #[test_case("    a simple\n      indented code block", "<pre><code>a simple\n  indented code block\n</code></pre>"; "example_107")]
-// #[test_case("  - foo\n\n    bar", "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>"; "example_108")]
-// #[test_case("1.  foo\n\n    - bar", "<ol>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>"; "example_109")]
+#[test_case("  - foo\n\n    bar", "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>"; "example_108")]
+#[test_case("1.  foo\n\n    - bar", "<ol>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>"; "example_109")]
 // #[test_case("    <a/>\n    *hi*\n\n    - one", "<pre><code>&lt;a/&gt;\n*hi*\n\n- one\n</code></pre>"; "example_110")]
 #[test_case("    chunk1\n\n    chunk2\n  \n \n \n    chunk3", "<pre><code>chunk1\n\nchunk2\n\n\n\nchunk3\n</code></pre>"; "example_111")]
 #[test_case("    chunk1\n      \n      chunk2", "<pre><code>chunk1\n  \n  chunk2\n</code></pre>"; "example_112")]
@@ -560,86 +559,84 @@ fn links(input: &str, output: &str) {
     run_spec_test(input, output);
 }
 
-#[allow(unused)]
-// #[test_case("A paragraph\nwith two lines.\n\n    indented code\n\n> A block quote.", "<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>"; "example_253")]
-// #[test_case("1.  A paragraph\n    with two lines.\n\n        indented code\n\n    > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_254")]
-// #[test_case("- one\n\n two", "<ul>\n<li>one</li>\n</ul>\n<p>two</p>"; "example_255")]
-// #[test_case("- one\n\n  two", "<ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>"; "example_256")]
-// #[test_case(" -    one\n\n     two", "<ul>\n<li>one</li>\n</ul>\n<pre><code> two\n</code></pre>"; "example_257")]
-// #[test_case(" -    one\n\n      two", "<ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>"; "example_258")]
-// #[test_case("   > > 1.  one\n>>\n>>     two", "<blockquote>\n<blockquote>\n<ol>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ol>\n</blockquote>\n</blockquote>"; "example_259")]
-// #[test_case(">>- one\n>>\n  >  > two", "<blockquote>\n<blockquote>\n<ul>\n<li>one</li>\n</ul>\n<p>two</p>\n</blockquote>\n</blockquote>"; "example_260")]
-// #[test_case("-one\n\n2.two", "<p>-one</p>\n<p>2.two</p>"; "example_261")]
-// #[test_case("- foo\n\n\n  bar", "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>"; "example_262")]
-// #[test_case("1.  foo\n\n    ```\n    bar\n    ```\n\n    baz\n\n    > bam", "<ol>\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n<p>baz</p>\n<blockquote>\n<p>bam</p>\n</blockquote>\n</li>\n</ol>"; "example_263")]
-// #[test_case("- Foo\n\n      bar\n\n\n      baz", "<ul>\n<li>\n<p>Foo</p>\n<pre><code>bar\n\n\nbaz\n</code></pre>\n</li>\n</ul>"; "example_264")]
-// #[test_case("123456789. ok", "<ol start=\"123456789\">\n<li>ok</li>\n</ol>"; "example_265")]
-// #[test_case("1234567890. not ok", "<p>1234567890. not ok</p>"; "example_266")]
-// #[test_case("0. ok", "<ol start=\"0\">\n<li>ok</li>\n</ol>"; "example_267")]
-// #[test_case("003. ok", "<ol start=\"3\">\n<li>ok</li>\n</ol>"; "example_268")]
-// #[test_case("-1. not ok", "<p>-1. not ok</p>"; "example_269")]
-// #[test_case("- foo\n\n      bar", "<ul>\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n</li>\n</ul>"; "example_270")]
-// #[test_case("  10.  foo\n\n           bar", "<ol start=\"10\">\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n</li>\n</ol>"; "example_271")]
-// #[test_case("    indented code\n\nparagraph\n\n    more code", "<pre><code>indented code\n</code></pre>\n<p>paragraph</p>\n<pre><code>more code\n</code></pre>"; "example_272")]
-// #[test_case("1.     indented code\n\n   paragraph\n\n       more code", "<ol>\n<li>\n<pre><code>indented code\n</code></pre>\n<p>paragraph</p>\n<pre><code>more code\n</code></pre>\n</li>\n</ol>"; "example_273")]
-// #[test_case("1.      indented code\n\n   paragraph\n\n       more code", "<ol>\n<li>\n<pre><code> indented code\n</code></pre>\n<p>paragraph</p>\n<pre><code>more code\n</code></pre>\n</li>\n</ol>"; "example_274")]
-// #[test_case("   foo\n\nbar", "<p>foo</p>\n<p>bar</p>"; "example_275")]
-// #[test_case("-    foo\n\n  bar", "<ul>\n<li>foo</li>\n</ul>\n<p>bar</p>"; "example_276")]
-// #[test_case("-  foo\n\n   bar", "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>"; "example_277")]
-// #[test_case("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n      baz", "<ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>\n<pre><code>baz\n</code></pre>\n</li>\n</ul>"; "example_278")]
-// #[test_case("-   \n  foo", "<ul>\n<li>foo</li>\n</ul>"; "example_279")]
-// #[test_case("-\n\n  foo", "<ul>\n<li></li>\n</ul>\n<p>foo</p>"; "example_280")]
-// #[test_case("- foo\n-\n- bar", "<ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>"; "example_281")]
-// #[test_case("- foo\n-   \n- bar", "<ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>"; "example_282")]
-// #[test_case("1. foo\n2.\n3. bar", "<ol>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ol>"; "example_283")]
-// #[test_case("*", "<ul>\n<li></li>\n</ul>"; "example_284")]
-// #[test_case("foo\n*\n\nfoo\n1.", "<p>foo\n*</p>\n<p>foo\n1.</p>"; "example_285")]
-// #[test_case(" 1.  A paragraph\n     with two lines.\n\n         indented code\n\n     > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_286")]
-// #[test_case("  1.  A paragraph\n      with two lines.\n\n          indented code\n\n      > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_287")]
-// #[test_case("   1.  A paragraph\n       with two lines.\n\n           indented code\n\n       > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_288")]
-// #[test_case("    1.  A paragraph\n        with two lines.\n\n            indented code\n\n        > A block quote.", "<pre><code>1.  A paragraph\n    with two lines.\n\n        indented code\n\n    &gt; A block quote.\n</code></pre>"; "example_289")]
-// #[test_case("  1.  A paragraph\nwith two lines.\n\n          indented code\n\n      > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_290")]
-// #[test_case("  1.  A paragraph\n    with two lines.", "<ol>\n<li>A paragraph\nwith two lines.</li>\n</ol>"; "example_291")]
-// #[test_case("> 1. > Blockquote\ncontinued here.", "<blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>"; "example_292")]
-// #[test_case("> 1. > Blockquote\n> continued here.", "<blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>"; "example_293")]
-// #[test_case("- foo\n  - bar\n    - baz\n      - boo", "<ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz\n<ul>\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>"; "example_294")]
-// #[test_case("- foo\n - bar\n  - baz\n   - boo", "<ul>\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n<li>boo</li>\n</ul>"; "example_295")]
-// #[test_case("10) foo\n    - bar", "<ol start=\"10\">\n<li>foo\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>"; "example_296")]
-// #[test_case("10) foo\n   - bar", "<ol start=\"10\">\n<li>foo</li>\n</ol>\n<ul>\n<li>bar</li>\n</ul>"; "example_297")]
-// #[test_case("- - foo", "<ul>\n<li>\n<ul>\n<li>foo</li>\n</ul>\n</li>\n</ul>"; "example_298")]
-// #[test_case("1. - 2. foo", "<ol>\n<li>\n<ul>\n<li>\n<ol start=\"2\">\n<li>foo</li>\n</ol>\n</li>\n</ul>\n</li>\n</ol>"; "example_299")]
-// #[test_case("- # Foo\n- Bar\n  ---\n  baz", "<ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<h2>Bar</h2>\nbaz</li>\n</ul>"; "example_300")]
+#[test_case("A paragraph\nwith two lines.\n\n    indented code\n\n> A block quote.", "<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>"; "example_253")]
+#[test_case("1.  A paragraph\n    with two lines.\n\n        indented code\n\n    > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_254")]
+#[test_case("- one\n\n two", "<ul>\n<li>one</li>\n</ul>\n<p>two</p>"; "example_255")]
+#[test_case("- one\n\n  two", "<ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>"; "example_256")]
+#[test_case(" -    one\n\n     two", "<ul>\n<li>one</li>\n</ul>\n<pre><code> two\n</code></pre>"; "example_257")]
+#[test_case(" -    one\n\n      two", "<ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>"; "example_258")]
+#[test_case("   > > 1.  one\n>>\n>>     two", "<blockquote>\n<blockquote>\n<ol>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ol>\n</blockquote>\n</blockquote>"; "example_259")]
+#[test_case(">>- one\n>>\n  >  > two", "<blockquote>\n<blockquote>\n<ul>\n<li>one</li>\n</ul>\n<p>two</p>\n</blockquote>\n</blockquote>"; "example_260")]
+#[test_case("-one\n\n2.two", "<p>-one</p>\n<p>2.two</p>"; "example_261")]
+#[test_case("- foo\n\n\n  bar", "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>"; "example_262")]
+#[test_case("1.  foo\n\n    ```\n    bar\n    ```\n\n    baz\n\n    > bam", "<ol>\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n<p>baz</p>\n<blockquote>\n<p>bam</p>\n</blockquote>\n</li>\n</ol>"; "example_263")]
+#[test_case("- Foo\n\n      bar\n\n\n      baz", "<ul>\n<li>\n<p>Foo</p>\n<pre><code>bar\n\n\nbaz\n</code></pre>\n</li>\n</ul>"; "example_264")]
+#[test_case("123456789. ok", "<ol start=\"123456789\">\n<li>ok</li>\n</ol>"; "example_265")]
+#[test_case("1234567890. not ok", "<p>1234567890. not ok</p>"; "example_266")]
+#[test_case("0. ok", "<ol start=\"0\">\n<li>ok</li>\n</ol>"; "example_267")]
+#[test_case("003. ok", "<ol start=\"3\">\n<li>ok</li>\n</ol>"; "example_268")]
+#[test_case("-1. not ok", "<p>-1. not ok</p>"; "example_269")]
+#[test_case("- foo\n\n      bar", "<ul>\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n</li>\n</ul>"; "example_270")]
+#[test_case("  10.  foo\n\n           bar", "<ol start=\"10\">\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n</li>\n</ol>"; "example_271")]
+#[test_case("    indented code\n\nparagraph\n\n    more code", "<pre><code>indented code\n</code></pre>\n<p>paragraph</p>\n<pre><code>more code\n</code></pre>"; "example_272")]
+#[test_case("1.     indented code\n\n   paragraph\n\n       more code", "<ol>\n<li>\n<pre><code>indented code\n</code></pre>\n<p>paragraph</p>\n<pre><code>more code\n</code></pre>\n</li>\n</ol>"; "example_273")]
+#[test_case("1.      indented code\n\n   paragraph\n\n       more code", "<ol>\n<li>\n<pre><code> indented code\n</code></pre>\n<p>paragraph</p>\n<pre><code>more code\n</code></pre>\n</li>\n</ol>"; "example_274")]
+#[test_case("   foo\n\nbar", "<p>foo</p>\n<p>bar</p>"; "example_275")]
+#[test_case("-    foo\n\n  bar", "<ul>\n<li>foo</li>\n</ul>\n<p>bar</p>"; "example_276")]
+#[test_case("-  foo\n\n   bar", "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>"; "example_277")]
+#[test_case("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n      baz", "<ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>\n<pre><code>baz\n</code></pre>\n</li>\n</ul>"; "example_278")]
+#[test_case("-   \n  foo", "<ul>\n<li>foo</li>\n</ul>"; "example_279")]
+#[test_case("-\n\n  foo", "<ul>\n<li></li>\n</ul>\n<p>foo</p>"; "example_280")]
+#[test_case("- foo\n-\n- bar", "<ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>"; "example_281")]
+#[test_case("- foo\n-   \n- bar", "<ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>"; "example_282")]
+#[test_case("1. foo\n2.\n3. bar", "<ol>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ol>"; "example_283")]
+#[test_case("*", "<ul>\n<li></li>\n</ul>"; "example_284")]
+#[test_case("foo\n*\n\nfoo\n1.", "<p>foo\n*</p>\n<p>foo\n1.</p>"; "example_285")]
+#[test_case(" 1.  A paragraph\n     with two lines.\n\n         indented code\n\n     > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_286")]
+#[test_case("  1.  A paragraph\n      with two lines.\n\n          indented code\n\n      > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_287")]
+#[test_case("   1.  A paragraph\n       with two lines.\n\n           indented code\n\n       > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_288")]
+#[test_case("    1.  A paragraph\n        with two lines.\n\n            indented code\n\n        > A block quote.", "<pre><code>1.  A paragraph\n    with two lines.\n\n        indented code\n\n    &gt; A block quote.\n</code></pre>"; "example_289")]
+#[test_case("  1.  A paragraph\nwith two lines.\n\n          indented code\n\n      > A block quote.", "<ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>"; "example_290")]
+#[test_case("  1.  A paragraph\n    with two lines.", "<ol>\n<li>A paragraph\nwith two lines.</li>\n</ol>"; "example_291")]
+#[test_case("> 1. > Blockquote\ncontinued here.", "<blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>"; "example_292")]
+#[test_case("> 1. > Blockquote\n> continued here.", "<blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>"; "example_293")]
+#[test_case("- foo\n  - bar\n    - baz\n      - boo", "<ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz\n<ul>\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>"; "example_294")]
+#[test_case("- foo\n - bar\n  - baz\n   - boo", "<ul>\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n<li>boo</li>\n</ul>"; "example_295")]
+#[test_case("10) foo\n    - bar", "<ol start=\"10\">\n<li>foo\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>"; "example_296")]
+#[test_case("10) foo\n   - bar", "<ol start=\"10\">\n<li>foo</li>\n</ol>\n<ul>\n<li>bar</li>\n</ul>"; "example_297")]
+#[test_case("- - foo", "<ul>\n<li>\n<ul>\n<li>foo</li>\n</ul>\n</li>\n</ul>"; "example_298")]
+#[test_case("1. - 2. foo", "<ol>\n<li>\n<ul>\n<li>\n<ol start=\"2\">\n<li>foo</li>\n</ol>\n</li>\n</ul>\n</li>\n</ol>"; "example_299")]
+#[test_case("- # Foo\n- Bar\n  ---\n  baz", "<ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<h2>Bar</h2>\nbaz</li>\n</ul>"; "example_300")]
 fn list_items(input: &str, output: &str) {
     run_spec_test(input, output);
 }
 
-#[allow(unused)]
-// #[test_case("- foo\n- bar\n+ baz", "<ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<ul>\n<li>baz</li>\n</ul>"; "example_301")]
-// #[test_case("1. foo\n2. bar\n3) baz", "<ol>\n<li>foo</li>\n<li>bar</li>\n</ol>\n<ol start=\"3\">\n<li>baz</li>\n</ol>"; "example_302")]
-// #[test_case("Foo\n- bar\n- baz", "<p>Foo</p>\n<ul>\n<li>bar</li>\n<li>baz</li>\n</ul>"; "example_303")]
-// #[test_case("The number of windows in my house is\n14.  The number of doors is 6.", "<p>The number of windows in my house is\n14.  The number of doors is 6.</p>"; "example_304")]
-// #[test_case("The number of windows in my house is\n1.  The number of doors is 6.", "<p>The number of windows in my house is</p>\n<ol>\n<li>The number of doors is 6.</li>\n</ol>"; "example_305")]
-// #[test_case("- foo\n\n- bar\n\n\n- baz", "<ul>\n<li>\n<p>foo</p>\n</li>\n<li>\n<p>bar</p>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>"; "example_306")]
-// #[test_case("- foo\n  - bar\n    - baz\n\n\n      bim", "<ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>\n<p>baz</p>\n<p>bim</p>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>"; "example_307")]
+#[test_case("- foo\n- bar\n+ baz", "<ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<ul>\n<li>baz</li>\n</ul>"; "example_301")]
+#[test_case("1. foo\n2. bar\n3) baz", "<ol>\n<li>foo</li>\n<li>bar</li>\n</ol>\n<ol start=\"3\">\n<li>baz</li>\n</ol>"; "example_302")]
+#[test_case("Foo\n- bar\n- baz", "<p>Foo</p>\n<ul>\n<li>bar</li>\n<li>baz</li>\n</ul>"; "example_303")]
+#[test_case("The number of windows in my house is\n14.  The number of doors is 6.", "<p>The number of windows in my house is\n14.  The number of doors is 6.</p>"; "example_304")]
+#[test_case("The number of windows in my house is\n1.  The number of doors is 6.", "<p>The number of windows in my house is</p>\n<ol>\n<li>The number of doors is 6.</li>\n</ol>"; "example_305")]
+#[test_case("- foo\n\n- bar\n\n\n- baz", "<ul>\n<li>\n<p>foo</p>\n</li>\n<li>\n<p>bar</p>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>"; "example_306")]
+#[test_case("- foo\n  - bar\n    - baz\n\n\n      bim", "<ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>\n<p>baz</p>\n<p>bim</p>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>"; "example_307")]
 // #[test_case("- foo\n- bar\n\n<!-- -->\n\n- baz\n- bim", "<ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<!-- -->\n<ul>\n<li>baz</li>\n<li>bim</li>\n</ul>"; "example_308")]
 // #[test_case("-   foo\n\n    notcode\n\n-   foo\n\n<!-- -->\n\n    code", "<ul>\n<li>\n<p>foo</p>\n<p>notcode</p>\n</li>\n<li>\n<p>foo</p>\n</li>\n</ul>\n<!-- -->\n<pre><code>code\n</code></pre>"; "example_309")]
-// #[test_case("- a\n - b\n  - c\n   - d\n  - e\n - f\n- g", "<ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d</li>\n<li>e</li>\n<li>f</li>\n<li>g</li>\n</ul>"; "example_310")]
-// #[test_case("1. a\n\n  2. b\n\n   3. c", "<ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ol>"; "example_311")]
-// #[test_case("- a\n - b\n  - c\n   - d\n    - e", "<ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d\n- e</li>\n</ul>"; "example_312")]
-// #[test_case("1. a\n\n  2. b\n\n    3. c", "<ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ol>\n<pre><code>3. c\n</code></pre>"; "example_313")]
-// #[test_case("- a\n- b\n\n- c", "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>"; "example_314")]
-// #[test_case("* a\n*\n\n* c", "<ul>\n<li>\n<p>a</p>\n</li>\n<li></li>\n<li>\n<p>c</p>\n</li>\n</ul>"; "example_315")]
-// #[test_case("- a\n- b\n\n  c\n- d", "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>"; "example_316")]
+#[test_case("- a\n - b\n  - c\n   - d\n  - e\n - f\n- g", "<ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d</li>\n<li>e</li>\n<li>f</li>\n<li>g</li>\n</ul>"; "example_310")]
+#[test_case("1. a\n\n  2. b\n\n   3. c", "<ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ol>"; "example_311")]
+#[test_case("- a\n - b\n  - c\n   - d\n    - e", "<ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d\n- e</li>\n</ul>"; "example_312")]
+#[test_case("1. a\n\n  2. b\n\n    3. c", "<ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ol>\n<pre><code>3. c\n</code></pre>"; "example_313")]
+#[test_case("- a\n- b\n\n- c", "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>"; "example_314")]
+#[test_case("* a\n*\n\n* c", "<ul>\n<li>\n<p>a</p>\n</li>\n<li></li>\n<li>\n<p>c</p>\n</li>\n</ul>"; "example_315")]
+#[test_case("- a\n- b\n\n  c\n- d", "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>"; "example_316")]
 // #[test_case("- a\n- b\n\n  [ref]: /url\n- d", "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>"; "example_317")]
-// #[test_case("- a\n- ```\n  b\n\n\n  ```\n- c", "<ul>\n<li>a</li>\n<li>\n<pre><code>b\n\n\n</code></pre>\n</li>\n<li>c</li>\n</ul>"; "example_318")]
-// #[test_case("- a\n  - b\n\n    c\n- d", "<ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>"; "example_319")]
+#[test_case("- a\n- ```\n  b\n\n\n  ```\n- c", "<ul>\n<li>a</li>\n<li>\n<pre><code>b\n\n\n</code></pre>\n</li>\n<li>c</li>\n</ul>"; "example_318")]
+#[test_case("- a\n  - b\n\n    c\n- d", "<ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>"; "example_319")]
 // #[test_case("* a\n  > b\n  >\n* c", "<ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>"; "example_320")]
-// #[test_case("- a\n  > b\n  ```\n  c\n  ```\n- d", "<ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n<pre><code>c\n</code></pre>\n</li>\n<li>d</li>\n</ul>"; "example_321")]
-// #[test_case("- a", "<ul>\n<li>a</li>\n</ul>"; "example_322")]
-// #[test_case("- a\n  - b", "<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>"; "example_323")]
-// #[test_case("1. ```\n   foo\n   ```\n\n   bar", "<ol>\n<li>\n<pre><code>foo\n</code></pre>\n<p>bar</p>\n</li>\n</ol>"; "example_324")]
-// #[test_case("* foo\n  * bar\n\n  baz", "<ul>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n<p>baz</p>\n</li>\n</ul>"; "example_325")]
-// #[test_case("- a\n  - b\n  - c\n\n- d\n  - e\n  - f", "<ul>\n<li>\n<p>a</p>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n<li>\n<p>d</p>\n<ul>\n<li>e</li>\n<li>f</li>\n</ul>\n</li>\n</ul>"; "example_326")]
+#[test_case("- a\n  > b\n  ```\n  c\n  ```\n- d", "<ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n<pre><code>c\n</code></pre>\n</li>\n<li>d</li>\n</ul>"; "example_321")]
+#[test_case("- a", "<ul>\n<li>a</li>\n</ul>"; "example_322")]
+#[test_case("- a\n  - b", "<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>"; "example_323")]
+#[test_case("1. ```\n   foo\n   ```\n\n   bar", "<ol>\n<li>\n<pre><code>foo\n</code></pre>\n<p>bar</p>\n</li>\n</ol>"; "example_324")]
+#[test_case("* foo\n  * bar\n\n  baz", "<ul>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n<p>baz</p>\n</li>\n</ul>"; "example_325")]
+#[test_case("- a\n  - b\n  - c\n\n- d\n  - e\n  - f", "<ul>\n<li>\n<p>a</p>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n<li>\n<p>d</p>\n<ul>\n<li>e</li>\n<li>f</li>\n</ul>\n</li>\n</ul>"; "example_326")]
 fn lists(input: &str, output: &str) {
     run_spec_test(input, output);
 }
@@ -699,16 +696,16 @@ fn raw_html(input: &str, output: &str) {
 #[test_case("Foo  \n-----", "<h2>Foo</h2>"; "example_89")]
 #[test_case("Foo\\\n----", "<h2>Foo\\</h2>"; "example_90")]
 // #[test_case("`Foo\n----\n`\n\n<a title=\"a lot\n---\nof dashes\"/>", "<h2>`Foo</h2>\n<p>`</p>\n<h2>&lt;a title=&quot;a lot</h2>\n<p>of dashes&quot;/&gt;</p>"; "example_91")]
-// #[test_case("> Foo\n---", "<blockquote>\n<p>Foo</p>\n</blockquote>\n<hr />"; "example_92")]
+#[test_case("> Foo\n---", "<blockquote>\n<p>Foo</p>\n</blockquote>\n<hr />"; "example_92")]
 // #[test_case("> foo\nbar\n===", "<blockquote>\n<p>foo\nbar\n===</p>\n</blockquote>"; "example_93")]
-// #[test_case("- Foo\n---", "<ul>\n<li>Foo</li>\n</ul>\n<hr />"; "example_94")]
+#[test_case("- Foo\n---", "<ul>\n<li>Foo</li>\n</ul>\n<hr />"; "example_94")]
 #[test_case("Foo\nBar\n---", "<h2>Foo\nBar</h2>"; "example_95")]
 #[test_case("---\nFoo\n---\nBar\n---\nBaz", "<hr />\n<h2>Foo</h2>\n<h2>Bar</h2>\n<p>Baz</p>"; "example_96")]
 #[test_case("\n====", "<p>====</p>"; "example_97")]
 #[test_case("---\n---", "<hr />\n<hr />"; "example_98")]
-// #[test_case("- foo\n-----", "<ul>\n<li>foo</li>\n</ul>\n<hr />"; "example_99")]
+#[test_case("- foo\n-----", "<ul>\n<li>foo</li>\n</ul>\n<hr />"; "example_99")]
 #[test_case("    foo\n---", "<pre><code>foo\n</code></pre>\n<hr />"; "example_100")]
-// #[test_case("> foo\n-----", "<blockquote>\n<p>foo</p>\n</blockquote>\n<hr />"; "example_101")]
+#[test_case("> foo\n-----", "<blockquote>\n<p>foo</p>\n</blockquote>\n<hr />"; "example_101")]
 #[test_case("\\> foo\n------", "<h2>&gt; foo</h2>"; "example_102")]
 #[test_case("Foo\n\nbar\n---\nbaz", "<p>Foo</p>\n<h2>bar</h2>\n<p>baz</p>"; "example_103")]
 #[test_case("Foo\nbar\n\n---\n\nbaz", "<p>Foo\nbar</p>\n<hr />\n<p>baz</p>"; "example_104")]
@@ -727,7 +724,7 @@ fn soft_line_breaks(input: &str, output: &str) {
 #[test_case("\tfoo\tbaz\t\tbim", "<pre><code>foo\tbaz\t\tbim\n</code></pre>"; "example_1")]
 #[test_case("  \tfoo\tbaz\t\tbim", "<pre><code>foo\tbaz\t\tbim\n</code></pre>"; "example_2")]
 #[test_case("    a\ta\n    \u{1f50}\ta", "<pre><code>a\ta\n\u{1f50}\ta\n</code></pre>"; "example_3")]
-// #[test_case("  - foo\n\n\tbar", "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>"; "example_4")]
+#[test_case("  - foo\n\n\tbar", "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>"; "example_4")]
 // #[test_case("- foo\n\n\t\tbar", "<ul>\n<li>\n<p>foo</p>\n<pre><code>  bar\n</code></pre>\n</li>\n</ul>"; "example_5")]
 // #[test_case(">\t\tfoo", "<blockquote>\n<pre><code>  foo\n</code></pre>\n</blockquote>"; "example_6")]
 // #[test_case("-\t\tfoo", "<ul>\n<li>\n<pre><code>  foo\n</code></pre>\n</li>\n</ul>"; "example_7")]
@@ -760,11 +757,11 @@ fn textual_content(input: &str, output: &str) {
 #[test_case("- - - -    ", "<hr />"; "example_54")]
 #[test_case("_ _ _ _ a\n\na------\n\n---a---", "<p>_ _ _ _ a</p>\n<p>a------</p>\n<p>---a---</p>"; "example_55")]
 #[test_case(" *-*", "<p><em>-</em></p>"; "example_56")]
-// #[test_case("- foo\n***\n- bar", "<ul>\n<li>foo</li>\n</ul>\n<hr />\n<ul>\n<li>bar</li>\n</ul>"; "example_57")]
+#[test_case("- foo\n***\n- bar", "<ul>\n<li>foo</li>\n</ul>\n<hr />\n<ul>\n<li>bar</li>\n</ul>"; "example_57")]
 #[test_case("Foo\n***\nbar", "<p>Foo</p>\n<hr />\n<p>bar</p>"; "example_58")]
 #[test_case("Foo\n---\nbar", "<h2>Foo</h2>\n<p>bar</p>"; "example_59")]
-// #[test_case("* Foo\n* * *\n* Bar", "<ul>\n<li>Foo</li>\n</ul>\n<hr />\n<ul>\n<li>Bar</li>\n</ul>"; "example_60")]
-// #[test_case("- Foo\n- * * *", "<ul>\n<li>Foo</li>\n<li>\n<hr />\n</li>\n</ul>"; "example_61")]
+#[test_case("* Foo\n* * *\n* Bar", "<ul>\n<li>Foo</li>\n</ul>\n<hr />\n<ul>\n<li>Bar</li>\n</ul>"; "example_60")]
+#[test_case("- Foo\n- * * *", "<ul>\n<li>Foo</li>\n<li>\n<hr />\n</li>\n</ul>"; "example_61")]
 fn thematic_breaks(input: &str, output: &str) {
     run_spec_test(input, output);
 }
@@ -0,0 +1,142 @@
+//! Tests for formatting message source text from the CST, which normalizes ICU expressions while
+//! keeping everything else exactly as the author wrote it.
+
+use intl_markdown::format_message_source;
+
+/// Format the input and check that it matches the expected output, and that formatting the output
+/// again doesn't change it.
+fn assert_formats(input: &str, expected: &str, include_blocks: bool) {
+    let output = format_message_source(input, include_blocks).unwrap();
+    assert_eq!(expected, output);
+
+    let reformatted = format_message_source(&output, include_blocks).unwrap();
+    assert_eq!(output, reformatted, "formatting is not idempotent");
+}
+
+#[test]
+fn plain_text_is_unchanged() {
+    assert_formats("", "", false);
+    assert_formats("hello world", "hello world", false);
+    assert_formats(
+        "  leading and trailing  ",
+        "  leading and trailing  ",
+        false,
+    );
+}
+
+#[test]
+fn markdown_syntax_is_preserved() {
+    assert_formats(
+        "__strong__ and *emphasis* with a [link](./foo 'title') and `{code}`",
+        "__strong__ and *emphasis* with a [link](./foo 'title') and `{code}`",
+        false,
+    );
+    assert_formats(
+        "Heading\n===\n\n* one\n* two\n\n```\n{ not, icu }\n```",
+        "Heading\n===\n\n* one\n* two\n\n```\n{ not, icu }\n```",
+        true,
+    );
+}
+
+#[test]
+fn simple_arguments() {
+    assert_formats("hello {  name }!", "hello {name}!", false);
+    assert_formats("{ when ,date }", "{when, date}", false);
+    assert_formats("{when,time,  short }", "{when, time, short}", false);
+    assert_formats(
+        "{amount,number,::currency/USD }",
+        "{amount, number, ::currency/USD}",
+        false,
+    );
+    assert_formats("!!{ unsafe }!!", "!!{unsafe}!!", false);
+}
+
+#[test]
+fn single_line_plurals() {
+    assert_formats(
+        "{count,plural,one{# item}   other {# items}}",
+        "{count, plural, one {# item} other {# items}}",
+        false,
+    );
+    assert_formats(
+        "{count ,plural,  offset:1   =0 {nobody} other {# others}}",
+        "{count, plural, offset:1 =0 {nobody} other {# others}}",
+        false,
+    );
+    assert_formats(
+        "{gender,select,male {he}other{they}}",
+        "{gender, select, male {he} other {they}}",
+        false,
+    );
+}
+
+#[test]
+fn arm_content_is_preserved() {
+    assert_formats(
+        "{count, plural, one { **one**  item } other {{ count } items}}",
+        "{count, plural, one { **one**  item } other {{count} items}}",
+        false,
+    );
+}
+
+#[test]
+fn multiline_plurals() {
+    assert_formats(
+        "{count, plural,\none {# item}\n      other {# items}}",
+        "{count, plural,\n  one {# item}\n  other {# items}\n}",
+        false,
+    );
+    assert_formats(
+        "You have {count, plural, offset:1\n    one {# item}  other {# items}\n}",
+        "You have {count, plural, offset:1\n  one {# item}\n  other {# items}\n}",
+        false,
+    );
+}
+
+#[test]
+fn nested_plurals_are_indented() {
+    assert_formats(
+        "{gender, select,\nmale {{count, plural,\none {# item}\nother {# items}}}\nother {nothing}}",
+        "{gender, select,\n  male {{count, plural,\n    one {# item}\n    other {# items}\n  }}\n  other {nothing}\n}",
+        false,
+    );
+    // Nested expressions written on one line stay on one line.
+    assert_formats(
+        "{gender, select,\nmale {{count,plural,one {#} other {#}}}\nother {nothing}}",
+        "{gender, select,\n  male {{count, plural, one {#} other {#}}}\n  other {nothing}\n}",
+        false,
+    );
+}
+
+#[test]
+fn indentation_follows_the_containing_line() {
+    assert_formats(
+        "* item\n\n  {count, plural,\n  one {#}\n  other {#}}",
+        "* item\n\n  {count, plural,\n    one {#}\n    other {#}\n  }",
+        true,
+    );
+}
+
+#[test]
+fn expressions_in_markdown() {
+    assert_formats(
+        "# Hello { name }\n\n[link]({ url }) and $[**{ count ,number}**](hook)",
+        "# Hello {name}\n\n[link]({url}) and $[**{count, number}**](hook)",
+        true,
+    );
+    assert_formats(
+        "| a | b |\n| --- | :-: |\n| { x } | {n,plural,one {#} other {#}} |",
+        "| a | b |\n| --- | :-: |\n| {x} | {n, plural, one {#} other {#}} |",
+        true,
+    );
+}
+
+#[test]
+fn block_prefixes_are_left_as_written() {
+    // The `>` prefixes are part of the source between the arms, so the expression is kept as-is.
+    assert_formats(
+        "> {count, plural,\n> one {#}\n> other {#}}",
+        "> {count, plural,\n> one {#}\n> other {#}}",
+        true,
+    );
+}
@@ -0,0 +1,163 @@
+//! Tests for the syntax errors that the parser recovers from, which are reported as diagnostics
+//! on the parsed Document rather than failing the parse.
+
+use intl_markdown::{parse_intl_message, SyntaxDiagnosticKind};
+
+/// Parse the input and return the kind of each diagnostic along with the source text it covers.
+fn diagnostics(input: &str) -> Vec<(SyntaxDiagnosticKind, &str)> {
+    parse_intl_message(input, false)
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| (diagnostic.kind(), &input[diagnostic.span().clone()]))
+        .collect()
+}
+
+#[test]
+fn valid_messages() {
+    assert!(diagnostics("hello {name}").is_empty());
+    assert!(diagnostics("{count, plural, one {# item} other {# items}}").is_empty());
+    assert!(diagnostics("$[text](someHook) and !!{unsafe}!!").is_empty());
+    assert!(diagnostics("[a link]({url})").is_empty());
+}
+
+#[test]
+fn plain_text_braces() {
+    // Braces that never start an ICU placeholder are just text.
+    assert!(diagnostics("{}").is_empty());
+    assert!(diagnostics("a { 1 } b").is_empty());
+    assert!(diagnostics("$[not a hook] at all").is_empty());
+}
+
+#[test]
+fn unclosed_placeholders() {
+    assert_eq!(
+        diagnostics("hello {name"),
+        vec![(SyntaxDiagnosticKind::UnclosedIcuPlaceholder, "{name")]
+    );
+    assert_eq!(
+        diagnostics("{count, plural, one {x}"),
+        vec![(
+            SyntaxDiagnosticKind::UnclosedIcuPlaceholder,
+            "{count, plural, one {x}"
+        )]
+    );
+}
+
+#[test]
+fn unclosed_at_end_of_input() {
+    // These all end in the middle of ICU-specific tokens, which must not run past the input.
+    for input in [
+        "{name, number, 'quoted",
+        "{name, number, ::currency",
+        "{n, plural, =",
+        "{n",
+    ] {
+        let diagnostics = diagnostics(input);
+        assert_eq!(diagnostics.len(), 1, "{input}");
+        assert_eq!(
+            diagnostics[0].0,
+            SyntaxDiagnosticKind::UnclosedIcuPlaceholder,
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn unclosed_unsafe_placeholders() {
+    assert_eq!(
+        diagnostics("hello !!{name}"),
+        vec![(SyntaxDiagnosticKind::UnclosedUnsafePlaceholder, "!!{name")]
+    );
+}
+
+#[test]
+fn unknown_format() {
+    assert_eq!(
+        diagnostics("{name, color}"),
+        vec![(SyntaxDiagnosticKind::UnknownIcuFormat, "color")]
+    );
+}
+
+#[test]
+fn plural_arms() {
+    assert_eq!(
+        diagnostics("{count, plural}"),
+        vec![(SyntaxDiagnosticKind::ExpectedPluralOptions, "plural")]
+    );
+    assert_eq!(
+        diagnostics("{count, plural, one}"),
+        vec![(SyntaxDiagnosticKind::InvalidPluralArm, "one")]
+    );
+    assert_eq!(
+        diagnostics("{count, plural, one {x"),
+        vec![(SyntaxDiagnosticKind::UnterminatedPluralArm, "{x")]
+    );
+}
+
+#[test]
+fn plural_offsets() {
+    assert!(diagnostics("{count, plural, offset:1 other {#}}").is_empty());
+    assert_eq!(
+        diagnostics("{count, plural, offset:x other {#}}"),
+        vec![(SyntaxDiagnosticKind::InvalidPluralOffset, "offset:x")]
+    );
+    assert_eq!(
+        diagnostics("{count, plural, offset: other {#}}"),
+        vec![(SyntaxDiagnosticKind::InvalidPluralOffset, "offset: other")]
+    );
+}
+
+#[test]
+fn invalid_hook_names() {
+    assert_eq!(
+        diagnostics("$[inner]({target})"),
+        vec![(SyntaxDiagnosticKind::InvalidHookName, "({")]
+    );
+}
+
+#[test]
+fn nested_errors_are_reported_once() {
+    // The link fails because of the unclosed placeholder, so the content is parsed again as text,
+    // but the placeholder should still only be reported a single time.
+    assert_eq!(
+        diagnostics("[a link]({url)"),
+        vec![(SyntaxDiagnosticKind::UnclosedIcuPlaceholder, "{url")]
+    );
+}
+
+#[test]
+fn number_skeletons() {
+    assert!(diagnostics("{n, number, ::currency/EUR .00} {n, number, percent}").is_empty());
+    assert_eq!(
+        diagnostics("{n, number,  :: currency/EUR fancy .00/x}"),
+        vec![
+            (SyntaxDiagnosticKind::UnknownNumberSkeletonToken, "fancy"),
+            (SyntaxDiagnosticKind::InvalidNumberSkeletonOptions, ".00/x"),
+        ]
+    );
+    assert_eq!(
+        diagnostics("{a, number, currency/EUR/USD} {b, number, ::percent/x}"),
+        vec![
+            (
+                SyntaxDiagnosticKind::InvalidNumberSkeletonOptions,
+                "currency/EUR/USD"
+            ),
+            (
+                SyntaxDiagnosticKind::InvalidNumberSkeletonOptions,
+                "percent/x"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn date_time_skeletons() {
+    assert!(diagnostics("{d, date, ::yyyyMMMd} {t, time, short}").is_empty());
+    assert_eq!(
+        diagnostics("{d, date, ::yyyyMMMMMMd} {t, time, ::hh 'Q' QQ}"),
+        vec![
+            (SyntaxDiagnosticKind::InvalidDateTimeSkeletonField, "MMMMMM"),
+            (SyntaxDiagnosticKind::UnsupportedDateTimeSkeletonField, "QQ"),
+        ]
+    );
+}
@@ -1,11 +1,22 @@
 use intl_markdown::Document;
 
+pub use crate::visit_mut_with::VisitMutWith;
 pub use crate::visit_with::VisitWith;
 pub use crate::visitor::Visit;
+pub use crate::visitor_mut::VisitMut;
 
+mod visit_mut_with;
 mod visit_with;
 mod visitor;
+mod visitor_mut;
 
+/// Visit every node of the document without modifying it. See [visit_mut] for rewriting a
+/// document in place.
 pub fn visit_with_mut<V: Visit>(document: &Document, visitor: &mut V) {
     document.visit_with(visitor);
 }
+
+/// Visit every node of the document with mutable access, allowing the visitor to rewrite it.
+pub fn visit_mut<V: VisitMut>(document: &mut Document, visitor: &mut V) {
+    document.visit_mut_with(visitor);
+}
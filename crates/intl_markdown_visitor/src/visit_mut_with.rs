@@ -0,0 +1,352 @@
+use intl_markdown::{
+    BlockNode, BlockQuote, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
+    IcuDateTimeStyle, IcuNumber, IcuNumberStyle, IcuPlural, IcuPluralArm, IcuSelect, IcuTime,
+    IcuVariable, InlineContent, Link, LinkDestination, List, ListItem, Paragraph, Strikethrough,
+    Strong, Table, TableCell, TableRow,
+};
+
+use crate::visitor_mut::VisitMut;
+
+pub trait VisitMutWith<V: ?Sized + VisitMut> {
+    fn visit_mut_with(&mut self, visitor: &mut V);
+    fn visit_mut_children_with(&mut self, visitor: &mut V);
+}
+
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<BlockNode> {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_block_nodes(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        for node in self {
+            node.visit_mut_with(visitor);
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<InlineContent> {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_inline_contents(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        for node in self {
+            node.visit_mut_with(visitor);
+        }
+    }
+}
+
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for BlockNode {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_block_node(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            BlockNode::Paragraph(paragraph) => paragraph.visit_mut_with(visitor),
+            BlockNode::Heading(heading) => heading.visit_mut_with(visitor),
+            BlockNode::CodeBlock(code_block) => code_block.visit_mut_with(visitor),
+            BlockNode::ThematicBreak => visitor.visit_mut_thematic_break(),
+            BlockNode::InlineContent(inline_content) => inline_content.visit_mut_with(visitor),
+            BlockNode::BlockQuote(block_quote) => block_quote.visit_mut_with(visitor),
+            BlockNode::List(list) => list.visit_mut_with(visitor),
+            BlockNode::Table(table) => table.visit_mut_with(visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for BlockQuote {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_block_quote(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for CodeBlock {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_code_block(self);
+    }
+
+    fn visit_mut_children_with(&mut self, _visitor: &mut V) {
+        // No children
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for CodeSpan {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_code_span(self);
+    }
+
+    fn visit_mut_children_with(&mut self, _visitor: &mut V) {
+        // No children
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Document {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_document(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.blocks_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Emphasis {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_emphasis(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Heading {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_heading(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Hook {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_hook(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Icu {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            Icu::IcuVariable(variable) => variable.visit_mut_with(visitor),
+            Icu::IcuPlural(plural) => plural.visit_mut_with(visitor),
+            Icu::IcuSelect(select) => select.visit_mut_with(visitor),
+            Icu::IcuDate(date) => date.visit_mut_with(visitor),
+            Icu::IcuTime(time) => time.visit_mut_with(visitor),
+            Icu::IcuNumber(number) => number.visit_mut_with(visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for IcuDate {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu_date(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.variable_mut().visit_mut_with(visitor);
+        if let Some(style) = self.style_mut() {
+            style.visit_mut_with(visitor);
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for IcuDateTimeStyle {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu_date_time_style(self);
+    }
+
+    fn visit_mut_children_with(&mut self, _visitor: &mut V) {
+        // No children
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for IcuNumber {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu_number(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.variable_mut().visit_mut_with(visitor);
+        if let Some(style) = self.style_mut() {
+            style.visit_mut_with(visitor);
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for IcuNumberStyle {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu_number_style(self);
+    }
+
+    fn visit_mut_children_with(&mut self, _visitor: &mut V) {
+        // No children
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for IcuPlural {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu_plural(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.variable_mut().visit_mut_with(visitor);
+        for arm in self.arms_mut() {
+            arm.visit_mut_with(visitor);
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for IcuPluralArm {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu_plural_arm(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for IcuSelect {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu_select(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.variable_mut().visit_mut_with(visitor);
+        for arm in self.arms_mut() {
+            arm.visit_mut_with(visitor);
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for IcuTime {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu_time(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.variable_mut().visit_mut_with(visitor);
+        if let Some(style) = self.style_mut() {
+            style.visit_mut_with(visitor);
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for IcuVariable {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_icu_variable(self);
+    }
+
+    fn visit_mut_children_with(&mut self, _visitor: &mut V) {
+        // No children
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for InlineContent {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_inline_content(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            InlineContent::Text(text) => visitor.visit_mut_text(text),
+            InlineContent::Emphasis(emphasis) => emphasis.visit_mut_with(visitor),
+            InlineContent::Strong(strong) => strong.visit_mut_with(visitor),
+            InlineContent::Link(link) => link.visit_mut_with(visitor),
+            InlineContent::CodeSpan(code_span) => code_span.visit_mut_with(visitor),
+            InlineContent::HardLineBreak => visitor.visit_mut_hard_line_break(),
+            InlineContent::Hook(hook) => hook.visit_mut_with(visitor),
+            InlineContent::Strikethrough(strikethrough) => strikethrough.visit_mut_with(visitor),
+            InlineContent::Icu(icu) => icu.visit_mut_with(visitor),
+            InlineContent::IcuPound => visitor.visit_mut_icu_pound(),
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Link {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_link(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.label_mut().visit_mut_with(visitor);
+        self.destination_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for List {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_list(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        for item in self.items_mut() {
+            item.visit_mut_with(visitor);
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for ListItem {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_list_item(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Table {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_table(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.header_mut().visit_mut_with(visitor);
+        for row in self.rows_mut() {
+            row.visit_mut_with(visitor);
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for TableRow {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_table_row(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        for cell in self.cells_mut() {
+            cell.visit_mut_with(visitor);
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for TableCell {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_table_cell(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Paragraph {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_paragraph(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Strikethrough {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_strikethrough(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Strong {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_strong(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.content_mut().visit_mut_with(visitor);
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for LinkDestination {
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        visitor.visit_mut_link_destination(self);
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        // Only placeholders need to be visited, since Text and Handler are both just static
+        // strings.
+        if let LinkDestination::Placeholder(placeholder) = self {
+            placeholder.visit_mut_with(visitor);
+        }
+    }
+}
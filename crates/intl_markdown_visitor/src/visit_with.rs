@@ -1,7 +1,8 @@
 use intl_markdown::{
-    BlockNode, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
+    BlockNode, BlockQuote, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
     IcuDateTimeStyle, IcuNumber, IcuNumberStyle, IcuPlural, IcuPluralArm, IcuSelect, IcuTime,
-    IcuVariable, InlineContent, Link, LinkDestination, Paragraph, Strikethrough, Strong,
+    IcuVariable, InlineContent, Link, LinkDestination, List, ListItem, Paragraph, Strikethrough,
+    Strong, Table, TableCell, TableRow,
 };
 
 use crate::visitor::Visit;
@@ -30,9 +31,21 @@ impl<V: ?Sized + Visit> VisitWith<V> for BlockNode {
             BlockNode::CodeBlock(code_block) => code_block.visit_with(visitor),
             BlockNode::ThematicBreak => visitor.visit_thematic_break(),
             BlockNode::InlineContent(inline_content) => visit_list(&inline_content, visitor),
+            BlockNode::BlockQuote(block_quote) => block_quote.visit_with(visitor),
+            BlockNode::List(list) => list.visit_with(visitor),
+            BlockNode::Table(table) => table.visit_with(visitor),
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for BlockQuote {
+    fn visit_with(&self, visitor: &mut V) {
+        visitor.visit_block_quote(self);
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        visit_list(self.content(), visitor);
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for CodeBlock {
     fn visit_with(&self, visitor: &mut V) {
         visitor.visit_code_block(self);
@@ -226,6 +239,52 @@ impl<V: ?Sized + Visit> VisitWith<V> for Link {
         visitor.visit_link_destination(self.destination());
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for List {
+    fn visit_with(&self, visitor: &mut V) {
+        visitor.visit_list(self);
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        visit_list(self.items(), visitor);
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for ListItem {
+    fn visit_with(&self, visitor: &mut V) {
+        visitor.visit_list_item(self);
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        visit_list(self.content(), visitor);
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for Table {
+    fn visit_with(&self, visitor: &mut V) {
+        visitor.visit_table(self);
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        self.header().visit_with(visitor);
+        visit_list(self.rows(), visitor);
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for TableRow {
+    fn visit_with(&self, visitor: &mut V) {
+        visitor.visit_table_row(self);
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        visit_list(self.cells(), visitor);
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for TableCell {
+    fn visit_with(&self, visitor: &mut V) {
+        visitor.visit_table_cell(self);
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        visit_list(self.content(), visitor);
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for Paragraph {
     fn visit_with(&self, visitor: &mut V) {
         visitor.visit_paragraph(self);
@@ -1,7 +1,8 @@
 use intl_markdown::{
-    BlockNode, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
+    BlockNode, BlockQuote, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
     IcuDateTimeStyle, IcuNumber, IcuNumberStyle, IcuPlural, IcuPluralArm, IcuSelect, IcuTime,
-    IcuVariable, InlineContent, Link, LinkDestination, Paragraph, Strikethrough, Strong,
+    IcuVariable, InlineContent, Link, LinkDestination, List, ListItem, Paragraph, Strikethrough,
+    Strong, Table, TableCell, TableRow,
 };
 
 use crate::visit_with::VisitWith;
@@ -10,6 +11,9 @@ pub trait Visit {
     fn visit_block_node(&mut self, node: &BlockNode) {
         node.visit_children_with(self);
     }
+    fn visit_block_quote(&mut self, node: &BlockQuote) {
+        node.visit_children_with(self);
+    }
     fn visit_code_block(&mut self, node: &CodeBlock) {
         node.visit_children_with(self);
     }
@@ -67,6 +71,12 @@ pub trait Visit {
     fn visit_link_destination(&mut self, node: &LinkDestination) {
         node.visit_children_with(self);
     }
+    fn visit_list(&mut self, node: &List) {
+        node.visit_children_with(self);
+    }
+    fn visit_list_item(&mut self, node: &ListItem) {
+        node.visit_children_with(self);
+    }
     fn visit_paragraph(&mut self, node: &Paragraph) {
         node.visit_children_with(self);
     }
@@ -76,6 +86,15 @@ pub trait Visit {
     fn visit_strong(&mut self, node: &Strong) {
         node.visit_children_with(self);
     }
+    fn visit_table(&mut self, node: &Table) {
+        node.visit_children_with(self);
+    }
+    fn visit_table_cell(&mut self, node: &TableCell) {
+        node.visit_children_with(self);
+    }
+    fn visit_table_row(&mut self, node: &TableRow) {
+        node.visit_children_with(self);
+    }
     fn visit_text(&mut self, _node: &String) {
         // Not a node type, just visible text of the message.
     }
@@ -0,0 +1,117 @@
+use intl_markdown::{
+    BlockNode, BlockQuote, CodeBlock, CodeSpan, Document, Emphasis, Heading, Hook, Icu, IcuDate,
+    IcuDateTimeStyle, IcuNumber, IcuNumberStyle, IcuPlural, IcuPluralArm, IcuSelect, IcuTime,
+    IcuVariable, InlineContent, Link, LinkDestination, List, ListItem, Paragraph, Strikethrough,
+    Strong, Table, TableCell, TableRow,
+};
+
+use crate::visit_mut_with::VisitMutWith;
+
+/// Like [crate::Visit], but with mutable access to every node, allowing the visitor to rewrite
+/// the Document in place. Nodes can be replaced entirely by assigning to the given reference.
+///
+/// The `visit_mut_block_nodes` and `visit_mut_inline_contents` methods are called with each list
+/// of children before its items are visited, which allows visitors to add or remove nodes, like
+/// replacing a node with its own content.
+pub trait VisitMut {
+    fn visit_mut_block_node(&mut self, node: &mut BlockNode) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_block_nodes(&mut self, nodes: &mut Vec<BlockNode>) {
+        nodes.visit_mut_children_with(self);
+    }
+    fn visit_mut_block_quote(&mut self, node: &mut BlockQuote) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_code_block(&mut self, node: &mut CodeBlock) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_code_span(&mut self, node: &mut CodeSpan) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_document(&mut self, node: &mut Document) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_emphasis(&mut self, node: &mut Emphasis) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_heading(&mut self, node: &mut Heading) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_hook(&mut self, node: &mut Hook) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu(&mut self, node: &mut Icu) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu_date(&mut self, node: &mut IcuDate) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu_date_time_style(&mut self, node: &mut IcuDateTimeStyle) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu_number(&mut self, node: &mut IcuNumber) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu_number_style(&mut self, node: &mut IcuNumberStyle) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu_plural(&mut self, node: &mut IcuPlural) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu_plural_arm(&mut self, node: &mut IcuPluralArm) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu_select(&mut self, node: &mut IcuSelect) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu_time(&mut self, node: &mut IcuTime) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_icu_variable(&mut self, node: &mut IcuVariable) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_inline_content(&mut self, node: &mut InlineContent) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_inline_contents(&mut self, nodes: &mut Vec<InlineContent>) {
+        nodes.visit_mut_children_with(self);
+    }
+    fn visit_mut_link(&mut self, node: &mut Link) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_link_destination(&mut self, node: &mut LinkDestination) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_list(&mut self, node: &mut List) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_list_item(&mut self, node: &mut ListItem) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_paragraph(&mut self, node: &mut Paragraph) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_strikethrough(&mut self, node: &mut Strikethrough) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_strong(&mut self, node: &mut Strong) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_table(&mut self, node: &mut Table) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_table_cell(&mut self, node: &mut TableCell) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_table_row(&mut self, node: &mut TableRow) {
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_text(&mut self, _node: &mut String) {
+        // Not a node type, just visible text of the message.
+    }
+
+    fn visit_mut_thematic_break(&mut self) {}
+    fn visit_mut_hard_line_break(&mut self) {}
+    fn visit_mut_icu_pound(&mut self) {}
+}
@@ -0,0 +1,126 @@
+use intl_markdown::{
+    format_to_icu_string, parse_intl_message, BlockNode, Document, Hook, Icu, IcuPlural,
+    IcuPluralArm, IcuPluralKind, IcuVariable, InlineContent, Strong,
+};
+use intl_markdown_visitor::{visit_mut, VisitMut, VisitMutWith};
+
+/// Parse the input, rewrite it with the visitor, and return the result as an ICU string.
+fn transform<V: VisitMut>(input: &str, visitor: &mut V) -> String {
+    let mut document = parse_intl_message(input, false);
+    visit_mut(&mut document, visitor);
+    format_to_icu_string(&document).unwrap()
+}
+
+struct RenameVariable(&'static str, &'static str);
+impl VisitMut for RenameVariable {
+    fn visit_mut_icu_variable(&mut self, node: &mut IcuVariable) {
+        if node.name() == self.0 {
+            node.set_name(self.1.into());
+        }
+    }
+}
+
+#[test]
+fn rename_variables() {
+    let mut visitor = RenameVariable("count", "total");
+    assert_eq!(
+        "{total, plural, one {# {total, number} item} other {{other}}}",
+        transform(
+            "{count, plural, one {# {count, number} item} other {{other}}}",
+            &mut visitor
+        )
+    );
+}
+
+/// Replaces strong and emphasis nodes with their own content.
+struct StripFormatting;
+impl VisitMut for StripFormatting {
+    fn visit_mut_inline_contents(&mut self, nodes: &mut Vec<InlineContent>) {
+        // Nested formatting is stripped first, so the content moved up is already plain.
+        nodes.visit_mut_children_with(self);
+        *nodes = std::mem::take(nodes)
+            .into_iter()
+            .flat_map(|node| match node {
+                InlineContent::Strong(strong) => strong.content().clone(),
+                InlineContent::Emphasis(emphasis) => emphasis.content().clone(),
+                node => vec![node],
+            })
+            .collect();
+    }
+}
+
+#[test]
+fn strip_formatting() {
+    assert_eq!(
+        "hello {name}, this is important",
+        transform(
+            "hello **{name}**, this is *__important__*",
+            &mut StripFormatting
+        )
+    );
+}
+
+/// Renames the `old` hook to `new` and replaces the `removed` hook with strong text.
+struct ReplaceHooks;
+impl VisitMut for ReplaceHooks {
+    fn visit_mut_inline_content(&mut self, node: &mut InlineContent) {
+        if let InlineContent::Hook(hook) = node {
+            if hook.name() == "removed" {
+                *node = InlineContent::Strong(Strong::new(hook.content().clone()));
+            }
+        }
+        node.visit_mut_children_with(self);
+    }
+    fn visit_mut_hook(&mut self, node: &mut Hook) {
+        if node.name() == "old" {
+            node.set_name("new".into());
+        }
+        node.visit_mut_children_with(self);
+    }
+}
+
+#[test]
+fn replace_hooks() {
+    assert_eq!(
+        "<new>click <b>here</b></new> or <b>{there}</b>",
+        transform(
+            "$[click **here**](old) or $[{there}](removed)",
+            &mut ReplaceHooks
+        )
+    );
+}
+
+struct UppercaseText;
+impl VisitMut for UppercaseText {
+    fn visit_mut_text(&mut self, node: &mut String) {
+        *node = node.to_uppercase();
+    }
+}
+
+#[test]
+fn constructed_documents() {
+    let plural = IcuPlural::new(
+        IcuVariable::new("count".into(), false),
+        IcuPluralKind::Plural,
+        0,
+        vec![
+            IcuPluralArm::new("one".into(), vec![InlineContent::Text("one item".into())]),
+            IcuPluralArm::new(
+                "other".into(),
+                vec![
+                    InlineContent::IcuPound,
+                    InlineContent::Text(" items".into()),
+                ],
+            ),
+        ],
+    );
+    let mut document = Document::new(vec![BlockNode::InlineContent(vec![
+        InlineContent::Text("You have ".into()),
+        InlineContent::Icu(Icu::IcuPlural(plural)),
+    ])]);
+    visit_mut(&mut document, &mut UppercaseText);
+    assert_eq!(
+        "YOU HAVE {count, plural, one {ONE ITEM} other {# ITEMS}}",
+        format_to_icu_string(&document).unwrap()
+    );
+}
@@ -20,13 +20,52 @@ export declare class IntlMessagesDatabase {
   getSourceFileKeyMap(filePath: string): Record<string, string>
   getMessage(key: string): IntlMessage
   generateTypes(sourceFilePath: string, outputFilePath: string, allowNullability?: boolean | undefined | null): void
-  precompile(filePath: string, locale: string, outputPath: string, options?: IntlMessageBundlerOptions | undefined | null): void
+  precompile(filePath: string, locale: string, outputPath: string, options?: IntlMessageBundlerOptions | undefined | null): IntlMessageBundleReport
   precompileToBuffer(filePath: string, locale: string, options?: IntlMessageBundlerOptions | undefined | null): Buffer
-  validateMessages(): Array<IntlDiagnostic>
+  validateMessages(options?: IntlValidatorOptions | undefined | null): Array<IntlDiagnostic>
   exportTranslations(fileExtension?: string | undefined | null): Array<string>
+  exportGettext(): Array<string>
+  exportXliff(version?: IntlXliffVersion | undefined | null): Array<string>
+  exportAndroidStrings(resourcesDirectory: string): IntlMobileExportReport
+  exportAppleStrings(resourcesDirectory: string): IntlMobileExportReport
+  generatePseudoLocale(options?: IntlPseudoLocaleOptions | undefined | null): number
+  saveSnapshot(snapshotPath: string): number
+  /**
+   * Replace the entire content of this database with the snapshot at `snapshotPath`, then
+   * process any files that have changed since it was saved, along with any new messages files
+   * found in `directories`.
+   */
+  loadSnapshot(snapshotPath: string, directories: Array<string>, defaultDefinitionLocale: string): IntlSnapshotLoadResult
+  /**
+   * Start or stop recording the changes made by every operation that modifies the database, so
+   * they can be retrieved with `takeChanges`.
+   */
+  setChangeTracking(enabled: boolean): void
+  /** Return every change made to the database since the last call. */
+  takeChanges(): Array<IntlDatabaseChange>
+  /**
+   * Call `callback` with the changes made by every operation that modifies the database, like
+   * processing a file or a batch applied by `watch`. Returns an id that can be given to
+   * `unsubscribe` to stop.
+   */
+  subscribe(callback: (changes: Array<IntlDatabaseChange>) => void): number
+  /** Stop calling the subscriber with the given `id`, returning whether it was subscribed. */
+  unsubscribe(id: number): boolean
+  /**
+   * Watch for messages files being created, modified, or deleted within `directories`, and
+   * apply each change to the database in the background: changed files are processed again
+   * and deleted files are removed along with their messages. `callback` is called with every
+   * batch of changes once it has been applied, and subscribers are called with the changes it
+   * made to the database. Starting a new watcher stops the previous one.
+   */
+  watch(directories: Array<string>, defaultDefinitionLocale: string, callback: (error: Error | null, batch: IntlWatchBatch) => void, options?: IntlWatchOptions | undefined | null): void
+  /** Stop the watcher started by `watch`, if any, waiting for any batch in progress to finish. */
+  stopWatching(): void
   getSourceFileMessageValues(filePath: string): Record<string, IntlMessageValue | undefined>
 }
 
+export declare function formatMessageSource(content: string): string
+
 export declare function hashMessageKey(key: string): string
 
 export declare const enum IntlCompiledMessageFormat {
@@ -34,12 +73,35 @@ export declare const enum IntlCompiledMessageFormat {
   KeylessJson = 1
 }
 
+export interface IntlDatabaseChange {
+  kind: IntlDatabaseChangeKind
+  /** Key of the affected message, unless the change is `localeAdded`. */
+  key?: string
+  /** Locale affected by the change, for translation changes and `localeAdded`. */
+  locale?: string
+}
+
+export declare const enum IntlDatabaseChangeKind {
+  MessageAdded = 'messageAdded',
+  MessageRemoved = 'messageRemoved',
+  MessageChanged = 'messageChanged',
+  MetaChanged = 'metaChanged',
+  TranslationAdded = 'translationAdded',
+  TranslationChanged = 'translationChanged',
+  TranslationRemoved = 'translationRemoved',
+  LocaleAdded = 'localeAdded'
+}
+
 export interface IntlDiagnostic {
   name: string
   key: string
   file: string
   line: number
   col: number
+  /** Line where the diagnostic ends, when it applies to a specific part of the message. */
+  endLine?: number
+  /** Column where the diagnostic ends, when it applies to a specific part of the message. */
+  endCol?: number
   locale: string
   severity: string
   description: string
@@ -59,15 +121,35 @@ export interface IntlMessage {
   meta: IntlMessageMeta
 }
 
+export interface IntlMessageBundleReport {
+  /**
+   * Map of message keys that weren't translated in the requested locale to the locale that
+   * their bundled value was taken from instead.
+   */
+  fallbacks: Record<string, string>
+}
+
 export interface IntlMessageBundlerOptions {
   format?: IntlCompiledMessageFormat
   bundleSecrets?: boolean
+  /**
+   * Fill in untranslated messages from the locale's CLDR parents and then the message's source
+   * locale, so the bundle is complete on its own.
+   */
+  fallbacks?: boolean
+  /**
+   * Map of locales to the ordered list of locales to fall back to, replacing the CLDR parents
+   * for those locales. Setting this enables `fallbacks`.
+   */
+  fallbackChains?: Record<string, Array<string>>
 }
 
 export interface IntlMessageMeta {
   secret: boolean
   translate: boolean
   translationsPath: string
+  suppressDiagnostics: Array<string>
+  sourceLocale?: string
 }
 
 export interface IntlMessagesFileDescriptor {
@@ -80,11 +162,34 @@ export interface IntlMessageValue {
   parsed: object
   variables: object
   filePosition: object
+  /** Whether the translation was imported as needing review, like a fuzzy gettext entry. */
+  fuzzy: boolean
+}
+
+export interface IntlMobileExportDiagnostic {
+  key: string
+  locale: string
+  file?: string
+  line?: number
+  col?: number
+  description: string
+}
+
+export interface IntlMobileExportReport {
+  /** Paths of every file that was written. */
+  files: Array<string>
+  /**
+   * Every message that was left out because it can't be represented faithfully on the
+   * platform.
+   */
+  diagnostics: Array<IntlMobileExportDiagnostic>
 }
 
 export interface IntlMultiProcessingFailure {
   file: string
   error: string
+  /** Positioned errors for each invalid definition in the file, if that's why it failed. */
+  diagnostics: Array<IntlSourceDiagnostic>
 }
 
 export interface IntlMultiProcessingResult {
@@ -92,6 +197,34 @@ export interface IntlMultiProcessingResult {
   failed: Array<IntlMultiProcessingFailure>
 }
 
+export interface IntlPseudoLocaleOptions {
+  /** Locale to insert the translations as. Defaults to `en-XA`, or `ar-XB` when `mirror` is set. */
+  locale?: string
+  accents?: boolean
+  /** Render every word right-to-left. */
+  mirror?: boolean
+  brackets?: boolean
+  /** Fraction to make the text of each message longer by, like `0.4` for 40% longer. */
+  expansion?: number
+}
+
+export interface IntlSnapshotLoadResult {
+  /** Result of processing every file that changed or was added since the snapshot was saved. */
+  reprocessed: IntlMultiProcessingResult
+  /** Files that no longer exist and were removed from the database. */
+  removed: Array<string>
+}
+
+export interface IntlSourceDiagnostic {
+  /** Name of the message with the error, or undefined for errors in the source file meta. */
+  key?: string
+  /** 1-based line where the error was written. */
+  line: number
+  /** 0-based column where the error was written. */
+  col: number
+  error: string
+}
+
 export interface IntlSourceFile {
   type: string
   file: string
@@ -100,6 +233,36 @@ export interface IntlSourceFile {
   locale?: number
 }
 
+export interface IntlValidatorOptions {
+  /**
+   * Map of diagnostic names to the severity they should be reported with, or `"off"` to
+   * disable the rule entirely. Rules that are not listed use their default severity.
+   */
+  rules?: Record<string, 'off' | 'info' | 'warning' | 'error'>
+}
+
+export interface IntlWatchBatch {
+  /** Result of processing every file that was created or modified. */
+  processed: IntlMultiProcessingResult
+  /** Files that were deleted and removed from the database. */
+  removed: Array<string>
+  /** Every locale represented by the processed and removed files. */
+  locales: Array<string>
+}
+
+export interface IntlWatchOptions {
+  /**
+   * How long, in milliseconds, the directories must go without any further changes before a
+   * batch is applied.
+   */
+  debounce?: number
+}
+
+export declare const enum IntlXliffVersion {
+  V1_2 = 0,
+  V2_0 = 1
+}
+
 export declare function isMessageDefinitionsFile(key: string): boolean
 
 export declare function isMessageTranslationsFile(key: string): boolean
@@ -73,17 +73,23 @@ const packagePath = `@discord/intl-message-database-${packageName}`;
 const nativeBinding = fs.existsSync(localPath) ? require(localPath) : require(packagePath);
 
 const {
+  formatMessageSource,
   hashMessageKey,
   isMessageDefinitionsFile,
   isMessageTranslationsFile,
   IntlMessagesDatabase,
   IntlCompiledMessageFormat,
+  IntlDatabaseChangeKind,
+  IntlXliffVersion,
 } = nativeBinding;
 
 module.exports = {
+  formatMessageSource,
   hashMessageKey,
   isMessageDefinitionsFile,
   isMessageTranslationsFile,
   IntlMessagesDatabase,
   IntlCompiledMessageFormat,
+  IntlDatabaseChangeKind,
+  IntlXliffVersion,
 };
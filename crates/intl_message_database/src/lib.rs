@@ -6,6 +6,7 @@ static GLOBAL: MiMalloc = MiMalloc;
 pub mod napi;
 pub mod sources;
 mod threading;
+pub mod watch;
 
 mod public;
 #[cfg(test)]
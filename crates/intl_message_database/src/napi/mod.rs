@@ -5,23 +5,97 @@
 //!
 //! This is the preferred way of using the library wherever possible.
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi::JsUnknown;
 use napi_derive::napi;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
 
 use crate::napi::types::{
-    IntlDiagnostic, IntlMessageBundlerOptions, IntlMessagesFileDescriptor,
-    IntlMultiProcessingResult,
+    IntlDatabaseChange, IntlDiagnostic, IntlMessageBundleReport, IntlMessageBundlerOptions,
+    IntlMessagesFileDescriptor, IntlMobileExportReport, IntlMultiProcessingResult,
+    IntlPseudoLocaleOptions, IntlSnapshotLoadResult, IntlValidatorOptions, IntlWatchBatch,
+    IntlWatchOptions, IntlXliffVersion,
 };
 use crate::public;
 use crate::sources::MessagesFileDescriptor;
+use crate::watch::MessagesFileWatcher;
 use intl_database_core::MessagesDatabase;
 
 mod types;
 
+/// A watcher started by `watch`, running on its own thread until it is stopped.
+struct RunningWatcher {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl RunningWatcher {
+    fn stop(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
+
+impl Drop for RunningWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn lock_database(database: &Mutex<MessagesDatabase>) -> MutexGuard<'_, MessagesDatabase> {
+    database.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A subscriber that can be called from the watcher thread. It's weak so that subscribing doesn't
+/// keep the process alive on its own.
+type ThreadsafeSubscriber =
+    ThreadsafeFunction<Vec<IntlDatabaseChange>, (), Vec<IntlDatabaseChange>, false, true>;
+
+type ThreadsafeSubscribers = Arc<Mutex<Vec<(u32, ThreadsafeSubscriber)>>>;
+
+fn lock_subscribers(
+    subscribers: &ThreadsafeSubscribers,
+) -> MutexGuard<'_, Vec<(u32, ThreadsafeSubscriber)>> {
+    subscribers.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Call every subscriber with the changes made by the last batch applied by the watcher. Like
+/// `notify_subscribers`, this takes the changes so they don't accumulate in the database.
+fn notify_threadsafe_subscribers(
+    database: &mut MessagesDatabase,
+    subscribers: &ThreadsafeSubscribers,
+) {
+    let subscribers = lock_subscribers(subscribers);
+    if subscribers.is_empty() {
+        return;
+    }
+    let changes = public::take_changes(database);
+    if changes.is_empty() {
+        return;
+    }
+    let changes: Vec<IntlDatabaseChange> = changes.into_iter().map(Into::into).collect();
+    for (_, callback) in subscribers.iter() {
+        callback.call(changes.clone(), ThreadsafeFunctionCallMode::NonBlocking);
+    }
+}
+
 #[napi]
 pub struct IntlMessagesDatabase {
-    database: MessagesDatabase,
+    /// Shared with the watcher thread while watching, which processes changes as they happen.
+    database: Arc<Mutex<MessagesDatabase>>,
+    watcher: Option<RunningWatcher>,
+    /// Whether changes were requested through `setChangeTracking`, separately from subscribers.
+    tracking_changes: bool,
+    change_subscribers: Vec<(u32, FunctionRef<Vec<IntlDatabaseChange>, ()>)>,
+    /// The same subscribers as `change_subscribers`, shared with the watcher thread so that the
+    /// changes made by each batch it applies are delivered as well.
+    threadsafe_subscribers: ThreadsafeSubscribers,
+    next_subscriber_id: u32,
 }
 
 #[napi]
@@ -29,10 +103,43 @@ impl IntlMessagesDatabase {
     #[napi(constructor)]
     pub fn new() -> Self {
         IntlMessagesDatabase {
-            database: MessagesDatabase::new(),
+            database: Arc::new(Mutex::new(MessagesDatabase::new())),
+            watcher: None,
+            tracking_changes: false,
+            change_subscribers: vec![],
+            threadsafe_subscribers: Arc::new(Mutex::new(vec![])),
+            next_subscriber_id: 0,
         }
     }
 
+    fn database(&self) -> MutexGuard<'_, MessagesDatabase> {
+        lock_database(&self.database)
+    }
+
+    /// Call every subscriber with the changes made by the last operation. Subscribers take the
+    /// changes, so they will not be returned by `takeChanges` afterward.
+    fn notify_subscribers(&mut self, env: &Env) -> anyhow::Result<()> {
+        if self.change_subscribers.is_empty() {
+            return Ok(());
+        }
+        let changes = public::take_changes(&mut self.database());
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let changes: Vec<IntlDatabaseChange> = changes.into_iter().map(Into::into).collect();
+        // Borrow every callback before calling any of them, since they may subscribe or
+        // unsubscribe while running.
+        let callbacks = self
+            .change_subscribers
+            .iter()
+            .map(|(_, callback)| callback.borrow_back(env))
+            .collect::<napi::Result<Vec<_>>>()?;
+        for callback in callbacks {
+            callback.call(changes.clone())?;
+        }
+        Ok(())
+    }
+
     #[napi]
     pub fn find_all_messages_files(
         &mut self,
@@ -68,96 +175,113 @@ impl IntlMessagesDatabase {
     #[napi]
     pub fn process_all_messages_files(
         &mut self,
+        env: Env,
         directories: Vec<IntlMessagesFileDescriptor>,
     ) -> anyhow::Result<IntlMultiProcessingResult> {
         let sources = public::process_all_messages_files(
-            &mut self.database,
+            &mut self.database(),
             directories.iter().map(MessagesFileDescriptor::from),
         )?;
+        self.notify_subscribers(&env)?;
         Ok(sources.into())
     }
 
     #[napi]
     pub fn process_definitions_file(
         &mut self,
+        env: Env,
         file_path: String,
         locale: Option<String>,
     ) -> anyhow::Result<String> {
-        let source_file = public::process_definitions_file(
-            &mut self.database,
+        let (source_file, diagnostics) = public::process_definitions_file(
+            &mut self.database(),
             &file_path,
             locale.as_ref().map(String::as_str),
         )?;
-        Ok(source_file.to_string())
+        // The valid definitions were still processed, so subscribers see them before the invalid
+        // ones are thrown.
+        self.notify_subscribers(&env)?;
+        Ok(public::require_valid_definitions(source_file, diagnostics)?.to_string())
     }
 
     #[napi]
     pub fn process_definitions_file_content(
         &mut self,
+        env: Env,
         file_path: String,
         content: String,
         locale: Option<String>,
     ) -> anyhow::Result<String> {
-        let source_file = public::process_definitions_file_content(
-            &mut self.database,
+        let (source_file, diagnostics) = public::process_definitions_file_content(
+            &mut self.database(),
             &file_path,
             &content,
             locale.as_ref().map(String::as_str),
         )?;
-        Ok(source_file.to_string())
+        // The valid definitions were still processed, so subscribers see them before the invalid
+        // ones are thrown.
+        self.notify_subscribers(&env)?;
+        Ok(public::require_valid_definitions(source_file, diagnostics)?.to_string())
     }
 
     #[napi]
     pub fn process_all_translation_files(
         &mut self,
+        env: Env,
         locale_map: HashMap<String, String>,
     ) -> anyhow::Result<IntlMultiProcessingResult> {
-        let result = public::process_all_translation_files(&mut self.database, locale_map)?;
+        let result = public::process_all_translation_files(&mut self.database(), locale_map)?;
+        self.notify_subscribers(&env)?;
         Ok(result.into())
     }
 
     #[napi]
     pub fn process_translation_file(
         &mut self,
+        env: Env,
         file_path: String,
         locale: String,
     ) -> anyhow::Result<String> {
         let source_file =
-            public::process_translation_file(&mut self.database, &file_path, &locale)?;
+            public::process_translation_file(&mut self.database(), &file_path, &locale)?;
+        self.notify_subscribers(&env)?;
         Ok(source_file.to_string())
     }
 
     #[napi]
     pub fn process_translation_file_content(
         &mut self,
+        env: Env,
         file_path: String,
         locale: String,
         content: String,
     ) -> anyhow::Result<String> {
         let source_file = public::process_translation_file_content(
-            &mut self.database,
+            &mut self.database(),
             &file_path,
             &locale,
             &content,
         )?;
+        self.notify_subscribers(&env)?;
         Ok(source_file.to_string())
     }
 
     #[napi]
     pub fn get_known_locales(&self) -> Vec<String> {
-        let locales = public::get_known_locales(&self.database);
+        let locales = public::get_known_locales(&self.database());
         Vec::from_iter(locales.into_iter().map(|locale| locale.to_string()))
     }
 
     #[napi(ts_return_type = "IntlSourceFile")]
     pub fn get_source_file(&self, env: Env, file_path: String) -> anyhow::Result<JsUnknown> {
-        let source = public::get_source_file(&self.database, &file_path)?;
+        let database = self.database();
+        let source = public::get_source_file(&database, &file_path)?;
         Ok(env.to_js_value(source)?)
     }
 
     #[napi]
     pub fn get_all_source_file_paths(&self) -> anyhow::Result<Vec<String>> {
-        let paths = public::get_all_source_file_paths(&self.database)?;
+        let paths = public::get_all_source_file_paths(&self.database())?;
         Ok(paths.into_iter().map(|path| path.to_string()).collect())
     }
 
@@ -169,13 +293,14 @@ impl IntlMessagesDatabase {
         env: Env,
         file_path: String,
     ) -> anyhow::Result<JsUnknown> {
-        let hashes = public::get_source_file_key_map(&self.database, &file_path)?;
+        let hashes = public::get_source_file_key_map(&self.database(), &file_path)?;
         Ok(env.to_js_value(&hashes)?)
     }
 
     #[napi(ts_return_type = "IntlMessage")]
     pub fn get_message(&self, env: Env, key: String) -> anyhow::Result<JsUnknown> {
-        let definition = public::get_message(&self.database, &key)?;
+        let database = self.database();
+        let definition = public::get_message(&database, &key)?;
         Ok(env.to_js_value(definition)?)
     }
 
@@ -187,7 +312,7 @@ impl IntlMessagesDatabase {
         allow_nullability: Option<bool>,
     ) -> anyhow::Result<()> {
         public::generate_types(
-            &self.database,
+            &self.database(),
             &source_file_path,
             &output_file_path,
             allow_nullability,
@@ -201,14 +326,15 @@ impl IntlMessagesDatabase {
         locale: String,
         output_path: String,
         options: Option<IntlMessageBundlerOptions>,
-    ) -> anyhow::Result<()> {
-        public::precompile(
-            &self.database,
+    ) -> anyhow::Result<IntlMessageBundleReport> {
+        let report = public::precompile(
+            &self.database(),
             &file_path,
             &locale,
             &output_path,
             options.unwrap_or_default().into(),
-        )
+        )?;
+        Ok(report.into())
     }
 
     #[napi]
@@ -219,7 +345,7 @@ impl IntlMessagesDatabase {
         options: Option<IntlMessageBundlerOptions>,
     ) -> anyhow::Result<Buffer> {
         let result = public::precompile_to_buffer(
-            &self.database,
+            &self.database(),
             &file_path,
             &locale,
             options.unwrap_or_default().into(),
@@ -228,8 +354,12 @@ impl IntlMessagesDatabase {
     }
 
     #[napi]
-    pub fn validate_messages(&self) -> anyhow::Result<Vec<IntlDiagnostic>> {
-        let result = public::validate_messages(&self.database)?;
+    pub fn validate_messages(
+        &self,
+        options: Option<IntlValidatorOptions>,
+    ) -> anyhow::Result<Vec<IntlDiagnostic>> {
+        let config = options.unwrap_or_default().try_into()?;
+        let result = public::validate_messages(&self.database(), &config)?;
         Ok(result.into_iter().map(IntlDiagnostic::from).collect())
     }
 
@@ -238,7 +368,185 @@ impl IntlMessagesDatabase {
         &self,
         file_extension: Option<String>,
     ) -> anyhow::Result<Vec<String>> {
-        public::export_translations(&self.database, file_extension)
+        public::export_translations(&self.database(), file_extension)
+    }
+
+    #[napi]
+    pub fn export_gettext(&self) -> anyhow::Result<Vec<String>> {
+        public::export_gettext(&self.database())
+    }
+
+    #[napi]
+    pub fn export_xliff(&self, version: Option<IntlXliffVersion>) -> anyhow::Result<Vec<String>> {
+        let version = version.unwrap_or(IntlXliffVersion::V1_2);
+        public::export_xliff(&self.database(), version.into())
+    }
+
+    #[napi]
+    pub fn export_android_strings(
+        &self,
+        resources_directory: String,
+    ) -> anyhow::Result<IntlMobileExportReport> {
+        let report = public::export_android_strings(&self.database(), &resources_directory)?;
+        Ok(report.into())
+    }
+
+    #[napi]
+    pub fn export_apple_strings(
+        &self,
+        resources_directory: String,
+    ) -> anyhow::Result<IntlMobileExportReport> {
+        let report = public::export_apple_strings(&self.database(), &resources_directory)?;
+        Ok(report.into())
+    }
+
+    #[napi]
+    pub fn generate_pseudo_locale(
+        &mut self,
+        env: Env,
+        options: Option<IntlPseudoLocaleOptions>,
+    ) -> anyhow::Result<u32> {
+        let count = public::generate_pseudo_locale(
+            &mut self.database(),
+            options.unwrap_or_default().into(),
+        )?;
+        self.notify_subscribers(&env)?;
+        Ok(count as u32)
+    }
+
+    #[napi]
+    pub fn save_snapshot(&self, snapshot_path: String) -> anyhow::Result<u32> {
+        let count = public::save_snapshot(&self.database(), &snapshot_path)?;
+        Ok(count as u32)
+    }
+
+    /// Replace the entire content of this database with the snapshot at `snapshotPath`, then
+    /// process any files that have changed since it was saved, along with any new messages files
+    /// found in `directories`.
+    #[napi]
+    pub fn load_snapshot(
+        &mut self,
+        snapshot_path: String,
+        directories: Vec<String>,
+        default_definition_locale: String,
+    ) -> anyhow::Result<IntlSnapshotLoadResult> {
+        let (database, result) =
+            public::load_snapshot(&snapshot_path, &directories, &default_definition_locale)?;
+        let mut current = self.database();
+        *current = database;
+        public::set_change_tracking(
+            &mut current,
+            self.tracking_changes || !self.change_subscribers.is_empty(),
+        );
+        Ok(result.into())
+    }
+
+    /// Start or stop recording the changes made by every operation that modifies the database, so
+    /// they can be retrieved with `takeChanges`.
+    #[napi]
+    pub fn set_change_tracking(&mut self, enabled: bool) {
+        self.tracking_changes = enabled;
+        public::set_change_tracking(
+            &mut self.database(),
+            enabled || !self.change_subscribers.is_empty(),
+        );
+    }
+
+    /// Return every change made to the database since the last call.
+    #[napi]
+    pub fn take_changes(&mut self) -> Vec<IntlDatabaseChange> {
+        public::take_changes(&mut self.database())
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Call `callback` with the changes made by every operation that modifies the database, like
+    /// processing a file or a batch applied by `watch`. Returns an id that can be given to
+    /// `unsubscribe` to stop.
+    #[napi(ts_args_type = "callback: (changes: Array<IntlDatabaseChange>) => void")]
+    pub fn subscribe(
+        &mut self,
+        callback: Function<'_, Vec<IntlDatabaseChange>, ()>,
+    ) -> anyhow::Result<u32> {
+        let id = self.next_subscriber_id;
+        self.next_subscriber_id += 1;
+        let threadsafe_callback = callback
+            .build_threadsafe_function()
+            .weak::<true>()
+            .build()?;
+        self.change_subscribers.push((id, callback.create_ref()?));
+        lock_subscribers(&self.threadsafe_subscribers).push((id, threadsafe_callback));
+        public::set_change_tracking(&mut self.database(), true);
+        Ok(id)
+    }
+
+    /// Stop calling the subscriber with the given `id`, returning whether it was subscribed.
+    #[napi]
+    pub fn unsubscribe(&mut self, id: u32) -> bool {
+        let count = self.change_subscribers.len();
+        self.change_subscribers
+            .retain(|(subscriber_id, _)| *subscriber_id != id);
+        lock_subscribers(&self.threadsafe_subscribers)
+            .retain(|(subscriber_id, _)| *subscriber_id != id);
+        if self.change_subscribers.is_empty() && !self.tracking_changes {
+            public::set_change_tracking(&mut self.database(), false);
+        }
+        self.change_subscribers.len() != count
+    }
+
+    /// Watch for messages files being created, modified, or deleted within `directories`, and
+    /// apply each change to the database in the background: changed files are processed again
+    /// and deleted files are removed along with their messages. `callback` is called with every
+    /// batch of changes once it has been applied, and subscribers are called with the changes it
+    /// made to the database. Starting a new watcher stops the previous one.
+    #[napi(
+        ts_args_type = "directories: Array<string>, defaultDefinitionLocale: string, callback: (error: Error | null, batch: IntlWatchBatch) => void, options?: IntlWatchOptions | undefined | null"
+    )]
+    pub fn watch(
+        &mut self,
+        directories: Vec<String>,
+        default_definition_locale: String,
+        callback: ThreadsafeFunction<IntlWatchBatch>,
+        options: Option<IntlWatchOptions>,
+    ) -> anyhow::Result<()> {
+        if directories.is_empty() {
+            anyhow::bail!("watch requires at least one directory to watch");
+        }
+        self.stop_watching();
+
+        // The initial scan happens immediately so that any change made after this call returns
+        // is picked up by the watcher.
+        let mut watcher = MessagesFileWatcher::new(directories, &default_definition_locale)?;
+        let options = options.unwrap_or_default().into();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let database = Arc::clone(&self.database);
+        let subscribers = Arc::clone(&self.threadsafe_subscribers);
+        let thread = std::thread::spawn(move || {
+            while let Some(changes) = watcher.wait_for_changes(&options, &thread_stop) {
+                let mut database = lock_database(&database);
+                let result = public::apply_watch_changes(&mut database, changes)
+                    .map(IntlWatchBatch::from)
+                    .map_err(|error| napi::Error::from_reason(error.to_string()));
+                notify_threadsafe_subscribers(&mut database, &subscribers);
+                drop(database);
+                callback.call(result, ThreadsafeFunctionCallMode::NonBlocking);
+            }
+        });
+        self.watcher = Some(RunningWatcher {
+            stop,
+            thread: Some(thread),
+        });
+        Ok(())
+    }
+
+    /// Stop the watcher started by `watch`, if any, waiting for any batch in progress to finish.
+    #[napi]
+    pub fn stop_watching(&mut self) {
+        if let Some(watcher) = self.watcher.take() {
+            watcher.stop();
+        }
     }
 
     #[napi(ts_return_type = "Record<string, IntlMessageValue | undefined>")]
@@ -247,11 +555,17 @@ impl IntlMessagesDatabase {
         env: Env,
         file_path: String,
     ) -> anyhow::Result<JsUnknown> {
-        let result = public::get_source_file_message_values(&self.database, &file_path)?;
+        let database = self.database();
+        let result = public::get_source_file_message_values(&database, &file_path)?;
         Ok(env.to_js_value(&result)?)
     }
 }
 
+#[napi]
+pub fn format_message_source(content: String) -> anyhow::Result<String> {
+    public::format_message_source(&content)
+}
+
 #[napi]
 pub fn hash_message_key(key: String) -> String {
     public::hash_message_key(&key)
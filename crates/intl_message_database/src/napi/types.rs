@@ -1,12 +1,15 @@
-use crate::public::MultiProcessingResult;
+use crate::public::{MultiProcessingResult, SnapshotLoadResult, WatchBatch};
 use crate::sources::MessagesFileDescriptor;
-use intl_database_core::key_symbol;
-use intl_database_exporter::CompiledMessageFormat;
-use intl_validator::MessageDiagnostic;
+use crate::watch::WatchOptions;
+use intl_database_core::{key_symbol, DatabaseChange, DatabaseError, MessageSourceDiagnostic};
+use intl_database_exporter::{CompiledMessageFormat, LocaleFallbacks};
+use intl_database_xliff_source::XliffVersion;
+use intl_validator::{DiagnosticName, DiagnosticSeverity, MessageDiagnostic, ValidatorConfig};
 use napi::{JsNumber, JsObject};
 use napi_derive::napi;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[napi(object)]
 #[derive(Default)]
@@ -14,6 +17,13 @@ pub struct IntlMessageBundlerOptions {
     pub format: Option<IntlCompiledMessageFormat>,
     #[napi(js_name = "bundleSecrets")]
     pub bundle_secrets: Option<bool>,
+    /// Fill in untranslated messages from the locale's CLDR parents and then the message's source
+    /// locale, so the bundle is complete on its own.
+    pub fallbacks: Option<bool>,
+    /// Map of locales to the ordered list of locales to fall back to, replacing the CLDR parents
+    /// for those locales. Setting this enables `fallbacks`.
+    #[napi(js_name = "fallbackChains")]
+    pub fallback_chains: Option<HashMap<String, Vec<String>>>,
 }
 
 impl Into<intl_database_exporter::IntlMessageBundlerOptions> for IntlMessageBundlerOptions {
@@ -25,10 +35,190 @@ impl Into<intl_database_exporter::IntlMessageBundlerOptions> for IntlMessageBund
         if let Some(format) = self.format {
             options = options.with_format(format.into());
         }
+        if self.fallbacks.unwrap_or(false) || self.fallback_chains.is_some() {
+            let mut fallbacks = LocaleFallbacks::default();
+            for (locale, chain) in self.fallback_chains.unwrap_or_default() {
+                fallbacks = fallbacks.with_chain(&locale, &chain);
+            }
+            options = options.with_fallbacks(fallbacks);
+        }
         options
     }
 }
 
+#[napi(object)]
+pub struct IntlMessageBundleReport {
+    /// Map of message keys that weren't translated in the requested locale to the locale that
+    /// their bundled value was taken from instead.
+    pub fallbacks: HashMap<String, String>,
+}
+
+impl From<intl_database_exporter::IntlMessageBundleReport> for IntlMessageBundleReport {
+    fn from(value: intl_database_exporter::IntlMessageBundleReport) -> Self {
+        Self {
+            fallbacks: value
+                .fallbacks
+                .into_iter()
+                .map(|(key, locale)| (key.to_string(), locale.to_string()))
+                .collect(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct IntlMobileExportDiagnostic {
+    pub key: String,
+    pub locale: String,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub col: Option<u32>,
+    pub description: String,
+}
+
+impl From<intl_database_exporter::MobileExportDiagnostic> for IntlMobileExportDiagnostic {
+    fn from(value: intl_database_exporter::MobileExportDiagnostic) -> Self {
+        Self {
+            key: value.key.to_string(),
+            locale: value.locale.to_string(),
+            file: value
+                .file_position
+                .map(|position| position.file.to_string()),
+            line: value.file_position.map(|position| position.line),
+            col: value.file_position.map(|position| position.col),
+            description: value.description,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct IntlMobileExportReport {
+    /// Paths of every file that was written.
+    pub files: Vec<String>,
+    /// Every message that was left out because it can't be represented faithfully on the
+    /// platform.
+    pub diagnostics: Vec<IntlMobileExportDiagnostic>,
+}
+
+impl From<intl_database_exporter::MobileExportReport> for IntlMobileExportReport {
+    fn from(value: intl_database_exporter::MobileExportReport) -> Self {
+        Self {
+            files: value.files,
+            diagnostics: value
+                .diagnostics
+                .into_iter()
+                .map(IntlMobileExportDiagnostic::from)
+                .collect(),
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct IntlPseudoLocaleOptions {
+    /// Locale to insert the translations as. Defaults to `en-XA`, or `ar-XB` when `mirror` is set.
+    pub locale: Option<String>,
+    pub accents: Option<bool>,
+    /// Render every word right-to-left.
+    pub mirror: Option<bool>,
+    pub brackets: Option<bool>,
+    /// Fraction to make the text of each message longer by, like `0.4` for 40% longer.
+    pub expansion: Option<f64>,
+}
+
+impl Into<intl_database_pseudo_locale::PseudoLocaleOptions> for IntlPseudoLocaleOptions {
+    fn into(self) -> intl_database_pseudo_locale::PseudoLocaleOptions {
+        let mut options = match self.mirror {
+            Some(true) => intl_database_pseudo_locale::PseudoLocaleOptions::mirrored(),
+            _ => intl_database_pseudo_locale::PseudoLocaleOptions::accented(),
+        };
+        if let Some(locale) = self.locale {
+            options = options.with_locale(key_symbol(&locale));
+        }
+        if let Some(accents) = self.accents {
+            options = options.with_accents(accents);
+        }
+        if let Some(brackets) = self.brackets {
+            options = options.with_brackets(brackets);
+        }
+        if let Some(expansion) = self.expansion {
+            options = options.with_expansion(expansion);
+        }
+        options
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct IntlValidatorOptions {
+    /// Map of diagnostic names to the severity they should be reported with, or `"off"` to
+    /// disable the rule entirely. Rules that are not listed use their default severity.
+    #[napi(ts_type = "Record<string, 'off' | 'info' | 'warning' | 'error'>")]
+    pub rules: Option<HashMap<String, String>>,
+}
+
+impl TryFrom<IntlValidatorOptions> for ValidatorConfig {
+    type Error = anyhow::Error;
+
+    fn try_from(value: IntlValidatorOptions) -> Result<Self, Self::Error> {
+        let mut config = ValidatorConfig::new();
+        for (name, level) in value.rules.unwrap_or_default() {
+            let Some(rule) = DiagnosticName::from_name(&name) else {
+                anyhow::bail!("Unknown validator rule '{name}'");
+            };
+            config = match level.as_str() {
+                "off" => config.with_rule_disabled(rule),
+                level => match DiagnosticSeverity::from_name(level) {
+                    Some(severity) => config.with_rule_severity(rule, severity),
+                    None => anyhow::bail!("Unknown severity '{level}' for validator rule '{name}'"),
+                },
+            };
+        }
+        Ok(config)
+    }
+}
+
+#[napi(string_enum = "camelCase")]
+pub enum IntlDatabaseChangeKind {
+    MessageAdded,
+    MessageRemoved,
+    MessageChanged,
+    MetaChanged,
+    TranslationAdded,
+    TranslationChanged,
+    TranslationRemoved,
+    LocaleAdded,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct IntlDatabaseChange {
+    pub kind: IntlDatabaseChangeKind,
+    /// Key of the affected message, unless the change is `localeAdded`.
+    pub key: Option<String>,
+    /// Locale affected by the change, for translation changes and `localeAdded`.
+    pub locale: Option<String>,
+}
+
+impl From<DatabaseChange> for IntlDatabaseChange {
+    fn from(value: DatabaseChange) -> Self {
+        let kind = match value {
+            DatabaseChange::MessageAdded { .. } => IntlDatabaseChangeKind::MessageAdded,
+            DatabaseChange::MessageRemoved { .. } => IntlDatabaseChangeKind::MessageRemoved,
+            DatabaseChange::MessageChanged { .. } => IntlDatabaseChangeKind::MessageChanged,
+            DatabaseChange::MetaChanged { .. } => IntlDatabaseChangeKind::MetaChanged,
+            DatabaseChange::TranslationAdded { .. } => IntlDatabaseChangeKind::TranslationAdded,
+            DatabaseChange::TranslationChanged { .. } => IntlDatabaseChangeKind::TranslationChanged,
+            DatabaseChange::TranslationRemoved { .. } => IntlDatabaseChangeKind::TranslationRemoved,
+            DatabaseChange::LocaleAdded { .. } => IntlDatabaseChangeKind::LocaleAdded,
+        };
+        IntlDatabaseChange {
+            kind,
+            key: value.key().map(|key| key.to_string()),
+            locale: value.locale().map(|locale| locale.to_string()),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct IntlDiagnostic {
     pub name: String,
@@ -36,6 +226,10 @@ pub struct IntlDiagnostic {
     pub file: String,
     pub line: u32,
     pub col: u32,
+    /// Line where the diagnostic ends, when it applies to a specific part of the message.
+    pub end_line: Option<u32>,
+    /// Column where the diagnostic ends, when it applies to a specific part of the message.
+    pub end_col: Option<u32>,
     pub locale: String,
     pub severity: String,
     pub description: String,
@@ -50,6 +244,8 @@ impl From<MessageDiagnostic> for IntlDiagnostic {
             file: value.file_position.file.to_string(),
             line: value.file_position.line,
             col: value.file_position.col,
+            end_line: value.end_position.map(|position| position.line),
+            end_col: value.end_position.map(|position| position.col),
             locale: value.locale.to_string(),
             severity: value.severity.to_string(),
             description: value.description,
@@ -77,6 +273,10 @@ pub struct IntlMessageMeta {
     pub translate: bool,
     #[napi(js_name = "translationsPath")]
     pub translations_path: String,
+    #[napi(js_name = "suppressDiagnostics")]
+    pub suppress_diagnostics: Vec<String>,
+    #[napi(js_name = "sourceLocale")]
+    pub source_locale: Option<String>,
 }
 
 // This is an unused struct purely for generating functional TS types.
@@ -104,6 +304,8 @@ pub struct IntlMessageValue {
     pub variables: JsObject,
     #[napi(js_name = "filePosition")]
     pub file_position: JsObject,
+    /// Whether the translation was imported as needing review, like a fuzzy gettext entry.
+    pub fuzzy: bool,
 }
 
 #[napi]
@@ -112,6 +314,21 @@ pub enum IntlCompiledMessageFormat {
     KeylessJson,
 }
 
+#[napi]
+pub enum IntlXliffVersion {
+    V1_2,
+    V2_0,
+}
+
+impl From<IntlXliffVersion> for XliffVersion {
+    fn from(value: IntlXliffVersion) -> Self {
+        match value {
+            IntlXliffVersion::V1_2 => XliffVersion::V1_2,
+            IntlXliffVersion::V2_0 => XliffVersion::V2_0,
+        }
+    }
+}
+
 impl From<IntlCompiledMessageFormat> for CompiledMessageFormat {
     fn from(value: IntlCompiledMessageFormat) -> Self {
         match value {
@@ -146,10 +363,34 @@ impl From<MessagesFileDescriptor> for IntlMessagesFileDescriptor {
     }
 }
 
+#[napi(object)]
+pub struct IntlSourceDiagnostic {
+    /// Name of the message with the error, or undefined for errors in the source file meta.
+    pub key: Option<String>,
+    /// 1-based line where the error was written.
+    pub line: u32,
+    /// 0-based column where the error was written.
+    pub col: u32,
+    pub error: String,
+}
+
+impl From<&MessageSourceDiagnostic> for IntlSourceDiagnostic {
+    fn from(value: &MessageSourceDiagnostic) -> Self {
+        Self {
+            key: value.name.map(|name| name.to_string()),
+            line: value.position.line,
+            col: value.position.col,
+            error: value.error.to_string(),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct IntlMultiProcessingFailure {
     pub file: String,
     pub error: String,
+    /// Positioned errors for each invalid definition in the file, if that's why it failed.
+    pub diagnostics: Vec<IntlSourceDiagnostic>,
 }
 
 #[napi(object)]
@@ -171,9 +412,82 @@ impl From<MultiProcessingResult> for IntlMultiProcessingResult {
                 .into_iter()
                 .map(|(key, error)| IntlMultiProcessingFailure {
                     file: key.to_string(),
+                    diagnostics: match &error {
+                        DatabaseError::InvalidDefinitions(_, diagnostics) => {
+                            diagnostics.iter().map(Into::into).collect()
+                        }
+                        _ => vec![],
+                    },
                     error: error.to_string(),
                 })
                 .collect(),
         }
     }
 }
+
+#[napi(object)]
+pub struct IntlSnapshotLoadResult {
+    /// Result of processing every file that changed or was added since the snapshot was saved.
+    pub reprocessed: IntlMultiProcessingResult,
+    /// Files that no longer exist and were removed from the database.
+    pub removed: Vec<String>,
+}
+
+impl From<SnapshotLoadResult> for IntlSnapshotLoadResult {
+    fn from(value: SnapshotLoadResult) -> Self {
+        IntlSnapshotLoadResult {
+            reprocessed: value.reprocessed.into(),
+            removed: value
+                .removed
+                .into_iter()
+                .map(|value| value.to_string())
+                .collect(),
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct IntlWatchOptions {
+    /// How long, in milliseconds, the directories must go without any further changes before a
+    /// batch is applied.
+    pub debounce: Option<u32>,
+}
+
+impl Into<WatchOptions> for IntlWatchOptions {
+    fn into(self) -> WatchOptions {
+        let mut options = WatchOptions::default();
+        if let Some(debounce) = self.debounce {
+            options = options.with_debounce(Duration::from_millis(debounce.into()));
+        }
+        options
+    }
+}
+
+#[napi(object)]
+pub struct IntlWatchBatch {
+    /// Result of processing every file that was created or modified.
+    pub processed: IntlMultiProcessingResult,
+    /// Files that were deleted and removed from the database.
+    pub removed: Vec<String>,
+    /// Every locale represented by the processed and removed files.
+    pub locales: Vec<String>,
+}
+
+impl From<WatchBatch> for IntlWatchBatch {
+    fn from(value: WatchBatch) -> Self {
+        IntlWatchBatch {
+            processed: value.processed.into(),
+            removed: value
+                .removed
+                .into_iter()
+                .map(|value| value.to_string())
+                .collect(),
+            locales: value
+                .locales
+                .into_iter()
+                .map(|value| value.to_string())
+                .collect(),
+        }
+    }
+}
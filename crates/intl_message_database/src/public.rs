@@ -7,14 +7,22 @@
 //! language-specific to the host (like constructing a host object for object-oriented languages).
 use crate::sources::{get_locale_from_file_name, MessagesFileDescriptor};
 use crate::threading::run_in_thread_pool;
+use crate::watch::WatchChanges;
 use intl_database_core::{
-    get_key_symbol, key_symbol, DatabaseError, DatabaseResult, KeySymbol, Message, MessageValue,
-    MessagesDatabase, RawMessageDefinition, RawMessageTranslation, SourceFile, DEFAULT_LOCALE,
+    get_key_symbol, key_symbol, DatabaseChange, DatabaseError, DatabaseResult, KeySymbol,
+    KeySymbolSet, Message, MessageSourceDiagnostic, MessageValue, MessagesDatabase,
+    RawMessageDefinition, RawMessageTranslation, SnapshotFile, SourceFile, SourceFileMeta,
+    DEFAULT_LOCALE,
 };
-use intl_database_exporter::{ExportTranslations, IntlMessageBundler, IntlMessageBundlerOptions};
+use intl_database_exporter::{
+    ExportAndroidStrings, ExportAppleStrings, ExportGettext, ExportTranslations, ExportXliff,
+    IntlMessageBundleReport, IntlMessageBundler, IntlMessageBundlerOptions, MobileExportReport,
+};
+use intl_database_pseudo_locale::{PseudoLocaleOptions, PseudoLocalizer};
 use intl_database_service::IntlDatabaseService;
 use intl_database_types_generator::IntlTypesGenerator;
-use intl_validator::{validate_message, MessageDiagnostic};
+use intl_database_xliff_source::XliffVersion;
+use intl_validator::{validate_message, MessageDiagnostic, ValidatorConfig};
 use rustc_hash::FxHashMap;
 use std::collections::HashMap;
 use std::io::Write;
@@ -94,62 +102,102 @@ pub fn process_all_messages_files(
         files,
         |descriptor| {
             let MessagesFileDescriptor { file_path, locale } = descriptor;
-            let content = std::fs::read_to_string(&file_path).expect(&format!(
-                "Failed to read messages file at {}",
-                file_path.display()
-            ));
-            let file_path = key_symbol(&file_path.to_string_lossy());
-
-            let (definitions, translations) = if is_message_definitions_file(&file_path) {
-                match crate::sources::extract_definitions_from_file(file_path, &content) {
-                    Ok((meta, definitions)) => (
-                        Some((meta, definitions.collect::<Vec<RawMessageDefinition>>())),
-                        None,
-                    ),
-                    _ => (None, None),
-                }
-            } else {
-                let translations = crate::sources::extract_translations_from_file(
-                    key_symbol(&file_path),
-                    &content,
-                )
-                .map(|translations| translations.collect::<Vec<RawMessageTranslation>>());
-                (None, Some(translations))
-            };
-            (locale, file_path, definitions, translations)
+            let file_key = key_symbol(&file_path.to_string_lossy());
+            // Files can be deleted or become unreadable after being found, which only fails that
+            // file rather than the whole batch.
+            let extracted = std::fs::read_to_string(&file_path)
+                .map_err(|error| DatabaseError::FileReadError(file_key.to_string(), error))
+                .map(|content| extract_messages_file(file_key, &content));
+            (locale, file_key, extracted)
         },
-        |(locale, file_path, definitions, translations)| {
-            let result = if let Some((source_meta, definitions)) = definitions {
-                crate::sources::insert_definitions(
-                    database,
-                    file_path,
-                    locale,
-                    source_meta,
-                    definitions.into_iter(),
-                )
-            } else if let Some(translations) = translations {
-                translations.and_then(|translations| {
-                    crate::sources::insert_translations(
+        |(locale, file_path, extracted)| {
+            let result = extracted.and_then(|(content_hash, definitions, translations)| {
+                if let Some((source_meta, definitions, diagnostics)) = definitions {
+                    crate::sources::insert_definitions(
                         database,
                         file_path,
                         locale,
-                        translations.into_iter(),
+                        content_hash,
+                        source_meta,
+                        definitions.into_iter(),
+                        diagnostics,
                     )
-                })
-            } else {
-                Err(DatabaseError::NoExtractableValues(file_path.to_string()))
-            };
+                    .and_then(|(file_key, diagnostics)| {
+                        require_valid_definitions(file_key, diagnostics)
+                    })
+                } else if let Some(translations) = translations {
+                    translations.and_then(|translations| {
+                        crate::sources::insert_translations(
+                            database,
+                            file_path,
+                            locale,
+                            content_hash,
+                            translations.into_iter(),
+                        )
+                    })
+                } else {
+                    Err(DatabaseError::NoExtractableValues(file_path.to_string()))
+                }
+            });
             (file_path, result)
         },
     )?;
     Ok(results.into())
 }
 
+/// Definitions extracted from a messages file, along with the diagnostics found while doing so.
+type ExtractedDefinitions = (
+    SourceFileMeta,
+    Vec<RawMessageDefinition>,
+    Vec<MessageSourceDiagnostic>,
+);
+
+/// Extract all of the definitions or translations from the `content` of a messages file, returning
+/// them with the hash of the content so they can be inserted into the database later.
+fn extract_messages_file(
+    file_key: KeySymbol,
+    content: &str,
+) -> (
+    u64,
+    Option<ExtractedDefinitions>,
+    Option<DatabaseResult<Vec<RawMessageTranslation>>>,
+) {
+    let content_hash = crate::sources::content_hash(content);
+    if is_message_definitions_file(&file_key) {
+        match crate::sources::extract_definitions_from_file(file_key, content) {
+            Ok((meta, definitions, diagnostics)) => (
+                content_hash,
+                Some((meta, definitions.collect(), diagnostics)),
+                None,
+            ),
+            _ => (content_hash, None, None),
+        }
+    } else {
+        let translations = crate::sources::extract_translations_from_file(file_key, content)
+            .map(|translations| translations.collect());
+        (content_hash, None, Some(translations))
+    }
+}
+
+/// Return `file_key` if none of the definitions in the file were invalid, or otherwise an error
+/// with the `diagnostics` for every invalid definition, so the file is reported as failed.
+pub fn require_valid_definitions(
+    file_key: KeySymbol,
+    diagnostics: Vec<MessageSourceDiagnostic>,
+) -> DatabaseResult<KeySymbol> {
+    match diagnostics.is_empty() {
+        true => Ok(file_key),
+        false => Err(DatabaseError::InvalidDefinitions(file_key, diagnostics)),
+    }
+}
+
+/// Process the definitions file at `file_path` into the database, returning its key along with
+/// diagnostics for any invalid definitions. Valid definitions in the file are processed either way.
 pub fn process_definitions_file(
     database: &mut MessagesDatabase,
     file_path: &str,
     locale: Option<&str>,
-) -> anyhow::Result<KeySymbol> {
+) -> anyhow::Result<(KeySymbol, Vec<MessageSourceDiagnostic>)> {
     let content = std::fs::read_to_string(&file_path)?;
     process_definitions_file_content(database, file_path, &content, locale)
 }
@@ -159,7 +207,7 @@ pub fn process_definitions_file_content(
     file_path: &str,
     content: &str,
     locale: Option<&str>,
-) -> anyhow::Result<KeySymbol> {
+) -> anyhow::Result<(KeySymbol, Vec<MessageSourceDiagnostic>)> {
     let source_file = crate::sources::process_definitions_file(
         database,
         &file_path,
@@ -176,23 +224,26 @@ pub fn process_all_translation_files(
     let results = run_in_thread_pool(
         locale_map.into_iter(),
         |(locale, file_path)| {
-            let content = std::fs::read_to_string(&file_path)
-                .expect(&format!("Failed to read translation file at {}", file_path));
-            (
-                key_symbol(&locale),
-                key_symbol(&file_path),
-                crate::sources::extract_translations_from_file(key_symbol(&file_path), &content)
-                    .map(|translations| translations.collect::<Vec<RawMessageTranslation>>()),
-            )
+            let file_key = key_symbol(&file_path);
+            let translations = std::fs::read_to_string(&file_path)
+                .map_err(|error| DatabaseError::FileReadError(file_path, error))
+                .and_then(|content| {
+                    let translations =
+                        crate::sources::extract_translations_from_file(file_key, &content)?
+                            .collect::<Vec<RawMessageTranslation>>();
+                    Ok((crate::sources::content_hash(&content), translations))
+                });
+            (key_symbol(&locale), file_key, translations)
         },
         |(locale, file_path, translations)| {
             (
                 file_path,
-                translations.and_then(|translations| {
+                translations.and_then(|(content_hash, translations)| {
                     crate::sources::insert_translations(
                         database,
                         file_path,
                         locale,
+                        content_hash,
                         translations.into_iter(),
                     )
                 }),
@@ -222,6 +273,18 @@ pub fn process_translation_file_content(
     Ok(source_file)
 }
 
+/// Start or stop recording the changes made to the database by every other operation, so that
+/// they can be retrieved with [take_changes].
+pub fn set_change_tracking(database: &mut MessagesDatabase, enabled: bool) {
+    database.set_change_tracking(enabled);
+}
+
+/// Return every change made to the database since the last call, as a diff from the previous
+/// state. Returns an empty list if changes are not being tracked.
+pub fn take_changes(database: &mut MessagesDatabase) -> Vec<DatabaseChange> {
+    database.take_changes()
+}
+
 pub fn get_known_locales(database: &MessagesDatabase) -> Vec<KeySymbol> {
     let locales = &database.known_locales;
 
@@ -303,10 +366,10 @@ pub fn precompile(
     locale: &str,
     output_path: &str,
     options: IntlMessageBundlerOptions,
-) -> anyhow::Result<()> {
-    let buffer = precompile_to_buffer(database, file_path, locale, options)?;
+) -> anyhow::Result<IntlMessageBundleReport> {
+    let (buffer, report) = bundle_to_buffer(database, file_path, locale, options)?;
     std::fs::write(output_path, buffer)?;
-    Ok(())
+    Ok(report)
 }
 
 pub fn precompile_to_buffer(
@@ -315,20 +378,39 @@ pub fn precompile_to_buffer(
     locale: &str,
     options: IntlMessageBundlerOptions,
 ) -> anyhow::Result<Vec<u8>> {
-    let locale_key = get_key_symbol_or_error(&locale)?;
+    Ok(bundle_to_buffer(database, file_path, locale, options)?.0)
+}
+
+fn bundle_to_buffer(
+    database: &MessagesDatabase,
+    file_path: &str,
+    locale: &str,
+    options: IntlMessageBundlerOptions,
+) -> anyhow::Result<(Vec<u8>, IntlMessageBundleReport)> {
+    // With fallbacks, a bundle can be built for a locale that has no translations at all, which
+    // means it may not be known to the database yet.
+    let locale_key = if options.has_fallbacks() {
+        key_symbol(locale)
+    } else {
+        get_key_symbol_or_error(&locale)?
+    };
     let source_key = get_key_symbol_or_error(file_path)?;
     let keys_count = database
         .get_source_file(source_key)
         .map_or(0, |source| source.message_keys().len());
     let mut result: Vec<u8> = Vec::with_capacity(keys_count * 80);
-    IntlMessageBundler::new(&database, &mut result, source_key, locale_key, options).run()?;
-    Ok(result.into())
+    let report =
+        IntlMessageBundler::new(&database, &mut result, source_key, locale_key, options).run()?;
+    Ok((result, report))
 }
 
-pub fn validate_messages(database: &MessagesDatabase) -> anyhow::Result<Vec<MessageDiagnostic>> {
+pub fn validate_messages(
+    database: &MessagesDatabase,
+    config: &ValidatorConfig,
+) -> anyhow::Result<Vec<MessageDiagnostic>> {
     let mut results = vec![];
     for message in database.messages.values() {
-        let diagnostics = validate_message(&message);
+        let diagnostics = validate_message(&message, database, config);
         if diagnostics.is_empty() {
             continue;
         }
@@ -347,6 +429,54 @@ pub fn export_translations(
     Ok(files)
 }
 
+/// Write a gettext template and a PO file for each locale into every translations directory,
+/// returning the list of files that were written.
+pub fn export_gettext(database: &MessagesDatabase) -> anyhow::Result<Vec<String>> {
+    let files = ExportGettext::new(&database).run()?;
+    Ok(files)
+}
+
+/// Write an XLIFF document for each locale into every translations directory, returning the list
+/// of files that were written.
+pub fn export_xliff(
+    database: &MessagesDatabase,
+    version: XliffVersion,
+) -> anyhow::Result<Vec<String>> {
+    let files = ExportXliff::new(&database, version).run()?;
+    Ok(files)
+}
+
+/// Write Android string resources for each locale into `resources_directory`, returning the
+/// files that were written and a diagnostic for every message that couldn't be exported.
+pub fn export_android_strings(
+    database: &MessagesDatabase,
+    resources_directory: &str,
+) -> anyhow::Result<MobileExportReport> {
+    let report = ExportAndroidStrings::new(&database, PathBuf::from(resources_directory)).run()?;
+    Ok(report)
+}
+
+/// Write Apple strings and stringsdict files for each locale into `resources_directory`,
+/// returning the files that were written and a diagnostic for every message that couldn't be
+/// exported.
+pub fn export_apple_strings(
+    database: &MessagesDatabase,
+    resources_directory: &str,
+) -> anyhow::Result<MobileExportReport> {
+    let report = ExportAppleStrings::new(&database, PathBuf::from(resources_directory)).run()?;
+    Ok(report)
+}
+
+/// Insert a pseudo-localized translation of every defined message into the database, returning
+/// the number of translations that were inserted.
+pub fn generate_pseudo_locale(
+    database: &mut MessagesDatabase,
+    options: PseudoLocaleOptions,
+) -> anyhow::Result<usize> {
+    let count = PseudoLocalizer::new(database, options).run()?;
+    Ok(count)
+}
+
 pub fn get_source_file_message_values<'a>(
     database: &'a MessagesDatabase,
     file_path: &str,
@@ -356,6 +486,15 @@ pub fn get_source_file_message_values<'a>(
     Ok(FxHashMap::from_iter(key_value_pairs))
 }
 
+/// Format the source text of a message, normalizing the ICU expressions it contains while keeping
+/// all of its Markdown syntax as written. Intended for formatting messages on save in an editor.
+pub fn format_message_source(content: &str) -> anyhow::Result<String> {
+    Ok(intl_markdown::format_message_source(
+        content,
+        intl_message_utils::message_may_have_blocks(content),
+    )?)
+}
+
 #[inline(always)]
 pub fn hash_message_key(key: &str) -> String {
     intl_message_utils::hash_message_key(key)
@@ -370,3 +509,292 @@ pub fn is_message_definitions_file(key: &str) -> bool {
 pub fn is_message_translations_file(key: &str) -> bool {
     intl_message_utils::is_message_translations_file(key)
 }
+
+pub struct WatchBatch {
+    /// Result of processing every file that was created or modified.
+    pub processed: MultiProcessingResult,
+    /// Files that were deleted and removed from the database.
+    pub removed: Vec<KeySymbol>,
+    /// Every locale represented by the processed and removed files.
+    pub locales: Vec<KeySymbol>,
+}
+
+/// Apply a batch of changes found by a [crate::watch::MessagesFileWatcher] to the database. Deleted
+/// files are removed along with all of the messages they provided, and created or modified files
+/// are processed again in the thread pool.
+pub fn apply_watch_changes(
+    database: &mut MessagesDatabase,
+    changes: WatchChanges,
+) -> anyhow::Result<WatchBatch> {
+    let mut locales = KeySymbolSet::default();
+    let mut removed = Vec::with_capacity(changes.removed.len());
+    for descriptor in changes.removed {
+        let file_key = key_symbol(&descriptor.file_path.to_string_lossy());
+        if let Some(source) = database.get_source_file(file_key) {
+            locales.insert(get_source_file_locale(database, source));
+        }
+        if database.remove_source_file(file_key).is_some() {
+            removed.push(file_key);
+        }
+    }
+
+    // Files can be deleted again between being seen by the watcher and being processed here.
+    let changed = changes
+        .changed
+        .into_iter()
+        .filter(|descriptor| descriptor.file_path.is_file())
+        .collect::<Vec<_>>();
+    let processed = process_all_messages_files(database, changed.into_iter())?;
+    for file_key in &processed.processed {
+        if let Some(source) = database.get_source_file(*file_key) {
+            locales.insert(get_source_file_locale(database, source));
+        }
+    }
+
+    Ok(WatchBatch {
+        processed,
+        removed,
+        locales: locales.into_iter().collect(),
+    })
+}
+
+/// Return the locale of the given source file. For definitions, this is the `sourceLocale` from
+/// the file's meta, or otherwise the locale the file was processed with, which is the source
+/// locale of any message that doesn't override it in its own meta.
+fn get_source_file_locale(database: &MessagesDatabase, source: &SourceFile) -> KeySymbol {
+    let definition = match source {
+        SourceFile::Translation(translation) => return *translation.locale(),
+        SourceFile::Definition(definition) => definition,
+    };
+    definition.meta().source_locale.unwrap_or_else(|| {
+        definition
+            .message_keys()
+            .iter()
+            .filter_map(|key| database.messages.get(key))
+            .find(|message| message.meta().source_locale.is_none())
+            .and_then(|message| *message.source_locale())
+            .unwrap_or_else(|| key_symbol(DEFAULT_LOCALE))
+    })
+}
+
+/// Write a binary snapshot of the entire database to `snapshot_path`, returning the number of
+/// source files that were recorded in it.
+///
+/// Every source file is recorded with the hash of the content it was processed from, so that
+/// [load_snapshot] can tell which files have changed since, including edits made after processing
+/// and before saving. Sources that don't exist on the file system, like content that was
+/// processed directly, are still included in the database but will never be re-processed.
+pub fn save_snapshot(database: &MessagesDatabase, snapshot_path: &str) -> anyhow::Result<usize> {
+    let mut files = Vec::with_capacity(database.sources.len());
+    for (file, source) in &database.sources {
+        let Some(content_hash) = source.content_hash() else {
+            continue;
+        };
+        if !std::path::Path::new(file.as_str()).is_file() {
+            continue;
+        }
+        files.push(SnapshotFile {
+            file: *file,
+            locale: get_source_file_locale(database, source),
+            content_hash,
+        });
+    }
+
+    std::fs::write(snapshot_path, database.write_snapshot(&files))?;
+    Ok(files.len())
+}
+
+pub struct SnapshotLoadResult {
+    /// Result of processing every file that changed or was added since the snapshot was saved.
+    pub reprocessed: MultiProcessingResult,
+    /// Files that no longer exist and were removed from the database.
+    pub removed: Vec<KeySymbol>,
+}
+
+/// Create a new database from a snapshot written by [save_snapshot]. Every file recorded in the
+/// snapshot is checked against its current content: files that have changed are processed again,
+/// and files that no longer exist are removed from the database along with their messages.
+///
+/// The snapshot only knows about the files it recorded, so `source_directories` are scanned as in
+/// [find_all_messages_files] to process any messages files created since it was saved. If no
+/// directories are given, new files are only found by scanning for them separately.
+pub fn load_snapshot<A: AsRef<str>>(
+    snapshot_path: &str,
+    source_directories: &[A],
+    default_definition_locale: &str,
+) -> anyhow::Result<(MessagesDatabase, SnapshotLoadResult)> {
+    let data = std::fs::read(snapshot_path)?;
+    let (mut database, files) = MessagesDatabase::read_snapshot(&data)?;
+
+    let mut changed = vec![];
+    let mut removed = vec![];
+    for SnapshotFile {
+        file,
+        locale,
+        content_hash,
+    } in files
+    {
+        match std::fs::read(file.as_str()) {
+            Ok(content) => {
+                if xxhash_rust::xxh64::xxh64(&content, 0) != content_hash {
+                    changed.push(MessagesFileDescriptor {
+                        file_path: PathBuf::from(file.as_str()),
+                        locale,
+                    });
+                }
+            }
+            Err(_) => {
+                database.remove_source_file(file);
+                removed.push(file);
+            }
+        }
+    }
+
+    if !source_directories.is_empty() {
+        let added = crate::sources::find_all_messages_files(
+            source_directories.iter(),
+            default_definition_locale,
+        )
+        .filter(|descriptor| {
+            let file_key = key_symbol(&descriptor.file_path.to_string_lossy());
+            !database.sources.contains_key(&file_key)
+        });
+        changed.extend(added);
+    }
+
+    let reprocessed = process_all_messages_files(&mut database, changed.into_iter())?;
+    Ok((
+        database,
+        SnapshotLoadResult {
+            reprocessed,
+            removed,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{key_symbol, MessagesDatabase};
+    use intl_database_exporter::{
+        CompiledMessageFormat, IntlMessageBundlerOptions, LocaleFallbacks,
+    };
+
+    use crate::sources::MessagesFileDescriptor;
+
+    use super::{
+        load_snapshot, precompile_to_buffer, process_all_messages_files, process_definitions_file,
+        process_definitions_file_content, save_snapshot,
+    };
+
+    #[test]
+    fn bundle_untranslated_locale_with_fallbacks() {
+        let mut database = MessagesDatabase::new();
+        process_definitions_file_content(
+            &mut database,
+            "Feature.messages.js",
+            "import {defineMessages} from '@discord/intl';\nexport default defineMessages({ GREETING: 'Hello' });\n",
+            None,
+        )
+        .unwrap();
+        let options =
+            || IntlMessageBundlerOptions::default().with_format(CompiledMessageFormat::Json);
+
+        assert!(
+            precompile_to_buffer(&database, "Feature.messages.js", "es-MX", options()).is_err()
+        );
+        let buffer = precompile_to_buffer(
+            &database,
+            "Feature.messages.js",
+            "es-MX",
+            options().with_fallbacks(LocaleFallbacks::default()),
+        )
+        .unwrap();
+        assert!(String::from_utf8(buffer).unwrap().contains("Hello"));
+    }
+
+    #[test]
+    fn snapshot_reprocesses_files_edited_before_saving() {
+        let directory = tempfile::tempdir().unwrap();
+        let file_path = directory.path().join("Feature.messages.js");
+        let file_path = file_path.to_str().unwrap();
+        let snapshot_path = directory.path().join("snapshot.bin");
+        let snapshot_path = snapshot_path.to_str().unwrap();
+        let definitions = |value: &str| {
+            format!("import {{defineMessages}} from '@discord/intl';\nexport default defineMessages({{ GREETING: '{value}' }});\n")
+        };
+
+        let mut database = MessagesDatabase::new();
+        std::fs::write(file_path, definitions("Hello")).unwrap();
+        process_definitions_file(&mut database, file_path, None).unwrap();
+        // The edit hasn't been processed, so the snapshot must not treat it as up to date.
+        std::fs::write(file_path, definitions("Goodbye")).unwrap();
+        assert_eq!(save_snapshot(&database, snapshot_path).unwrap(), 1);
+
+        let (database, result) = load_snapshot::<&str>(snapshot_path, &[], "en-US").unwrap();
+        assert_eq!(result.reprocessed.processed, vec![key_symbol(file_path)]);
+        let message = database.get_message("GREETING").unwrap();
+        assert_eq!(message.get_source_translation().unwrap().raw, "Goodbye");
+    }
+
+    #[test]
+    fn snapshot_processes_files_added_after_saving() {
+        let directory = tempfile::tempdir().unwrap();
+        let source_directory = directory.path().to_str().unwrap();
+        let existing_path = directory.path().join("Existing.messages.js");
+        let added_path = directory.path().join("Added.messages.js");
+        let snapshot_path = directory.path().join("snapshot.bin");
+        let snapshot_path = snapshot_path.to_str().unwrap();
+        let definitions = |name: &str| {
+            format!("import {{defineMessages}} from '@discord/intl';\nexport default defineMessages({{ {name}: 'Hello' }});\n")
+        };
+
+        let mut database = MessagesDatabase::new();
+        std::fs::write(&existing_path, definitions("EXISTING")).unwrap();
+        process_definitions_file(&mut database, existing_path.to_str().unwrap(), None).unwrap();
+        assert_eq!(save_snapshot(&database, snapshot_path).unwrap(), 1);
+        std::fs::write(&added_path, definitions("ADDED")).unwrap();
+
+        let (database, result) =
+            load_snapshot(snapshot_path, &[source_directory], "en-US").unwrap();
+        assert_eq!(
+            result.reprocessed.processed,
+            vec![key_symbol(added_path.to_str().unwrap())]
+        );
+        assert!(database.get_message("EXISTING").is_some());
+        assert!(database.get_message("ADDED").is_some());
+    }
+
+    #[test]
+    fn unreadable_files_fail_without_panicking() {
+        let directory = tempfile::tempdir().unwrap();
+        let file_path = directory.path().join("Missing.messages.js");
+        let mut database = MessagesDatabase::new();
+        let result = process_all_messages_files(
+            &mut database,
+            vec![MessagesFileDescriptor {
+                file_path: file_path.clone(),
+                locale: key_symbol("en-US"),
+            }]
+            .into_iter(),
+        )
+        .unwrap();
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].0, key_symbol(file_path.to_str().unwrap()));
+    }
+
+    #[test]
+    fn invalid_definitions_are_returned_with_valid_ones_inserted() {
+        let mut database = MessagesDatabase::new();
+        let (file_key, diagnostics) = process_definitions_file_content(
+            &mut database,
+            "Feature.messages.js",
+            "import {defineMessages} from '@discord/intl';\nexport default defineMessages({ GREETING: 'Hello', BROKEN: 42 });\n",
+            None,
+        )
+        .unwrap();
+        assert_eq!(file_key, key_symbol("Feature.messages.js"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].name, Some(key_symbol("BROKEN")));
+        assert!(database.get_message("GREETING").unwrap().is_defined());
+    }
+}
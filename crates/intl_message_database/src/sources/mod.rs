@@ -1,11 +1,14 @@
 use ignore::WalkBuilder;
 use intl_database_core::{
     key_symbol, DatabaseError, DatabaseResult, DefinitionFile, FilePosition, KeySymbol,
-    KeySymbolSet, MessageDefinitionSource, MessageTranslationSource, MessagesDatabase, RawMessage,
-    RawMessageDefinition, RawMessageTranslation, SourceFile, SourceFileMeta, TranslationFile,
+    KeySymbolSet, MessageDefinitionSource, MessageSourceDiagnostic, MessageSourceResult,
+    MessageTranslationSource, MessagesDatabase, RawMessage, RawMessageDefinition,
+    RawMessageTranslation, SourceFile, SourceFileMeta, TranslationFile,
 };
 use intl_database_js_source::JsMessageSource;
 use intl_database_json_source::JsonMessageSource;
+use intl_database_po_source::PoMessageSource;
+use intl_database_xliff_source::XliffMessageSource;
 use intl_message_utils::{is_any_messages_file, is_message_translations_file};
 use rustc_hash::FxHashSet;
 use serde::Serialize;
@@ -54,16 +57,60 @@ impl<T: RawMessage, I: Iterator<Item = T>> FusedIterator
 }
 
 fn get_definition_source_from_file_name(file_name: &str) -> Option<impl MessageDefinitionSource> {
-    if file_name.ends_with(".js") {
+    if [".js", ".jsx", ".ts", ".tsx"]
+        .iter()
+        .any(|extension| file_name.ends_with(extension))
+    {
         Some(JsMessageSource)
     } else {
         None
     }
 }
 
+/// Every supported format for translation files. Each one has its own iterator of translations,
+/// so they are dispatched through this enum rather than returned directly.
+enum TranslationSource {
+    Json(JsonMessageSource),
+    Po(PoMessageSource),
+    Xliff(XliffMessageSource),
+}
+
+impl MessageTranslationSource for TranslationSource {
+    fn get_locale_from_file_name(&self, file_name: &str) -> KeySymbol {
+        match self {
+            TranslationSource::Json(source) => source.get_locale_from_file_name(file_name),
+            TranslationSource::Po(source) => source.get_locale_from_file_name(file_name),
+            TranslationSource::Xliff(source) => source.get_locale_from_file_name(file_name),
+        }
+    }
+
+    fn extract_translations(
+        self,
+        file_name: KeySymbol,
+        content: &str,
+    ) -> MessageSourceResult<impl Iterator<Item = RawMessageTranslation> + '_> {
+        let translations: Box<dyn Iterator<Item = RawMessageTranslation>> = match self {
+            TranslationSource::Json(source) => {
+                Box::new(source.extract_translations(file_name, content)?)
+            }
+            TranslationSource::Po(source) => {
+                Box::new(source.extract_translations(file_name, content)?)
+            }
+            TranslationSource::Xliff(source) => {
+                Box::new(source.extract_translations(file_name, content)?)
+            }
+        };
+        Ok(translations)
+    }
+}
+
 fn get_translation_source_from_file_name(file_name: &str) -> Option<impl MessageTranslationSource> {
     if file_name.ends_with(".json") || file_name.ends_with(".jsona") {
-        Some(JsonMessageSource)
+        Some(TranslationSource::Json(JsonMessageSource))
+    } else if file_name.ends_with(".po") {
+        Some(TranslationSource::Po(PoMessageSource))
+    } else if file_name.ends_with(".xlf") || file_name.ends_with(".xliff") {
+        Some(TranslationSource::Xliff(XliffMessageSource))
     } else {
         None
     }
@@ -121,30 +168,45 @@ pub fn find_all_messages_files<A: AsRef<str>>(
         }
         found_files.insert(file_path.clone());
 
-        let Some(basename) = file_path.file_name() else {
-            return None;
-        };
-        let basename = &basename.to_string_lossy();
-        if item.file_type().is_some_and(|file_type| file_type.is_dir())
-            || !is_any_messages_file(basename)
-        {
+        if item.file_type().is_some_and(|file_type| file_type.is_dir()) {
             return None;
         }
-        let locale = get_locale_from_file_name(&basename, default_definition_locale);
-        Some(MessagesFileDescriptor { file_path, locale })
+        get_messages_file_descriptor(file_path, default_definition_locale)
     })
 }
 
+/// Return the descriptor for `file_path` if its name marks it as a messages file, with the locale
+/// it should represent, defaulting to `default_definition_locale` for definitions.
+pub(crate) fn get_messages_file_descriptor(
+    file_path: PathBuf,
+    default_definition_locale: KeySymbol,
+) -> Option<MessagesFileDescriptor> {
+    let basename = file_path.file_name()?.to_string_lossy();
+    if !is_any_messages_file(&basename) {
+        return None;
+    }
+    let locale = get_locale_from_file_name(&basename, default_definition_locale);
+    Some(MessagesFileDescriptor { file_path, locale })
+}
+
 pub fn process_definitions_file(
     db: &mut MessagesDatabase,
     file_name: &str,
     content: &str,
     locale: &str,
-) -> DatabaseResult<KeySymbol> {
+) -> DatabaseResult<(KeySymbol, Vec<MessageSourceDiagnostic>)> {
     let file_key = key_symbol(file_name);
     let locale_key = key_symbol(locale);
-    let (file_meta, definitions) = extract_definitions_from_file(file_key, content)?;
-    insert_definitions(db, file_key, locale_key, file_meta, definitions)
+    let (file_meta, definitions, diagnostics) = extract_definitions_from_file(file_key, content)?;
+    insert_definitions(
+        db,
+        file_key,
+        locale_key,
+        content_hash(content),
+        file_meta,
+        definitions,
+        diagnostics,
+    )
 }
 
 pub fn extract_definitions_from_file(
@@ -153,6 +215,7 @@ pub fn extract_definitions_from_file(
 ) -> DatabaseResult<(
     SourceFileMeta,
     impl Iterator<Item = RawMessageDefinition> + '_,
+    Vec<MessageSourceDiagnostic>,
 )> {
     let source = get_definition_source_from_file_name(&file_key)
         .ok_or(DatabaseError::NoSourceImplementation(file_key.to_string()))?;
@@ -162,13 +225,23 @@ pub fn extract_definitions_from_file(
         .map_err(DatabaseError::SourceError)
 }
 
+/// Return the hash of the `content` of a source file, recorded on the file when it's processed.
+pub fn content_hash(content: &str) -> u64 {
+    xxhash_rust::xxh64::xxh64(content.as_bytes(), 0)
+}
+
+/// Insert all of the given definitions from the source file into the database. If any
+/// `diagnostics` were reported while extracting them, the valid definitions are still inserted,
+/// and the diagnostics are returned along with the file key so the caller can report them.
 pub fn insert_definitions(
     db: &mut MessagesDatabase,
     file_key: KeySymbol,
     locale_key: KeySymbol,
+    content_hash: u64,
     source_file_meta: SourceFileMeta,
     definitions: impl Iterator<Item = RawMessageDefinition>,
-) -> DatabaseResult<KeySymbol> {
+    diagnostics: Vec<MessageSourceDiagnostic>,
+) -> DatabaseResult<(KeySymbol, Vec<MessageSourceDiagnostic>)> {
     let source_file = db.get_or_create_source_file(
         file_key,
         SourceFile::Definition(DefinitionFile::new(
@@ -190,11 +263,12 @@ pub fn insert_definitions(
     }
 
     db.set_source_file_keys(file_key, iterator.inserted_keys)?;
+    db.set_source_file_content_hash(file_key, content_hash)?;
     for key in iterator.removed_keys {
         db.remove_definition(key);
     }
 
-    Ok(file_key)
+    Ok((file_key, diagnostics))
 }
 
 pub fn process_translations_file(
@@ -206,7 +280,13 @@ pub fn process_translations_file(
     let file_key = key_symbol(file_name);
     let locale_key = key_symbol(&locale);
     let translations = extract_translations_from_file(file_key, content)?;
-    insert_translations(db, file_key, locale_key, translations)
+    insert_translations(
+        db,
+        file_key,
+        locale_key,
+        content_hash(content),
+        translations,
+    )
 }
 
 pub fn extract_translations_from_file(
@@ -224,6 +304,7 @@ pub fn insert_translations(
     db: &mut MessagesDatabase,
     file_key: KeySymbol,
     locale_key: KeySymbol,
+    content_hash: u64,
     translations: impl Iterator<Item = RawMessageTranslation>,
 ) -> DatabaseResult<KeySymbol> {
     let source_file = db.get_or_create_source_file(
@@ -252,5 +333,6 @@ pub fn insert_translations(
     }
 
     db.set_source_file_keys(file_key, iterator.inserted_keys)?;
+    db.set_source_file_content_hash(file_key, content_hash)?;
     Ok(file_key)
 }
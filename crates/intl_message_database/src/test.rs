@@ -1,14 +1,16 @@
 use std::collections::HashMap;
 use std::path::Path;
 
-use crate::napi::IntlMessagesDatabase;
+use intl_database_core::MessagesDatabase;
+
+use crate::public;
 
 #[test]
 pub fn test() {
     let input_root = Path::new("./data/input").canonicalize().expect("success");
     let output_root = Path::new("./data/output").canonicalize().expect("success");
     let definitions_path = input_root.join("en-US.js").to_string_lossy().to_string();
-    let mut database = IntlMessagesDatabase::new();
+    let mut database = MessagesDatabase::new();
 
     let locales = vec![
         "bg", "cs", "da", "de", "el", "en-GB", "es-419", "es-ES", "fi", "fr", "hi", "hr", "hu",
@@ -33,8 +35,7 @@ pub fn test() {
         );
     }
 
-    database
-        .process_definitions_file(definitions_path, Some("en-US".into()))
+    public::process_definitions_file(&mut database, &definitions_path, Some("en-US"))
         .expect("failed to process definitions");
 
     // for (locale, [input, output]) in source_files {
@@ -45,5 +46,5 @@ pub fn test() {
 
     let source = input_root.join("en-US.js").to_string_lossy().to_string();
     let output = input_root.join("en-US.d.ts").to_string_lossy().to_string();
-    database.generate_types(source, output, None);
+    public::generate_types(&database, &source, &output, None).ok();
 }
@@ -38,6 +38,9 @@ pub(crate) fn get_reasonable_thread_count() -> usize {
 /// pre-determined size (i.e., some threads may be reused if there are more items than threads
 /// available). The result for each element is sent back to the main thread, where `processor` is
 /// called with it as the argument.
+///
+/// If `thread_func` panics for any element, every other result is still processed, and then an
+/// error is returned rather than waiting forever for the missing result.
 pub(crate) fn run_in_thread_pool<
     Data: IntoIterator<Item = T> + ExactSizeIterator,
     T: Send + Sync + 'static, // Data being processed
@@ -51,7 +54,8 @@ pub(crate) fn run_in_thread_pool<
     mut processor: F,
 ) -> anyhow::Result<Vec<R>> {
     let num_jobs = data.len();
-    let pool = ThreadPool::new(get_reasonable_thread_count());
+    // Small machines can compute zero threads, but there always needs to be one to do the work.
+    let pool = ThreadPool::new(get_reasonable_thread_count().max(1));
     let (tx, rx) = channel();
     for datum in data {
         let tx = tx.clone();
@@ -63,9 +67,39 @@ pub(crate) fn run_in_thread_pool<
         });
     }
 
+    // Only the jobs hold senders from here on, so the channel closes once every job has either
+    // sent its result or panicked and dropped its sender while unwinding.
+    drop(tx);
+
     let mut results = Vec::with_capacity(num_jobs);
-    for result in rx.iter().take(num_jobs) {
+    for result in rx.iter() {
         results.push(processor(result));
     }
+    if results.len() < num_jobs {
+        anyhow::bail!(
+            "{} of {num_jobs} jobs in the thread pool failed to complete",
+            num_jobs - results.len()
+        );
+    }
     Ok(results)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::run_in_thread_pool;
+
+    #[test]
+    fn panicked_jobs_do_not_block() {
+        let result = run_in_thread_pool(
+            (0..4).collect::<Vec<u32>>().into_iter(),
+            |value| {
+                if value == 2 {
+                    panic!("job failed");
+                }
+                value
+            },
+            |value| value,
+        );
+        assert!(result.is_err());
+    }
+}
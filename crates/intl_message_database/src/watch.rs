@@ -0,0 +1,506 @@
+//! Native watch mode for messages files, so that bundler plugins don't each need to implement
+//! their own watcher that calls back into the database one file at a time.
+//!
+//! The watcher subscribes to file system notifications from the operating system for the source
+//! directories. The paths in each notification are filtered the same way as
+//! [crate::sources::find_all_messages_files], which is also used for the initial scan and for
+//! directories that are created or moved into the watched directories. Once a change is seen,
+//! notifications continue to be collected until the directories have been quiet for the debounce
+//! duration, and every change in that time is reported together as a single batch.
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use intl_database_core::{key_symbol, KeySymbol};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use rustc_hash::FxHashMap;
+
+use crate::sources::{
+    find_all_messages_files, get_messages_file_descriptor, MessagesFileDescriptor,
+};
+
+/// How often `stop` is checked while waiting for the first change of a batch.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+pub struct WatchOptions {
+    /// How long the directories must go without any further changes before a batch is reported.
+    pub debounce: Duration,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            debounce: Duration::from_millis(50),
+        }
+    }
+}
+
+impl WatchOptions {
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WatchChanges {
+    /// Files that were created or modified.
+    pub changed: Vec<MessagesFileDescriptor>,
+    /// Files that were deleted.
+    pub removed: Vec<MessagesFileDescriptor>,
+}
+
+impl WatchChanges {
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Names of the files with ignore rules that apply to the directory they're in, in order of
+/// precedence. `.gitignore` only applies within a git repository.
+const IGNORE_FILE_NAMES: [&str; 2] = [".ignore", ".gitignore"];
+
+/// The same rules that the walker used by [find_all_messages_files] uses to skip paths, applied to
+/// paths from notifications: hidden entries, and anything matched by an `.ignore` file, or by a
+/// `.gitignore` file or `.git/info/exclude` in a git repository, in the path's directory or any
+/// of its ancestors, where the deepest match decides.
+#[derive(Default)]
+struct IgnoreRules {
+    /// The rules from the ignore files in each directory, loaded as they are needed.
+    directories: FxHashMap<PathBuf, Vec<Gitignore>>,
+}
+
+impl IgnoreRules {
+    /// Return whether the walker would skip `path` when scanning `root`, either because it is
+    /// ignored itself or because it is inside an ignored directory.
+    fn is_ignored(&mut self, root: &Path, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(root) else {
+            return false;
+        };
+        let components: Vec<_> = relative.components().collect();
+        let mut current = root.to_path_buf();
+        for (index, component) in components.iter().enumerate() {
+            current.push(component);
+            if component.as_os_str().to_string_lossy().starts_with('.') {
+                return true;
+            }
+            let is_dir = index + 1 < components.len() || current.is_dir();
+            if self.matches(&current, is_dir) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn matches(&mut self, path: &Path, is_dir: bool) -> bool {
+        for directory in path.ancestors().skip(1) {
+            for rules in self.rules_for(directory) {
+                match rules.matched(path, is_dir) {
+                    Match::Ignore(_) => return true,
+                    Match::Whitelist(_) => return false,
+                    Match::None => {}
+                }
+            }
+        }
+        false
+    }
+
+    fn rules_for(&mut self, directory: &Path) -> &[Gitignore] {
+        self.directories
+            .entry(directory.to_path_buf())
+            .or_insert_with(|| {
+                let is_git_repository = directory
+                    .ancestors()
+                    .any(|ancestor| ancestor.join(".git").exists());
+                let mut files: Vec<PathBuf> = IGNORE_FILE_NAMES
+                    .iter()
+                    .filter(|name| is_git_repository || **name != ".gitignore")
+                    .map(|name| directory.join(name))
+                    .collect();
+                files.push(directory.join(".git").join("info").join("exclude"));
+                files
+                    .into_iter()
+                    .filter(|file| file.is_file())
+                    .filter_map(|file| {
+                        let mut builder = GitignoreBuilder::new(directory);
+                        builder.add(file);
+                        builder.build().ok()
+                    })
+                    .collect()
+            })
+    }
+
+    /// Forget every loaded rule if `path` is a file that rules are loaded from.
+    fn invalidate(&mut self, path: &Path) {
+        let is_ignore_file = path
+            .file_name()
+            .is_some_and(|name| IGNORE_FILE_NAMES.iter().any(|ignore| name == *ignore))
+            || path.ends_with(Path::new(".git").join("info").join("exclude"));
+        if is_ignore_file {
+            self.directories.clear();
+        }
+    }
+}
+
+pub struct MessagesFileWatcher {
+    directories: Vec<String>,
+    ignore_rules: IgnoreRules,
+    default_definition_locale: KeySymbol,
+    /// Every messages file known to exist within the directories, with the locale it represents.
+    files: FxHashMap<PathBuf, KeySymbol>,
+    events: Receiver<notify::Result<Event>>,
+    /// Notifications stop being sent once this is dropped.
+    _watcher: RecommendedWatcher,
+}
+
+impl MessagesFileWatcher {
+    /// Create a watcher for all messages files within `directories`. Files that already exist are
+    /// treated as up to date, so only changes made after this point will be reported.
+    pub fn new(directories: Vec<String>, default_definition_locale: &str) -> notify::Result<Self> {
+        let (sender, events) = channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        // Watching starts before the initial scan so that nothing changed in between is missed.
+        for directory in &directories {
+            watcher.watch(Path::new(directory), RecursiveMode::Recursive)?;
+        }
+        let mut watcher = Self {
+            directories,
+            default_definition_locale: key_symbol(default_definition_locale),
+            ignore_rules: IgnoreRules::default(),
+            files: FxHashMap::default(),
+            events,
+            _watcher: watcher,
+        };
+        watcher.files = watcher.scan_files(&watcher.directories);
+        Ok(watcher)
+    }
+
+    fn scan_files<A: AsRef<str>>(&self, directories: &[A]) -> FxHashMap<PathBuf, KeySymbol> {
+        find_all_messages_files(directories.iter(), &self.default_definition_locale)
+            .map(|MessagesFileDescriptor { file_path, locale }| (file_path, locale))
+            .collect()
+    }
+
+    /// Block until any file changes, then keep collecting changes until none are seen for the
+    /// debounce duration, returning everything that changed in that time as one batch. Returns
+    /// `None` once `stop` is set.
+    pub fn wait_for_changes(
+        &mut self,
+        options: &WatchOptions,
+        stop: &AtomicBool,
+    ) -> Option<WatchChanges> {
+        // Each file only keeps its latest state, so a file that is modified and then deleted
+        // within the same batch is only reported as removed, and vice versa.
+        let mut pending: FxHashMap<PathBuf, (KeySymbol, bool)> = FxHashMap::default();
+        loop {
+            let timeout = match pending.is_empty() {
+                true => STOP_CHECK_INTERVAL,
+                false => options.debounce,
+            };
+            match self.events.recv_timeout(timeout) {
+                Ok(Ok(event)) if event.need_rescan() => self.rescan(&mut pending),
+                Ok(Ok(event)) => self.handle_event(event, &mut pending),
+                // Notifications may have been lost, so the only way to know what changed is to
+                // look at everything again.
+                Ok(Err(_)) => self.rescan(&mut pending),
+                Err(RecvTimeoutError::Timeout) if !pending.is_empty() => break,
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return None,
+            }
+            if stop.load(Ordering::Relaxed) {
+                return None;
+            }
+        }
+
+        let mut changes = WatchChanges::default();
+        for (file_path, (locale, exists)) in pending {
+            let descriptor = MessagesFileDescriptor { file_path, locale };
+            match exists {
+                true => changes.changed.push(descriptor),
+                false => changes.removed.push(descriptor),
+            }
+        }
+        Some(changes)
+    }
+
+    fn handle_event(&mut self, event: Event, pending: &mut FxHashMap<PathBuf, (KeySymbol, bool)>) {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        // Directories that are created or moved in don't have notifications for the files
+        // already inside them, so those are found by scanning. Other changes to a directory, like
+        // its metadata, don't affect the files inside it.
+        let is_added = matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(_))
+        );
+        for path in event.paths {
+            self.ignore_rules.invalidate(&path);
+            if path.exists() && self.is_ignored(&path) {
+                continue;
+            }
+            if path.is_dir() {
+                if !is_added {
+                    continue;
+                }
+                for (file_path, locale) in self.scan_files(&[path.to_string_lossy()]) {
+                    self.files.insert(file_path.clone(), locale);
+                    pending.insert(file_path, (locale, true));
+                }
+            } else if path.is_file() {
+                let Some(MessagesFileDescriptor { file_path, locale }) =
+                    get_messages_file_descriptor(path, self.default_definition_locale)
+                else {
+                    continue;
+                };
+                self.files.insert(file_path.clone(), locale);
+                pending.insert(file_path, (locale, true));
+            } else {
+                // The path no longer exists, and may have been a directory of known files.
+                let removed: Vec<PathBuf> = self
+                    .files
+                    .keys()
+                    .filter(|file_path| file_path.starts_with(&path))
+                    .cloned()
+                    .collect();
+                for file_path in removed {
+                    if let Some(locale) = self.files.remove(&file_path) {
+                        pending.insert(file_path, (locale, false));
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_ignored(&mut self, path: &Path) -> bool {
+        let Some(root) = self
+            .directories
+            .iter()
+            .map(Path::new)
+            .find(|root| path.starts_with(root))
+        else {
+            return true;
+        };
+        self.ignore_rules.is_ignored(root, path)
+    }
+
+    /// Scan the directories again, treating every file that exists as changed and every known
+    /// file that no longer exists as removed.
+    fn rescan(&mut self, pending: &mut FxHashMap<PathBuf, (KeySymbol, bool)>) {
+        let files = self.scan_files(&self.directories);
+        for (file_path, locale) in &self.files {
+            if !files.contains_key(file_path) {
+                pending.insert(file_path.clone(), (*locale, false));
+            }
+        }
+        for (file_path, locale) in &files {
+            pending.insert(file_path.clone(), (*locale, true));
+        }
+        self.files = files;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::sync::atomic::AtomicBool;
+    use std::time::Duration;
+
+    use intl_database_core::{key_symbol, DatabaseChange, MessagesDatabase};
+
+    use super::{MessagesFileWatcher, WatchOptions};
+    use crate::public;
+
+    fn write_definitions(path: &Path, messages: &[(&str, &str)]) {
+        let mut content = String::from(
+            "import {defineMessages} from '@discord/intl';\nexport default defineMessages({\n",
+        );
+        for (key, value) in messages {
+            content.push_str(&format!("  {key}: '{value}',\n"));
+        }
+        content.push_str("});\n");
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn options() -> WatchOptions {
+        WatchOptions::default().with_debounce(Duration::from_millis(50))
+    }
+
+    fn watcher(directory: &Path) -> MessagesFileWatcher {
+        MessagesFileWatcher::new(vec![directory.to_string_lossy().to_string()], "en-US").unwrap()
+    }
+
+    #[test]
+    fn test_watch_changes() {
+        let directory = tempfile::tempdir().unwrap();
+        let definitions = directory.path().join("Feature.messages.js");
+        let translations = directory.path().join("fr.messages.json");
+        write_definitions(&definitions, &[("GREETING", "Hello")]);
+
+        let mut database = MessagesDatabase::new();
+        let files =
+            public::find_all_messages_files([directory.path().to_string_lossy()].iter(), "en-US");
+        public::process_all_messages_files(&mut database, files.into_iter()).unwrap();
+        let mut watcher = watcher(directory.path());
+        let stop = AtomicBool::new(false);
+
+        write_definitions(
+            &definitions,
+            &[("GREETING", "Hello there"), ("FAREWELL", "Goodbye")],
+        );
+        std::fs::write(&translations, r#"{"GREETING": "Bonjour"}"#).unwrap();
+        let changes = watcher.wait_for_changes(&options(), &stop).unwrap();
+        assert_eq!(changes.changed.len(), 2);
+        let batch = public::apply_watch_changes(&mut database, changes).unwrap();
+        assert!(batch.processed.failed.is_empty());
+        assert!(batch.locales.contains(&key_symbol("en-US")));
+        assert!(batch.locales.contains(&key_symbol("fr")));
+        assert!(database.get_message("FAREWELL").is_some());
+        assert!(database
+            .get_message("GREETING")
+            .unwrap()
+            .translations()
+            .contains_key(&key_symbol("fr")));
+
+        std::fs::remove_file(&definitions).unwrap();
+        let changes = watcher.wait_for_changes(&options(), &stop).unwrap();
+        assert_eq!(changes.removed.len(), 1);
+        let batch = public::apply_watch_changes(&mut database, changes).unwrap();
+        let definitions_key = key_symbol(&definitions.to_string_lossy());
+        assert_eq!(batch.removed, vec![definitions_key]);
+        assert!(database.get_source_file(definitions_key).is_none());
+        assert!(!database.get_message("FAREWELL").unwrap().is_defined());
+    }
+
+    #[test]
+    fn test_watch_records_changes() {
+        let directory = tempfile::tempdir().unwrap();
+        let definitions = directory.path().join("Feature.messages.js");
+        write_definitions(&definitions, &[("GREETING", "Hello")]);
+
+        let mut database = MessagesDatabase::new();
+        let files =
+            public::find_all_messages_files([directory.path().to_string_lossy()].iter(), "en-US");
+        public::process_all_messages_files(&mut database, files.into_iter()).unwrap();
+        public::set_change_tracking(&mut database, true);
+        let mut watcher = watcher(directory.path());
+        let stop = AtomicBool::new(false);
+
+        write_definitions(&definitions, &[("GREETING", "Hello there")]);
+        let changes = watcher.wait_for_changes(&options(), &stop).unwrap();
+        public::apply_watch_changes(&mut database, changes).unwrap();
+        assert_eq!(
+            public::take_changes(&mut database),
+            vec![DatabaseChange::MessageChanged {
+                key: key_symbol("GREETING")
+            }]
+        );
+    }
+
+    #[test]
+    fn test_watch_moved_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        let feature = outside.path().join("feature");
+        std::fs::create_dir(&feature).unwrap();
+        write_definitions(
+            &feature.join("Feature.messages.js"),
+            &[("GREETING", "Hello")],
+        );
+        std::fs::write(feature.join("notes.txt"), "Not a messages file").unwrap();
+
+        let mut watcher = watcher(directory.path());
+        let stop = AtomicBool::new(false);
+
+        let moved = directory.path().join("feature");
+        std::fs::rename(&feature, &moved).unwrap();
+        let changes = watcher.wait_for_changes(&options(), &stop).unwrap();
+        assert_eq!(changes.changed.len(), 1);
+        assert_eq!(
+            changes.changed[0].file_path,
+            moved.join("Feature.messages.js")
+        );
+
+        std::fs::remove_dir_all(&moved).unwrap();
+        let changes = watcher.wait_for_changes(&options(), &stop).unwrap();
+        assert!(changes.changed.is_empty());
+        assert_eq!(changes.removed.len(), 1);
+    }
+
+    #[test]
+    fn test_watch_directory_metadata() {
+        let directory = tempfile::tempdir().unwrap();
+        let feature = directory.path().join("feature");
+        std::fs::create_dir(&feature).unwrap();
+        write_definitions(
+            &feature.join("Feature.messages.js"),
+            &[("GREETING", "Hello")],
+        );
+        let mut watcher = watcher(directory.path());
+        let stop = AtomicBool::new(false);
+
+        let mut permissions = std::fs::metadata(&feature).unwrap().permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&feature, permissions.clone()).unwrap();
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        std::fs::set_permissions(&feature, permissions).unwrap();
+        let other = directory.path().join("Other.messages.js");
+        write_definitions(&other, &[("FAREWELL", "Goodbye")]);
+        let changes = watcher.wait_for_changes(&options(), &stop).unwrap();
+        assert_eq!(changes.changed.len(), 1);
+        assert_eq!(changes.changed[0].file_path, other);
+    }
+
+    #[test]
+    fn test_watch_ignored_paths() {
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::create_dir(directory.path().join(".git")).unwrap();
+        std::fs::write(directory.path().join(".gitignore"), "dist/\n").unwrap();
+        std::fs::write(
+            directory.path().join(".ignore"),
+            "*.generated.messages.js\n",
+        )
+        .unwrap();
+        for ignored in ["dist", ".cache", "node_modules"] {
+            std::fs::create_dir(directory.path().join(ignored)).unwrap();
+        }
+        std::fs::write(
+            directory.path().join("node_modules").join(".gitignore"),
+            "*\n",
+        )
+        .unwrap();
+        let mut watcher = watcher(directory.path());
+        let stop = AtomicBool::new(false);
+
+        for ignored in [
+            directory.path().join("dist").join("Feature.messages.js"),
+            directory.path().join(".cache").join("Feature.messages.js"),
+            directory
+                .path()
+                .join("node_modules")
+                .join("Feature.messages.js"),
+            directory.path().join("Feature.generated.messages.js"),
+        ] {
+            write_definitions(&ignored, &[("GREETING", "Hello")]);
+        }
+        let definitions = directory.path().join("Feature.messages.js");
+        write_definitions(&definitions, &[("GREETING", "Hello")]);
+        let changes = watcher.wait_for_changes(&options(), &stop).unwrap();
+        assert_eq!(changes.changed.len(), 1);
+        assert_eq!(changes.changed[0].file_path, definitions);
+    }
+
+    #[test]
+    fn test_watch_stop() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut watcher = watcher(directory.path());
+        assert!(watcher
+            .wait_for_changes(&options(), &AtomicBool::new(true))
+            .is_none());
+    }
+}
@@ -0,0 +1,326 @@
+//! A minimal binary encoding used for persisting data between runs, like database snapshots.
+//!
+//! The format has no schema or self-description: values are written in a fixed order and must be
+//! read back in exactly the same order, so any change to what a type writes must also bump the
+//! version of whatever container holds it.
+//!
+//! Integers are written as little-endian, lengths as `u32`, and strings as UTF-8 bytes prefixed by
+//! their length.
+use std::ops::Range;
+
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum BinaryReadError {
+    #[error("Unexpected end of data while reading {0}")]
+    UnexpectedEnd(&'static str),
+    #[error("Invalid UTF-8 in string at offset {0}")]
+    InvalidUtf8(usize),
+    #[error("Invalid tag {tag} for {name} at offset {offset}")]
+    InvalidTag {
+        name: &'static str,
+        tag: u8,
+        offset: usize,
+    },
+}
+
+pub type BinaryReadResult<T> = Result<T, BinaryReadError>;
+
+/// A type that can be written to a [BinaryWriter].
+pub trait BinaryEncode {
+    fn encode(&self, writer: &mut BinaryWriter);
+}
+
+/// A type that can be read back from a [BinaryReader] after being written with [BinaryEncode].
+pub trait BinaryDecode: Sized {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self>;
+}
+
+#[derive(Default)]
+pub struct BinaryWriter {
+    buffer: Vec<u8>,
+}
+
+impl BinaryWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+    pub fn write_u8(&mut self, value: u8) {
+        self.buffer.push(value);
+    }
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_u8(value as u8);
+    }
+    pub fn write_u32(&mut self, value: u32) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+    pub fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+    /// Write a length or offset. Values are stored as `u32`, so anything larger will panic.
+    pub fn write_usize(&mut self, value: usize) {
+        self.write_u32(u32::try_from(value).expect("binary lengths must fit in a u32"));
+    }
+    pub fn write_str(&mut self, value: &str) {
+        self.write_usize(value.len());
+        self.write_bytes(value.as_bytes());
+    }
+
+    pub fn write<T: BinaryEncode + ?Sized>(&mut self, value: &T) {
+        value.encode(self);
+    }
+}
+
+pub struct BinaryReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// Current position of the reader from the start of the data.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offset >= self.data.len()
+    }
+
+    pub fn read_bytes(&mut self, length: usize, name: &'static str) -> BinaryReadResult<&'a [u8]> {
+        let end = self
+            .offset
+            .checked_add(length)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(BinaryReadError::UnexpectedEnd(name))?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+    pub fn read_u8(&mut self) -> BinaryReadResult<u8> {
+        Ok(self.read_bytes(1, "u8")?[0])
+    }
+    pub fn read_bool(&mut self) -> BinaryReadResult<bool> {
+        Ok(self.read_u8()? != 0)
+    }
+    pub fn read_u32(&mut self) -> BinaryReadResult<u32> {
+        let bytes = self.read_bytes(4, "u32")?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+    pub fn read_u64(&mut self) -> BinaryReadResult<u64> {
+        let bytes = self.read_bytes(8, "u64")?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+    pub fn read_usize(&mut self) -> BinaryReadResult<usize> {
+        Ok(self.read_u32()? as usize)
+    }
+    pub fn read_str(&mut self) -> BinaryReadResult<&'a str> {
+        let length = self.read_usize()?;
+        let start = self.offset;
+        let bytes = self.read_bytes(length, "string")?;
+        std::str::from_utf8(bytes).map_err(|_| BinaryReadError::InvalidUtf8(start))
+    }
+
+    /// Read a one-byte tag for an enum variant, to be matched by the caller.
+    pub fn read_tag(&mut self) -> BinaryReadResult<u8> {
+        self.read_u8()
+    }
+    /// Return the error for a `tag` that was just read but doesn't match any variant of `name`.
+    pub fn invalid_tag(&self, name: &'static str, tag: u8) -> BinaryReadError {
+        BinaryReadError::InvalidTag {
+            name,
+            tag,
+            offset: self.offset - 1,
+        }
+    }
+
+    pub fn read<T: BinaryDecode>(&mut self) -> BinaryReadResult<T> {
+        T::decode(self)
+    }
+}
+
+impl BinaryEncode for bool {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_bool(*self);
+    }
+}
+impl BinaryDecode for bool {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        reader.read_bool()
+    }
+}
+
+impl BinaryEncode for u8 {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_u8(*self);
+    }
+}
+impl BinaryDecode for u8 {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        reader.read_u8()
+    }
+}
+
+impl BinaryEncode for u32 {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_u32(*self);
+    }
+}
+impl BinaryDecode for u32 {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        reader.read_u32()
+    }
+}
+
+impl BinaryEncode for u64 {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_u64(*self);
+    }
+}
+impl BinaryDecode for u64 {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        reader.read_u64()
+    }
+}
+
+impl BinaryEncode for usize {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_usize(*self);
+    }
+}
+impl BinaryDecode for usize {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        reader.read_usize()
+    }
+}
+
+impl BinaryEncode for str {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_str(self);
+    }
+}
+impl BinaryEncode for String {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_str(self);
+    }
+}
+impl BinaryDecode for String {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        reader.read_str().map(String::from)
+    }
+}
+
+impl<T: BinaryEncode> BinaryEncode for Option<T> {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        match self {
+            Some(value) => {
+                writer.write_bool(true);
+                value.encode(writer);
+            }
+            None => writer.write_bool(false),
+        }
+    }
+}
+impl<T: BinaryDecode> BinaryDecode for Option<T> {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        match reader.read_bool()? {
+            true => Ok(Some(T::decode(reader)?)),
+            false => Ok(None),
+        }
+    }
+}
+
+impl<T: BinaryEncode> BinaryEncode for Box<T> {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        self.as_ref().encode(writer);
+    }
+}
+impl<T: BinaryDecode> BinaryDecode for Box<T> {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        T::decode(reader).map(Box::new)
+    }
+}
+
+impl<T: BinaryEncode> BinaryEncode for [T] {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_usize(self.len());
+        for item in self {
+            item.encode(writer);
+        }
+    }
+}
+impl<T: BinaryEncode> BinaryEncode for Vec<T> {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        self.as_slice().encode(writer);
+    }
+}
+impl<T: BinaryDecode> BinaryDecode for Vec<T> {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        let length = reader.read_usize()?;
+        // Every item takes at least one byte, so this avoids allocating huge vectors from a
+        // corrupted length.
+        let mut items = Vec::with_capacity(length.min(reader.data.len() - reader.offset));
+        for _ in 0..length {
+            items.push(T::decode(reader)?);
+        }
+        Ok(items)
+    }
+}
+
+impl BinaryEncode for Range<usize> {
+    fn encode(&self, writer: &mut BinaryWriter) {
+        writer.write_usize(self.start);
+        writer.write_usize(self.end);
+    }
+}
+impl BinaryDecode for Range<usize> {
+    fn decode(reader: &mut BinaryReader) -> BinaryReadResult<Self> {
+        Ok(reader.read_usize()?..reader.read_usize()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinaryReadError, BinaryReader, BinaryWriter};
+
+    #[test]
+    fn round_trip() {
+        let mut writer = BinaryWriter::new();
+        writer.write(&Some(String::from("héllo")));
+        writer.write(&vec![1u32, 2, 3]);
+        writer.write(&(4usize..8));
+        writer.write(&None::<u64>);
+        let data = writer.into_inner();
+
+        let mut reader = BinaryReader::new(&data);
+        assert_eq!(Some(String::from("héllo")), reader.read().unwrap());
+        assert_eq!(vec![1u32, 2, 3], reader.read::<Vec<u32>>().unwrap());
+        assert_eq!(4..8, reader.read::<std::ops::Range<usize>>().unwrap());
+        assert_eq!(None, reader.read::<Option<u64>>().unwrap());
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn truncated_data() {
+        let mut writer = BinaryWriter::new();
+        writer.write_str("truncated");
+        let data = writer.into_inner();
+
+        let mut reader = BinaryReader::new(&data[..6]);
+        assert_eq!(
+            Err(BinaryReadError::UnexpectedEnd("string")),
+            reader.read_str()
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use memchr::memmem;
 use once_cell::sync::Lazy;
 
+pub mod binary;
+
 /// Name of the JS runtime package that should be used for all generated code or parsing for imports
 /// that read from the package.
 pub static RUNTIME_PACKAGE_NAME: &str = "@discord/intl";
@@ -53,7 +55,11 @@ pub fn is_message_definitions_file(file_name: &str) -> bool {
 }
 
 pub fn is_message_translations_file(file_name: &str) -> bool {
-    file_name.ends_with(".messages.json") || file_name.ends_with(".messages.jsona")
+    file_name.ends_with(".messages.json")
+        || file_name.ends_with(".messages.jsona")
+        || file_name.ends_with(".messages.po")
+        || file_name.ends_with(".messages.xlf")
+        || file_name.ends_with(".messages.xliff")
 }
 
 pub fn is_any_messages_file(file_name: &str) -> bool {
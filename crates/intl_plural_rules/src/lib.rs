@@ -0,0 +1,328 @@
+//! CLDR plural rules for determining which plural category a number belongs to in a given locale,
+//! for both cardinal (`{count, plural, ...}`) and ordinal (`{place, selectordinal, ...}`) plurals.
+//!
+//! The rule data is embedded directly in the crate (see `data.rs`) and parsed once on first use,
+//! so lookups never touch the file system or network.
+use std::fmt::{Display, Formatter};
+
+use once_cell::sync::Lazy;
+use rustc_hash::FxHashMap;
+
+pub use operands::{InvalidOperandsError, PluralOperands};
+use rule::Condition;
+
+mod data;
+mod operands;
+mod rule;
+
+/// A CLDR plural category. `Other` is present in every locale and is used for any number that
+/// does not match a more specific category.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub const ALL: [PluralCategory; 6] = [
+        PluralCategory::Zero,
+        PluralCategory::One,
+        PluralCategory::Two,
+        PluralCategory::Few,
+        PluralCategory::Many,
+        PluralCategory::Other,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+
+    /// Return the category with the given name, as it would be written as a plural selector.
+    pub fn from_name(name: &str) -> Option<PluralCategory> {
+        PluralCategory::ALL
+            .into_iter()
+            .find(|category| category.as_str() == name)
+    }
+}
+
+impl Display for PluralCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralRuleType {
+    /// Rules for counting quantities, like "1 item" and "2 items".
+    Cardinal,
+    /// Rules for ordering, like "1st" and "2nd".
+    Ordinal,
+}
+
+/// The set of plural rules for a single locale and rule type.
+#[derive(Debug)]
+pub struct PluralRules {
+    /// Conditions for every category other than `Other`, in CLDR order.
+    conditions: Vec<(PluralCategory, Condition)>,
+    /// Every category that this rule set can select, always ending with `Other`.
+    categories: Vec<PluralCategory>,
+}
+
+impl PluralRules {
+    fn from_data(rules: &[(&str, &str)]) -> Self {
+        let conditions: Vec<(PluralCategory, Condition)> = rules
+            .iter()
+            .map(|(category, condition)| {
+                let category = PluralCategory::from_name(category)
+                    .unwrap_or_else(|| panic!("Unknown plural category in data: {category}"));
+                let condition = Condition::parse(condition)
+                    .unwrap_or_else(|error| panic!("Invalid plural rule in data: {error}"));
+                (category, condition)
+            })
+            .collect();
+        let mut categories: Vec<PluralCategory> =
+            conditions.iter().map(|(category, _)| *category).collect();
+        categories.push(PluralCategory::Other);
+        Self {
+            conditions,
+            categories,
+        }
+    }
+
+    /// Every category that numbers can be assigned to with these rules, in CLDR order (`zero`,
+    /// `one`, `two`, `few`, `many`, `other`). `Other` is always included.
+    pub fn categories(&self) -> &[PluralCategory] {
+        &self.categories
+    }
+
+    pub fn has_category(&self, category: PluralCategory) -> bool {
+        self.categories.contains(&category)
+    }
+
+    /// Return the category that the given number belongs to.
+    pub fn select(&self, operands: impl Into<PluralOperands>) -> PluralCategory {
+        let operands = operands.into();
+        self.conditions
+            .iter()
+            .find(|(_, condition)| condition.matches(&operands))
+            .map_or(PluralCategory::Other, |(category, _)| *category)
+    }
+}
+
+struct PluralRulesTable {
+    rule_sets: Vec<PluralRules>,
+    locales: FxHashMap<&'static str, usize>,
+}
+
+impl PluralRulesTable {
+    fn new(data: &'static [data::RuleSetData]) -> Self {
+        let mut locales = FxHashMap::default();
+        let rule_sets = data
+            .iter()
+            .enumerate()
+            .map(|(index, (set_locales, rules))| {
+                for locale in *set_locales {
+                    locales.insert(*locale, index);
+                }
+                PluralRules::from_data(rules)
+            })
+            .collect();
+        Self { rule_sets, locales }
+    }
+
+    fn get(&self, locale: &str) -> &PluralRules {
+        let index = resolve_locale(locale)
+            .into_iter()
+            .find_map(|candidate| self.locales.get(candidate.as_str()))
+            .or_else(|| self.locales.get("root"))
+            .expect("Plural rules data always contains a root locale");
+        &self.rule_sets[*index]
+    }
+}
+
+static CARDINAL_RULES: Lazy<PluralRulesTable> =
+    Lazy::new(|| PluralRulesTable::new(data::CARDINAL_RULES));
+static ORDINAL_RULES: Lazy<PluralRulesTable> =
+    Lazy::new(|| PluralRulesTable::new(data::ORDINAL_RULES));
+
+/// Return the candidate names to look up for `locale`, from most to least specific. For example,
+/// `pt-PT-x-foo` yields `pt_PT_x_foo`, `pt_PT_x`, `pt_PT`, and `pt`. Subtags are normalized to
+/// the casing CLDR uses, so `PT_pt` resolves the same way as `pt-PT`.
+fn resolve_locale(locale: &str) -> Vec<String> {
+    let mut subtags = locale.split(['-', '_']).filter(|subtag| !subtag.is_empty());
+    let Some(language) = subtags.next() else {
+        return vec![];
+    };
+    let mut candidate = language.to_ascii_lowercase();
+    let mut candidates = vec![candidate.clone()];
+    for subtag in subtags {
+        candidate.push('_');
+        candidate.push_str(&normalize_subtag(subtag));
+        candidates.push(candidate.clone());
+    }
+    candidates.reverse();
+    candidates
+}
+
+/// Return `subtag` with the casing BCP 47 recommends: title case for scripts like `Hant`, upper
+/// case for regions like `PT` or `419`, and lower case for everything else.
+fn normalize_subtag(subtag: &str) -> String {
+    let is_alphabetic = subtag.chars().all(|c| c.is_ascii_alphabetic());
+    let is_numeric = subtag.chars().all(|c| c.is_ascii_digit());
+    match subtag.len() {
+        4 if is_alphabetic => {
+            let (first, rest) = subtag.split_at(1);
+            first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+        }
+        2 if is_alphabetic => subtag.to_ascii_uppercase(),
+        3 if is_numeric => subtag.to_string(),
+        _ => subtag.to_ascii_lowercase(),
+    }
+}
+
+/// Return the plural rules of the given type for `locale`, which may be a BCP 47 tag like `en-US`
+/// or a CLDR locale id like `pt_PT`. Unknown locales use the root rules, which only contain
+/// `other`.
+pub fn plural_rules_for_locale(locale: &str, rule_type: PluralRuleType) -> &'static PluralRules {
+    match rule_type {
+        PluralRuleType::Cardinal => CARDINAL_RULES.get(locale),
+        PluralRuleType::Ordinal => ORDINAL_RULES.get(locale),
+    }
+}
+
+/// Return the plural category that `value` belongs to in `locale`.
+pub fn select_plural_category(
+    locale: &str,
+    rule_type: PluralRuleType,
+    value: impl Into<PluralOperands>,
+) -> PluralCategory {
+    plural_rules_for_locale(locale, rule_type).select(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        data, plural_rules_for_locale, resolve_locale, select_plural_category, PluralCategory,
+        PluralOperands, PluralRuleType, PluralRules,
+    };
+
+    use PluralCategory::*;
+    use PluralRuleType::*;
+
+    fn select(locale: &str, rule_type: PluralRuleType, value: &str) -> PluralCategory {
+        select_plural_category(locale, rule_type, value.parse::<PluralOperands>().unwrap())
+    }
+
+    #[test]
+    fn all_data_parses() {
+        for (_, rules) in data::CARDINAL_RULES.iter().chain(data::ORDINAL_RULES) {
+            PluralRules::from_data(rules);
+        }
+    }
+
+    #[test]
+    fn operands() {
+        assert_eq!(
+            "-1.250".parse::<PluralOperands>().unwrap(),
+            PluralOperands {
+                n: 1.25,
+                i: 1,
+                v: 3,
+                w: 2,
+                f: 250,
+                t: 25,
+                e: 0,
+            }
+        );
+        let compact = "1.2c3".parse::<PluralOperands>().unwrap();
+        assert_eq!(
+            (compact.n, compact.i, compact.v, compact.e),
+            (1200.0, 1200, 0, 3)
+        );
+        assert_eq!(PluralOperands::from(2.5).f, 5);
+        let large = PluralOperands::from(-1e20);
+        assert_eq!((large.n, large.i, large.v), (1e20, u64::MAX, 0));
+        assert_eq!(select_plural_category("en", Cardinal, large), Other);
+        assert!("abc".parse::<PluralOperands>().is_err());
+    }
+
+    #[test]
+    fn english() {
+        assert_eq!(select("en-US", Cardinal, "1"), One);
+        assert_eq!(select("en-US", Cardinal, "1.0"), Other);
+        assert_eq!(select("en-US", Cardinal, "0"), Other);
+        assert_eq!(select("en", Ordinal, "1"), One);
+        assert_eq!(select("en", Ordinal, "22"), Two);
+        assert_eq!(select("en", Ordinal, "103"), Few);
+        assert_eq!(select("en", Ordinal, "111"), Other);
+    }
+
+    #[test]
+    fn slavic() {
+        assert_eq!(select("pl", Cardinal, "1"), One);
+        assert_eq!(select("pl", Cardinal, "22"), Few);
+        assert_eq!(select("pl", Cardinal, "12"), Many);
+        assert_eq!(select("pl", Cardinal, "1.5"), Other);
+        assert_eq!(select("ru", Cardinal, "21"), One);
+        assert_eq!(select("uk", Ordinal, "43"), Few);
+        assert_eq!(select("cs", Cardinal, "0.5"), Many);
+    }
+
+    #[test]
+    fn arabic() {
+        assert_eq!(select("ar", Cardinal, "0"), Zero);
+        assert_eq!(select("ar", Cardinal, "2"), Two);
+        assert_eq!(select("ar", Cardinal, "105"), Few);
+        assert_eq!(select("ar", Cardinal, "111"), Many);
+        assert_eq!(select("ar", Cardinal, "100"), Other);
+    }
+
+    #[test]
+    fn regional_rules() {
+        assert_eq!(select("pt-BR", Cardinal, "0"), One);
+        assert_eq!(select("pt-PT", Cardinal, "0"), Other);
+        assert_eq!(select("pt-pt", Cardinal, "0"), Other);
+        assert_eq!(select("PT_pt", Cardinal, "0"), Other);
+        assert_eq!(select("fr", Cardinal, "1000000"), Many);
+        assert_eq!(select("fr", Cardinal, "1.2c6"), Many);
+    }
+
+    #[test]
+    fn locale_candidates() {
+        assert_eq!(
+            resolve_locale("ZH-hant-tw"),
+            vec!["zh_Hant_TW", "zh_Hant", "zh"]
+        );
+        assert_eq!(resolve_locale("es_419"), vec!["es_419", "es"]);
+        assert_eq!(
+            resolve_locale("pt-PT-X-Foo"),
+            vec!["pt_PT_x_foo", "pt_PT_x", "pt_PT", "pt"]
+        );
+    }
+
+    #[test]
+    fn category_sets() {
+        let categories =
+            |locale, rule_type| plural_rules_for_locale(locale, rule_type).categories();
+        assert_eq!(categories("ja", Cardinal), &[Other]);
+        assert_eq!(categories("en-GB", Cardinal), &[One, Other]);
+        assert_eq!(categories("pl", Cardinal), &[One, Few, Many, Other]);
+        assert_eq!(
+            categories("ar", Cardinal),
+            &[Zero, One, Two, Few, Many, Other]
+        );
+        assert_eq!(categories("en", Ordinal), &[One, Two, Few, Other]);
+        assert_eq!(categories("not-a-locale", Cardinal), &[Other]);
+    }
+}
@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+/// The operands of a number used when evaluating plural rules, as defined by
+/// [UTS #35](https://unicode.org/reports/tr35/tr35-numbers.html#Operands).
+///
+/// Plural categories can depend on the visible representation of a number, not just its value
+/// (`1` and `1.0` are different categories in English), so operands are best created from the
+/// string that will be displayed. Creating operands from an `f64` uses its shortest representation,
+/// which never has trailing zeros.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PluralOperands {
+    /// Absolute value of the source number.
+    pub n: f64,
+    /// Integer digits of `n`.
+    pub i: u64,
+    /// Number of visible fraction digits in `n`, with trailing zeros.
+    pub v: u32,
+    /// Number of visible fraction digits in `n`, without trailing zeros.
+    pub w: u32,
+    /// Visible fraction digits in `n`, with trailing zeros, as an integer.
+    pub f: u64,
+    /// Visible fraction digits in `n`, without trailing zeros, as an integer.
+    pub t: u64,
+    /// Compact decimal exponent value, like `6` in `1.2c6`.
+    pub e: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidOperandsError(pub String);
+
+impl std::fmt::Display for InvalidOperandsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a valid plural operand value", self.0)
+    }
+}
+
+impl std::error::Error for InvalidOperandsError {}
+
+impl FromStr for PluralOperands {
+    type Err = InvalidOperandsError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidOperandsError(value.into());
+        let unsigned = value.strip_prefix('-').unwrap_or(value);
+        let (decimal, exponent) = match unsigned.split_once(['c', 'e']) {
+            Some((decimal, exponent)) => (decimal, exponent.parse().map_err(|_| invalid())?),
+            None => (unsigned, 0u32),
+        };
+        let (integer, fraction) = decimal.split_once('.').unwrap_or((decimal, ""));
+        if integer.is_empty()
+            || !integer.bytes().all(|byte| byte.is_ascii_digit())
+            || !fraction.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        // With a compact exponent, the decimal point is shifted right by that many digits before
+        // the other operands are determined, like `1.2c3` being the same number as `1200`.
+        let shift = (exponent as usize).min(fraction.len());
+        let mut integer_digits = integer.to_string();
+        integer_digits.push_str(&fraction[..shift]);
+        integer_digits.extend(std::iter::repeat('0').take(exponent as usize - shift));
+        let fraction = &fraction[shift..];
+        let trimmed_fraction = fraction.trim_end_matches('0');
+
+        let parse_digits = |digits: &str| -> Result<u64, InvalidOperandsError> {
+            if digits.is_empty() {
+                Ok(0)
+            } else {
+                digits.parse().map_err(|_| invalid())
+            }
+        };
+
+        let i = parse_digits(&integer_digits)?;
+        let f = parse_digits(fraction)?;
+        let n = if fraction.is_empty() {
+            i as f64
+        } else {
+            format!("{integer_digits}.{fraction}")
+                .parse()
+                .map_err(|_| invalid())?
+        };
+
+        Ok(PluralOperands {
+            n,
+            i,
+            v: fraction.len() as u32,
+            w: trimmed_fraction.len() as u32,
+            f,
+            t: parse_digits(trimmed_fraction)?,
+            e: exponent,
+        })
+    }
+}
+
+impl From<f64> for PluralOperands {
+    fn from(value: f64) -> Self {
+        if !value.is_finite() {
+            return PluralOperands {
+                n: value.abs(),
+                ..Default::default()
+            };
+        }
+        let n = value.abs();
+        // Integer operands saturate when the integer digits don't fit in a `u64`. Values that
+        // large are always integers, so there are no fraction digits to lose.
+        let integer = PluralOperands {
+            n,
+            i: n as u64,
+            ..Default::default()
+        };
+        if n >= u64::MAX as f64 {
+            return integer;
+        }
+        // `Display` for f64 never uses exponents and gives the shortest representation that
+        // round-trips, which is the same visible form that JS would give by default.
+        format!("{n}").parse().unwrap_or(integer)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for PluralOperands {
+            fn from(value: $ty) -> Self {
+                let i = value.unsigned_abs() as u64;
+                PluralOperands {
+                    n: i as f64,
+                    i,
+                    ..Default::default()
+                }
+            }
+        })*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, isize);
+
+macro_rules! impl_from_unsigned {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for PluralOperands {
+            fn from(value: $ty) -> Self {
+                PluralOperands {
+                    n: value as f64,
+                    i: value as u64,
+                    ..Default::default()
+                }
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
@@ -0,0 +1,130 @@
+//! Parsing and evaluation for conditions written in the CLDR plural rule syntax, like
+//! `v = 0 and i % 10 = 2..4 and i % 100 != 12..14`.
+//!
+//! Only the subset of the syntax used by the CLDR data is supported: `and`/`or` conditions of
+//! `=`/`!=` relations against lists of values and ranges. Sample lists (`@integer`, `@decimal`)
+//! are not part of the embedded data and are not accepted.
+use crate::operands::PluralOperands;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operand {
+    N,
+    I,
+    V,
+    W,
+    F,
+    T,
+    E,
+}
+
+impl Operand {
+    fn value(&self, operands: &PluralOperands) -> f64 {
+        match self {
+            Operand::N => operands.n,
+            Operand::I => operands.i as f64,
+            Operand::V => operands.v as f64,
+            Operand::W => operands.w as f64,
+            Operand::F => operands.f as f64,
+            Operand::T => operands.t as f64,
+            Operand::E => operands.e as f64,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Relation {
+    operand: Operand,
+    modulus: Option<u64>,
+    negated: bool,
+    /// Inclusive ranges of values. Single values are represented as a range of one value.
+    ranges: Vec<(u64, u64)>,
+}
+
+impl Relation {
+    fn matches(&self, operands: &PluralOperands) -> bool {
+        let mut value = self.operand.value(operands);
+        if let Some(modulus) = self.modulus {
+            value %= modulus as f64;
+        }
+        // Range membership is only satisfied by integers, so `n = 0..1` does not match `0.5`.
+        let is_member = value.fract() == 0.0
+            && self
+                .ranges
+                .iter()
+                .any(|(start, end)| value >= *start as f64 && value <= *end as f64);
+        is_member != self.negated
+    }
+}
+
+/// A parsed plural rule condition, as a disjunction of conjunctions of relations.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Condition(Vec<Vec<Relation>>);
+
+impl Condition {
+    pub(crate) fn matches(&self, operands: &PluralOperands) -> bool {
+        self.0.iter().any(|and_condition| {
+            and_condition
+                .iter()
+                .all(|relation| relation.matches(operands))
+        })
+    }
+
+    pub(crate) fn parse(source: &str) -> Result<Condition, String> {
+        let mut or_conditions = vec![];
+        for and_source in source.split(" or ") {
+            let relations = and_source
+                .split(" and ")
+                .map(parse_relation)
+                .collect::<Result<Vec<_>, _>>()?;
+            or_conditions.push(relations);
+        }
+        Ok(Condition(or_conditions))
+    }
+}
+
+fn parse_relation(source: &str) -> Result<Relation, String> {
+    let (expression, negated, range_list) = if let Some((left, right)) = source.split_once("!=") {
+        (left, true, right)
+    } else if let Some((left, right)) = source.split_once('=') {
+        (left, false, right)
+    } else {
+        return Err(format!("Relation '{source}' has no operator"));
+    };
+
+    let (operand, modulus) = match expression.split_once('%') {
+        Some((operand, modulus)) => (operand, Some(parse_value(modulus)?)),
+        None => (expression, None),
+    };
+    let operand = match operand.trim() {
+        "n" => Operand::N,
+        "i" => Operand::I,
+        "v" => Operand::V,
+        "w" => Operand::W,
+        "f" => Operand::F,
+        "t" => Operand::T,
+        "e" | "c" => Operand::E,
+        other => return Err(format!("Unknown plural operand '{other}'")),
+    };
+
+    let ranges = range_list
+        .split(',')
+        .map(|range| match range.split_once("..") {
+            Some((start, end)) => Ok((parse_value(start)?, parse_value(end)?)),
+            None => parse_value(range).map(|value| (value, value)),
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(Relation {
+        operand,
+        modulus,
+        negated,
+        ranges,
+    })
+}
+
+fn parse_value(source: &str) -> Result<u64, String> {
+    source
+        .trim()
+        .parse()
+        .map_err(|_| format!("'{source}' is not a valid plural rule value"))
+}
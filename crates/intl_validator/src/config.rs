@@ -0,0 +1,88 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
+use crate::validators::default_validators;
+use crate::validators::validator::Validator;
+use crate::DiagnosticSeverity;
+
+type ValidatorFactory = Arc<dyn Fn() -> Box<dyn Validator> + Send + Sync>;
+
+/// Configuration for which validation rules are run and how their diagnostics are reported. By
+/// default, every rule is enabled and reports diagnostics with its own severity.
+///
+/// Individual messages and source files can also suppress diagnostics through the
+/// `suppressDiagnostics` field of their meta, which is applied on top of this configuration.
+///
+/// The built-in rules are always available, and more can be added with
+/// [ValidatorConfig::with_validator].
+#[derive(Clone, Default)]
+pub struct ValidatorConfig {
+    disabled_rules: HashSet<DiagnosticName>,
+    severity_overrides: HashMap<DiagnosticName, DiagnosticSeverity>,
+    custom_validators: Vec<ValidatorFactory>,
+}
+
+impl ValidatorConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Never run the rule with the given `name`.
+    pub fn with_rule_disabled(mut self, name: DiagnosticName) -> Self {
+        self.disabled_rules.insert(name);
+        self
+    }
+
+    /// Report all diagnostics from the rule with the given `name` with `severity`, rather than the
+    /// severity that the rule chooses. This also enables the rule if it was disabled.
+    pub fn with_rule_severity(
+        mut self,
+        name: DiagnosticName,
+        severity: DiagnosticSeverity,
+    ) -> Self {
+        self.disabled_rules.remove(&name);
+        self.severity_overrides.insert(name, severity);
+        self
+    }
+
+    /// Also run the validator created by `factory`. A new validator is created for every value,
+    /// and it runs after all of the built-in rules.
+    pub fn with_validator(
+        mut self,
+        factory: impl Fn() -> Box<dyn Validator> + Send + Sync + 'static,
+    ) -> Self {
+        self.custom_validators.push(Arc::new(factory));
+        self
+    }
+
+    /// Return a new instance of every validator to run: the built-in rules followed by any that
+    /// were added with [ValidatorConfig::with_validator].
+    pub fn validators(&self) -> Vec<Box<dyn Validator>> {
+        let mut validators = default_validators();
+        validators.extend(self.custom_validators.iter().map(|factory| factory()));
+        validators
+    }
+
+    pub fn is_rule_enabled(&self, name: DiagnosticName) -> bool {
+        !self.disabled_rules.contains(&name)
+    }
+
+    /// Apply any configured severity override to the given `diagnostic`.
+    pub(crate) fn apply_severity(&self, diagnostic: &mut ValueDiagnostic) {
+        if let Some(severity) = self.severity_overrides.get(&diagnostic.name) {
+            diagnostic.severity = *severity;
+        }
+    }
+}
+
+impl Debug for ValidatorConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidatorConfig")
+            .field("disabled_rules", &self.disabled_rules)
+            .field("severity_overrides", &self.severity_overrides)
+            .field("custom_validators", &self.custom_validators.len())
+            .finish()
+    }
+}
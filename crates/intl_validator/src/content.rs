@@ -1,25 +1,41 @@
-use intl_database_core::MessageValue;
-
+use crate::config::ValidatorConfig;
+use crate::context::ValidationContext;
 use crate::diagnostic::ValueDiagnostic;
-use crate::validators;
 use crate::validators::validator::Validator;
 
-pub fn validate_message_value(message: &MessageValue) -> Vec<ValueDiagnostic> {
+/// Validate the single value described by `context` with every rule enabled by `config`,
+/// including any validators registered on it.
+pub fn validate_message_value(
+    context: &ValidationContext,
+    config: &ValidatorConfig,
+) -> Vec<ValueDiagnostic> {
+    validate_message_value_with(context, config, config.validators())
+}
+
+/// Validate the single value described by `context` with the given `validators`, rather than
+/// the ones from `config`. Rules that `config` disables are still skipped, and its severity
+/// overrides are still applied.
+pub fn validate_message_value_with(
+    context: &ValidationContext,
+    config: &ValidatorConfig,
+    validators: Vec<Box<dyn Validator>>,
+) -> Vec<ValueDiagnostic> {
     let mut diagnostics: Vec<ValueDiagnostic> = vec![];
-    let mut validators: Vec<Box<dyn Validator>> = vec![
-        Box::new(validators::NoUnicodeVariableNames::new()),
-        Box::new(validators::NoRepeatedPluralNames::new()),
-        Box::new(validators::NoRepeatedPluralOptions::new()),
-        Box::new(validators::NoTrimmableWhitespace::new()),
-    ];
-    for validator in validators.iter_mut() {
-        if let Some(result) = validator.validate_raw(message) {
+    for mut validator in validators {
+        let name = validator.name();
+        if !config.is_rule_enabled(name) || context.meta().is_diagnostic_suppressed(name.as_str()) {
+            continue;
+        }
+        if let Some(result) = validator.validate_raw(context) {
             diagnostics.extend(result);
         }
-        if let Some(result) = validator.validate_ast(message) {
+        if let Some(result) = validator.validate_ast(context) {
             diagnostics.extend(result);
         }
     }
 
+    for diagnostic in diagnostics.iter_mut() {
+        config.apply_severity(diagnostic);
+    }
     diagnostics
 }
@@ -0,0 +1,48 @@
+use intl_database_core::{KeySymbol, Message, MessageMeta, MessageValue, MessagesDatabase};
+
+/// Everything a [Validator](crate::validators::validator::Validator) may need to know about the
+/// value it is validating: the value itself, the locale it is written in, the message it belongs
+/// to (including its source translation and meta), and the database containing that message.
+pub struct ValidationContext<'a> {
+    pub database: &'a MessagesDatabase,
+    pub message: &'a Message,
+    /// The locale of the value being validated.
+    pub locale: KeySymbol,
+    /// The value being validated, which is the translation of `message` in `locale`.
+    pub value: &'a MessageValue,
+}
+
+impl<'a> ValidationContext<'a> {
+    pub fn new(
+        database: &'a MessagesDatabase,
+        message: &'a Message,
+        locale: KeySymbol,
+        value: &'a MessageValue,
+    ) -> Self {
+        Self {
+            database,
+            message,
+            locale,
+            value,
+        }
+    }
+
+    /// The source translation of the message, if it has a definition.
+    pub fn source(&self) -> Option<&'a MessageValue> {
+        self.message.get_source_translation()
+    }
+
+    pub fn source_locale(&self) -> Option<KeySymbol> {
+        *self.message.source_locale()
+    }
+
+    /// Returns true if the value being validated is the source definition of the message, rather
+    /// than a translation of it.
+    pub fn is_source(&self) -> bool {
+        self.source_locale() == Some(self.locale)
+    }
+
+    pub fn meta(&self) -> &'a MessageMeta {
+        self.message.meta()
+    }
+}
@@ -1,29 +1,60 @@
-use intl_database_core::{FilePosition, KeySymbol};
+use std::ops::Range;
+
+use intl_database_core::{FilePosition, KeySymbol, MessageValue};
 
 use crate::DiagnosticSeverity;
 
-#[derive(Clone, Copy, Debug)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum DiagnosticName {
     NoExtraTranslationVariables,
+    NoFuzzyTranslations,
+    NoInvalidPluralCategories,
     NoMissingSourceVariables,
     NoRepeatedPluralNames,
     NoRepeatedPluralOptions,
+    NoSyntaxErrors,
     NoTrimmableWhitespace,
     NoUnicodeVariableNames,
+    /// A rule from a validator added outside of this crate, identified by its own name.
+    Custom(&'static str),
 }
 
 impl DiagnosticName {
+    /// Every built-in rule.
+    pub const ALL: [DiagnosticName; 9] = [
+        DiagnosticName::NoExtraTranslationVariables,
+        DiagnosticName::NoFuzzyTranslations,
+        DiagnosticName::NoInvalidPluralCategories,
+        DiagnosticName::NoMissingSourceVariables,
+        DiagnosticName::NoRepeatedPluralNames,
+        DiagnosticName::NoRepeatedPluralOptions,
+        DiagnosticName::NoSyntaxErrors,
+        DiagnosticName::NoTrimmableWhitespace,
+        DiagnosticName::NoUnicodeVariableNames,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             DiagnosticName::NoExtraTranslationVariables => "NoExtraTranslationVariables",
+            DiagnosticName::NoFuzzyTranslations => "NoFuzzyTranslations",
+            DiagnosticName::NoInvalidPluralCategories => "NoInvalidPluralCategories",
             DiagnosticName::NoMissingSourceVariables => "NoMissingSourceVariables",
             DiagnosticName::NoRepeatedPluralNames => "NoRepeatedPluralNames",
             DiagnosticName::NoRepeatedPluralOptions => "NoRepeatedPluralOptions",
+            DiagnosticName::NoSyntaxErrors => "NoSyntaxErrors",
             DiagnosticName::NoTrimmableWhitespace => "NoTrimmableWhitespace",
             DiagnosticName::NoUnicodeVariableNames => "NoUnicodeVariableNames",
+            DiagnosticName::Custom(name) => name,
         }
     }
+
+    /// Return the built-in diagnostic with the given `name`, as it is written by
+    /// [DiagnosticName::as_str].
+    pub fn from_name(name: &str) -> Option<DiagnosticName> {
+        DiagnosticName::ALL
+            .into_iter()
+            .find(|diagnostic| diagnostic.as_str() == name)
+    }
 }
 
 impl ToString for DiagnosticName {
@@ -34,9 +65,16 @@ impl ToString for DiagnosticName {
 
 pub struct MessageDiagnostic {
     pub key: KeySymbol,
+    /// Position in the source file where the diagnostic starts. This is the start of the message
+    /// value, unless the diagnostic has a `span`.
     pub file_position: FilePosition,
+    /// Position in the source file where the diagnostic ends, if it has a `span`.
+    pub end_position: Option<FilePosition>,
     pub locale: KeySymbol,
     pub name: DiagnosticName,
+    /// Byte range of the diagnostic within the message value, if the validator could determine
+    /// one.
+    pub span: Option<Range<usize>>,
     pub severity: DiagnosticSeverity,
     pub description: String,
     pub help: Option<String>,
@@ -45,7 +83,8 @@ pub struct MessageDiagnostic {
 #[derive(Debug, Clone)]
 pub struct ValueDiagnostic {
     pub name: DiagnosticName,
-    pub span: Option<usize>,
+    /// Byte range within the raw content of the value that the diagnostic applies to.
+    pub span: Option<Range<usize>>,
     pub severity: DiagnosticSeverity,
     pub description: String,
     pub help: Option<String>,
@@ -64,28 +103,33 @@ impl MessageDiagnosticsBuilder {
         }
     }
 
-    pub fn add(&mut self, diagnostic: MessageDiagnostic) {
-        self.diagnostics.push(diagnostic);
-    }
-
     pub fn extend_from_value_diagnostics(
         &mut self,
         value_diagnostics: Vec<ValueDiagnostic>,
-        file_position: FilePosition,
+        value: &MessageValue,
         locale: KeySymbol,
     ) {
-        let converted_diagnostics =
-            value_diagnostics
-                .into_iter()
-                .map(|diagnostic| MessageDiagnostic {
-                    key: self.key,
-                    file_position,
-                    locale,
-                    name: diagnostic.name,
-                    severity: diagnostic.severity,
-                    description: diagnostic.description,
-                    help: diagnostic.help,
-                });
+        let value_position = value.file_position.unwrap();
+        let converted_diagnostics = value_diagnostics.into_iter().map(|diagnostic| {
+            let (file_position, end_position) = match &diagnostic.span {
+                Some(span) => (
+                    value.file_position_at(span.start).unwrap_or(value_position),
+                    value.file_position_at(span.end),
+                ),
+                None => (value_position, None),
+            };
+            MessageDiagnostic {
+                key: self.key,
+                file_position,
+                end_position,
+                locale,
+                name: diagnostic.name,
+                span: diagnostic.span,
+                severity: diagnostic.severity,
+                description: diagnostic.description,
+                help: diagnostic.help,
+            }
+        });
 
         self.diagnostics.extend(converted_diagnostics);
     }
@@ -1,11 +1,17 @@
-use intl_database_core::Message;
+use intl_database_core::{Message, MessagesDatabase};
 
-pub use crate::content::validate_message_value;
-pub use crate::diagnostic::MessageDiagnostic;
-use crate::diagnostic::{DiagnosticName, MessageDiagnosticsBuilder};
+pub use crate::config::ValidatorConfig;
+pub use crate::content::{validate_message_value, validate_message_value_with};
+pub use crate::context::ValidationContext;
+use crate::diagnostic::MessageDiagnosticsBuilder;
+pub use crate::diagnostic::{DiagnosticName, MessageDiagnostic, ValueDiagnostic};
 pub use crate::severity::DiagnosticSeverity;
+pub use crate::validators::default_validators;
+pub use crate::validators::validator::Validator;
 
+mod config;
 mod content;
+mod context;
 mod diagnostic;
 mod severity;
 mod validators;
@@ -17,71 +23,196 @@ mod validators;
 /// of truth (a definition) to check against. Undefined messages can still have
 /// diagnostics presented from general errors, like invalid syntax or
 /// unsupported syntax.
-pub fn validate_message(message: &Message) -> Vec<MessageDiagnostic> {
-    let Some(source) = message.get_source_translation() else {
+///
+/// Which rules are run and how their diagnostics are reported is determined by `config`.
+pub fn validate_message(
+    message: &Message,
+    database: &MessagesDatabase,
+    config: &ValidatorConfig,
+) -> Vec<MessageDiagnostic> {
+    if message.get_source_translation().is_none() {
         return vec![];
-    };
+    }
 
-    // SAFETY: If the message has a source translation, it must have a source locale.
-    let source_locale = message.source_locale().unwrap();
     let mut diagnostics = MessageDiagnosticsBuilder::new(message.key());
-
-    let source_variables = &source.variables;
-    let source_has_variables = source_variables
-        .as_ref()
-        .is_some_and(|variables| variables.count() > 0);
-
     for (locale, translation) in message.translations() {
+        let context = ValidationContext::new(database, message, *locale, translation);
         diagnostics.extend_from_value_diagnostics(
-            validate_message_value(translation),
-            translation.file_position.unwrap(),
+            validate_message_value(&context, config),
+            translation,
             *locale,
         );
-        if *locale == source_locale {
-            continue;
+    }
+
+    diagnostics.diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{
+        key_symbol, FilePosition, Message, MessageMeta, MessageValue, MessagesDatabase,
+        RawPosition, SourceOffsetMap,
+    };
+
+    use crate::{
+        validate_message, DiagnosticName, DiagnosticSeverity, ValidationContext, Validator,
+        ValidatorConfig, ValueDiagnostic,
+    };
+
+    fn message_value(content: &str) -> MessageValue {
+        MessageValue::from_raw(content).with_file_position(FilePosition {
+            file: key_symbol("test.messages.js"),
+            line: 1,
+            col: 1,
+        })
+    }
+
+    fn validate(
+        source: &str,
+        translation: &str,
+        meta: MessageMeta,
+        config: &ValidatorConfig,
+    ) -> Vec<(&'static str, &'static str)> {
+        let mut message = Message::from_definition(
+            key_symbol("TEST"),
+            message_value(source),
+            key_symbol("en-US"),
+            meta,
+        );
+        message.set_translation(key_symbol("fr"), message_value(translation));
+        validate_message(&message, &MessagesDatabase::new(), config)
+            .into_iter()
+            .map(|diagnostic| (diagnostic.name.as_str(), diagnostic.severity.as_str()))
+            .collect()
+    }
+
+    fn diagnostic_names(source: &str, translation: &str) -> Vec<&'static str> {
+        validate(
+            source,
+            translation,
+            MessageMeta::default(),
+            &ValidatorConfig::default(),
+        )
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    #[test]
+    fn cross_translation_variables() {
+        assert!(diagnostic_names("Hello {name}", "Bonjour {name}").is_empty());
+        assert_eq!(
+            diagnostic_names("Hello", "Bonjour {name}"),
+            vec!["NoExtraTranslationVariables"]
+        );
+        assert_eq!(
+            diagnostic_names("Hello {name}", "Bonjour"),
+            vec!["NoMissingSourceVariables"]
+        );
+    }
+
+    #[test]
+    fn fuzzy_translations() {
+        let mut message = Message::from_definition(
+            key_symbol("TEST"),
+            message_value("Hello"),
+            key_symbol("en-US"),
+            MessageMeta::default(),
+        );
+        message.set_translation(key_symbol("fr"), message_value("Bonjour").with_fuzzy(true));
+        let diagnostics = validate_message(
+            &message,
+            &MessagesDatabase::new(),
+            &ValidatorConfig::default(),
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].name, DiagnosticName::NoFuzzyTranslations);
+        assert_eq!(diagnostics[0].locale, key_symbol("fr"));
+    }
+
+    #[test]
+    fn configured_rules() {
+        let disabled =
+            ValidatorConfig::new().with_rule_disabled(DiagnosticName::NoTrimmableWhitespace);
+        assert!(validate("Hello ", "Bonjour", MessageMeta::default(), &disabled).is_empty());
+
+        let raised = ValidatorConfig::new().with_rule_severity(
+            DiagnosticName::NoTrimmableWhitespace,
+            DiagnosticSeverity::Error,
+        );
+        assert_eq!(
+            validate("Hello ", "Bonjour", MessageMeta::default(), &raised),
+            vec![("NoTrimmableWhitespace", "error")]
+        );
+    }
+
+    /// A validator from outside the crate, which reports every value that still contains `TODO`.
+    struct NoTodos;
+    impl Validator for NoTodos {
+        fn name(&self) -> DiagnosticName {
+            DiagnosticName::Custom("NoTodos")
         }
 
-        let _translation_variables = match &translation.variables {
-            // If the translation contains variables but the source does not,
-            // it's likely unintended (the only time this should reasonably
-            // happen is when translations are out-of-date, which should be
-            // fixed automatically once the translations are imported again).
-            Some(translation_variables)
-                if !source_has_variables && translation_variables.count() > 0 =>
-            {
-                diagnostics.add(MessageDiagnostic {
-                        key: message.key(),
-                        file_position: translation.file_position.unwrap(),
-                        locale: locale.clone(),
-                        name: DiagnosticName::NoExtraTranslationVariables,
-                        severity: DiagnosticSeverity::Warning,
-                        description: "Translation includes variables, but the source message does not"
-                            .into(),
-                        help: Some("This is okay, but likely unintentional. Check that the source message is defined as expected.".into())
-                    });
-                continue;
-            }
-
-            Some(translated_variables) => translated_variables,
-            // If the translation has no variables, but the source does, this
-            // also likely not intentional, but still won't break things.
-            _ => {
-                if source_has_variables {
-                    diagnostics.add(MessageDiagnostic {
-                        key: message.key(),
-                        file_position: translation.file_position.unwrap(),
-                        locale: locale.clone(),
-                        name: DiagnosticName::NoMissingSourceVariables,
-                        severity: DiagnosticSeverity::Warning,
-                        description: "Source message includes variables, but this translation has none.".into(),
-                        help: Some("This is okay, but likely unintentional. Check that the source message is defined as expected.".into())
-                    });
-                }
-
-                continue;
-            }
-        };
+        fn validate_raw(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
+            let start = context.value.raw.find("TODO")?;
+            Some(vec![ValueDiagnostic {
+                name: self.name(),
+                span: Some(start..start + 4),
+                severity: DiagnosticSeverity::Warning,
+                description: format!("Value in '{}' contains a TODO", context.locale),
+                help: None,
+            }])
+        }
     }
 
-    diagnostics.diagnostics
+    #[test]
+    fn custom_validators() {
+        let config = ValidatorConfig::new().with_validator(|| Box::new(NoTodos));
+        assert_eq!(
+            validate("Hello", "TODO", MessageMeta::default(), &config),
+            vec![("NoTodos", "warning")]
+        );
+
+        let config = config.with_rule_disabled(DiagnosticName::Custom("NoTodos"));
+        assert!(validate("Hello", "TODO", MessageMeta::default(), &config).is_empty());
+        let meta = MessageMeta::default().with_suppressed_diagnostic("NoTodos");
+        let config = ValidatorConfig::new().with_validator(|| Box::new(NoTodos));
+        assert!(validate("Hello", "TODO", meta, &config).is_empty());
+    }
+
+    #[test]
+    fn suppressed_diagnostics() {
+        let meta = MessageMeta::default().with_suppressed_diagnostic("NoTrimmableWhitespace");
+        assert!(validate("Hello ", "Bonjour ", meta, &ValidatorConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn diagnostic_positions() {
+        // As if written in a file as `  KEY: 'Hi\t{nåme}',` on line 3.
+        let content = "Hi\t{nåme}";
+        let source_offsets =
+            SourceOffsetMap::from_literal(r"Hi\t{nåme}", RawPosition { line: 3, col: 10 }, content);
+        let message = Message::from_definition(
+            key_symbol("TEST"),
+            message_value(content).with_source_offsets(source_offsets),
+            key_symbol("en-US"),
+            MessageMeta::default(),
+        );
+
+        let diagnostics = validate_message(
+            &message,
+            &MessagesDatabase::new(),
+            &ValidatorConfig::default(),
+        );
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.name, DiagnosticName::NoUnicodeVariableNames);
+        assert_eq!(diagnostic.span, Some(4..9));
+        assert_eq!(
+            (diagnostic.file_position.line, diagnostic.file_position.col),
+            (3, 15)
+        );
+        let end_position = diagnostic.end_position.unwrap();
+        assert_eq!((end_position.line, end_position.col), (3, 19));
+    }
 }
@@ -10,13 +10,23 @@ pub enum DiagnosticSeverity {
 }
 
 impl DiagnosticSeverity {
-    pub fn as_str(&self) -> &str {
+    pub fn as_str(&self) -> &'static str {
         match self {
             Self::Info => "info",
             Self::Warning => "warning",
             Self::Error => "error",
         }
     }
+
+    /// Return the severity with the given `name`, as it is written by [DiagnosticSeverity::as_str].
+    pub fn from_name(name: &str) -> Option<DiagnosticSeverity> {
+        match name {
+            "info" => Some(Self::Info),
+            "warning" => Some(Self::Warning),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
 }
 
 impl Serialize for DiagnosticSeverity {
@@ -1,11 +1,36 @@
+pub use no_extra_translation_variables::NoExtraTranslationVariables;
+pub use no_fuzzy_translations::NoFuzzyTranslations;
+pub use no_invalid_plural_categories::NoInvalidPluralCategories;
+pub use no_missing_source_variables::NoMissingSourceVariables;
 pub use no_repeated_plural_names::NoRepeatedPluralNames;
 pub use no_repeated_plural_options::NoRepeatedPluralOptions;
+pub use no_syntax_errors::NoSyntaxErrors;
 pub use no_trimmable_whitespace::NoTrimmableWhitespace;
 pub use no_unicode_variable_names::NoUnicodeVariableNames;
 
+mod no_extra_translation_variables;
+mod no_fuzzy_translations;
+mod no_invalid_plural_categories;
+mod no_missing_source_variables;
 mod no_repeated_plural_names;
 mod no_repeated_plural_options;
+mod no_syntax_errors;
 mod no_trimmable_whitespace;
 mod no_unicode_variable_names;
 
 pub mod validator;
+
+/// Return a new instance of every built-in validator, in the order they are run.
+pub fn default_validators() -> Vec<Box<dyn validator::Validator>> {
+    vec![
+        Box::new(NoSyntaxErrors::new()),
+        Box::new(NoUnicodeVariableNames::new()),
+        Box::new(NoRepeatedPluralNames::new()),
+        Box::new(NoRepeatedPluralOptions::new()),
+        Box::new(NoTrimmableWhitespace::new()),
+        Box::new(NoInvalidPluralCategories::new()),
+        Box::new(NoExtraTranslationVariables::new()),
+        Box::new(NoMissingSourceVariables::new()),
+        Box::new(NoFuzzyTranslations::new()),
+    ]
+}
@@ -0,0 +1,44 @@
+use intl_database_core::MessageValue;
+
+use crate::context::ValidationContext;
+use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
+use crate::validators::validator::Validator;
+use crate::DiagnosticSeverity;
+
+/// If a translation contains variables but the source does not, it's likely unintended. The only
+/// time this should reasonably happen is when translations are out-of-date, which should be fixed
+/// automatically once the translations are imported again.
+pub struct NoExtraTranslationVariables;
+impl NoExtraTranslationVariables {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Validator for NoExtraTranslationVariables {
+    fn name(&self) -> DiagnosticName {
+        DiagnosticName::NoExtraTranslationVariables
+    }
+
+    fn validate_ast(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
+        let source = context.source()?;
+        if context.is_source() || has_variables(source) || !has_variables(context.value) {
+            return None;
+        }
+
+        Some(vec![ValueDiagnostic {
+            name: DiagnosticName::NoExtraTranslationVariables,
+            span: None,
+            severity: DiagnosticSeverity::Warning,
+            description: "Translation includes variables, but the source message does not".into(),
+            help: Some("This is okay, but likely unintentional. Check that the source message is defined as expected.".into()),
+        }])
+    }
+}
+
+pub(crate) fn has_variables(value: &MessageValue) -> bool {
+    value
+        .variables
+        .as_ref()
+        .is_some_and(|variables| variables.count() > 0)
+}
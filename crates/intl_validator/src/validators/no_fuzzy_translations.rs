@@ -0,0 +1,32 @@
+use crate::context::ValidationContext;
+use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
+use crate::validators::validator::Validator;
+use crate::DiagnosticSeverity;
+
+pub struct NoFuzzyTranslations;
+impl NoFuzzyTranslations {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Validator for NoFuzzyTranslations {
+    fn name(&self) -> DiagnosticName {
+        DiagnosticName::NoFuzzyTranslations
+    }
+
+    fn validate_raw(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
+        if !context.value.fuzzy {
+            return None;
+        }
+        Some(vec![ValueDiagnostic {
+            name: DiagnosticName::NoFuzzyTranslations,
+            span: None,
+            severity: DiagnosticSeverity::Warning,
+            description: "Translation is marked as fuzzy and has not been reviewed".into(),
+            help: Some(
+                "Review the translation in the translation platform to clear the fuzzy flag".into(),
+            ),
+        }])
+    }
+}
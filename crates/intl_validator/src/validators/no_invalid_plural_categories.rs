@@ -0,0 +1,210 @@
+use std::ops::Range;
+
+use intl_markdown::{IcuPlural, IcuPluralKind};
+use intl_markdown_visitor::{visit_with_mut, Visit, VisitWith};
+use intl_plural_rules::{plural_rules_for_locale, PluralCategory, PluralRuleType, PluralRules};
+
+use crate::context::ValidationContext;
+use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
+use crate::validators::validator::Validator;
+use crate::DiagnosticSeverity;
+
+/// Checks that the arms of every plural in a message match the CLDR plural categories of the
+/// locale the message is written in: every category the locale uses should have an arm, no arm
+/// should use a category the locale never selects, and `other` must always be present.
+pub struct NoInvalidPluralCategories {
+    diagnostics: Vec<ValueDiagnostic>,
+    /// Rules for the locale of the value being validated, set at the start of each validation.
+    cardinal_rules: Option<&'static PluralRules>,
+    ordinal_rules: Option<&'static PluralRules>,
+}
+
+impl NoInvalidPluralCategories {
+    pub fn new() -> Self {
+        Self {
+            diagnostics: vec![],
+            cardinal_rules: None,
+            ordinal_rules: None,
+        }
+    }
+
+    fn add_diagnostic(
+        &mut self,
+        span: &Range<usize>,
+        severity: DiagnosticSeverity,
+        description: String,
+        help: String,
+    ) {
+        self.diagnostics.push(ValueDiagnostic {
+            name: DiagnosticName::NoInvalidPluralCategories,
+            span: Some(span.clone()),
+            severity,
+            description,
+            help: Some(help),
+        });
+    }
+}
+
+impl Validator for NoInvalidPluralCategories {
+    fn name(&self) -> DiagnosticName {
+        DiagnosticName::NoInvalidPluralCategories
+    }
+
+    fn validate_ast(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
+        self.cardinal_rules = Some(plural_rules_for_locale(
+            &context.locale,
+            PluralRuleType::Cardinal,
+        ));
+        self.ordinal_rules = Some(plural_rules_for_locale(
+            &context.locale,
+            PluralRuleType::Ordinal,
+        ));
+        visit_with_mut(&context.value.parsed, self);
+        Some(self.diagnostics.clone())
+    }
+}
+
+impl Visit for NoInvalidPluralCategories {
+    fn visit_icu_plural(&mut self, node: &IcuPlural) {
+        let plural_name = node.name();
+        let rules = match node.kind() {
+            IcuPluralKind::Plural => self.cardinal_rules,
+            IcuPluralKind::SelectOrdinal => self.ordinal_rules,
+        };
+        let Some(rules) = rules else {
+            return;
+        };
+
+        let mut present_categories = Vec::with_capacity(node.arms().len());
+        for arm in node.arms() {
+            let selector = arm.selector();
+            // Exact selectors like `=0` are always valid and don't count towards any category.
+            if selector.starts_with('=') {
+                continue;
+            }
+
+            match PluralCategory::from_name(selector) {
+                Some(category) if rules.has_category(category) => {
+                    present_categories.push(category)
+                }
+                Some(_) => self.add_diagnostic(
+                    arm.selector_span(),
+                    DiagnosticSeverity::Warning,
+                    format!("The plural category '{selector}' is never used in this locale"),
+                    format!("The option '{selector}' in the plural value '{plural_name}' will never be selected. Remove it, or use an exact selector like '=1' if it is meant to match a specific number."),
+                ),
+                None => self.add_diagnostic(
+                    arm.selector_span(),
+                    DiagnosticSeverity::Error,
+                    format!("'{selector}' is not a valid plural category"),
+                    format!("Plural options must be one of 'zero', 'one', 'two', 'few', 'many', 'other', or an exact selector like '=1'. Rename or remove '{selector}' in the plural value '{plural_name}'."),
+                ),
+            }
+        }
+
+        if !present_categories.contains(&PluralCategory::Other) {
+            self.add_diagnostic(
+                node.variable().span(),
+                DiagnosticSeverity::Error,
+                "Plural values must always include an 'other' option".into(),
+                format!("Add an 'other' option to the plural value '{plural_name}'. It is used whenever no other option matches the value."),
+            );
+        }
+
+        let missing_categories: Vec<&str> = rules
+            .categories()
+            .iter()
+            .filter(|category| {
+                **category != PluralCategory::Other && !present_categories.contains(category)
+            })
+            .map(PluralCategory::as_str)
+            .collect();
+        if !missing_categories.is_empty() {
+            let categories = missing_categories.join("', '");
+            self.add_diagnostic(
+                node.variable().span(),
+                DiagnosticSeverity::Warning,
+                "Plural value is missing categories that are required by this locale".into(),
+                format!("Add options for '{categories}' to the plural value '{plural_name}'. Without them, those numbers will fall back to 'other', which is likely grammatically incorrect."),
+            );
+        }
+
+        node.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{key_symbol, Message, MessageValue, MessagesDatabase};
+
+    use crate::context::ValidationContext;
+    use crate::validators::validator::Validator;
+
+    use super::NoInvalidPluralCategories;
+
+    fn validate(locale: &str, content: &str) -> Vec<String> {
+        let locale = key_symbol(locale);
+        let message =
+            Message::from_translation(key_symbol("TEST"), locale, MessageValue::from_raw(content));
+        let database = MessagesDatabase::new();
+        let context = ValidationContext::new(
+            &database,
+            &message,
+            locale,
+            message.translations().get(&locale).unwrap(),
+        );
+        NoInvalidPluralCategories::new()
+            .validate_ast(&context)
+            .unwrap()
+            .into_iter()
+            .map(|diagnostic| diagnostic.description)
+            .collect()
+    }
+
+    #[test]
+    fn matching_categories() {
+        assert!(validate("en-US", "{count, plural, =0 {none} one {#} other {#}}").is_empty());
+        assert!(validate("ja", "{count, plural, other {#}}").is_empty());
+        assert!(validate(
+            "en-US",
+            "{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn missing_categories() {
+        assert_eq!(
+            validate("pl", "{count, plural, one {#} other {#}}"),
+            vec!["Plural value is missing categories that are required by this locale"]
+        );
+    }
+
+    #[test]
+    fn unused_and_unknown_categories() {
+        assert_eq!(
+            validate("ja", "{count, plural, one {#} other {#}}"),
+            vec!["The plural category 'one' is never used in this locale"]
+        );
+        assert_eq!(
+            validate("en-US", "{count, plural, one {#} some {#} other {#}}"),
+            vec!["'some' is not a valid plural category"]
+        );
+    }
+
+    #[test]
+    fn missing_other() {
+        assert_eq!(
+            validate("en-US", "{count, plural, one {#}}"),
+            vec!["Plural values must always include an 'other' option"]
+        );
+    }
+
+    #[test]
+    fn nested_plurals() {
+        assert_eq!(
+            validate("en-US", "{a, plural, one {#} other {{b, plural, one {#}}}}"),
+            vec!["Plural values must always include an 'other' option"]
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::context::ValidationContext;
+use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
+use crate::validators::no_extra_translation_variables::has_variables;
+use crate::validators::validator::Validator;
+use crate::DiagnosticSeverity;
+
+/// If the translation has no variables, but the source does, this is also likely not intentional,
+/// but still won't break anything at runtime.
+pub struct NoMissingSourceVariables;
+impl NoMissingSourceVariables {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Validator for NoMissingSourceVariables {
+    fn name(&self) -> DiagnosticName {
+        DiagnosticName::NoMissingSourceVariables
+    }
+
+    fn validate_ast(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
+        let source = context.source()?;
+        if context.is_source() || !has_variables(source) || has_variables(context.value) {
+            return None;
+        }
+
+        Some(vec![ValueDiagnostic {
+            name: DiagnosticName::NoMissingSourceVariables,
+            span: None,
+            severity: DiagnosticSeverity::Warning,
+            description: "Source message includes variables, but this translation has none.".into(),
+            help: Some("This is okay, but likely unintentional. Check that the source message is defined as expected.".into()),
+        }])
+    }
+}
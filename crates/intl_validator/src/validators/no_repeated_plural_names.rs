@@ -1,7 +1,8 @@
-use intl_database_core::{key_symbol, KeySymbol, MessageValue};
+use intl_database_core::{key_symbol, KeySymbol};
 use intl_markdown::{IcuPlural, IcuPluralArm, IcuSelect, IcuVariable};
 use intl_markdown_visitor::{visit_with_mut, Visit, VisitWith};
 
+use crate::context::ValidationContext;
 use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
 use crate::validators::validator::Validator;
 use crate::DiagnosticSeverity;
@@ -23,8 +24,12 @@ impl NoRepeatedPluralNames {
 }
 
 impl Validator for NoRepeatedPluralNames {
-    fn validate_ast(&mut self, message: &MessageValue) -> Option<Vec<ValueDiagnostic>> {
-        visit_with_mut(&message.parsed, self);
+    fn name(&self) -> DiagnosticName {
+        DiagnosticName::NoRepeatedPluralNames
+    }
+
+    fn validate_ast(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
+        visit_with_mut(&context.value.parsed, self);
         Some(self.diagnostics.clone())
     }
 }
@@ -71,7 +76,7 @@ impl Visit for NoRepeatedPluralNames {
         if plural_name.eq(node.name()) {
             let diagnostic = ValueDiagnostic {
                 name: DiagnosticName::NoRepeatedPluralNames,
-                span: None,
+                span: Some(node.span().clone()),
                 severity: DiagnosticSeverity::Warning,
                 description: String::from("Plural variable names should use # instead of repeating the name of the variable"),
                 help: Some(String::from("Replace this variable name with #")),
@@ -1,8 +1,8 @@
-use intl_database_core::MessageValue;
-use intl_markdown::IcuPlural;
+use intl_markdown::{IcuPlural, IcuPluralArm};
 use intl_markdown_visitor::{visit_with_mut, Visit};
 use std::collections::HashSet;
 
+use crate::context::ValidationContext;
 use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
 use crate::validators::validator::Validator;
 use crate::DiagnosticSeverity;
@@ -20,8 +20,12 @@ impl NoRepeatedPluralOptions {
 }
 
 impl Validator for NoRepeatedPluralOptions {
-    fn validate_ast(&mut self, message: &MessageValue) -> Option<Vec<ValueDiagnostic>> {
-        visit_with_mut(&message.parsed, self);
+    fn name(&self) -> DiagnosticName {
+        DiagnosticName::NoRepeatedPluralOptions
+    }
+
+    fn validate_ast(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
+        visit_with_mut(&context.value.parsed, self);
         Some(self.diagnostics.clone())
     }
 }
@@ -29,25 +33,26 @@ impl Validator for NoRepeatedPluralOptions {
 impl Visit for NoRepeatedPluralOptions {
     fn visit_icu_plural(&mut self, node: &IcuPlural) {
         let plural_name = node.name();
-        let arm_names = node.arms().iter().map(|arm| arm.selector().as_str());
         let mut seen = HashSet::new();
         // Allotting enough capacity to handle basically every possible case. More than 4
         // repetitions is egregious and there will almost never be more than 1, but this just
         // ensures it's always consistent allocation.
-        let mut repeated_names: Vec<&str> = Vec::with_capacity(4);
+        let mut repeated_arms: Vec<&IcuPluralArm> = Vec::with_capacity(4);
 
-        for name in arm_names {
+        for arm in node.arms() {
+            let name = arm.selector().as_str();
             if seen.contains(name) {
-                repeated_names.push(name);
+                repeated_arms.push(arm);
             } else {
                 seen.insert(name);
             }
         }
 
-        for name in repeated_names {
+        for arm in repeated_arms {
+            let name = arm.selector();
             let diagnostic = ValueDiagnostic {
                 name: DiagnosticName::NoRepeatedPluralOptions,
-                span: None,
+                span: Some(arm.selector_span().clone()),
                 severity: DiagnosticSeverity::Error,
                 description: String::from(
                     "Plural options must be unique within the plural selector",
@@ -0,0 +1,88 @@
+use crate::context::ValidationContext;
+use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
+use crate::validators::validator::Validator;
+use crate::DiagnosticSeverity;
+
+/// Reports the syntax errors that the parser recovered from while parsing the message. The parser
+/// never fails outright, and instead treats malformed syntax as plain text, so these messages will
+/// still render, but almost certainly not the way the author intended.
+pub struct NoSyntaxErrors;
+impl NoSyntaxErrors {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Validator for NoSyntaxErrors {
+    fn name(&self) -> DiagnosticName {
+        DiagnosticName::NoSyntaxErrors
+    }
+
+    fn validate_ast(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
+        let diagnostics = context.value.parsed.diagnostics();
+        if diagnostics.is_empty() {
+            return None;
+        }
+
+        Some(
+            diagnostics
+                .iter()
+                .map(|diagnostic| ValueDiagnostic {
+                    name: DiagnosticName::NoSyntaxErrors,
+                    span: Some(diagnostic.span().clone()),
+                    severity: DiagnosticSeverity::Error,
+                    description: diagnostic.message().into(),
+                    help: Some("This part of the message will be rendered as plain text. Fix the syntax so it is formatted as intended.".into()),
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use intl_database_core::{key_symbol, Message, MessageValue, MessagesDatabase};
+
+    use crate::context::ValidationContext;
+    use crate::validators::validator::Validator;
+
+    use super::NoSyntaxErrors;
+
+    fn validate(content: &str) -> Vec<(String, &str)> {
+        let locale = key_symbol("en-US");
+        let message =
+            Message::from_translation(key_symbol("TEST"), locale, MessageValue::from_raw(content));
+        let database = MessagesDatabase::new();
+        let context = ValidationContext::new(
+            &database,
+            &message,
+            locale,
+            message.translations().get(&locale).unwrap(),
+        );
+        NoSyntaxErrors::new()
+            .validate_ast(&context)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|diagnostic| (diagnostic.description, &content[diagnostic.span.unwrap()]))
+            .collect()
+    }
+
+    #[test]
+    fn valid_message() {
+        assert!(validate("hello {name}, you have {count, plural, other {#}}").is_empty());
+    }
+
+    #[test]
+    fn syntax_errors() {
+        assert_eq!(
+            validate("hello {name, color} and {user"),
+            vec![
+                (
+                    "Unknown ICU format type. Expected one of 'plural', 'select', 'selectordinal', 'number', 'date', or 'time'".into(),
+                    "color"
+                ),
+                ("ICU placeholder is missing its closing '}'".into(), "{user"),
+            ]
+        );
+    }
+}
@@ -1,5 +1,4 @@
-use intl_database_core::MessageValue;
-
+use crate::context::ValidationContext;
 use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
 use crate::validators::validator::Validator;
 use crate::DiagnosticSeverity;
@@ -12,22 +11,28 @@ impl NoTrimmableWhitespace {
 }
 
 impl Validator for NoTrimmableWhitespace {
-    fn validate_raw(&mut self, message: &MessageValue) -> Option<Vec<ValueDiagnostic>> {
+    fn name(&self) -> DiagnosticName {
+        DiagnosticName::NoTrimmableWhitespace
+    }
+
+    fn validate_raw(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
         let mut diagnostics = vec![];
-        let content = &message.raw;
-        if content.trim_start() != content {
+        let content = &context.value.raw;
+        let leading_length = content.len() - content.trim_start().len();
+        if leading_length > 0 {
             diagnostics.push(ValueDiagnostic {
                 name: DiagnosticName::NoTrimmableWhitespace,
-                span: None,
+                span: Some(0..leading_length),
                 severity: DiagnosticSeverity::Warning,
                 description: "Avoid leading whitespace on messages".into(),
                 help: Some("Leading whitespace is visually ambiguous when translating and leads to inconsistency".into())
             })
         }
-        if content.trim_end() != content {
+        let trimmed_end = content.trim_end().len();
+        if trimmed_end < content.len() {
             diagnostics.push(ValueDiagnostic {
                 name: DiagnosticName::NoTrimmableWhitespace,
-                span: None,
+                span: Some(trimmed_end..content.len()),
                 severity: DiagnosticSeverity::Warning,
                 description: "Avoid trailing whitespace on messages".into(),
                 help: Some("Trailing whitespace is visually ambiguous when translating and leads to inconsistency".into())
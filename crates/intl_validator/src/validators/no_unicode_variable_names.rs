@@ -1,7 +1,7 @@
-use intl_database_core::MessageValue;
 use intl_markdown::IcuVariable;
 use intl_markdown_visitor::{visit_with_mut, Visit};
 
+use crate::context::ValidationContext;
 use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
 use crate::validators::validator::Validator;
 use crate::DiagnosticSeverity;
@@ -19,8 +19,12 @@ impl NoUnicodeVariableNames {
 }
 
 impl Validator for NoUnicodeVariableNames {
-    fn validate_ast(&mut self, message: &MessageValue) -> Option<Vec<ValueDiagnostic>> {
-        visit_with_mut(&message.parsed, self);
+    fn name(&self) -> DiagnosticName {
+        DiagnosticName::NoUnicodeVariableNames
+    }
+
+    fn validate_ast(&mut self, context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
+        visit_with_mut(&context.value.parsed, self);
         Some(self.diagnostics.clone())
     }
 }
@@ -32,7 +36,7 @@ impl Visit for NoUnicodeVariableNames {
             let help_text = format!("\"{name}\" should be renamed to only use ASCII characters. If this is a translation, ensure the name matches the expected name in the source text");
             self.diagnostics.push(ValueDiagnostic {
                 name: DiagnosticName::NoUnicodeVariableNames,
-                span: None,
+                span: Some(node.span().clone()),
                 severity: DiagnosticSeverity::Error,
                 description: "Variable names should not contain unicode characters to avoid ambiguity during translation".into(),
                 help: Some(help_text),
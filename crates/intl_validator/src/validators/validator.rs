@@ -1,13 +1,19 @@
-use intl_database_core::MessageValue;
-
-use crate::diagnostic::ValueDiagnostic;
+use crate::context::ValidationContext;
+use crate::diagnostic::{DiagnosticName, ValueDiagnostic};
 
+/// A rule that checks message values and reports diagnostics about them. A new instance of each
+/// validator is created for every value that is validated, so implementations are free to
+/// collect state while visiting a value.
 pub trait Validator {
-    fn validate_raw(&mut self, _message: &MessageValue) -> Option<Vec<ValueDiagnostic>> {
+    /// The name of the rule, used to enable, disable, or suppress it and to identify its
+    /// diagnostics.
+    fn name(&self) -> DiagnosticName;
+
+    fn validate_raw(&mut self, _context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
         None
     }
 
-    fn validate_ast(&mut self, _message: &MessageValue) -> Option<Vec<ValueDiagnostic>> {
+    fn validate_ast(&mut self, _context: &ValidationContext) -> Option<Vec<ValueDiagnostic>> {
         None
     }
 }
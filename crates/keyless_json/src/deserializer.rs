@@ -0,0 +1,550 @@
+use serde::de::{self, IntoDeserializer, Visitor};
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+
+/// A deserializer for keyless JSON, reading the same structures that [crate::Serializer] writes.
+///
+/// Structs are read from arrays of their fields in order, enums are read from their variant index
+/// (for unit variants) or an array of the index followed by the variant's data, and booleans are
+/// read from `0` and `1`. Everything else is read like plain JSON, so self-describing types like
+/// maps, sequences, and untagged values can also be read from plain JSON input.
+pub struct Deserializer<'de> {
+    input: &'de str,
+    position: usize,
+}
+
+impl<'de> Deserializer<'de> {
+    pub fn new(input: &'de str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    /// Check that only whitespace remains after the value that was deserialized.
+    pub fn end(&mut self) -> Result<()> {
+        match self.peek_non_whitespace() {
+            None => Ok(()),
+            Some(_) => Err(self.syntax_error("trailing characters after value")),
+        }
+    }
+
+    fn syntax_error(&self, message: &str) -> Error {
+        Error::SyntaxError {
+            message: message.into(),
+            position: self.position,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.position).copied()
+    }
+
+    fn peek_non_whitespace(&mut self) -> Option<u8> {
+        while let Some(b' ' | b'\n' | b'\r' | b'\t') = self.peek() {
+            self.position += 1;
+        }
+        self.peek()
+    }
+
+    fn expect_byte(&mut self, expected: u8, message: &str) -> Result<()> {
+        if self.peek_non_whitespace() == Some(expected) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.syntax_error(message))
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        if self.input[self.position..].starts_with(keyword) {
+            self.position += keyword.len();
+            Ok(())
+        } else {
+            Err(self.syntax_error("expected a value"))
+        }
+    }
+
+    /// Consume the separator between elements of an array or object if there is one, returning
+    /// false once the closing delimiter is reached instead.
+    fn has_next_element(&mut self, is_first: bool, closing: u8) -> Result<bool> {
+        match self.peek_non_whitespace() {
+            Some(byte) if byte == closing => Ok(false),
+            Some(b',') if !is_first => {
+                self.position += 1;
+                Ok(true)
+            }
+            Some(_) if is_first => Ok(true),
+            Some(_) => Err(self.syntax_error("expected `,` between elements")),
+            None => Err(self.syntax_error("unexpected end of input")),
+        }
+    }
+
+    fn parse_number<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        let start = self.position;
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+        let mut is_float = false;
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'0'..=b'9' => {}
+                b'.' | b'e' | b'E' | b'+' | b'-' => is_float = true,
+                _ => break,
+            }
+            self.position += 1;
+        }
+
+        let text = &self.input[start..self.position];
+        let invalid = || Error::SyntaxError {
+            message: format!("invalid number `{text}`"),
+            position: start,
+        };
+        if is_float {
+            visitor.visit_f64(text.parse().map_err(|_| invalid())?)
+        } else if text.starts_with('-') {
+            visitor.visit_i64(text.parse().map_err(|_| invalid())?)
+        } else {
+            visitor.visit_u64(text.parse().map_err(|_| invalid())?)
+        }
+    }
+
+    fn parse_u32(&mut self) -> Result<u32> {
+        self.peek_non_whitespace();
+        let start = self.position;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+        self.input[start..self.position]
+            .parse()
+            .map_err(|_| self.syntax_error("expected a variant index"))
+    }
+
+    fn parse_string<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        self.expect_byte(b'"', "expected a string")?;
+        let start = self.position;
+        // Strings without any escapes can be borrowed directly from the input.
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    let value = &self.input[start..self.position];
+                    self.position += 1;
+                    return visitor.visit_borrowed_str(value);
+                }
+                Some(b'\\') => break,
+                Some(_) => self.position += 1,
+                None => return Err(self.syntax_error("unterminated string")),
+            }
+        }
+
+        let mut value = String::from(&self.input[start..self.position]);
+        loop {
+            let Some(next) = self.input[self.position..].find(['"', '\\']) else {
+                return Err(self.syntax_error("unterminated string"));
+            };
+            value.push_str(&self.input[self.position..self.position + next]);
+            self.position += next;
+            if self.peek() == Some(b'"') {
+                self.position += 1;
+                return visitor.visit_string(value);
+            }
+            self.position += 1;
+            value.push(self.parse_escape()?);
+        }
+    }
+
+    /// Parse the escape sequence following a backslash, returning the character it represents.
+    fn parse_escape(&mut self) -> Result<char> {
+        let Some(byte) = self.peek() else {
+            return Err(self.syntax_error("unterminated string"));
+        };
+        self.position += 1;
+        Ok(match byte {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\x08',
+            b'f' => '\x0c',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let high = self.parse_hex_escape()?;
+                let code_point = if (0xD800..0xDC00).contains(&high) {
+                    self.expect_keyword("\\u")
+                        .map_err(|_| self.syntax_error("unpaired surrogate in escape"))?;
+                    let low = self.parse_hex_escape()?;
+                    0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF)
+                } else {
+                    high
+                };
+                char::from_u32(code_point)
+                    .ok_or_else(|| self.syntax_error("invalid unicode escape"))?
+            }
+            _ => return Err(self.syntax_error("invalid escape sequence")),
+        })
+    }
+
+    fn parse_hex_escape(&mut self) -> Result<u32> {
+        let digits = self
+            .input
+            .get(self.position..self.position + 4)
+            .ok_or_else(|| self.syntax_error("invalid unicode escape"))?;
+        let value = u32::from_str_radix(digits, 16)
+            .map_err(|_| self.syntax_error("invalid unicode escape"))?;
+        self.position += 4;
+        Ok(value)
+    }
+}
+
+// By convention, the public API of a Serde deserializer is one or more `from_abc` functions
+// matching the `to_abc` functions of the serializer.
+pub fn from_str<'de, T>(input: &'de str) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    let mut deserializer = Deserializer::new(input);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    // Keyless JSON is self-describing in every case except that structs lose their field names,
+    // which `deserialize_struct` handles by reading them as sequences.
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.peek_non_whitespace() {
+            Some(b'n') => {
+                self.expect_keyword("null")?;
+                visitor.visit_unit()
+            }
+            Some(b't') => {
+                self.expect_keyword("true")?;
+                visitor.visit_bool(true)
+            }
+            Some(b'f') => {
+                self.expect_keyword("false")?;
+                visitor.visit_bool(false)
+            }
+            Some(b'"') => self.parse_string(visitor),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(visitor),
+            Some(b'[') => {
+                self.position += 1;
+                let value = visitor.visit_seq(ArrayAccess::new(self))?;
+                self.expect_byte(b']', "expected `]` at the end of the array")?;
+                Ok(value)
+            }
+            Some(b'{') => {
+                self.position += 1;
+                let value = visitor.visit_map(ObjectAccess::new(self))?;
+                self.expect_byte(b'}', "expected `}` at the end of the object")?;
+                Ok(value)
+            }
+            Some(_) => Err(self.syntax_error("expected a value")),
+            None => Err(self.syntax_error("unexpected end of input")),
+        }
+    }
+
+    // Booleans are written as `0` and `1`, but plain JSON booleans are accepted as well.
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.peek_non_whitespace() {
+            Some(b'0') => {
+                self.position += 1;
+                visitor.visit_bool(false)
+            }
+            Some(b'1') => {
+                self.position += 1;
+                visitor.visit_bool(true)
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    // An absent optional is represented as `null`, and a present one as just the contained value.
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.peek_non_whitespace() == Some(b'n') {
+            self.expect_keyword("null")?;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // Enums are written as their variant index for unit variants, or `[<index>, ...<data>]` for
+    // all other variants.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.peek_non_whitespace() {
+            Some(b'[') => {
+                self.position += 1;
+                let value = visitor.visit_enum(VariantAccess {
+                    deserializer: self,
+                    is_unit: false,
+                })?;
+                self.expect_byte(b']', "expected `]` at the end of the enum variant")?;
+                Ok(value)
+            }
+            _ => visitor.visit_enum(VariantAccess {
+                deserializer: self,
+                is_unit: true,
+            }),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(de::IgnoredAny)?;
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+/// Access to the elements of an array, up to but not including the closing `]`.
+struct ArrayAccess<'a, 'de: 'a> {
+    deserializer: &'a mut Deserializer<'de>,
+    is_first: bool,
+}
+
+impl<'a, 'de> ArrayAccess<'a, 'de> {
+    fn new(deserializer: &'a mut Deserializer<'de>) -> Self {
+        Self {
+            deserializer,
+            is_first: true,
+        }
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for ArrayAccess<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if !self.deserializer.has_next_element(self.is_first, b']')? {
+            return Ok(None);
+        }
+        self.is_first = false;
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+}
+
+/// Access to the entries of an object, up to but not including the closing `}`.
+struct ObjectAccess<'a, 'de: 'a> {
+    deserializer: &'a mut Deserializer<'de>,
+    is_first: bool,
+}
+
+impl<'a, 'de> ObjectAccess<'a, 'de> {
+    fn new(deserializer: &'a mut Deserializer<'de>) -> Self {
+        Self {
+            deserializer,
+            is_first: true,
+        }
+    }
+}
+
+impl<'de> de::MapAccess<'de> for ObjectAccess<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if !self.deserializer.has_next_element(self.is_first, b'}')? {
+            return Ok(None);
+        }
+        self.is_first = false;
+        if self.deserializer.peek_non_whitespace() != Some(b'"') {
+            return Err(self.deserializer.syntax_error("expected an object key"));
+        }
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        self.deserializer
+            .expect_byte(b':', "expected `:` after an object key")?;
+        seed.deserialize(&mut *self.deserializer)
+    }
+}
+
+/// Access to an enum variant, either a bare variant index or the contents of an array starting
+/// with the variant index.
+struct VariantAccess<'a, 'de: 'a> {
+    deserializer: &'a mut Deserializer<'de>,
+    is_unit: bool,
+}
+
+impl<'de> de::EnumAccess<'de> for VariantAccess<'_, 'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self)>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let index = self.deserializer.parse_u32()?;
+        let variant = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(index))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess<'_, 'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if self.is_unit {
+            return Err(self.deserializer.syntax_error("expected variant data"));
+        }
+        self.deserializer
+            .expect_byte(b',', "expected `,` after the variant index")?;
+        seed.deserialize(&mut *self.deserializer)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        if self.is_unit {
+            return Err(self.deserializer.syntax_error("expected variant data"));
+        }
+        // The remaining elements of the array are the fields of the variant, so reading them
+        // continues as if the variant index was the first element of the sequence.
+        visitor.visit_seq(ArrayAccess {
+            deserializer: self.deserializer,
+            is_first: false,
+        })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.tuple_variant(fields.len(), visitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::from_str;
+    use crate::to_string;
+
+    fn assert_round_trip<'de, T>(value: &T, expected: &'de str)
+    where
+        T: Serialize + Deserialize<'de> + PartialEq + std::fmt::Debug,
+    {
+        assert_eq!(to_string(value).unwrap(), expected);
+        assert_eq!(&from_str::<T>(expected).unwrap(), value);
+    }
+
+    #[test]
+    fn test_bool() {
+        assert_round_trip(&true, "1");
+        assert_round_trip(&false, "0");
+        assert!(from_str::<bool>("true").unwrap());
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_round_trip(&42u32, "42");
+        assert_round_trip(&-7i64, "-7");
+        assert_round_trip(&1.5f64, "1.5");
+    }
+
+    #[test]
+    fn test_strings() {
+        assert_round_trip(&String::from("hello"), r#""hello""#);
+        assert_round_trip(
+            &String::from("quote \" slash \\ newline \n"),
+            r#""quote \" slash \\ newline \n""#,
+        );
+        assert_eq!(from_str::<String>(r#""é😀""#).unwrap(), "é😀");
+        // Strings without escapes are borrowed from the input.
+        assert_eq!(from_str::<&str>(r#""borrowed""#).unwrap(), "borrowed");
+    }
+
+    #[test]
+    fn test_struct() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            int: u32,
+            seq: Vec<String>,
+            option: Option<bool>,
+        }
+
+        let test = Test {
+            int: 5,
+            seq: vec!["a".into(), "b".into()],
+            option: None,
+        };
+        assert_round_trip(&test, r#"[5,["a","b"],null]"#);
+    }
+
+    #[test]
+    fn test_enum() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum E {
+            Unit,
+            Newtype(u32),
+            Tuple(u32, u32),
+            Struct { a: u32 },
+        }
+
+        assert_round_trip(&E::Unit, "0");
+        assert_round_trip(&E::Newtype(5), "[1,5]");
+        assert_round_trip(&E::Tuple(1, 2), "[2,1,2]");
+        assert_round_trip(&E::Struct { a: 1 }, "[3,1]");
+    }
+
+    #[test]
+    fn test_map() {
+        let mut map = BTreeMap::new();
+        map.insert(String::from("first"), String::from("value1"));
+        map.insert(String::from("second"), String::from("value2"));
+
+        assert_round_trip(&map, r#"{"first":"value1","second":"value2"}"#);
+    }
+
+    #[test]
+    fn test_whitespace() {
+        assert_eq!(
+            from_str::<Vec<Vec<u8>>>(" [ [1 , 2] ,\n[] ] ").unwrap(),
+            vec![vec![1, 2], vec![]]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(from_str::<Vec<u8>>("[1,2").is_err());
+        assert!(from_str::<Vec<u8>>("[1 2]").is_err());
+        assert!(from_str::<String>(r#""unterminated"#).is_err());
+        assert!(from_str::<u8>("1 2").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use serde::ser;
+use serde::{de, ser};
 use thiserror::Error as ThisError;
 
 #[derive(Debug, ThisError)]
@@ -7,6 +7,8 @@ pub enum Error {
     IoError(std::io::Error),
     #[error("{0}")]
     CustomError(String),
+    #[error("{message} at position {position}")]
+    SyntaxError { message: String, position: usize },
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -23,3 +25,10 @@ impl ser::Error for Error {
         Error::CustomError(msg.to_string())
     }
 }
+
+impl de::Error for Error {
+    #[cold]
+    fn custom<T: std::fmt::Display>(msg: T) -> Error {
+        Error::CustomError(msg.to_string())
+    }
+}
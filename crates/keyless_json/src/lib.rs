@@ -1,6 +1,9 @@
+pub use deserializer::{from_str, Deserializer};
+pub use error::Error;
 pub use serializer::{to_string, to_writer, Serializer};
 pub use string::write_escaped_str_contents;
 
+mod deserializer;
 mod error;
 mod serializer;
 mod string;
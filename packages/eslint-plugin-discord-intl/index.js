@@ -2,6 +2,7 @@ module.exports = {
   rules: {
     'no-repeated-plural-names': require('./rules/native/no-repeated-plural-names'),
     'no-repeated-plural-options': require('./rules/native/no-repeated-plural-options'),
+    'no-syntax-errors': require('./rules/native/no-syntax-errors'),
     'no-trimmable-whitespace': require('./rules/native/no-trimmable-whitespace'),
     'no-unicode-variable-names': require('./rules/native/no-unicode-variable-names'),
 
@@ -16,6 +17,7 @@ module.exports = {
         '@discord/discord-intl/no-trimmable-whitespace': 'error',
         '@discord/discord-intl/no-repeated-plural-names': 'error',
         '@discord/discord-intl/no-repeated-plural-options': 'error',
+        '@discord/discord-intl/no-syntax-errors': 'error',
         '@discord/discord-intl/no-unicode-variable-names': 'error',
 
         // JS rules
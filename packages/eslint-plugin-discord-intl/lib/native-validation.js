@@ -65,6 +65,15 @@ function traverseAndReportMatchingNativeValidations(context, predicate) {
 
       context.report({
         node: value,
+        // Diagnostics that apply to a specific part of the message, like a single variable, know
+        // exactly where that part is in the file, which is more helpful than the whole value.
+        loc:
+          diagnostic.endLine != null && diagnostic.endCol != null
+            ? {
+                start: { line: diagnostic.line, column: diagnostic.col },
+                end: { line: diagnostic.endLine, column: diagnostic.endCol },
+              }
+            : undefined,
         message: diagnostic.description,
       });
     }
@@ -0,0 +1,16 @@
+const { traverseAndReportMatchingNativeValidations } = require('../../lib/native-validation');
+
+module.exports = /** @type {import('eslint').Rule.RuleModule} */ ({
+  meta: {
+    docs: {
+      description: 'Disallow malformed ICU and markdown syntax in intl messages',
+      category: 'Possible Errors',
+    },
+  },
+  create(context) {
+    return traverseAndReportMatchingNativeValidations(
+      context,
+      (diagnostic) => diagnostic.name === 'NoSyntaxErrors',
+    );
+  },
+});
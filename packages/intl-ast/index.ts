@@ -19,9 +19,9 @@ export enum FormatJsNodeType {
 // provided to convert between this and the FormatJS compatible version as needed.
 export type LiteralNode = string;
 export type ArgumentNode = [FormatJsNodeType.Argument, string];
-export type NumberNode = [FormatJsNodeType.Number, string, string | undefined];
-export type DateNode = [FormatJsNodeType.Date, string, string | undefined];
-export type TimeNode = [FormatJsNodeType.Time, string, string | undefined];
+export type NumberNode = [FormatJsNodeType.Number, string, NumberStyle | undefined];
+export type DateNode = [FormatJsNodeType.Date, string, DateTimeStyle | undefined];
+export type TimeNode = [FormatJsNodeType.Time, string, DateTimeStyle | undefined];
 export type SelectNode = [FormatJsNodeType.Select, string, Record<string, AstNode[]>];
 export type PluralNode = [
   FormatJsNodeType.Plural,
@@ -55,6 +55,37 @@ export const enum AstNodeIndices {
   Control = 3,
 }
 
+//#region Styles
+//
+// Named styles like `percent` or `short` are kept as strings, while skeletons are parsed at compile
+// time into the same objects that FormatJS' `@formatjs/icu-skeleton-parser` produces.
+export const enum SkeletonType {
+  Number = 0,
+  DateTime = 1,
+}
+
+export interface NumberSkeletonToken {
+  stem: string;
+  options: string[];
+}
+
+export interface NumberSkeleton {
+  type: SkeletonType.Number;
+  tokens: NumberSkeletonToken[];
+  parsedOptions: Intl.NumberFormatOptions & { scale?: number };
+}
+
+export interface DateTimeSkeleton {
+  type: SkeletonType.DateTime;
+  pattern: string;
+  parsedOptions: Intl.DateTimeFormatOptions;
+}
+
+export type NumberStyle = string | NumberSkeleton;
+export type DateTimeStyle = string | DateTimeSkeleton;
+
+//#endregion
+
 //#region Full FormatJS Node types
 //
 // These are complete, strongly-typed Object nodes that match FormatJS' AST as closely as possible.
@@ -72,19 +103,19 @@ export interface FullFormatJsArgument {
 export interface FullFormatJsNumber {
   type: FormatJsNodeType.Number;
   value: string;
-  style?: string;
+  style?: NumberStyle;
 }
 
 export interface FullFormatJsDate {
   type: FormatJsNodeType.Date;
   value: string;
-  style?: string;
+  style?: DateTimeStyle;
 }
 
 export interface FullFormatJsTime {
   type: FormatJsNodeType.Time;
   value: string;
-  style?: string;
+  style?: DateTimeStyle;
 }
 
 export interface FullFormatJsSelect {
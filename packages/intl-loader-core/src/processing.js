@@ -10,6 +10,8 @@ const { findAllTranslationFiles, getLocaleFromTranslationsFileName } = require('
  * @typedef {{
  *   format?: IntlCompiledMessageFormat,
  *   bundleSecrets?: boolean,
+ *   fallbacks?: boolean,
+ *   fallbackChains?: Record<string, string[]>,
  * }} IntlPrecompileOptions
  */
 
@@ -82,15 +84,15 @@ function processAllMessagesFiles(files) {
 function processDefinitionsFile(sourcePath, sourceContent, options = {}) {
   const {
     processTranslations = false,
-    // TODO: Make this more configurable/automatically determined.
-    locale = 'en-US',
+    // Files can declare their own `sourceLocale` in `meta`, which takes precedence over this.
+    locale: defaultLocale = 'en-US',
   } = options;
-  debug(`[${sourcePath}] Processing definitions with locale "${locale}"`);
+  debug(`[${sourcePath}] Processing definitions with default locale "${defaultLocale}"`);
 
   if (sourceContent != null) {
-    database.processDefinitionsFileContent(sourcePath, sourceContent, locale);
+    database.processDefinitionsFileContent(sourcePath, sourceContent, defaultLocale);
   } else {
-    database.processDefinitionsFile(sourcePath, locale);
+    database.processDefinitionsFile(sourcePath, defaultLocale);
   }
 
   const sourceFile = database.getSourceFile(sourcePath);
@@ -101,6 +103,7 @@ function processDefinitionsFile(sourcePath, sourceContent, options = {}) {
     );
   }
 
+  const locale = sourceFile.meta.sourceLocale ?? defaultLocale;
   const messageKeys = database.getSourceFileKeyMap(sourcePath);
   const translationsPath = path.resolve(path.dirname(sourcePath), sourceFile.meta.translationsPath);
   const translationsLocaleMap = buildTranslationsLocaleMap(
@@ -161,19 +164,23 @@ function processTranslationsFile(sourcePath, sourceContent, options = {}) {
  * will be written in.
  *
  * By default, the compiled content will be returned as a Buffer containing the serialized string,
- * but if `outputFile` is given then the content will be written directly to the file and the
- * function becomes `void`.
+ * but if `outputFile` is given then the content will be written directly to the file and a report
+ * of the bundled content is returned instead.
  *
  * Compiling automatically handles filtering out messages based on the meta information like
  * `translate`, `secret`, and `bundleSecrets`, to ensure that all consumers apply these values
  * accurately and consistently.
  *
+ * When `fallbacks` or `fallbackChains` are given, messages without a translation in `locale` use
+ * the value from the first fallback locale that has one, ending with the message's source locale,
+ * so the result is complete on its own.
+ *
  * @param {string} sourcePath
  * @param {string} locale
  * @param {string=} outputFile
  * @param {IntlPrecompileOptions} [options]
  *
- * @returns {Buffer | void}
+ * @returns {Buffer | import('@discord/intl-message-database').IntlMessageBundleReport}
  */
 function precompileFileForLocale(sourcePath, locale, outputFile, options = {}) {
   return outputFile != null
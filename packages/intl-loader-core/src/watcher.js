@@ -17,7 +17,7 @@ const ALWAYS_IGNORE_PATTERNS = [
 ];
 // TODO: This should come from the database extension? Or Utilities? Unsure, but the extensions
 // should have some centralized location in general
-const MESSAGE_DEFINITION_FILE_PATTERNS = ['**/*.messages.js'];
+const MESSAGE_DEFINITION_FILE_PATTERNS = ['**/*.messages.{js,jsx,ts,tsx}'];
 const DEFAULT_LOCALE = 'en-US';
 
 /**
@@ -35,7 +35,10 @@ function processFile(filePath, assetExtension, options = {}) {
 
   try {
     // Convert the file name from `.messages.js` to `.compiled.messages.jsona` for output.
-    const outputPath = filePath.replace(/\.messages\.js$/, `.compiled.messages.${assetExtension}`);
+    const outputPath = filePath.replace(
+      /\.messages\.[jt]sx?$/,
+      `.compiled.messages.${assetExtension}`,
+    );
     const result = processDefinitionsFile(filePath);
     precompileFileForLocale(filePath, result.locale, undefined, {
       format,
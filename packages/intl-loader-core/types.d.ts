@@ -81,8 +81,8 @@ export interface ProcessDefinitionsResult {
    */
   sourceFile: IntlSourceFile;
   /**
-   * The locale that was either determined from the sourceFile name or overridden by the options
-   * provided to this call.
+   * The locale that the definitions are written in, taken from the `sourceLocale` declared in the
+   * file's `meta` when present, or from the options provided to this call otherwise.
    */
   locale: string;
   /**
@@ -99,28 +99,31 @@ export function bindFormatValuesWithBuilder<T, Builder extends FormatBuilder<T>>
         break;
       case FormatJsNodeType.Date: {
         const nodeStyle = node[AstNodeIndices.Style];
-        // Distinct from FormatJS: We don't currently parse the skeleton ahead of time in the AST,
-        // so this manages parsing the skeleton as well before passing it onto the date formatter.
+        // Skeletons are parsed ahead of time by the compiler. Strings are either named styles or,
+        // for ASTs compiled before skeletons were parsed, a skeleton that still needs parsing.
         const style =
-          nodeStyle in formatConfig.date
-            ? formatConfig.date[nodeStyle]
-            : nodeStyle != null
-              ? parseDateTimeSkeleton(nodeStyle)
-              : formatConfig.time.medium;
+          nodeStyle == null
+            ? formatConfig.time.medium
+            : typeof nodeStyle === 'object'
+              ? nodeStyle.parsedOptions
+              : nodeStyle in formatConfig.date
+                ? formatConfig.date[nodeStyle]
+                : parseDateTimeSkeleton(nodeStyle);
         // @ts-expect-error Cast string values to dates properly.
         builder.pushLiteralText(formatters.getDateTimeFormat(locales, style).format(value));
         break;
       }
       case FormatJsNodeType.Time: {
         const nodeStyle = node[AstNodeIndices.Style];
-        // Distinct from FormatJS: We don't currently parse the skeleton ahead of time in the AST,
-        // so this manages parsing the skeleton as well before passing it onto the date formatter.
+        // See the Date case above for how styles are resolved.
         const style =
-          nodeStyle in formatConfig.time
-            ? formatConfig.time[nodeStyle]
-            : nodeStyle != null
-              ? parseDateTimeSkeleton(nodeStyle)
-              : undefined; // TODO: parseSkeleton();
+          nodeStyle == null
+            ? undefined
+            : typeof nodeStyle === 'object'
+              ? nodeStyle.parsedOptions
+              : nodeStyle in formatConfig.time
+                ? formatConfig.time[nodeStyle]
+                : parseDateTimeSkeleton(nodeStyle);
         builder.pushLiteralText(
           // @ts-expect-error Cast string values to dates properly.
           formatters.getDateTimeFormat(locales, style).format(value),
@@ -129,14 +132,15 @@ export function bindFormatValuesWithBuilder<T, Builder extends FormatBuilder<T>>
       }
       case FormatJsNodeType.Number: {
         const nodeStyle = node[AstNodeIndices.Style];
-        // Distinct from FormatJS: We don't currently parse the skeleton ahead of time in the AST,
-        // so this manages parsing the skeleton as well before passing it onto the date formatter.
+        // See the Date case above for how styles are resolved.
         const style =
-          nodeStyle in formatConfig.number
-            ? formatConfig.number[nodeStyle]
-            : nodeStyle != null
-              ? parseNumberSkeleton(parseNumberSkeletonFromString(nodeStyle))
-              : undefined;
+          nodeStyle == null
+            ? undefined
+            : typeof nodeStyle === 'object'
+              ? nodeStyle.parsedOptions
+              : nodeStyle in formatConfig.number
+                ? formatConfig.number[nodeStyle]
+                : parseNumberSkeleton(parseNumberSkeletonFromString(nodeStyle));
         const scaledValue =
           // @ts-expect-error This is a weird cast that's not accurate, but works in the short term.
           typeof value !== 'number' ? (value as number) : (value as number) * (style?.scale ?? 1);
@@ -5,6 +5,9 @@ import {
   compressFormatJsToAst,
   isCompressedAst,
   AstNodeIndices,
+  DateTimeStyle,
+  NumberStyle,
+  SkeletonType,
   TagNode,
 } from '@discord/intl-ast';
 
@@ -30,6 +33,15 @@ export class InternalIntlMessage {
   }
 }
 
+/**
+ * Return the source text of a style, rebuilding skeletons that were parsed at compile time.
+ */
+function serializeStyle(style: NumberStyle | DateTimeStyle): string {
+  if (typeof style === 'string') return style;
+  if (style.type === SkeletonType.DateTime) return '::' + style.pattern;
+  return '::' + style.tokens.map((token) => [token.stem, ...token.options].join('/')).join(' ');
+}
+
 // Accepting an object as the `result` parameter lets the same string get passed around and
 // appended, rather than creating a bunch of intermediate strings.
 function serializeAst(ast: AstNode[], result: { value: string }) {
@@ -46,21 +58,21 @@ function serializeAst(ast: AstNode[], result: { value: string }) {
       case FormatJsNodeType.Date:
         result.value += '{' + node[AstNodeIndices.Value] + ', date';
         if (node[AstNodeIndices.Style] != null) {
-          result.value += ', ' + node[AstNodeIndices.Style];
+          result.value += ', ' + serializeStyle(node[AstNodeIndices.Style]);
         }
         result.value += '}';
         break;
       case FormatJsNodeType.Time:
         result.value += '{' + node[AstNodeIndices.Value] + ', time';
         if (node[AstNodeIndices.Style] != null) {
-          result.value += ', ' + node[AstNodeIndices.Style];
+          result.value += ', ' + serializeStyle(node[AstNodeIndices.Style]);
         }
         result.value += '}';
         break;
       case FormatJsNodeType.Number:
         result.value += '{' + node[AstNodeIndices.Value] + ', number';
         if (node[AstNodeIndices.Style] != null) {
-          result.value += ', ' + node[AstNodeIndices.Style];
+          result.value += ', ' + serializeStyle(node[AstNodeIndices.Style]);
         }
         result.value += '}';
         break;
@@ -39,7 +39,7 @@ function transformToString({
       locale: 'en-US',
     });
     const compiledSourcePath = filename.replace(
-      /\.messages\.js$/,
+      /\.messages\.[jt]sx?$/,
       `.compiled.messages.${getTranslationAssetExtension()}`,
     );
 